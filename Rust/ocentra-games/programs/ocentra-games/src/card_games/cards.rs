@@ -0,0 +1,58 @@
+use crate::card_games::suit_declarations::SuitDeclarations;
+use crate::error::GameError;
+use anchor_lang::prelude::*;
+
+/// Compact card encoding shared by card games
+/// Payloads carry cards as (suit, value) pairs: suit 0-3, value 2-14 (ace high)
+/// On-chain storage packs a card into one byte: suit * 13 + (value - 2) = 0-51
+pub struct Cards;
+
+impl Cards {
+    pub const LOWEST_VALUE: u8 = 2;
+    pub const ACE: u8 = 14;
+    pub const DECK_SIZE: u8 = 52;
+
+    /// Validate a (suit, value) pair and pack it into a single byte
+    pub fn encode(suit: u8, value: u8) -> Result<u8> {
+        SuitDeclarations::validate_suit(suit)?;
+        require!(
+            (Self::LOWEST_VALUE..=Self::ACE).contains(&value),
+            GameError::InvalidPayload
+        );
+        Ok(suit * 13 + (value - Self::LOWEST_VALUE))
+    }
+
+    /// Parse `N` (suit, value) pairs from the start of a payload
+    pub fn parse_pairs<const N: usize>(payload: &[u8]) -> Result<[u8; N]> {
+        require!(payload.len() >= N * 2, GameError::InvalidPayload);
+        let mut cards = [0u8; N];
+        for (i, card) in cards.iter_mut().enumerate() {
+            *card = Self::encode(payload[i * 2], payload[i * 2 + 1])?;
+        }
+        Ok(cards)
+    }
+
+    /// Suit (0-3) of a packed card
+    pub fn suit(card: u8) -> u8 {
+        card / 13
+    }
+
+    /// Value (2-14) of a packed card
+    pub fn value(card: u8) -> u8 {
+        card % 13 + Self::LOWEST_VALUE
+    }
+
+    /// Validate no packed card appears twice across the given sets
+    pub fn validate_distinct(sets: &[&[u8]]) -> Result<()> {
+        let mut seen: u64 = 0;
+        for set in sets {
+            for &card in set.iter() {
+                require!(card < Self::DECK_SIZE, GameError::InvalidPayload);
+                let bit = 1u64 << card;
+                require!(seen & bit == 0, GameError::InvalidPayload); // Duplicate card
+                seen |= bit;
+            }
+        }
+        Ok(())
+    }
+}
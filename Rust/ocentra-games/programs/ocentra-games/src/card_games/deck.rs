@@ -0,0 +1,86 @@
+use crate::card_games::cards::Cards;
+use solana_sha256_hasher::{hash, hashv};
+
+/// Dealer deck shared by card games that deal on-chain.
+/// The dealer (match authority, never a seat) picks a secret key and commits the end of a hash
+/// chain over it before play. Each later deal reveals the next key down the chain, and cards are
+/// drawn from that key out of the cards not dealt yet - so nobody chooses cards, the deal can be
+/// checked as soon as a key is revealed, and no card is ever dealt twice.
+///
+/// Trust: the dealer holds the whole chain, so it knows every card - board and hole cards -
+/// before anything is dealt. Seats cannot steer the deal, but the dealer must not play or share
+/// what it knows with a seat. A dealer that stops revealing keys is cut off by
+/// DEALER_KEY_TIMEOUT_SECONDS (cancel_match then refunds every seat in full).
+pub struct Deck;
+
+/// How long play may wait on the dealer's next key before anyone can cancel the match
+pub const DEALER_KEY_TIMEOUT_SECONDS: i64 = 10 * 60;
+
+impl Deck {
+    /// A revealed key must hash to the key (or commitment) revealed before it
+    pub fn verify_key(key: &[u8; 32], previous: &[u8; 32]) -> bool {
+        hash(key).to_bytes() == *previous
+    }
+
+    /// Draw `out.len()` cards keyed by `key` from the cards not in `dealt`
+    /// (callers never draw more cards than the deck has left)
+    pub fn draw(key: &[u8; 32], dealt: &[u8], out: &mut [u8]) {
+        let mut remaining: u64 = (1u64 << Cards::DECK_SIZE) - 1;
+        for &card in dealt {
+            remaining &= !(1u64 << card);
+        }
+
+        for (i, slot) in out.iter_mut().enumerate() {
+            let digest = hashv(&[key, &[i as u8]]).to_bytes();
+            let mut word = [0u8; 8];
+            word.copy_from_slice(&digest[..8]);
+            let mut pick = (u64::from_le_bytes(word) % remaining.count_ones() as u64) as u32;
+
+            // pick-th card still in the deck
+            let mut cards = remaining;
+            while pick > 0 {
+                cards &= cards - 1;
+                pick -= 1;
+            }
+            let card = cards.trailing_zeros() as u8;
+            remaining &= !(1u64 << card);
+            *slot = card;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draws_are_distinct_and_skip_dealt_cards() {
+        let mut board = [0u8; 5];
+        Deck::draw(&[7u8; 32], &[], &mut board);
+        let mut hole = [0u8; 20];
+        Deck::draw(&[8u8; 32], &board, &mut hole);
+
+        assert!(Cards::validate_distinct(&[&board, &hole]).is_ok());
+    }
+
+    #[test]
+    fn the_key_alone_decides_the_cards() {
+        let mut first = [0u8; 3];
+        let mut again = [0u8; 3];
+        let mut other = [0u8; 3];
+        Deck::draw(&[1u8; 32], &[], &mut first);
+        Deck::draw(&[1u8; 32], &[], &mut again);
+        Deck::draw(&[2u8; 32], &[], &mut other);
+
+        assert_eq!(first, again);
+        assert_ne!(first, other);
+    }
+
+    #[test]
+    fn keys_are_checked_down_the_hash_chain() {
+        let key = [3u8; 32];
+        let commitment = hash(&key).to_bytes();
+        assert!(Deck::verify_key(&key, &commitment));
+        assert!(!Deck::verify_key(&[4u8; 32], &commitment));
+    }
+}
@@ -1,5 +1,7 @@
 // Shared card game logic - applies to card games (CLAIM, Poker, etc.)
 
+pub mod cards;
+pub mod deck;
 pub mod floor_card;
 pub mod hand_management;
 pub mod suit_declarations;
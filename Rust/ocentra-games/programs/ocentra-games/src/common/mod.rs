@@ -1,4 +1,8 @@
 // Common game logic - applies to ALL games (not game-specific)
 
+pub mod prize_structure;
 pub mod replay_protection;
+pub mod skill_rating;
+pub mod token_escrow;
+pub mod tournament_bracket;
 pub mod validation_base;
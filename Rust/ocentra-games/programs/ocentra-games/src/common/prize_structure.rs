@@ -0,0 +1,148 @@
+use crate::error::GameError;
+use crate::state::enums::prize_structure;
+use crate::state::Match;
+use anchor_lang::prelude::*;
+
+/// Prize structure logic - validates the structure declared at create_match and computes
+/// payouts from the recorded match result at distribution time
+pub struct PrizeStructure;
+
+impl PrizeStructure {
+    /// Validate a prize structure and its rank shares before storing them on a match
+    pub fn validate(structure: u8, split_bps: &[u16], max_players: u8) -> Result<()> {
+        match structure {
+            prize_structure::TOP_N_SPLIT => {
+                require!(
+                    !split_bps.is_empty() && split_bps.len() <= max_players.min(10) as usize,
+                    GameError::InvalidPrizeStructure
+                );
+                // Every paid rank gets something, and a lower rank never out-earns a higher one
+                require!(
+                    split_bps.iter().all(|&bps| bps > 0)
+                        && split_bps.windows(2).all(|pair| pair[0] >= pair[1]),
+                    GameError::InvalidPrizeStructure
+                );
+                let total_bps: u32 = split_bps.iter().map(|&bps| bps as u32).sum();
+                require!(total_bps == 10_000, GameError::InvalidPrizeStructure);
+            }
+            prize_structure::WINNER_TAKES_ALL | prize_structure::PROPORTIONAL => {
+                require!(split_bps.is_empty(), GameError::InvalidPrizeStructure);
+            }
+            _ => return Err(GameError::InvalidPrizeStructure.into()),
+        }
+        Ok(())
+    }
+
+    /// Compute each seat's share of `prize_pool` from Match.scores under the match's prize
+    /// structure. Forfeited seats are not paid. Tied seats split the shares of the ranks they
+    /// occupy equally; the shares of ranks no seat reached (and proportional rounding) go to
+    /// the top-scoring seats, so the payouts always sum to `prize_pool`. Indivisible dust is
+    /// handed out one unit at a time from a seed-drawn offset, never by seat index.
+    pub fn compute_payouts(match_account: &Match, prize_pool: u64) -> Result<[u64; 10]> {
+        require!(
+            match_account.scores_recorded(),
+            GameError::ScoresNotRecorded
+        );
+
+        // Eligible seats ranked by score, highest first (equal scores are ties - the seat
+        // index only fixes the order within a tie, which never decides who is paid more)
+        let scores = match_account.scores;
+        let player_count = (match_account.player_count as usize).min(10);
+        let mut ranked: Vec<usize> = (0..player_count)
+            .filter(|&seat| !match_account.is_forfeited(seat))
+            .collect();
+        require!(!ranked.is_empty(), GameError::InvalidPayload);
+        ranked.sort_by(|&a, &b| scores[b].cmp(&scores[a]).then(a.cmp(&b)));
+        let seed = match_account.seed;
+
+        let mut payouts = [0u64; 10];
+        match match_account.prize_structure {
+            prize_structure::WINNER_TAKES_ALL | prize_structure::TOP_N_SPLIT => {
+                let mut rank_bps = [0u16; 10];
+                if match_account.prize_structure == prize_structure::WINNER_TAKES_ALL {
+                    rank_bps[0] = 10_000;
+                } else {
+                    let split_bps = match_account.get_prize_split_bps();
+                    rank_bps[..split_bps.len()].copy_from_slice(split_bps);
+                }
+
+                let mut rank = 0;
+                while rank < ranked.len() {
+                    let tied = Self::tied_from(&ranked, &scores, rank);
+                    let group_bps: u64 = rank_bps[rank..rank + tied]
+                        .iter()
+                        .map(|&bps| bps as u64)
+                        .sum();
+                    let group_amount = Self::share(prize_pool, group_bps, 10_000)?;
+                    Self::split_evenly(
+                        &mut payouts,
+                        &ranked[rank..rank + tied],
+                        group_amount,
+                        seed,
+                    )?;
+                    rank += tied;
+                }
+            }
+            prize_structure::PROPORTIONAL => {
+                let total_weight: u64 = ranked.iter().map(|&seat| scores[seat].max(0) as u64).sum();
+                if total_weight == 0 {
+                    // Nobody scored - split evenly between eligible seats
+                    Self::split_evenly(&mut payouts, &ranked, prize_pool, seed)?;
+                } else {
+                    for &seat in &ranked {
+                        payouts[seat] =
+                            Self::share(prize_pool, scores[seat].max(0) as u64, total_weight)?;
+                    }
+                }
+            }
+            _ => return Err(GameError::InvalidPrizeStructure.into()),
+        }
+
+        // Unreached rank shares and rounding go to the top-scoring seats
+        let paid: u64 = payouts.iter().sum();
+        let leftover = prize_pool.checked_sub(paid).ok_or(GameError::Overflow)?;
+        let top = Self::tied_from(&ranked, &scores, 0);
+        Self::split_evenly(&mut payouts, &ranked[..top], leftover, seed)?;
+        Ok(payouts)
+    }
+
+    /// Number of seats in `ranked` from `rank` on that share its score
+    fn tied_from(ranked: &[usize], scores: &[i32; 10], rank: usize) -> usize {
+        let score = scores[ranked[rank]];
+        ranked[rank..]
+            .iter()
+            .take_while(|&&seat| scores[seat] == score)
+            .count()
+    }
+
+    /// Add an even split of `amount` between `seats` to their payouts. The indivisible
+    /// remainder goes one unit each to the seats in rotation from an offset drawn from the
+    /// match seed, so no seat index is favoured.
+    fn split_evenly(
+        payouts: &mut [u64; 10],
+        seats: &[usize],
+        amount: u64,
+        seed: u32,
+    ) -> Result<()> {
+        let count = seats.len();
+        let each = amount / count as u64;
+        let dust = (amount % count as u64) as usize;
+        let offset = seed as usize % count;
+        for (position, &seat) in seats.iter().enumerate() {
+            let extra = u64::from((position + count - offset) % count < dust);
+            payouts[seat] = payouts[seat]
+                .checked_add(each + extra)
+                .ok_or(GameError::Overflow)?;
+        }
+        Ok(())
+    }
+
+    /// amount * numerator / denominator without intermediate overflow
+    fn share(amount: u64, numerator: u64, denominator: u64) -> Result<u64> {
+        let value = (amount as u128)
+            .checked_mul(numerator as u128)
+            .and_then(|x| x.checked_div(denominator as u128))
+            .ok_or(GameError::Overflow)?;
+        u64::try_from(value).map_err(|_| GameError::Overflow.into())
+    }
+}
@@ -0,0 +1,104 @@
+use crate::error::GameError;
+use anchor_lang::prelude::*;
+
+/// Expected score (basis points) of the higher-rated player for rating differences of
+/// 0, 25, 50, ... 800 points: 10,000 / (1 + 10^(-diff / 400)). Linearly interpolated between
+/// steps; differences beyond 800 points use the last entry.
+const EXPECTED_SCORE_BPS: [i64; 33] = [
+    5000, 5359, 5715, 6063, 6401, 6725, 7034, 7325, 7597, 7850, 8083, 8296, 8490, 8666, 8823, 8965,
+    9091, 9203, 9302, 9390, 9468, 9536, 9595, 9648, 9693, 9733, 9768, 9799, 9825, 9848, 9868, 9886,
+    9901,
+];
+const EXPECTED_SCORE_STEP: i64 = 25;
+const MAX_RATING_DIFF: i64 = 800;
+
+/// Ratings never drop below this floor
+pub const RATING_FLOOR: u32 = 100;
+
+/// Matches a player needs before leaving the provisional (fast-moving) K-factor
+const PROVISIONAL_GAMES: u32 = 30;
+
+/// Rating from which established players move at the lowest K-factor
+const MASTER_RATING: u32 = 2400;
+
+/// Skill rating logic - pairwise Elo in fixed-point integer math.
+/// A match with N seats is scored as N - 1 heads-up results per seat (higher standing wins,
+/// equal standings draw); each seat's change is the average of its pairwise Elo changes, so a
+/// two-player match is plain Elo. All changes are computed from the pre-match ratings.
+pub struct SkillRating;
+
+impl SkillRating {
+    /// K-factor: 40 while provisional, 20 once established, 10 at master level
+    pub fn k_factor(rating: u32, games_rated: u32) -> i64 {
+        if games_rated < PROVISIONAL_GAMES {
+            40
+        } else if rating < MASTER_RATING {
+            20
+        } else {
+            10
+        }
+    }
+
+    /// Expected score of `rating` against `opponent` in basis points (0-10,000)
+    pub fn expected_score_bps(rating: u32, opponent: u32) -> i64 {
+        let diff = rating as i64 - opponent as i64;
+        let distance = diff.abs().min(MAX_RATING_DIFF);
+        let step = (distance / EXPECTED_SCORE_STEP) as usize;
+        let remainder = distance % EXPECTED_SCORE_STEP;
+        let mut expected = EXPECTED_SCORE_BPS[step];
+        if let Some(&next) = EXPECTED_SCORE_BPS.get(step + 1) {
+            expected += (next - expected) * remainder / EXPECTED_SCORE_STEP;
+        }
+        if diff < 0 {
+            10_000 - expected
+        } else {
+            expected
+        }
+    }
+
+    /// New ratings for one match. `players` holds each seat's (rating, games_rated) and
+    /// `standings` each seat's result, higher is better (equal standings are draws).
+    pub fn rate_match(players: &[(u32, u32)], standings: &[i64]) -> Result<Vec<u32>> {
+        let count = players.len();
+        require!(
+            count >= 2 && standings.len() == count,
+            GameError::InvalidPayload
+        );
+        let opponents = (count - 1) as i64;
+
+        let mut ratings = Vec::with_capacity(count);
+        for (seat, &(rating, games_rated)) in players.iter().enumerate() {
+            // Sum of (actual - expected) over every opponent, in basis points
+            let surplus_bps: i64 = players
+                .iter()
+                .enumerate()
+                .filter(|&(other, _)| other != seat)
+                .map(|(other, &(opponent, _))| {
+                    let actual = match standings[seat].cmp(&standings[other]) {
+                        std::cmp::Ordering::Greater => 10_000,
+                        std::cmp::Ordering::Equal => 5_000,
+                        std::cmp::Ordering::Less => 0,
+                    };
+                    actual - Self::expected_score_bps(rating, opponent)
+                })
+                .sum();
+            let change = Self::round_div(
+                Self::k_factor(rating, games_rated) * surplus_bps,
+                10_000 * opponents,
+            );
+            let new_rating = (rating as i64 + change).max(RATING_FLOOR as i64);
+            ratings.push(u32::try_from(new_rating).map_err(|_| GameError::Overflow)?);
+        }
+        Ok(ratings)
+    }
+
+    /// Integer division rounding half away from zero (gains and losses round alike)
+    fn round_div(numerator: i64, denominator: i64) -> i64 {
+        let half = denominator / 2;
+        if numerator >= 0 {
+            (numerator + half) / denominator
+        } else {
+            (numerator - half) / denominator
+        }
+    }
+}
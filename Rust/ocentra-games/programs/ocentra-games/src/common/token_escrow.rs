@@ -0,0 +1,201 @@
+use crate::error::GameError;
+use crate::state::enums::payment_method;
+use crate::state::UserDepositAccount;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, TokenAccount, Transfer};
+
+/// SPL token escrow logic - token-denominated matches hold stakes in an escrow vault token
+/// account owned by the EscrowAccount PDA, and platform balances in a deposit vault token
+/// account owned by the player's token UserDepositAccount ledger
+pub struct TokenEscrow;
+
+impl TokenEscrow {
+    /// Escrow vault of a match: [b"escrow_vault", match_pda]
+    pub fn escrow_vault_address(match_pda: &Pubkey, program_id: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"escrow_vault", match_pda.as_ref()], program_id).0
+    }
+
+    /// Token deposit ledger of a player: [b"user_deposit", authority, mint]
+    pub fn deposit_ledger_address(
+        authority: &Pubkey,
+        mint: &Pubkey,
+        program_id: &Pubkey,
+    ) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"user_deposit", authority.as_ref(), mint.as_ref()],
+            program_id,
+        )
+        .0
+    }
+
+    /// Deposit vault of a token ledger: [b"deposit_vault", ledger]
+    pub fn deposit_vault_address(ledger: &Pubkey, program_id: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"deposit_vault", ledger.as_ref()], program_id).0
+    }
+
+    /// Payout accounts per seat in remaining_accounts for token escrows:
+    /// WALLET = a token account owned by the seat's wallet, PLATFORM = [token ledger, deposit vault]
+    pub fn accounts_per_seat(method: u8) -> usize {
+        if method == payment_method::PLATFORM {
+            2
+        } else {
+            1
+        }
+    }
+
+    /// Validate a token account holds `mint` and is owned by `owner`
+    pub fn validate_token_account(info: &AccountInfo, mint: &Pubkey, owner: &Pubkey) -> Result<()> {
+        require!(info.owner == &token::ID, GameError::InvalidTokenAccount);
+        let token_account = TokenAccount::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        require!(
+            token_account.mint == *mint && token_account.owner == *owner,
+            GameError::InvalidTokenAccount
+        );
+        Ok(())
+    }
+
+    /// Transfer tokens out of a token UserDepositAccount's deposit vault, signed by the ledger PDA
+    pub fn transfer_from_deposit<'info>(
+        token_program: &AccountInfo<'info>,
+        deposit_vault: &AccountInfo<'info>,
+        to: &AccountInfo<'info>,
+        ledger: &AccountInfo<'info>,
+        ledger_seeds: (&Pubkey, &Pubkey, u8),
+        amount: u64,
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        let (authority, mint, bump) = ledger_seeds;
+        let seeds: &[&[u8]] = &[b"user_deposit", authority.as_ref(), mint.as_ref(), &[bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                Transfer {
+                    from: deposit_vault.clone(),
+                    to: to.clone(),
+                    authority: ledger.clone(),
+                },
+                &[seeds],
+            ),
+            amount,
+        )
+    }
+}
+
+/// Escrow vault of one token-denominated match, with what is needed to sign for it
+pub struct EscrowVault<'a, 'info> {
+    pub token_program: &'a AccountInfo<'info>,
+    pub vault: &'a AccountInfo<'info>,
+    pub escrow: &'a AccountInfo<'info>,
+    pub match_pda: Pubkey,
+    pub escrow_bump: u8,
+    pub mint: Pubkey,
+}
+
+impl<'a, 'info> EscrowVault<'a, 'info> {
+    /// Validate the vault is the match's escrow vault for the escrow's mint
+    pub fn validate(&self, program_id: &Pubkey) -> Result<()> {
+        require!(
+            self.vault.key() == TokenEscrow::escrow_vault_address(&self.match_pda, program_id),
+            GameError::InvalidTokenAccount
+        );
+        TokenEscrow::validate_token_account(self.vault, &self.mint, &self.escrow.key())
+    }
+
+    /// Transfer tokens out of the vault, signed by the EscrowAccount PDA
+    pub fn transfer_out(&self, to: &AccountInfo<'info>, amount: u64) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        let seeds: &[&[u8]] = &[b"escrow", self.match_pda.as_ref(), &[self.escrow_bump]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.clone(),
+                Transfer {
+                    from: self.vault.clone(),
+                    to: to.clone(),
+                    authority: self.escrow.clone(),
+                },
+                &[seeds],
+            ),
+            amount,
+        )
+    }
+
+    /// Close the (emptied) vault, returning its rent to `destination`
+    pub fn close(&self, destination: &AccountInfo<'info>) -> Result<()> {
+        let seeds: &[&[u8]] = &[b"escrow", self.match_pda.as_ref(), &[self.escrow_bump]];
+        token::close_account(CpiContext::new_with_signer(
+            self.token_program.clone(),
+            CloseAccount {
+                account: self.vault.clone(),
+                destination: destination.clone(),
+                authority: self.escrow.clone(),
+            },
+            &[seeds],
+        ))
+    }
+
+    /// Pay `payout` to a WALLET seat's own token account
+    pub fn pay_wallet(
+        &self,
+        token_account: &AccountInfo<'info>,
+        wallet: &Pubkey,
+        payout: u64,
+    ) -> Result<()> {
+        TokenEscrow::validate_token_account(token_account, &self.mint, wallet)?;
+        self.transfer_out(token_account, payout)
+    }
+
+    /// Pay `payout` into a PLATFORM seat's deposit vault and settle its token ledger: the whole
+    /// stake leaves in_play and the payout is credited to available
+    pub fn pay_deposit(
+        &self,
+        ledger: &AccountLoader<'info, UserDepositAccount>,
+        deposit_vault: &AccountInfo<'info>,
+        wallet: &Pubkey,
+        amounts: (u64, u64),
+        program_id: &Pubkey,
+    ) -> Result<()> {
+        let (stake, payout) = amounts;
+        require!(
+            ledger.key() == TokenEscrow::deposit_ledger_address(wallet, &self.mint, program_id),
+            GameError::InvalidPayload
+        );
+        require!(
+            deposit_vault.key() == TokenEscrow::deposit_vault_address(&ledger.key(), program_id),
+            GameError::InvalidTokenAccount
+        );
+        self.transfer_out(deposit_vault, payout)?;
+
+        let mut deposit_account = ledger.load_mut()?;
+        deposit_account.available_lamports = deposit_account
+            .available_lamports
+            .checked_add(payout)
+            .ok_or(GameError::Overflow)?;
+        deposit_account.in_play_lamports = deposit_account
+            .in_play_lamports
+            .checked_sub(stake)
+            .ok_or(GameError::EscrowLedgerMismatch)?;
+        Ok(())
+    }
+
+    /// Pay `payout` to a seat from its remaining_accounts (see TokenEscrow::accounts_per_seat)
+    /// and settle its stake
+    pub fn settle_seat(
+        &self,
+        method: u8,
+        wallet: &Pubkey,
+        seat_accounts: &'info [AccountInfo<'info>],
+        amounts: (u64, u64),
+        program_id: &Pubkey,
+    ) -> Result<()> {
+        if method == payment_method::PLATFORM {
+            let ledger = AccountLoader::<UserDepositAccount>::try_from(&seat_accounts[0])?;
+            self.pay_deposit(&ledger, &seat_accounts[1], wallet, amounts, program_id)
+        } else {
+            self.pay_wallet(&seat_accounts[0], wallet, amounts.1)
+        }
+    }
+}
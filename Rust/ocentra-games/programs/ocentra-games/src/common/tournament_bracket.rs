@@ -0,0 +1,228 @@
+use crate::error::GameError;
+use crate::state::enums::{tournament_format, tournament_phase};
+use crate::state::{
+    Tournament, MAX_TOURNAMENT_ENTRANTS, MAX_TOURNAMENT_PAIRINGS, MAX_TOURNAMENT_PAYOUTS,
+    PAIRING_DRAW, PAIRING_PENDING, TOURNAMENT_BYE,
+};
+use anchor_lang::prelude::*;
+
+/// Points awarded per result (byes count as wins)
+const WIN_POINTS: u16 = 2;
+const DRAW_POINTS: u16 = 1;
+
+/// Tournament bracket logic - seeds rounds for each format, records pairing results and
+/// advances the bracket until the final standings are known
+pub struct TournamentBracket;
+
+impl TournamentBracket {
+    /// Rounds needed to finish a tournament of `entrant_count` entrants
+    pub fn total_rounds(format: u8, entrant_count: u8) -> Result<u8> {
+        require!(entrant_count >= 2, GameError::InvalidPayload);
+        let rounds = match format {
+            // log2 of the bracket size (entrants rounded up to a power of two)
+            tournament_format::SINGLE_ELIMINATION | tournament_format::SWISS => {
+                (entrant_count as u32).next_power_of_two().trailing_zeros() as u8
+            }
+            // Every entrant meets every other once (odd fields add a bye slot)
+            tournament_format::ROUND_ROBIN => Self::even_field(entrant_count) - 1,
+            _ => return Err(GameError::InvalidPayload.into()),
+        };
+        Ok(rounds)
+    }
+
+    /// Close registration and pair the first round
+    pub fn start(tournament: &mut Tournament) -> Result<()> {
+        tournament.total_rounds = Self::total_rounds(tournament.format, tournament.entrant_count)?;
+        tournament.current_round = 0;
+        tournament.phase = tournament_phase::IN_PROGRESS;
+        Self::pair_round(tournament)?;
+        Self::advance(tournament)
+    }
+
+    /// Record the result of a pairing in the current round (`result` = winning entrant index
+    /// or PAIRING_DRAW) and advance the bracket if that completed the round
+    pub fn report(tournament: &mut Tournament, pairing: usize, result: u8) -> Result<()> {
+        require!(
+            pairing < tournament.pairing_count as usize,
+            GameError::NotInTournamentBracket
+        );
+        require!(
+            tournament.pairing_results[pairing] == PAIRING_PENDING,
+            GameError::PairingAlreadyReported
+        );
+        let [a, b] = tournament.pairings[pairing];
+        if result == PAIRING_DRAW {
+            require!(
+                tournament.format != tournament_format::SINGLE_ELIMINATION,
+                GameError::InvalidPayload
+            );
+            Self::award(tournament, a, DRAW_POINTS)?;
+            Self::award(tournament, b, DRAW_POINTS)?;
+        } else {
+            require!(result == a || result == b, GameError::InvalidPayload);
+            Self::award(tournament, result, WIN_POINTS)?;
+        }
+        tournament.pairing_results[pairing] = result;
+        Self::advance(tournament)
+    }
+
+    /// Share of the prize pool for each place in Tournament.placements. Places beyond the
+    /// field size and rounding dust go to first place, so payouts sum to `prize_pool`.
+    pub fn compute_payouts(
+        tournament: &Tournament,
+        prize_pool: u64,
+    ) -> Result<[u64; MAX_TOURNAMENT_PAYOUTS]> {
+        let mut payouts = [0u64; MAX_TOURNAMENT_PAYOUTS];
+        let places = tournament
+            .get_payout_bps()
+            .len()
+            .min(tournament.entrant_count as usize);
+        for (place, payout) in payouts.iter_mut().enumerate().take(places) {
+            let share = (prize_pool as u128)
+                .checked_mul(tournament.payout_bps[place] as u128)
+                .and_then(|x| x.checked_div(10_000))
+                .ok_or(GameError::Overflow)?;
+            *payout = u64::try_from(share).map_err(|_| GameError::Overflow)?;
+        }
+        let paid: u64 = payouts.iter().sum();
+        payouts[0] = payouts[0]
+            .checked_add(prize_pool.checked_sub(paid).ok_or(GameError::Overflow)?)
+            .ok_or(GameError::Overflow)?;
+        Ok(payouts)
+    }
+
+    /// Move to the next round (or finish) while the current round is complete
+    fn advance(tournament: &mut Tournament) -> Result<()> {
+        while tournament.phase == tournament_phase::IN_PROGRESS && tournament.is_round_complete() {
+            if tournament.current_round + 1 >= tournament.total_rounds {
+                Self::finish(tournament);
+            } else {
+                tournament.current_round += 1;
+                Self::pair_round(tournament)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Seed the pairings of the current round and resolve byes
+    fn pair_round(tournament: &mut Tournament) -> Result<()> {
+        let entrant_count = tournament.entrant_count;
+        let round = tournament.current_round;
+        let mut pairings = [[TOURNAMENT_BYE; 2]; MAX_TOURNAMENT_PAIRINGS];
+        let pairing_count;
+
+        match tournament.format {
+            tournament_format::SINGLE_ELIMINATION => {
+                if round == 0 {
+                    // Seed i meets seed (bracket size - 1 - i); missing seeds are byes
+                    let bracket = (entrant_count as usize).next_power_of_two();
+                    pairing_count = bracket / 2;
+                    for (i, pairing) in pairings.iter_mut().enumerate().take(pairing_count) {
+                        let opponent = bracket - 1 - i;
+                        *pairing = [
+                            i as u8,
+                            if opponent < entrant_count as usize {
+                                opponent as u8
+                            } else {
+                                TOURNAMENT_BYE
+                            },
+                        ];
+                    }
+                } else {
+                    // Winners of pairings 2k and 2k+1 meet in pairing k
+                    let previous = tournament.pairing_results;
+                    pairing_count = tournament.pairing_count as usize / 2;
+                    for (k, pairing) in pairings.iter_mut().enumerate().take(pairing_count) {
+                        *pairing = [previous[2 * k], previous[2 * k + 1]];
+                    }
+                }
+            }
+            tournament_format::SWISS => {
+                // Entrants ranked by points (seed breaks ties) play their neighbour
+                let ranked = Self::ranked_entrants(tournament);
+                pairing_count = (entrant_count as usize).div_ceil(2);
+                for (k, pairing) in pairings.iter_mut().enumerate().take(pairing_count) {
+                    *pairing = [
+                        ranked[2 * k],
+                        ranked.get(2 * k + 1).copied().unwrap_or(TOURNAMENT_BYE),
+                    ];
+                }
+            }
+            tournament_format::ROUND_ROBIN => {
+                // Circle method: slot 0 stays fixed, the others rotate one place per round
+                let field = Self::even_field(entrant_count) as usize;
+                let rotation = field - 1;
+                let slot = |position: usize| -> u8 {
+                    let index = if position == 0 {
+                        0
+                    } else {
+                        (position - 1 + round as usize) % rotation + 1
+                    };
+                    if index < entrant_count as usize {
+                        index as u8
+                    } else {
+                        TOURNAMENT_BYE
+                    }
+                };
+                pairing_count = field / 2;
+                for (i, pairing) in pairings.iter_mut().enumerate().take(pairing_count) {
+                    *pairing = [slot(i), slot(field - 1 - i)];
+                }
+            }
+            _ => return Err(GameError::InvalidPayload.into()),
+        }
+
+        tournament.pairings = pairings;
+        tournament.pairing_count = pairing_count as u8;
+        tournament.pairing_matches = [Pubkey::default(); MAX_TOURNAMENT_PAIRINGS];
+        tournament.pairing_results = [PAIRING_PENDING; MAX_TOURNAMENT_PAIRINGS];
+
+        // An entrant without an opponent advances / scores as a win
+        for (pairing, entrants) in pairings.iter().enumerate().take(pairing_count) {
+            match *entrants {
+                [TOURNAMENT_BYE, TOURNAMENT_BYE] => {
+                    tournament.pairing_results[pairing] = PAIRING_DRAW;
+                }
+                [entrant, TOURNAMENT_BYE] | [TOURNAMENT_BYE, entrant] => {
+                    Self::award(tournament, entrant, WIN_POINTS)?;
+                    tournament.pairing_results[pairing] = entrant;
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Final standings: points, then seed. In single elimination every round survived is a
+    /// win, so this ranks the champion first and the finalist second.
+    fn finish(tournament: &mut Tournament) {
+        let ranked = Self::ranked_entrants(tournament);
+        tournament.placements = [TOURNAMENT_BYE; MAX_TOURNAMENT_ENTRANTS];
+        tournament.placements[..ranked.len()].copy_from_slice(&ranked);
+        tournament.phase = tournament_phase::COMPLETED;
+    }
+
+    fn ranked_entrants(tournament: &Tournament) -> Vec<u8> {
+        let mut ranked: Vec<u8> = (0..tournament.entrant_count).collect();
+        ranked.sort_by(|&a, &b| {
+            tournament.points[b as usize]
+                .cmp(&tournament.points[a as usize])
+                .then(a.cmp(&b))
+        });
+        ranked
+    }
+
+    fn award(tournament: &mut Tournament, entrant: u8, points: u16) -> Result<()> {
+        require!(
+            entrant < tournament.entrant_count,
+            GameError::InvalidPayload
+        );
+        let total = &mut tournament.points[entrant as usize];
+        *total = total.checked_add(points).ok_or(GameError::Overflow)?;
+        Ok(())
+    }
+
+    fn even_field(entrant_count: u8) -> u8 {
+        entrant_count + entrant_count % 2
+    }
+}
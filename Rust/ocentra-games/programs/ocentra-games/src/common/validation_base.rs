@@ -39,6 +39,19 @@ impl CommonValidation {
         Ok(())
     }
 
+    /// Validate signer owns the seat (wallet bound at join_match)
+    pub fn validate_seat_signer(
+        match_account: &Match,
+        player_index: usize,
+        signer: &Pubkey,
+    ) -> Result<()> {
+        require!(
+            match_account.get_player_wallet(player_index) == Some(*signer),
+            GameError::SeatSignerMismatch
+        );
+        Ok(())
+    }
+
     /// Validate action type is within bounds
     pub fn validate_action_type(action_type: u8, max_action: u8) -> Result<()> {
         require!(action_type <= max_action, GameError::InvalidAction);
@@ -140,4 +140,225 @@ pub enum GameError {
 
     #[msg("Invalid payment method - payment method mismatch")]
     InvalidPaymentMethod,
+
+    // Seat ownership errors
+    #[msg("Signer does not own this player seat")]
+    SeatSignerMismatch,
+
+    #[msg("Wallet already owns a seat in this match")]
+    WalletAlreadySeated,
+
+    // Poker errors
+    #[msg("Player has not committed a hand hash")]
+    HandNotCommitted,
+
+    #[msg("Revealed hand does not match committed hash")]
+    HandRevealMismatch,
+
+    #[msg("Invalid bet amount")]
+    InvalidBetAmount,
+
+    // Word search errors
+    #[msg("Word claim has not been committed")]
+    ClaimNotCommitted,
+
+    #[msg("Revealed claim does not match committed hash")]
+    ClaimRevealMismatch,
+
+    #[msg("Claimed word is not on the board")]
+    WordNotOnBoard,
+
+    // Score replay errors
+    #[msg("Move account does not belong to this match")]
+    MoveAccountMismatch,
+
+    #[msg("Moves must be supplied in move_index order")]
+    MoveOutOfOrder,
+
+    #[msg("Score replay already completed")]
+    ScoreReplayCompleted,
+
+    // Turn timer errors
+    #[msg("Game has no turn timer")]
+    TurnTimerDisabled,
+
+    #[msg("Turn deadline has not passed")]
+    TurnNotExpired,
+
+    #[msg("Player has forfeited the match")]
+    PlayerForfeited,
+
+    // Cancellation errors
+    #[msg("Match cannot be cancelled in its current phase")]
+    MatchNotCancellable,
+
+    // Lobby expiry errors
+    #[msg("Game lobbies do not expire")]
+    LobbyExpiryDisabled,
+
+    #[msg("Lobby has not expired yet")]
+    LobbyNotExpired,
+
+    // Settlement errors
+    #[msg("Escrow stakes do not reconcile with deposit ledgers")]
+    EscrowLedgerMismatch,
+
+    // Prize structure errors
+    #[msg("Invalid prize structure")]
+    InvalidPrizeStructure,
+
+    #[msg("Match scores have not been recorded")]
+    ScoresNotRecorded,
+
+    // Refund settlement errors
+    #[msg("Every staked seat must be settled")]
+    IncompleteSettlement,
+
+    // SPL token errors
+    #[msg("Token mint is not supported")]
+    MintNotSupported,
+
+    #[msg("Entry fee is outside the allowed range")]
+    EntryFeeOutOfRange,
+
+    #[msg("Token account does not match the expected mint or owner")]
+    InvalidTokenAccount,
+
+    // Tournament errors
+    #[msg("Tournament is not in the required phase")]
+    InvalidTournamentPhase,
+
+    #[msg("Tournament registration is not open")]
+    RegistrationNotOpen,
+
+    #[msg("Tournament is full")]
+    TournamentFull,
+
+    #[msg("Wallet is already registered for this tournament")]
+    AlreadyRegistered,
+
+    #[msg("Match or player is not part of the tournament bracket")]
+    NotInTournamentBracket,
+
+    #[msg("Tournament pairing result already reported")]
+    PairingAlreadyReported,
+
+    // Rating errors
+    #[msg("Match ratings have already been applied")]
+    RatingsAlreadyApplied,
+
+    // Leaderboard errors
+    #[msg("Match results have already been recorded")]
+    ResultsAlreadyRecorded,
+
+    #[msg("Leaderboard does not belong to the current season and game type")]
+    SeasonMismatch,
+
+    // Season errors
+    #[msg("Season has not ended yet")]
+    SeasonNotEnded,
+
+    #[msg("Season reward already claimed")]
+    SeasonRewardClaimed,
+
+    // Dispute voting errors
+    #[msg("Validator stake is below the minimum required to vote")]
+    InsufficientValidatorStake,
+
+    #[msg("Dispute voting window has closed")]
+    DisputeVotingClosed,
+
+    #[msg("Validator has already voted on this dispute")]
+    ValidatorAlreadyVoted,
+
+    #[msg("Dispute has not reached quorum and its voting window is still open")]
+    DisputeQuorumNotReached,
+
+    // Dispute index errors
+    #[msg("Too many open disputes on this match")]
+    TooManyOpenDisputes,
+
+    // Dispute settlement errors
+    #[msg("Dispute window for this match has closed")]
+    DisputeWindowClosed,
+
+    #[msg("Escrow is frozen by an open dispute")]
+    EscrowContested,
+
+    #[msg("Escrow is waiting for corrected scores from an upheld dispute")]
+    ScoreCorrectionPending,
+
+    #[msg("No upheld dispute is waiting for corrected scores")]
+    NoScoreCorrectionPending,
+
+    #[msg("Escrow was cancelled - stakes can only be refunded")]
+    EscrowCancelled,
+
+    // Validator staking errors
+    #[msg("Unstaked lamports are still unbonding")]
+    StakeStillUnbonding,
+
+    #[msg("No unstaked lamports to withdraw")]
+    NoUnbondedStake,
+
+    // Dispute committee errors
+    #[msg("Validator is not on this dispute's committee")]
+    NotDisputeCommitteeMember,
+
+    #[msg("No staked validator is eligible for the dispute committee")]
+    NoEligibleValidators,
+
+    // Score finality errors
+    #[msg("Match scores are final and can no longer be replayed")]
+    ScoresFinalized,
+
+    // Dispute committee draw errors
+    #[msg("Dispute committee has already been drawn")]
+    CommitteeAlreadyDrawn,
+
+    #[msg("No slot after the flag has a hash yet - draw the committee in a later slot")]
+    CommitteeDrawTooEarly,
+
+    #[msg("Dispute committee has not been drawn yet")]
+    DisputeCommitteeNotDrawn,
+
+    // Paid match scoring errors
+    #[msg("Game has no on-chain scoring - it can only be played in free matches")]
+    GameNotScorable,
+
+    // Turn timeout errors
+    #[msg("No seat is holding up play - there is no stalled turn to time out")]
+    NoStalledTurn,
+
+    // Result recording errors
+    #[msg("UserAccount is bound to a different wallet than the one in the seat")]
+    UserWalletMismatch,
+
+    // Tournament no-show errors
+    #[msg("Bracket match no-show deadline has not passed yet")]
+    NoShowDeadlineNotPassed,
+
+    #[msg("Tournament pairing still pending - report a no-show or reschedule it first")]
+    TournamentPairingPending,
+
+    // Dealer deck errors
+    #[msg("Dealer has not committed the deck")]
+    DeckNotCommitted,
+
+    #[msg("Dealer key does not match the committed deck")]
+    DeckKeyMismatch,
+
+    #[msg("The match authority deals the deck and cannot hold a seat")]
+    DealerSeated,
+
+    #[msg("Dealer key deadline has not passed yet")]
+    DealerKeyNotExpired,
+
+    // Dispute committee draw window errors
+    #[msg("Slot after the flag has aged out - the committee can no longer be drawn")]
+    CommitteeDrawExpired,
+
+    // Prize distribution errors
+    #[msg("Post-match dispute window is still open")]
+    DisputeWindowOpen,
 }
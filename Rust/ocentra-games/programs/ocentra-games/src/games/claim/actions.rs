@@ -1,6 +1,7 @@
 use crate::card_games::floor_card::FloorCard;
 use crate::card_games::hand_management::HandManagement;
 use crate::card_games::suit_declarations::SuitDeclarations;
+use crate::games::claim::state::ClaimState;
 use crate::state::Match;
 use anchor_lang::prelude::*;
 
@@ -16,10 +17,13 @@ pub fn apply_claim_action(
 ) -> Result<()> {
     match action_type {
         2 => {
-            // Declare intent: record the declared suit
+            // Declare intent: record the declared suit and the order it was declared in
             if payload.len() >= 1 {
                 let suit = payload[0];
                 SuitDeclarations::record_declaration(match_account, player_index, suit);
+                let mut state = ClaimState::load(match_account);
+                state.record_declaration(player_index);
+                state.store(match_account);
             }
         }
         0 => {
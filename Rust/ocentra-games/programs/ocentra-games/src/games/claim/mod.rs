@@ -2,6 +2,8 @@
 
 pub mod actions;
 pub mod rules;
+pub mod scoring;
+pub mod state;
 pub mod validation;
 
 pub use rules::ClaimRules;
@@ -1,26 +1,18 @@
+use crate::games::claim::state::ClaimState;
 use crate::games::trait_def::GameRules;
 use crate::state::Match;
 use anchor_lang::prelude::*;
 
-/// CLAIM game constants
+/// CLAIM game rules
 pub struct ClaimRules;
 
-impl ClaimRules {
-    pub const MAX_HAND_SIZE: u8 = 13;
-    pub const MAX_ACTION_TYPE: u8 = 5; // 0=pick_up, 1=decline, 2=declare_intent, 3=call_showdown, 4=rebuttal, 5=reveal_floor_card
-}
-
 impl GameRules for ClaimRules {
-    fn max_hand_size(&self) -> u8 {
-        Self::MAX_HAND_SIZE
-    }
-
-    fn max_action_type(&self) -> u8 {
-        Self::MAX_ACTION_TYPE
-    }
+    const GAME_TYPE: u8 = 0;
+    const MAX_HAND_SIZE: u8 = 13;
+    const MAX_ACTION_TYPE: u8 = 5; // 0=pick_up, 1=decline, 2=declare_intent, 3=call_showdown, 4=rebuttal, 5=reveal_floor_card
+    const HAS_ON_CHAIN_SCORING: bool = false; // Card values stay off-chain - free matches only
 
     fn validate_action(
-        &self,
         match_account: &Match,
         player_index: usize,
         action_type: u8,
@@ -35,7 +27,6 @@ impl GameRules for ClaimRules {
     }
 
     fn apply_action_state(
-        &self,
         match_account: &mut Match,
         player_index: usize,
         action_type: u8,
@@ -50,4 +41,36 @@ impl GameRules for ClaimRules {
             advance_turn,
         )
     }
+
+    fn on_match_start(match_account: &mut Match) -> Result<()> {
+        // Keep the dealt hand sizes: pick-ups grow hand_sizes during play, and score replay
+        // needs the starting sizes back
+        ClaimState::new(match_account.hand_sizes).store(match_account);
+        Ok(())
+    }
+
+    fn on_replay_start(match_account: &mut Match) -> Result<()> {
+        let dealt_hand_sizes = ClaimState::load(match_account).dealt_hand_sizes;
+        match_account.hand_sizes = dealt_hand_sizes;
+        ClaimState::new(dealt_hand_sizes).store(match_account);
+        Ok(())
+    }
+
+    fn advance_turn_after_batch(match_account: &mut Match, player_index: usize, last_action: u8) {
+        // Only pick_up (0) and decline (1) pass the turn
+        if last_action == 0 || last_action == 1 {
+            // Use player_count (actual players in match) not max_players (max allowed)
+            match_account.current_player =
+                ((player_index + 1) % match_account.player_count as usize) as u8;
+        }
+        // For other moves (declare intent, rebuttal, call showdown), turn doesn't advance
+    }
+
+    fn timeout_action(_match_account: &Match, _player_index: usize) -> Option<u8> {
+        Some(1) // Decline the floor card
+    }
+
+    fn score_match(match_account: &Match) -> [i32; 10] {
+        crate::games::claim::scoring::score_claim_match(match_account)
+    }
 }
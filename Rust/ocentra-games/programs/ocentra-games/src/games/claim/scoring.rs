@@ -0,0 +1,109 @@
+use crate::games::claim::state::ClaimState;
+use crate::state::Match;
+
+/// Points for holding a declared suit at the end of the match
+const DECLARATION_POINTS: i32 = 20;
+
+/// Bonus for the seat that declared first (in move order)
+const FIRST_DECLARATION_BONUS: i32 = 5;
+
+/// Penalty per completed round for a seat that never declared
+const UNDECLARED_PENALTY_PER_ROUND: i32 = 2;
+
+/// CLAIM end-of-match scoring from on-chain match state - a ranking for free play only.
+/// Card values stay off-chain, so this can't tell who actually won and CLAIM can't back paid
+/// matches or tournaments (HAS_ON_CHAIN_SCORING = false). The score is built from what the
+/// moves recorded:
+/// - Declared seats: DECLARATION_POINTS, plus one point per completed round, plus
+///   FIRST_DECLARATION_BONUS for the seat whose declaration came first in move order
+/// - Undeclared seats: UNDECLARED_PENALTY_PER_ROUND per completed round (at least one)
+///
+/// A score depends only on what the seat did and when - never on its seat index - so
+/// score replay and distribute_prizes rank the same play the same way whatever the seating.
+pub fn score_claim_match(match_account: &Match) -> [i32; 10] {
+    let mut scores: [i32; 10] = [0; 10];
+    let player_count = match_account.player_count as usize;
+    if player_count == 0 {
+        return scores;
+    }
+
+    let state = ClaimState::load(match_account);
+    let rounds = (match_account.move_count as usize / player_count) as i32;
+
+    for (seat, score) in scores.iter_mut().enumerate().take(player_count) {
+        *score = if match_account.has_declared_suit(seat) {
+            let first_bonus = if state.declaration_order[seat] == 1 {
+                FIRST_DECLARATION_BONUS
+            } else {
+                0
+            };
+            DECLARATION_POINTS + rounds + first_bonus
+        } else {
+            -(UNDECLARED_PENALTY_PER_ROUND * rounds.max(1))
+        };
+
+        // Keep scores in a reasonable range (prevents overflow downstream)
+        *score = (*score).clamp(-100, 200);
+    }
+
+    scores
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card_games::suit_declarations::SuitDeclarations;
+
+    fn playing_match(player_count: u8, move_count: u16) -> Box<Match> {
+        let mut match_account: Box<Match> = Box::new(bytemuck::Zeroable::zeroed());
+        match_account.player_count = player_count;
+        match_account.move_count = move_count;
+        match_account.hand_sizes = [3; 10];
+        ClaimState::new(match_account.hand_sizes).store(&mut match_account);
+        match_account
+    }
+
+    fn declare(match_account: &mut Match, seat: usize, suit: u8) {
+        SuitDeclarations::record_declaration(match_account, seat, suit);
+        let mut state = ClaimState::load(match_account);
+        state.record_declaration(seat);
+        state.store(match_account);
+    }
+
+    #[test]
+    fn first_declaration_bonus_follows_move_order_not_seat_index() {
+        let mut match_account = playing_match(3, 9);
+        declare(&mut match_account, 2, 0);
+        declare(&mut match_account, 0, 1);
+
+        let scores = score_claim_match(&match_account);
+        assert_eq!(scores[2], DECLARATION_POINTS + 3 + FIRST_DECLARATION_BONUS);
+        assert_eq!(scores[0], DECLARATION_POINTS + 3);
+        assert_eq!(scores[1], -(UNDECLARED_PENALTY_PER_ROUND * 3));
+    }
+
+    #[test]
+    fn mirrored_play_gives_mirrored_scores() {
+        let mut first = playing_match(2, 4);
+        declare(&mut first, 0, 0);
+        declare(&mut first, 1, 1);
+        let mut mirrored = playing_match(2, 4);
+        declare(&mut mirrored, 1, 0);
+        declare(&mut mirrored, 0, 1);
+
+        let first_scores = score_claim_match(&first);
+        let mirrored_scores = score_claim_match(&mirrored);
+        assert_eq!(first_scores[0], mirrored_scores[1]);
+        assert_eq!(first_scores[1], mirrored_scores[0]);
+    }
+
+    #[test]
+    fn undeclared_seats_pay_at_least_one_round() {
+        let match_account = playing_match(4, 1);
+        let scores = score_claim_match(&match_account);
+        assert!(scores[..4]
+            .iter()
+            .all(|&score| score == -UNDECLARED_PENALTY_PER_ROUND));
+        assert_eq!(scores[4..], [0; 6]);
+    }
+}
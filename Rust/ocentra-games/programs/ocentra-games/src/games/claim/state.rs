@@ -0,0 +1,50 @@
+use crate::state::Match;
+
+/// CLAIM state - stored in Match.game_state (21 of 512 bytes).
+/// Declarations themselves live in Match.declared_suits; this keeps what scoring needs on top.
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct ClaimState {
+    pub dealt_hand_sizes: [u8; 10], // Hand sizes at start_match (score replay restores them)
+    pub declaration_order: [u8; 10], // Order each seat declared in, 1 = first (0 = undeclared)
+    pub declarations: u8,           // Seats that have declared so far
+}
+
+// Layout must fit in Match.game_state and match SIZE (no implicit padding)
+const _: () = assert!(
+    ClaimState::SIZE == core::mem::size_of::<ClaimState>()
+        && ClaimState::SIZE <= Match::GAME_STATE_SIZE
+);
+
+impl ClaimState {
+    pub const SIZE: usize = 10 + // dealt_hand_sizes
+        10 +                     // declaration_order
+        1; // declarations
+
+    /// Read CLAIM state from the match's game state blob
+    pub fn load(match_account: &Match) -> Self {
+        bytemuck::pod_read_unaligned(&match_account.game_state[..Self::SIZE])
+    }
+
+    /// Write CLAIM state back to the match's game state blob
+    pub fn store(&self, match_account: &mut Match) {
+        match_account.game_state[..Self::SIZE].copy_from_slice(bytemuck::bytes_of(self));
+    }
+
+    /// Start-of-play state for the dealt hands
+    pub fn new(dealt_hand_sizes: [u8; 10]) -> Self {
+        Self {
+            dealt_hand_sizes,
+            declaration_order: [0; 10],
+            declarations: 0,
+        }
+    }
+
+    /// Record a seat's declaration in play order
+    pub fn record_declaration(&mut self, player_index: usize) {
+        if player_index < 10 && self.declaration_order[player_index] == 0 {
+            self.declarations = self.declarations.saturating_add(1);
+            self.declaration_order[player_index] = self.declarations;
+        }
+    }
+}
@@ -2,6 +2,7 @@ use crate::card_games::validation::CardGameValidation;
 use crate::common::validation_base::CommonValidation;
 use crate::error::GameError;
 use crate::games::claim::rules::ClaimRules;
+use crate::games::trait_def::GameRules;
 use crate::state::Match;
 use anchor_lang::prelude::*;
 
@@ -1,8 +1,6 @@
 use crate::error::GameError;
-use crate::games::claim::actions::apply_claim_action;
-use crate::games::claim::rules::ClaimRules;
-use crate::games::claim::validation::validate_claim_action;
-use crate::state::Match;
+use crate::games::game_table::find_game_entry;
+use crate::state::{game_phase, timeout_policy, Match};
 use anchor_lang::prelude::*;
 
 /// Validate action using appropriate game rules (static dispatch - no trait objects for Solana)
@@ -12,19 +10,20 @@ pub fn validate_move(
     action_type: u8,
     payload: &[u8],
 ) -> Result<()> {
+    let game = find_game_entry(match_account.game_type).ok_or(GameError::InvalidPayload)?;
+
+    // Forfeited players (turn timeout) are out of the match
+    require!(
+        !match_account.is_forfeited(player_index),
+        GameError::PlayerForfeited
+    );
+
     // Validate action type bounds based on game
-    match match_account.game_type {
-        0 => {
-            // CLAIM game
-            if action_type > ClaimRules::MAX_ACTION_TYPE {
-                return Err(GameError::InvalidAction.into());
-            }
-            validate_claim_action(match_account, player_index, action_type, payload)
-        }
-        // Future games:
-        // 1 => validate_poker_action(...),
-        _ => Err(GameError::InvalidPayload.into()),
-    }
+    require!(
+        action_type <= game.max_action_type,
+        GameError::InvalidAction
+    );
+    (game.validate_action)(match_account, player_index, action_type, payload)
 }
 
 /// Apply action state updates using appropriate game rules (static dispatch)
@@ -37,19 +36,320 @@ pub fn apply_action_state(
     payload: &[u8],
     advance_turn: bool,
 ) -> Result<()> {
-    match match_account.game_type {
-        0 => {
-            // CLAIM game
-            apply_claim_action(
-                match_account,
-                player_index,
-                action_type,
-                payload,
-                advance_turn,
-            )
-        }
-        // Future games:
-        // 1 => apply_poker_action(..., advance_turn),
-        _ => Err(GameError::InvalidPayload.into()),
+    let game = find_game_entry(match_account.game_type).ok_or(GameError::InvalidPayload)?;
+    (game.apply_action_state)(
+        match_account,
+        player_index,
+        action_type,
+        payload,
+        advance_turn,
+    )?;
+    match_account.skip_forfeited_seats();
+    Ok(())
+}
+
+/// Initialize game-specific state when the match enters the Playing phase
+pub fn start_game(match_account: &mut Match) -> Result<()> {
+    match find_game_entry(match_account.game_type) {
+        Some(game) => (game.on_match_start)(match_account),
+        None => Ok(()), // Games without on-chain rules only track lifecycle
+    }
+}
+
+/// Record the dealer's deck commitment for games dealt from a dealer deck (e.g. Poker)
+pub fn commit_deck(match_account: &mut Match, deck_commitment: [u8; 32]) -> Result<()> {
+    let game = find_game_entry(match_account.game_type).ok_or(GameError::InvalidAction)?;
+    (game.on_deck_commit)(match_account, deck_commitment)
+}
+
+/// Reset a scratch copy of the match to its start-of-play state for score replay
+/// (mirrors start_match: Playing phase, no moves, no floor card, no recorded scores)
+pub fn begin_replay(match_account: &mut Match) -> Result<()> {
+    let game = find_game_entry(match_account.game_type).ok_or(GameError::InvalidPayload)?;
+
+    match_account.phase = game_phase::PLAYING;
+    match_account.current_player = 0;
+    match_account.move_count = 0;
+    match_account.declared_suits = [0u8; 5];
+    match_account.floor_card_hash = [0u8; 32];
+    match_account.set_floor_card_revealed(false);
+    match_account.set_scores_recorded(false);
+    match_account.scores = [0i32; 10];
+    match_account.forfeited_players = 0;
+    match_account.timed_out_player = 255;
+
+    (game.on_replay_start)(match_account)
+}
+
+/// Advance the turn once after a batch of moves (batch moves apply with advance_turn = false)
+pub fn advance_turn_after_batch(match_account: &mut Match, player_index: usize, last_action: u8) {
+    if let Some(game) = find_game_entry(match_account.game_type) {
+        (game.advance_turn_after_batch)(match_account, player_index, last_action);
+    }
+    match_account.skip_forfeited_seats();
+}
+
+/// Whether play is waiting on the dealer's next deck key (false for games without a dealer deck)
+pub fn awaiting_dealer(match_account: &Match) -> bool {
+    find_game_entry(match_account.game_type)
+        .is_some_and(|game| (game.awaiting_dealer)(match_account))
+}
+
+/// Seat a turn timeout would resolve against - the same seat for claim_turn_timeout and
+/// score replay. Games without turns have no turn timer; a turn-based game may also be
+/// waiting on no seat in particular (NoStalledTurn).
+pub fn stalled_player(match_account: &Match) -> Result<usize> {
+    let game = find_game_entry(match_account.game_type).ok_or(GameError::InvalidPayload)?;
+    require!(game.has_turns, GameError::TurnTimerDisabled);
+    (game.stalled_player)(match_account).ok_or(GameError::NoStalledTurn.into())
+}
+
+/// Resolve an expired turn for the stalled player under a timeout policy (enums::timeout_policy).
+/// Shared by claim_turn_timeout and score replay so both reach the same state.
+/// Skips go through the game's skip_turn hook so each game keeps its own turn order.
+pub fn resolve_turn_timeout(match_account: &mut Match, policy: u8) -> Result<()> {
+    let game = find_game_entry(match_account.game_type).ok_or(GameError::InvalidPayload)?;
+    let player_index = stalled_player(match_account)?;
+
+    match policy {
+        timeout_policy::AUTO_DECLINE => {
+            // Fall back to skipping when the game has no default action or it isn't legal now
+            let action = (game.timeout_action)(match_account, player_index).filter(|&action| {
+                (game.validate_action)(match_account, player_index, action, &[]).is_ok()
+            });
+            match action {
+                Some(action) => {
+                    (game.apply_action_state)(match_account, player_index, action, &[], true)?
+                }
+                None => (game.skip_turn)(match_account, player_index)?,
+            }
+        }
+        timeout_policy::SKIP => (game.skip_turn)(match_account, player_index)?,
+        timeout_policy::FORFEIT => {
+            match_account.set_forfeited(player_index);
+            match_account.timed_out_player = player_index as u8;
+            (game.on_player_forfeit)(match_account, player_index)?;
+
+            // Last active player standing - match is over
+            if match_account.active_player_count() <= 1 {
+                match_account.phase = game_phase::ENDED; // ended_at set by instruction handler with clock
+            }
+        }
+        _ => return Err(GameError::InvalidPayload.into()),
+    }
+
+    match_account.skip_forfeited_seats();
+    Ok(())
+}
+
+/// Whether the game is turn-based, so a turn timer can apply (false for games without
+/// on-chain rules and for simultaneous-play games)
+pub fn has_turns(game_type: u8) -> bool {
+    find_game_entry(game_type).is_some_and(|game| game.has_turns)
+}
+
+/// End-of-match scores from the game rules (all zeros for games without on-chain rules)
+pub fn score_match(match_account: &Match) -> [i32; 10] {
+    match find_game_entry(match_account.game_type) {
+        Some(game) => (game.score_match)(match_account),
+        None => [0; 10],
+    }
+}
+
+/// Compute and persist final scores on the match (idempotent - first result is authoritative)
+pub fn record_final_scores(match_account: &mut Match) {
+    if !match_account.scores_recorded() {
+        let scores = score_match(match_account);
+        match_account.record_scores(scores);
+    }
+}
+
+/// Whether the game's rules score matches on-chain. Games without on-chain rules score all
+/// zeros, and CLAIM's scores only rank free play (card values stay off-chain), so neither can
+/// back paid matches or tournaments.
+pub fn has_on_chain_scoring(game_type: u8) -> bool {
+    find_game_entry(game_type).is_some_and(|game| game.has_on_chain_scoring)
+}
+
+/// Maximum hand size for the game (None = game has no on-chain rules)
+pub fn max_hand_size(game_type: u8) -> Option<u8> {
+    find_game_entry(game_type).map(|game| game.max_hand_size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card_games::cards::Cards;
+    use crate::games::poker::rules::poker_action;
+    use crate::games::poker::state::{street, PokerState};
+    use solana_sha256_hasher::{hash, hashv};
+
+    const CLAIM: u8 = 0;
+    const POKER: u8 = 1;
+    const WORD_SEARCH: u8 = 2;
+
+    /// Dealer hash chain: [commitment, flop, turn, river, hole cards]
+    fn dealer_keys() -> [[u8; 32]; 5] {
+        let mut keys = [[42u8; 32]; 5];
+        for level in (0..4).rev() {
+            keys[level] = hash(&keys[level + 1]).to_bytes();
+        }
+        keys
+    }
+
+    fn started_match(game_type: u8, player_count: u8) -> Box<Match> {
+        let mut match_account: Box<Match> = Box::new(bytemuck::Zeroable::zeroed());
+        match_account.game_type = game_type;
+        match_account.player_count = player_count;
+        match_account.phase = game_phase::PLAYING;
+        match_account.timed_out_player = 255;
+        match_account.hand_sizes = [7; 10];
+        for seat in 0..player_count as usize {
+            match_account.set_committed_hand_hash(seat, [seat as u8 + 1; 32]);
+        }
+        if game_type == POKER {
+            commit_deck(&mut match_account, dealer_keys()[0]).unwrap();
+        }
+        start_game(&mut match_account).unwrap();
+        match_account
+    }
+
+    fn play(match_account: &mut Match, action_type: u8, payload: &[u8]) {
+        let player_index = match_account.current_player as usize;
+        validate_move(match_account, player_index, action_type, payload).unwrap();
+        apply_action_state(match_account, player_index, action_type, payload, true).unwrap();
+    }
+
+    #[test]
+    fn claim_skip_and_forfeit_rotate_past_forfeited_seats() {
+        let mut match_account = started_match(CLAIM, 3);
+        resolve_turn_timeout(&mut match_account, timeout_policy::SKIP).unwrap();
+        assert_eq!(match_account.current_player, 1);
+
+        resolve_turn_timeout(&mut match_account, timeout_policy::FORFEIT).unwrap();
+        assert!(match_account.is_forfeited(1));
+        assert_eq!(match_account.current_player, 2);
+
+        resolve_turn_timeout(&mut match_account, timeout_policy::SKIP).unwrap();
+        assert_eq!(match_account.current_player, 0);
+    }
+
+    #[test]
+    fn only_games_scored_on_chain_can_back_paid_matches() {
+        assert!(!has_on_chain_scoring(CLAIM));
+        assert!(has_on_chain_scoring(POKER));
+        assert!(has_on_chain_scoring(WORD_SEARCH));
+        assert!(!has_on_chain_scoring(4));
+    }
+
+    #[test]
+    fn word_search_has_no_turn_timer() {
+        let mut match_account = started_match(WORD_SEARCH, 2);
+        let before = match_account.game_state;
+
+        assert!(!has_turns(WORD_SEARCH));
+        for policy in [
+            timeout_policy::AUTO_DECLINE,
+            timeout_policy::SKIP,
+            timeout_policy::FORFEIT,
+        ] {
+            let err = resolve_turn_timeout(&mut match_account, policy).unwrap_err();
+            assert_eq!(err, GameError::TurnTimerDisabled.into());
+        }
+        assert!(!match_account.is_forfeited(0));
+        assert_eq!(match_account.game_state, before);
+    }
+
+    #[test]
+    fn poker_skip_folds_a_seat_facing_a_bet() {
+        // Seed 0: button 0, blinds on seats 1 and 2, seat 0 acts first facing the big blind
+        let mut match_account = started_match(POKER, 3);
+        assert_eq!(match_account.current_player, 0);
+
+        resolve_turn_timeout(&mut match_account, timeout_policy::SKIP).unwrap();
+        let state = PokerState::load(&match_account);
+        assert!(state.is_folded(0));
+        assert_eq!(match_account.current_player, 1);
+    }
+
+    #[test]
+    fn poker_skip_follows_betting_order_past_folded_seats() {
+        // Seed 0, four seats: button 0, blinds on 1 and 2, seat 3 opens and folds
+        let mut match_account = started_match(POKER, 4);
+        play(&mut match_account, poker_action::FOLD, &[]);
+        play(&mut match_account, poker_action::CALL, &[]);
+        play(&mut match_account, poker_action::CALL, &[]);
+        play(&mut match_account, poker_action::CHECK, &[]);
+
+        // Betting round closed - any seat may deal, so nobody is stalling
+        assert!(PokerState::load(&match_account).awaiting_board == 1);
+        let err = resolve_turn_timeout(&mut match_account, timeout_policy::FORFEIT).unwrap_err();
+        assert_eq!(err, GameError::NoStalledTurn.into());
+        assert_eq!(match_account.forfeited_players, 0);
+
+        let dealer = match_account.current_player as usize;
+        let flop = dealer_keys()[1];
+        validate_move(&match_account, dealer, poker_action::DEAL_BOARD, &flop).unwrap();
+        apply_action_state(
+            &mut match_account,
+            dealer,
+            poker_action::DEAL_BOARD,
+            &flop,
+            true,
+        )
+        .unwrap();
+
+        // Post-flop: seat 1 checks, seat 2 times out - its check passes the turn over the
+        // folded seat 3 to seat 0
+        assert_eq!(match_account.current_player, 1);
+        play(&mut match_account, poker_action::CHECK, &[]);
+        assert_eq!(match_account.current_player, 2);
+        resolve_turn_timeout(&mut match_account, timeout_policy::SKIP).unwrap();
+
+        let state = PokerState::load(&match_account);
+        assert!(!state.is_folded(2) && state.has_acted(2));
+        assert_eq!(match_account.current_player, 0);
+    }
+
+    #[test]
+    fn poker_showdown_timer_waits_on_a_seat_that_has_not_revealed() {
+        // Heads-up, seed 0: the button (seat 0) posts the small blind and acts first
+        let mut match_account = started_match(POKER, 2);
+        play(&mut match_account, poker_action::CALL, &[]);
+        play(&mut match_account, poker_action::CHECK, &[]);
+        let keys = dealer_keys();
+        let deal = |match_account: &mut Match, key: &[u8; 32]| {
+            let dealer = match_account.current_player as usize;
+            validate_move(match_account, dealer, poker_action::DEAL_BOARD, key).unwrap();
+            apply_action_state(match_account, dealer, poker_action::DEAL_BOARD, key, true).unwrap();
+        };
+        for key in &keys[1..4] {
+            deal(&mut match_account, key);
+            play(&mut match_account, poker_action::CHECK, &[]);
+            play(&mut match_account, poker_action::CHECK, &[]);
+        }
+        deal(&mut match_account, &keys[4]);
+        let state = PokerState::load(&match_account);
+        assert_eq!(state.street, street::SHOWDOWN);
+        assert_eq!(match_account.current_player, 1);
+
+        // Seat 1 shows the cards the deck dealt it - the timer moves on to seat 0, which still
+        // has to reveal or muck
+        let mut reveal = Vec::new();
+        for card in state.dealt_hole_cards(1) {
+            reveal.extend_from_slice(&[Cards::suit(card), Cards::value(card)]);
+        }
+        reveal.extend_from_slice(&[9u8; 32]);
+        let commitment = hashv(&[&reveal[..4], &reveal[4..]]).to_bytes();
+        match_account.set_committed_hand_hash(1, commitment);
+        play(&mut match_account, poker_action::REVEAL_HAND, &reveal);
+        assert_eq!(match_account.current_player, 0);
+
+        // Seat 0 stalls: the skip mucks its hand and seat 1 takes the pot
+        resolve_turn_timeout(&mut match_account, timeout_policy::SKIP).unwrap();
+        let state = PokerState::load(&match_account);
+        assert!(state.is_folded(0));
+        assert_eq!(match_account.phase, game_phase::ENDED);
+        assert!(state.stacks[1] > state.stacks[0]);
     }
 }
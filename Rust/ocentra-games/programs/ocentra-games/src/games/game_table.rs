@@ -0,0 +1,66 @@
+use crate::games::claim::rules::ClaimRules;
+use crate::games::poker::rules::PokerRules;
+use crate::games::trait_def::GameRules;
+use crate::games::word_search::rules::WordSearchRules;
+use crate::state::Match;
+use anchor_lang::prelude::*;
+
+/// Resolved `GameRules` hooks for one game type (fn pointers - static dispatch, no trait objects)
+#[derive(Clone, Copy)]
+pub struct GameEntry {
+    pub game_type: u8,
+    pub max_hand_size: u8,
+    pub max_action_type: u8,
+    pub has_turns: bool,
+    pub has_on_chain_scoring: bool,
+    pub validate_action: fn(&Match, usize, u8, &[u8]) -> Result<()>,
+    pub apply_action_state: fn(&mut Match, usize, u8, &[u8], bool) -> Result<()>,
+    pub on_match_start: fn(&mut Match) -> Result<()>,
+    pub on_replay_start: fn(&mut Match) -> Result<()>,
+    pub on_deck_commit: fn(&mut Match, [u8; 32]) -> Result<()>,
+    pub advance_turn_after_batch: fn(&mut Match, usize, u8),
+    pub awaiting_dealer: fn(&Match) -> bool,
+    pub stalled_player: fn(&Match) -> Option<usize>,
+    pub skip_turn: fn(&mut Match, usize) -> Result<()>,
+    pub timeout_action: fn(&Match, usize) -> Option<u8>,
+    pub on_player_forfeit: fn(&mut Match, usize) -> Result<()>,
+    pub score_match: fn(&Match) -> [i32; 10],
+}
+
+impl GameEntry {
+    /// Build the table entry for a `GameRules` implementation at compile time
+    pub const fn of<R: GameRules>() -> Self {
+        Self {
+            game_type: R::GAME_TYPE,
+            max_hand_size: R::MAX_HAND_SIZE,
+            max_action_type: R::MAX_ACTION_TYPE,
+            has_turns: R::HAS_TURNS,
+            has_on_chain_scoring: R::HAS_ON_CHAIN_SCORING,
+            validate_action: R::validate_action,
+            apply_action_state: R::apply_action_state,
+            on_match_start: R::on_match_start,
+            on_replay_start: R::on_replay_start,
+            on_deck_commit: R::on_deck_commit,
+            advance_turn_after_batch: R::advance_turn_after_batch,
+            awaiting_dealer: R::awaiting_dealer,
+            stalled_player: R::stalled_player,
+            skip_turn: R::skip_turn,
+            timeout_action: R::timeout_action,
+            on_player_forfeit: R::on_player_forfeit,
+            score_match: R::score_match,
+        }
+    }
+}
+
+/// Compile-time game table: game_type → rules.
+/// Adding a game = one module implementing `GameRules` + one entry here.
+pub const GAME_TABLE: [GameEntry; 3] = [
+    GameEntry::of::<ClaimRules>(),      // 0 = CLAIM
+    GameEntry::of::<PokerRules>(),      // 1 = Poker
+    GameEntry::of::<WordSearchRules>(), // 2 = Word search
+];
+
+/// Look up the rules for a game type (None = no on-chain rules for this game)
+pub fn find_game_entry(game_type: u8) -> Option<&'static GameEntry> {
+    GAME_TABLE.iter().find(|entry| entry.game_type == game_type)
+}
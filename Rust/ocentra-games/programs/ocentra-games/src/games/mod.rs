@@ -2,6 +2,13 @@
 
 pub mod claim;
 pub mod dispatcher;
+pub mod game_table;
+pub mod poker;
 pub mod trait_def;
+pub mod word_search;
 
-pub use dispatcher::{apply_action_state, validate_move};
+pub use dispatcher::{
+    advance_turn_after_batch, apply_action_state, awaiting_dealer, begin_replay, commit_deck, has_on_chain_scoring,
+    has_turns, max_hand_size, record_final_scores, resolve_turn_timeout, score_match,
+    stalled_player, start_game, validate_move,
+};
@@ -0,0 +1,516 @@
+use crate::card_games::cards::Cards;
+use crate::card_games::deck::Deck;
+use crate::error::GameError;
+use crate::games::poker::hand_rank::HandRank;
+use crate::games::poker::rules::{poker_action, PokerRules};
+use crate::games::poker::state::{street, PokerState};
+use crate::games::poker::validation::{read_bet_amount, read_deck_key};
+use crate::state::Match;
+use anchor_lang::prelude::*;
+
+/// Record the dealer's deck commitment during the Dealing phase (commit_deck)
+pub fn commit_poker_deck(match_account: &mut Match, deck_commitment: [u8; 32]) -> Result<()> {
+    let mut state = PokerState::load(match_account);
+    state.deck_commitment = deck_commitment;
+    state.store(match_account);
+    Ok(())
+}
+
+/// Initialize the hand at start_match: stacks, button and blinds.
+/// The dealer must have committed the deck, and every seat its hole-card hash, during the
+/// Dealing phase. The deck commitment is the only state carried over, so score replay
+/// rebuilds the same start state from the ended match.
+pub fn start_poker_hand(match_account: &mut Match) -> Result<()> {
+    let player_count = match_account.player_count as usize;
+    require!(player_count >= 2, GameError::InsufficientPlayers);
+    for seat in 0..player_count {
+        require!(
+            match_account.get_committed_hand_hash(seat).is_some(),
+            GameError::HandNotCommitted
+        );
+    }
+
+    // Cards come from the dealer's deck - a dealer holding a seat would know every hand
+    let deck_commitment = PokerState::load(match_account).deck_commitment;
+    require!(deck_commitment != [0u8; 32], GameError::DeckNotCommitted);
+    require!(
+        !match_account.has_player_wallet(&match_account.authority),
+        GameError::DealerSeated
+    );
+
+    let mut state: PokerState = bytemuck::Zeroable::zeroed();
+    state.deck_commitment = deck_commitment;
+    state.stacks[..player_count].fill(PokerRules::STARTING_STACK);
+    state.small_blind = PokerRules::SMALL_BLIND;
+    state.big_blind = PokerRules::BIG_BLIND;
+    state.min_raise = PokerRules::BIG_BLIND;
+    state.street = street::PREFLOP;
+
+    // Button derived from the match seed (deterministic, replayable)
+    let button = (match_account.seed as usize) % player_count;
+    state.button = button as u8;
+
+    // Heads-up: the button posts the small blind
+    let (small_blind_seat, big_blind_seat) = if player_count == 2 {
+        (button, (button + 1) % player_count)
+    } else {
+        ((button + 1) % player_count, (button + 2) % player_count)
+    };
+    state.commit_chips(small_blind_seat, PokerRules::SMALL_BLIND);
+    state.commit_chips(big_blind_seat, PokerRules::BIG_BLIND);
+    state.current_bet = state.street_bets[big_blind_seat].max(state.street_bets[small_blind_seat]);
+
+    // First to act preflop is left of the big blind
+    progress_hand(&mut state, match_account, big_blind_seat);
+    state.store(match_account);
+    Ok(())
+}
+
+/// Poker-specific action state updates
+/// `advance_turn` is ignored: betting order is part of the poker rules, so the turn always
+/// passes to the next seat that can act (batched poker moves are validated turn by turn).
+pub fn apply_poker_action(
+    match_account: &mut Match,
+    player_index: usize,
+    action_type: u8,
+    payload: &[u8],
+    _advance_turn: bool,
+) -> Result<()> {
+    let mut state = PokerState::load(match_account);
+    let mut from = player_index;
+
+    match action_type {
+        poker_action::FOLD if state.street == street::SHOWDOWN => {
+            // Muck: forfeit the pot without showing
+            state.folded |= 1 << player_index;
+        }
+        poker_action::DEAL_BOARD => {
+            deal_board(&mut state, payload)?;
+            // Post-flop action starts left of the button
+            from = state.button as usize;
+        }
+        poker_action::REVEAL_HAND => {
+            reveal_hand(&mut state, player_index, payload)?;
+        }
+        _ => {
+            apply_bet(&mut state, player_index, action_type, payload)?;
+            state.acted |= 1 << player_index;
+        }
+    }
+
+    progress_hand(&mut state, match_account, from);
+    state.store(match_account);
+    Ok(())
+}
+
+/// Fold a forfeited seat out of the hand (turn timeout) regardless of whose turn it is
+pub fn forfeit_poker_seat(match_account: &mut Match, player_index: usize) {
+    let mut state = PokerState::load(match_account);
+    if state.is_live(player_index) {
+        state.folded |= 1 << player_index;
+        progress_hand(&mut state, match_account, player_index);
+        state.store(match_account);
+    }
+}
+
+fn apply_bet(
+    state: &mut PokerState,
+    player_index: usize,
+    action_type: u8,
+    payload: &[u8],
+) -> Result<()> {
+    match action_type {
+        poker_action::FOLD => {
+            state.folded |= 1 << player_index;
+        }
+        poker_action::CHECK => {}
+        poker_action::CALL => {
+            let to_call = state.to_call(player_index);
+            state.commit_chips(player_index, to_call);
+        }
+        poker_action::RAISE => {
+            let amount = read_bet_amount(payload)?;
+            let added = amount - state.street_bets[player_index];
+            state.commit_chips(player_index, added);
+            state.min_raise = amount - state.current_bet;
+            state.current_bet = amount;
+            state.acted = 0; // Raise reopens the action for everyone else
+        }
+        poker_action::ALL_IN => {
+            let stack = state.stacks[player_index];
+            state.commit_chips(player_index, stack);
+            let new_bet = state.street_bets[player_index];
+            if new_bet > state.current_bet {
+                // Short all-in raises don't change the minimum raise increment
+                let raise = new_bet - state.current_bet;
+                if raise >= state.min_raise {
+                    state.min_raise = raise;
+                }
+                state.current_bet = new_bet;
+                state.acted = 0;
+            }
+        }
+        _ => return Err(GameError::InvalidAction.into()),
+    }
+    Ok(())
+}
+
+/// Deal the next street from the dealer key in the payload (validated against the chain).
+/// The flop, turn and river each come from their own key; the key after the river deals the
+/// hole cards, which opens the showdown.
+fn deal_board(state: &mut PokerState, payload: &[u8]) -> Result<()> {
+    let key = read_deck_key(payload)?;
+    let dealt = state.board_count as usize;
+    let count = match state.street {
+        street::PREFLOP => 3,
+        street::RIVER => 0,
+        _ => 1,
+    };
+    let (board, undealt) = state.board.split_at_mut(dealt);
+    Deck::draw(&key, board, &mut undealt[..count]);
+    state.board_count += count as u8;
+    state.deck_key = key;
+    state.street += 1;
+    state.awaiting_board = 0;
+    Ok(())
+}
+
+fn reveal_hand(state: &mut PokerState, player_index: usize, payload: &[u8]) -> Result<()> {
+    let hole = Cards::parse_pairs::<2>(payload)?;
+
+    let mut seven = [0u8; 7];
+    seven[..2].copy_from_slice(&hole);
+    seven[2..].copy_from_slice(&state.board);
+
+    state.hole_cards[player_index] = hole;
+    state.hand_ranks[player_index] = HandRank::evaluate(&seven);
+    state.revealed |= 1 << player_index;
+    Ok(())
+}
+
+/// Move the hand forward after an action: award the pot, close the betting round,
+/// or pass the turn to the next seat that can act after `from`.
+fn progress_hand(state: &mut PokerState, match_account: &mut Match, from: usize) {
+    let player_count = match_account.player_count as usize;
+
+    // Everyone else folded (or mucked) - last live seat takes the pot
+    if state.live_count(player_count) <= 1 {
+        settle_pots(state, match_account);
+        return;
+    }
+
+    if state.street == street::SHOWDOWN {
+        // Reveals are not turn-gated; the turn points at the first live seat left of the
+        // button that still has to show, so the turn timer waits on a seat that is stalling
+        let button = state.button as usize;
+        match (1..=player_count)
+            .map(|offset| (button + offset) % player_count)
+            .find(|&seat| state.is_live(seat) && !state.is_revealed(seat))
+        {
+            Some(seat) => match_account.current_player = seat as u8,
+            None => settle_pots(state, match_account),
+        }
+        return;
+    }
+
+    if state.awaiting_board == 1 {
+        return; // Waiting for the next street to be dealt
+    }
+
+    if betting_round_closed(state, player_count) {
+        // Reset street betting - contributions keep the pot
+        state.street_bets = [0u32; 10];
+        state.current_bet = 0;
+        state.min_raise = state.big_blind;
+        state.acted = 0;
+
+        // After the river the dealer's last key deals the hole cards for the showdown
+        state.awaiting_board = 1;
+    } else if let Some(next) = state.next_actor(from, player_count) {
+        match_account.current_player = next as u8;
+    }
+}
+
+/// Betting round is closed when every seat that can act has matched the current bet and
+/// either acted since the last raise or is the only one left able to act.
+fn betting_round_closed(state: &PokerState, player_count: usize) -> bool {
+    let mut actors = (0..player_count).filter(|&seat| state.can_act(seat));
+    if actors.clone().any(|seat| state.to_call(seat) > 0) {
+        return false;
+    }
+    state.actor_count(player_count) <= 1 || actors.all(|seat| state.has_acted(seat))
+}
+
+/// Award the main pot and side pots, then end the match.
+/// Each pot level is capped by the smallest live contribution; only live seats that
+/// contributed to a level can win it. Odd chips go to winners left of the button first.
+fn settle_pots(state: &mut PokerState, match_account: &mut Match) {
+    let player_count = match_account.player_count as usize;
+    let mut remaining = state.contributions;
+    let mut last_winners: u16 = 0;
+
+    while let Some(level) = (0..player_count)
+        .filter(|&seat| state.is_live(seat) && remaining[seat] > 0)
+        .map(|seat| remaining[seat])
+        .min()
+    {
+        let eligible: u16 = (0..player_count)
+            .filter(|&seat| state.is_live(seat) && remaining[seat] >= level)
+            .fold(0, |mask, seat| mask | (1 << seat));
+
+        let mut pot: u32 = 0;
+        for chips in remaining.iter_mut().take(player_count) {
+            let taken = (*chips).min(level);
+            *chips -= taken;
+            pot += taken;
+        }
+
+        let best = (0..player_count)
+            .filter(|&seat| eligible & (1 << seat) != 0)
+            .map(|seat| state.hand_ranks[seat])
+            .max()
+            .unwrap_or(0);
+        let winners: u16 = (0..player_count)
+            .filter(|&seat| eligible & (1 << seat) != 0 && state.hand_ranks[seat] == best)
+            .fold(0, |mask, seat| mask | (1 << seat));
+
+        award(state, winners, pot, player_count);
+        last_winners = winners;
+    }
+
+    // Chips folded above the highest live contribution go to the last pot's winners
+    let leftover: u32 = remaining.iter().sum();
+    if leftover > 0 {
+        award(state, last_winners, leftover, player_count);
+    }
+
+    state.contributions = [0u32; 10];
+    state.street_bets = [0u32; 10];
+    state.current_bet = 0;
+    match_account.phase = 2; // Ended - ended_at set by instruction handler with clock
+}
+
+fn award(state: &mut PokerState, winners: u16, amount: u32, player_count: usize) {
+    let winner_count = winners.count_ones();
+    if winner_count == 0 {
+        return;
+    }
+    let share = amount / winner_count;
+    let mut odd_chips = amount % winner_count;
+
+    let button = state.button as usize;
+    for seat in (1..=player_count).map(|offset| (button + offset) % player_count) {
+        if winners & (1 << seat) == 0 {
+            continue;
+        }
+        state.stacks[seat] += share;
+        if odd_chips > 0 {
+            state.stacks[seat] += 1;
+            odd_chips -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::games::trait_def::GameRules;
+    use crate::games::{
+        apply_action_state, awaiting_dealer, begin_replay, commit_deck, stalled_player, start_game,
+        validate_move,
+    };
+    use crate::state::game_phase;
+    use solana_sha256_hasher::{hash, hashv};
+
+    /// Dealer hash chain: [commitment, flop, turn, river, hole cards]
+    fn dealer_keys() -> [[u8; 32]; 5] {
+        let mut keys = [[17u8; 32]; 5];
+        for level in (0..4).rev() {
+            keys[level] = hash(&keys[level + 1]).to_bytes();
+        }
+        keys
+    }
+
+    /// Heads-up match in the Dealing phase with both hole-card hashes committed
+    fn dealing_match() -> Box<Match> {
+        let mut match_account: Box<Match> = Box::new(bytemuck::Zeroable::zeroed());
+        match_account.game_type = PokerRules::GAME_TYPE;
+        match_account.player_count = 2;
+        match_account.authority = Pubkey::new_from_array([1; 32]);
+        for seat in 0..2 {
+            match_account.set_player_wallet(seat, Pubkey::new_from_array([seat as u8 + 2; 32]));
+            match_account.set_committed_hand_hash(seat, [seat as u8 + 1; 32]);
+        }
+        match_account
+    }
+
+    fn started_match() -> Box<Match> {
+        let mut match_account = dealing_match();
+        commit_deck(&mut match_account, dealer_keys()[0]).unwrap();
+        match_account.phase = game_phase::PLAYING;
+        start_game(&mut match_account).unwrap();
+        match_account
+    }
+
+    fn play(match_account: &mut Match, seat: usize, action_type: u8, payload: &[u8]) -> Result<()> {
+        validate_move(match_account, seat, action_type, payload)?;
+        apply_action_state(match_account, seat, action_type, payload, true)
+    }
+
+    /// Check the hand down to the showdown, dealing every street from the dealer's keys
+    fn check_down(match_account: &mut Match) {
+        // Seed 0: the button (seat 0) posts the small blind and acts first preflop
+        play(match_account, 0, poker_action::CALL, &[]).unwrap();
+        play(match_account, 1, poker_action::CHECK, &[]).unwrap();
+        let keys = dealer_keys();
+        for key in &keys[1..4] {
+            play(match_account, 0, poker_action::DEAL_BOARD, key).unwrap();
+            play(match_account, 1, poker_action::CHECK, &[]).unwrap();
+            play(match_account, 0, poker_action::CHECK, &[]).unwrap();
+        }
+        play(match_account, 0, poker_action::DEAL_BOARD, &keys[4]).unwrap();
+    }
+
+    /// Reveal payload for hole cards, committing its hash for the seat
+    fn committed_reveal(match_account: &mut Match, seat: usize, hole: [u8; 2]) -> Vec<u8> {
+        let mut reveal = Vec::new();
+        for card in hole {
+            reveal.extend_from_slice(&[Cards::suit(card), Cards::value(card)]);
+        }
+        reveal.extend_from_slice(&[seat as u8; 32]);
+        let commitment = hashv(&[&reveal[..4], &reveal[4..]]).to_bytes();
+        match_account.set_committed_hand_hash(seat, commitment);
+        reveal
+    }
+
+    #[test]
+    fn a_hand_needs_a_deck_committed_by_an_unseated_dealer() {
+        let mut match_account = dealing_match();
+        assert_eq!(
+            start_poker_hand(&mut match_account).unwrap_err(),
+            GameError::DeckNotCommitted.into()
+        );
+
+        commit_deck(&mut match_account, dealer_keys()[0]).unwrap();
+        match_account.authority = match_account.player_wallets[1];
+        assert_eq!(
+            start_poker_hand(&mut match_account).unwrap_err(),
+            GameError::DealerSeated.into()
+        );
+    }
+
+    #[test]
+    fn streets_are_dealt_only_from_the_next_key_in_the_chain() {
+        let mut match_account = started_match();
+        play(&mut match_account, 0, poker_action::CALL, &[]).unwrap();
+        play(&mut match_account, 1, poker_action::CHECK, &[]).unwrap();
+
+        // A chosen payload or a key further down the chain doesn't deal the flop
+        let keys = dealer_keys();
+        for payload in [[0u8; 32], keys[2]] {
+            assert_eq!(
+                play(&mut match_account, 0, poker_action::DEAL_BOARD, &payload).unwrap_err(),
+                GameError::DeckKeyMismatch.into()
+            );
+        }
+
+        play(&mut match_account, 1, poker_action::DEAL_BOARD, &keys[1]).unwrap();
+        let state = PokerState::load(&match_account);
+        let mut flop = [0u8; 3];
+        Deck::draw(&keys[1], &[], &mut flop);
+        assert_eq!(state.board_count, 3);
+        assert_eq!(state.board[..3], flop);
+        assert_eq!(state.street, street::FLOP);
+    }
+
+    #[test]
+    fn play_waits_on_the_dealer_only_between_betting_rounds() {
+        let mut match_account = started_match();
+        assert!(!awaiting_dealer(&match_account));
+        assert_eq!(stalled_player(&match_account).unwrap(), 0);
+
+        play(&mut match_account, 0, poker_action::CALL, &[]).unwrap();
+        play(&mut match_account, 1, poker_action::CHECK, &[]).unwrap();
+        assert!(awaiting_dealer(&match_account));
+        assert_eq!(
+            stalled_player(&match_account).unwrap_err(),
+            GameError::NoStalledTurn.into()
+        );
+
+        let flop_key = dealer_keys()[1];
+        play(&mut match_account, 0, poker_action::DEAL_BOARD, &flop_key).unwrap();
+        assert!(!awaiting_dealer(&match_account));
+    }
+
+    #[test]
+    fn showdown_pays_the_best_hand_the_deck_dealt() {
+        let mut match_account = started_match();
+        check_down(&mut match_account);
+
+        let state = PokerState::load(&match_account);
+        assert_eq!(state.street, street::SHOWDOWN);
+        let dealt = [state.dealt_hole_cards(0), state.dealt_hole_cards(1)];
+        assert!(Cards::validate_distinct(&[&state.board, &dealt[0], &dealt[1]]).is_ok());
+
+        for seat in [1, 0] {
+            let reveal = committed_reveal(&mut match_account, seat, dealt[seat]);
+            play(&mut match_account, seat, poker_action::REVEAL_HAND, &reveal).unwrap();
+        }
+
+        let state = PokerState::load(&match_account);
+        let rank = |seat: usize| {
+            let mut seven = [0u8; 7];
+            seven[..2].copy_from_slice(&dealt[seat]);
+            seven[2..].copy_from_slice(&state.board);
+            HandRank::evaluate(&seven)
+        };
+        assert_eq!(state.hand_ranks[0], rank(0));
+        assert_eq!(state.hand_ranks[1], rank(1));
+        assert_eq!(match_account.phase, game_phase::ENDED);
+
+        let start = PokerRules::STARTING_STACK;
+        let expected = match rank(0).cmp(&rank(1)) {
+            core::cmp::Ordering::Greater => [start + 100, start - 100],
+            core::cmp::Ordering::Less => [start - 100, start + 100],
+            core::cmp::Ordering::Equal => [start, start],
+        };
+        assert_eq!(state.stacks[..2], expected);
+    }
+
+    #[test]
+    fn self_chosen_hole_cards_cannot_be_revealed() {
+        let mut match_account = started_match();
+        check_down(&mut match_account);
+
+        // Seat 0 committed aces it was never dealt - the commitment matches, the deck doesn't
+        let state = PokerState::load(&match_account);
+        let dealt = state.dealt_hole_cards(0);
+        let aces: Vec<u8> = (0..4)
+            .map(|suit| Cards::encode(suit, Cards::ACE).unwrap())
+            .filter(|card| !dealt.contains(card) && !state.board.contains(card))
+            .take(2)
+            .collect();
+        let reveal = committed_reveal(&mut match_account, 0, [aces[0], aces[1]]);
+        assert_eq!(
+            play(&mut match_account, 0, poker_action::REVEAL_HAND, &reveal).unwrap_err(),
+            GameError::HandRevealMismatch.into()
+        );
+
+        // The dealt cards reveal in either order
+        let reveal = committed_reveal(&mut match_account, 0, [dealt[1], dealt[0]]);
+        play(&mut match_account, 0, poker_action::REVEAL_HAND, &reveal).unwrap();
+    }
+
+    #[test]
+    fn score_replay_restarts_the_hand_on_the_committed_deck() {
+        let mut match_account = started_match();
+        check_down(&mut match_account);
+
+        begin_replay(&mut match_account).unwrap();
+        let state = PokerState::load(&match_account);
+        assert_eq!(state.deck_commitment, dealer_keys()[0]);
+        assert_eq!(state.deck_key, [0u8; 32]);
+        assert_eq!(state.board_count, 0);
+        assert_eq!(state.street, street::PREFLOP);
+    }
+}
@@ -0,0 +1,235 @@
+use crate::card_games::cards::Cards;
+
+/// Hand category constants (higher beats lower)
+pub mod hand_category {
+    pub const HIGH_CARD: u32 = 0;
+    pub const PAIR: u32 = 1;
+    pub const TWO_PAIR: u32 = 2;
+    pub const THREE_OF_A_KIND: u32 = 3;
+    pub const STRAIGHT: u32 = 4;
+    pub const FLUSH: u32 = 5;
+    pub const FULL_HOUSE: u32 = 6;
+    pub const FOUR_OF_A_KIND: u32 = 7;
+    pub const STRAIGHT_FLUSH: u32 = 8;
+}
+
+/// Best five-card hand evaluator (hole cards + board, up to 7 packed cards).
+/// Rank format: category << 20 | up to five 4-bit tie-break values (most significant first),
+/// so ranks compare directly as integers and are always > 0.
+pub struct HandRank;
+
+impl HandRank {
+    pub fn evaluate(cards: &[u8]) -> u32 {
+        use hand_category::*;
+
+        // Value counts and per-suit value bitmasks (bit N = value N, 2-14)
+        let mut counts = [0u8; 15];
+        let mut suit_masks = [0u16; 4];
+        for &card in cards {
+            let value = Cards::value(card);
+            counts[value as usize] += 1;
+            suit_masks[Cards::suit(card) as usize] |= 1 << value;
+        }
+        let value_mask = suit_masks.iter().fold(0u16, |acc, m| acc | m);
+        let flush_mask = suit_masks.iter().copied().find(|m| m.count_ones() >= 5);
+
+        if let Some(high) = flush_mask.and_then(Self::straight_high) {
+            return Self::score(STRAIGHT_FLUSH, &[high]);
+        }
+
+        // Highest values holding exactly N copies
+        let of_count = |n: u8| (2..=14u8).rev().filter(move |&v| counts[v as usize] == n);
+        let quad = of_count(4).next();
+        let mut trips = of_count(3);
+        let best_trips = trips.next();
+        let second_trips = trips.next();
+        let mut pairs = of_count(2);
+        let best_pair = pairs.next();
+        let second_pair = pairs.next();
+
+        if let Some(q) = quad {
+            return Self::score(FOUR_OF_A_KIND, &[q, Self::kickers::<1>(&counts, &[q])[0]]);
+        }
+        if let Some(t) = best_trips {
+            // Second trips play as the pair when higher than any pair
+            if let Some(p) = second_trips.max(best_pair) {
+                return Self::score(FULL_HOUSE, &[t, p]);
+            }
+        }
+        if let Some(mask) = flush_mask {
+            let top = Self::top_values::<5>(mask);
+            return Self::score(FLUSH, &top);
+        }
+        if let Some(high) = Self::straight_high(value_mask) {
+            return Self::score(STRAIGHT, &[high]);
+        }
+        if let Some(t) = best_trips {
+            let k = Self::kickers::<2>(&counts, &[t]);
+            return Self::score(THREE_OF_A_KIND, &[t, k[0], k[1]]);
+        }
+        if let (Some(p1), Some(p2)) = (best_pair, second_pair) {
+            let k = Self::kickers::<1>(&counts, &[p1, p2]);
+            return Self::score(TWO_PAIR, &[p1, p2, k[0]]);
+        }
+        if let Some(p) = best_pair {
+            let k = Self::kickers::<3>(&counts, &[p]);
+            return Self::score(PAIR, &[p, k[0], k[1], k[2]]);
+        }
+        Self::score(HIGH_CARD, &Self::top_values::<5>(value_mask))
+    }
+
+    /// Highest straight in a value bitmask (ace also plays low: A-2-3-4-5)
+    fn straight_high(mask: u16) -> Option<u8> {
+        let mask = if mask & (1 << 14) != 0 {
+            mask | (1 << 1)
+        } else {
+            mask
+        };
+        (5..=14u8).rev().find(|&high| {
+            let window = 0b11111u16 << (high - 4);
+            mask & window == window
+        })
+    }
+
+    /// Highest N values set in a bitmask
+    fn top_values<const N: usize>(mask: u16) -> [u8; N] {
+        let mut out = [0u8; N];
+        let mut values = (2..=14u8).rev().filter(|&v| mask & (1 << v) != 0);
+        for slot in out.iter_mut() {
+            *slot = values.next().unwrap_or(0);
+        }
+        out
+    }
+
+    /// Highest N values present in the hand, excluding the made-hand values
+    fn kickers<const N: usize>(counts: &[u8; 15], exclude: &[u8]) -> [u8; N] {
+        let mask = (2..=14u8)
+            .filter(|&v| counts[v as usize] > 0 && !exclude.contains(&v))
+            .fold(0u16, |acc, v| acc | (1 << v));
+        Self::top_values::<N>(mask)
+    }
+
+    fn score(category: u32, values: &[u8]) -> u32 {
+        values
+            .iter()
+            .enumerate()
+            .fold(category << 20, |acc, (i, &v)| {
+                acc | ((v as u32) << (16 - 4 * i))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Pack (suit, value) pairs
+    fn cards<const N: usize>(pairs: [(u8, u8); N]) -> [u8; N] {
+        pairs.map(|(suit, value)| Cards::encode(suit, value).unwrap())
+    }
+
+    fn category(rank: u32) -> u32 {
+        rank >> 20
+    }
+
+    #[test]
+    fn categories_rank_in_poker_order() {
+        use hand_category::*;
+        let hands = [
+            (
+                HIGH_CARD,
+                cards([(0, 2), (1, 5), (2, 7), (3, 9), (0, 11), (1, 13), (2, 14)]),
+            ),
+            (
+                PAIR,
+                cards([(0, 2), (1, 2), (2, 7), (3, 9), (0, 11), (1, 13), (2, 14)]),
+            ),
+            (
+                TWO_PAIR,
+                cards([(0, 2), (1, 2), (2, 7), (3, 7), (0, 11), (1, 13), (2, 14)]),
+            ),
+            (
+                THREE_OF_A_KIND,
+                cards([(0, 7), (1, 7), (2, 7), (3, 9), (0, 11), (1, 13), (2, 2)]),
+            ),
+            (
+                STRAIGHT,
+                cards([(0, 5), (1, 6), (2, 7), (3, 8), (0, 9), (1, 13), (2, 2)]),
+            ),
+            (
+                FLUSH,
+                cards([(0, 2), (0, 5), (0, 7), (0, 9), (0, 11), (1, 13), (2, 14)]),
+            ),
+            (
+                FULL_HOUSE,
+                cards([(0, 7), (1, 7), (2, 7), (3, 9), (0, 9), (1, 13), (2, 2)]),
+            ),
+            (
+                FOUR_OF_A_KIND,
+                cards([(0, 7), (1, 7), (2, 7), (3, 7), (0, 9), (1, 13), (2, 2)]),
+            ),
+            (
+                STRAIGHT_FLUSH,
+                cards([(3, 5), (3, 6), (3, 7), (3, 8), (3, 9), (1, 13), (2, 2)]),
+            ),
+        ];
+
+        let mut previous = 0;
+        for (expected, hand) in hands {
+            let rank = HandRank::evaluate(&hand);
+            assert_eq!(category(rank), expected);
+            assert!(rank > previous);
+            previous = rank;
+        }
+    }
+
+    #[test]
+    fn the_wheel_is_the_lowest_straight() {
+        let wheel = cards([(0, 14), (1, 2), (2, 3), (3, 4), (0, 5), (1, 9), (2, 12)]);
+        let six_high = cards([(0, 6), (1, 2), (2, 3), (3, 4), (0, 5), (1, 9), (2, 12)]);
+
+        assert_eq!(
+            category(HandRank::evaluate(&wheel)),
+            hand_category::STRAIGHT
+        );
+        assert!(HandRank::evaluate(&six_high) > HandRank::evaluate(&wheel));
+    }
+
+    #[test]
+    fn kickers_break_ties_and_a_playing_board_splits() {
+        let board = cards([(0, 14), (1, 14), (2, 8), (3, 6), (0, 3)]);
+        let with = |hole: [u8; 2]| {
+            let mut seven = [0u8; 7];
+            seven[..2].copy_from_slice(&hole);
+            seven[2..].copy_from_slice(&board);
+            HandRank::evaluate(&seven)
+        };
+
+        let king_kicker = with(cards([(1, 13), (2, 2)]));
+        let queen_kicker = with(cards([(2, 12), (3, 2)]));
+        assert!(king_kicker > queen_kicker);
+
+        // The same values in other suits rank the same - the pot splits
+        assert_eq!(with(cards([(1, 4), (2, 2)])), with(cards([(3, 4), (0, 2)])));
+    }
+
+    #[test]
+    fn full_house_and_flush_use_the_best_five_cards() {
+        // Two sets of trips: the higher plays as trips, the lower as the pair
+        let two_trips = cards([(0, 9), (1, 9), (2, 9), (0, 4), (1, 4), (2, 4), (3, 13)]);
+        let over_pair = cards([(0, 9), (1, 9), (2, 9), (0, 5), (1, 5), (2, 4), (3, 13)]);
+        assert_eq!(
+            category(HandRank::evaluate(&two_trips)),
+            hand_category::FULL_HOUSE
+        );
+        assert!(HandRank::evaluate(&over_pair) > HandRank::evaluate(&two_trips));
+
+        // Six suited cards: only the top five count
+        let six_flush = cards([(2, 2), (2, 5), (2, 7), (2, 9), (2, 11), (2, 13), (0, 14)]);
+        let five_flush = cards([(2, 3), (2, 5), (2, 7), (2, 9), (2, 11), (2, 13), (0, 14)]);
+        assert_eq!(
+            HandRank::evaluate(&six_flush),
+            HandRank::evaluate(&five_flush)
+        );
+    }
+}
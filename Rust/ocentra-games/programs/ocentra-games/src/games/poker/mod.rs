@@ -0,0 +1,9 @@
+// Poker (No-Limit Texas Hold'em) game-specific implementation
+
+pub mod actions;
+pub mod hand_rank;
+pub mod rules;
+pub mod state;
+pub mod validation;
+
+pub use rules::PokerRules;
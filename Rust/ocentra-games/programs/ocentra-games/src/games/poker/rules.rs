@@ -0,0 +1,137 @@
+use crate::games::trait_def::GameRules;
+use crate::state::Match;
+use anchor_lang::prelude::*;
+
+/// Poker action type constants
+pub mod poker_action {
+    pub const FOLD: u8 = 0; // Fold (or muck at showdown)
+    pub const CHECK: u8 = 1; // Check (nothing to call)
+    pub const CALL: u8 = 2; // Call the current bet (capped at stack = all-in)
+    pub const RAISE: u8 = 3; // Bet/raise to total street bet - payload: [amount u32 LE]
+    pub const ALL_IN: u8 = 4; // Commit the whole stack
+    pub const DEAL_BOARD: u8 = 5; // Deal next street, then hole cards - payload: [dealer key(32)]
+    pub const REVEAL_HAND: u8 = 6; // Showdown reveal - payload: [(suit, value) × 2 | salt(32)]
+}
+
+/// Poker (No-Limit Texas Hold'em) constants
+/// A poker match is a single hand: blinds are posted at start_match and the match ends
+/// when one player remains or all live players have revealed at showdown.
+/// Cards come from the dealer's deck (card_games::deck): the match authority commits a hash
+/// chain with commit_deck, then reveals a key per street - flop, turn, river, hole cards.
+pub struct PokerRules;
+
+impl PokerRules {
+    pub const STARTING_STACK: u32 = 10_000; // Chips per seat
+    pub const SMALL_BLIND: u32 = 50;
+    pub const BIG_BLIND: u32 = 100;
+    pub const REVEAL_SALT_SIZE: usize = 32; // Salt appended to hole cards in the committed hash
+}
+
+impl GameRules for PokerRules {
+    const GAME_TYPE: u8 = 1;
+    const MAX_HAND_SIZE: u8 = 2; // Two hole cards
+    const MAX_ACTION_TYPE: u8 = 6; // 0=fold, 1=check, 2=call, 3=raise, 4=all_in, 5=deal_board, 6=reveal_hand
+
+    fn validate_action(
+        match_account: &Match,
+        player_index: usize,
+        action_type: u8,
+        payload: &[u8],
+    ) -> Result<()> {
+        crate::games::poker::validation::validate_poker_action(
+            match_account,
+            player_index,
+            action_type,
+            payload,
+        )
+    }
+
+    fn apply_action_state(
+        match_account: &mut Match,
+        player_index: usize,
+        action_type: u8,
+        payload: &[u8],
+        advance_turn: bool,
+    ) -> Result<()> {
+        crate::games::poker::actions::apply_poker_action(
+            match_account,
+            player_index,
+            action_type,
+            payload,
+            advance_turn,
+        )
+    }
+
+    fn on_match_start(match_account: &mut Match) -> Result<()> {
+        crate::games::poker::actions::start_poker_hand(match_account)
+    }
+
+    fn on_replay_start(match_account: &mut Match) -> Result<()> {
+        // start_poker_hand rebuilds the hand around the committed deck - keep the commitment
+        crate::games::poker::actions::start_poker_hand(match_account)
+    }
+
+    fn on_deck_commit(match_account: &mut Match, deck_commitment: [u8; 32]) -> Result<()> {
+        crate::games::poker::actions::commit_poker_deck(match_account, deck_commitment)
+    }
+
+    // advance_turn_after_batch: default no-op - the turn advances while applying each action
+
+    fn awaiting_dealer(match_account: &Match) -> bool {
+        crate::games::poker::state::PokerState::load(match_account).awaiting_board == 1
+    }
+
+    fn stalled_player(match_account: &Match) -> Option<usize> {
+        // Between betting rounds the dealer owes the next key - no seat is stalling
+        if Self::awaiting_dealer(match_account) {
+            None
+        } else {
+            Some(match_account.current_player as usize)
+        }
+    }
+
+    fn skip_turn(match_account: &mut Match, player_index: usize) -> Result<()> {
+        // A seat can't pass its betting turn without acting - a skip checks or folds
+        let action = Self::timeout_action(match_account, player_index)
+            .ok_or(crate::error::GameError::InvalidAction)?;
+        crate::games::poker::actions::apply_poker_action(
+            match_account,
+            player_index,
+            action,
+            &[],
+            true,
+        )
+    }
+
+    fn timeout_action(match_account: &Match, player_index: usize) -> Option<u8> {
+        // Check when nothing is owed, otherwise fold (muck at showdown)
+        let state = crate::games::poker::state::PokerState::load(match_account);
+        if state.street < crate::games::poker::state::street::SHOWDOWN
+            && state.to_call(player_index) == 0
+        {
+            Some(poker_action::CHECK)
+        } else {
+            Some(poker_action::FOLD)
+        }
+    }
+
+    fn on_player_forfeit(match_account: &mut Match, player_index: usize) -> Result<()> {
+        crate::games::poker::actions::forfeit_poker_seat(match_account, player_index);
+        Ok(())
+    }
+
+    fn score_match(match_account: &Match) -> [i32; 10] {
+        // Net chips won or lost (uncollected contributions count as still owned)
+        let state = crate::games::poker::state::PokerState::load(match_account);
+        let mut scores = [0i32; 10];
+        for (i, score) in scores
+            .iter_mut()
+            .enumerate()
+            .take(match_account.player_count as usize)
+        {
+            let chips = state.stacks[i] as i64 + state.contributions[i] as i64;
+            *score = (chips - Self::STARTING_STACK as i64) as i32;
+        }
+        scores
+    }
+}
@@ -0,0 +1,148 @@
+use crate::card_games::deck::Deck;
+use crate::state::Match;
+
+/// Betting street constants
+pub mod street {
+    pub const PREFLOP: u8 = 0;
+    pub const FLOP: u8 = 1;
+    pub const TURN: u8 = 2;
+    pub const RIVER: u8 = 3;
+    pub const SHOWDOWN: u8 = 4;
+}
+
+/// Poker betting state - stored in Match.game_state (280 of 512 bytes).
+/// Chip amounts are in-game chips (u32), not lamports.
+/// Per-player flags are u16 bitmasks (bit N = seat N).
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct PokerState {
+    pub stacks: [u32; 10],        // Chips behind (not yet committed to the pot)
+    pub street_bets: [u32; 10],   // Chips committed on the current street
+    pub contributions: [u32; 10], // Chips committed over the whole hand (pot share / side pots)
+    pub hand_ranks: [u32; 10],    // Showdown rank (0 = not revealed, higher wins)
+    pub current_bet: u32,         // Highest street bet to match
+    pub min_raise: u32,           // Minimum raise increment (last full raise size)
+    pub small_blind: u32,
+    pub big_blind: u32,
+    pub folded: u16,               // Bitmask: folded (or mucked) seats
+    pub all_in: u16,               // Bitmask: seats with no chips behind
+    pub acted: u16,                // Bitmask: seats that acted since the last full raise
+    pub revealed: u16,             // Bitmask: seats that revealed at showdown
+    pub street: u8,                // street::PREFLOP..SHOWDOWN
+    pub button: u8,                // Dealer button seat
+    pub board_count: u8,           // Community cards dealt (0, 3, 4, 5)
+    pub awaiting_board: u8,        // 1 = betting round closed, next street's cards must be dealt
+    pub board: [u8; 5],            // Community cards (packed, see card_games::cards)
+    pub hole_cards: [[u8; 2]; 10], // Revealed hole cards (packed, valid when revealed bit set)
+    pub deck_commitment: [u8; 32], // Dealer hash chain head, set before start_match (commit_deck)
+    pub deck_key: [u8; 32],        // Last dealer key revealed (all zeros = none yet)
+    pub _padding: [u8; 3],         // Explicit padding to 4-byte alignment (277 + 3 = 280)
+}
+
+// Layout must fit in Match.game_state and match SIZE (no implicit padding)
+const _: () = assert!(
+    PokerState::SIZE == core::mem::size_of::<PokerState>()
+        && PokerState::SIZE <= Match::GAME_STATE_SIZE
+);
+
+impl PokerState {
+    pub const SIZE: usize = (4 * 10) * 4 + // stacks + street_bets + contributions + hand_ranks
+        4 * 4 +                            // current_bet + min_raise + small_blind + big_blind
+        2 * 4 +                            // folded + all_in + acted + revealed
+        1 + 1 + 1 + 1 +                    // street + button + board_count + awaiting_board
+        5 + 20 +                           // board + hole_cards
+        32 + 32 +                          // deck_commitment + deck_key
+        3; // _padding
+
+    /// Read poker state from the match's game state blob
+    pub fn load(match_account: &Match) -> Self {
+        bytemuck::pod_read_unaligned(&match_account.game_state[..Self::SIZE])
+    }
+
+    /// Write poker state back to the match's game state blob
+    pub fn store(&self, match_account: &mut Match) {
+        match_account.game_state[..Self::SIZE].copy_from_slice(bytemuck::bytes_of(self));
+    }
+
+    // Bitmask helpers
+    pub fn is_folded(&self, seat: usize) -> bool {
+        self.folded & (1 << seat) != 0
+    }
+
+    pub fn is_all_in(&self, seat: usize) -> bool {
+        self.all_in & (1 << seat) != 0
+    }
+
+    pub fn has_acted(&self, seat: usize) -> bool {
+        self.acted & (1 << seat) != 0
+    }
+
+    pub fn is_revealed(&self, seat: usize) -> bool {
+        self.revealed & (1 << seat) != 0
+    }
+
+    /// Seat is still contesting the pot
+    pub fn is_live(&self, seat: usize) -> bool {
+        !self.is_folded(seat)
+    }
+
+    /// Seat can still make betting decisions (live with chips behind)
+    pub fn can_act(&self, seat: usize) -> bool {
+        self.is_live(seat) && !self.is_all_in(seat)
+    }
+
+    pub fn live_count(&self, player_count: usize) -> usize {
+        (0..player_count).filter(|&i| self.is_live(i)).count()
+    }
+
+    pub fn actor_count(&self, player_count: usize) -> usize {
+        (0..player_count).filter(|&i| self.can_act(i)).count()
+    }
+
+    /// Chips needed for a seat to match the current bet
+    pub fn to_call(&self, seat: usize) -> u32 {
+        self.current_bet.saturating_sub(self.street_bets[seat])
+    }
+
+    /// Next seat clockwise from `from` that can still act (None if nobody can)
+    pub fn next_actor(&self, from: usize, player_count: usize) -> Option<usize> {
+        (1..=player_count)
+            .map(|offset| (from + offset) % player_count)
+            .find(|&seat| self.can_act(seat))
+    }
+
+    /// Move chips from a seat's stack into the pot (capped at the stack, marks all-in)
+    pub fn commit_chips(&mut self, seat: usize, amount: u32) -> u32 {
+        let paid = amount.min(self.stacks[seat]);
+        self.stacks[seat] -= paid;
+        self.street_bets[seat] += paid;
+        self.contributions[seat] += paid;
+        if self.stacks[seat] == 0 {
+            self.all_in |= 1 << seat;
+        }
+        paid
+    }
+
+    /// Key the next dealer key must hash to (the commitment until the flop is dealt)
+    pub fn previous_deck_key(&self) -> &[u8; 32] {
+        if self.deck_key == [0u8; 32] {
+            &self.deck_commitment
+        } else {
+            &self.deck_key
+        }
+    }
+
+    /// Hole cards the deck dealt a seat. Valid at showdown, once the last dealer key is out:
+    /// hole cards are drawn after the board, from the cards it left.
+    /// Seats are dealt in order, two cards each.
+    pub fn dealt_hole_cards(&self, seat: usize) -> [u8; 2] {
+        let mut hole_cards = [0u8; 20];
+        Deck::draw(&self.deck_key, &self.board, &mut hole_cards[..seat * 2 + 2]);
+        [hole_cards[seat * 2], hole_cards[seat * 2 + 1]]
+    }
+
+    /// Total chips in the pot
+    pub fn pot(&self) -> u32 {
+        self.contributions.iter().sum()
+    }
+}
@@ -0,0 +1,163 @@
+use crate::card_games::cards::Cards;
+use crate::card_games::deck::Deck;
+use crate::common::validation_base::CommonValidation;
+use crate::error::GameError;
+use crate::games::poker::rules::{poker_action, PokerRules};
+use crate::games::poker::state::{street, PokerState};
+use crate::games::trait_def::GameRules;
+use crate::state::Match;
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+/// Poker-specific validation logic
+pub fn validate_poker_action(
+    match_account: &Match,
+    player_index: usize,
+    action_type: u8,
+    payload: &[u8],
+) -> Result<()> {
+    CommonValidation::validate_phase(match_account, 1)?;
+    let state = PokerState::load(match_account);
+
+    match action_type {
+        poker_action::FOLD if state.street == street::SHOWDOWN => {
+            // Muck: a live player may give up the pot instead of revealing
+            require!(
+                state.is_live(player_index) && !state.is_revealed(player_index),
+                GameError::InvalidAction
+            );
+            Ok(())
+        }
+        poker_action::FOLD
+        | poker_action::CHECK
+        | poker_action::CALL
+        | poker_action::RAISE
+        | poker_action::ALL_IN => {
+            validate_betting_action(match_account, &state, player_index, action_type, payload)
+        }
+        poker_action::DEAL_BOARD => validate_deal_board(&state, payload),
+        poker_action::REVEAL_HAND => {
+            validate_reveal_hand(match_account, &state, player_index, payload)
+        }
+        _ => Err(GameError::InvalidAction.into()),
+    }
+}
+
+fn validate_betting_action(
+    match_account: &Match,
+    state: &PokerState,
+    player_index: usize,
+    action_type: u8,
+    payload: &[u8],
+) -> Result<()> {
+    // Betting is only open on a street whose cards have been dealt
+    require!(
+        state.street < street::SHOWDOWN && state.awaiting_board == 0,
+        GameError::InvalidPhase
+    );
+    CommonValidation::validate_player_turn(match_account, player_index)?;
+    require!(state.can_act(player_index), GameError::InvalidAction);
+
+    let to_call = state.to_call(player_index);
+    match action_type {
+        poker_action::CHECK => require!(to_call == 0, GameError::InvalidAction),
+        poker_action::CALL => require!(to_call > 0, GameError::InvalidAction),
+        poker_action::RAISE => {
+            let amount = read_bet_amount(payload)?;
+            let player_count = match_account.player_count as usize;
+
+            // Raising is pointless (and not allowed) when nobody else can respond
+            require!(
+                state.actor_count(player_count) > 1,
+                GameError::InvalidAction
+            );
+
+            // Raise-to amount must be a full raise and affordable (short all-ins use ALL_IN)
+            require!(amount > state.current_bet, GameError::InvalidBetAmount);
+            require!(
+                amount - state.current_bet >= state.min_raise,
+                GameError::InvalidBetAmount
+            );
+            require!(
+                amount - state.street_bets[player_index] <= state.stacks[player_index],
+                GameError::InvalidBetAmount
+            );
+        }
+        _ => {} // Fold and all-in are always available to the player in turn
+    }
+    Ok(())
+}
+
+fn validate_deal_board(state: &PokerState, payload: &[u8]) -> Result<()> {
+    // Any seated player may submit the dealer's next key once the betting round has closed
+    // (like CLAIM's floor card reveal) - the key alone decides the cards
+    require!(state.awaiting_board == 1, GameError::InvalidPhase);
+    require!(state.street <= street::RIVER, GameError::InvalidPhase);
+
+    let key = read_deck_key(payload)?;
+    require!(
+        Deck::verify_key(&key, state.previous_deck_key()),
+        GameError::DeckKeyMismatch
+    );
+    Ok(())
+}
+
+fn validate_reveal_hand(
+    match_account: &Match,
+    state: &PokerState,
+    player_index: usize,
+    payload: &[u8],
+) -> Result<()> {
+    require!(state.street == street::SHOWDOWN, GameError::InvalidPhase);
+    require!(
+        state.is_live(player_index) && !state.is_revealed(player_index),
+        GameError::InvalidAction
+    );
+
+    // Payload: hole cards as (suit, value) pairs followed by the commitment salt
+    let cards_len = PokerRules::MAX_HAND_SIZE as usize * 2;
+    require!(
+        payload.len() >= cards_len + PokerRules::REVEAL_SALT_SIZE,
+        GameError::InvalidPayload
+    );
+    let hole = Cards::parse_pairs::<2>(payload)?;
+
+    // Revealed cards must match the hash committed during the Dealing phase
+    let committed = match_account
+        .get_committed_hand_hash(player_index)
+        .ok_or(GameError::HandNotCommitted)?;
+    let revealed = hashv(&[
+        &payload[..cards_len],
+        &payload[cards_len..cards_len + PokerRules::REVEAL_SALT_SIZE],
+    ]);
+    require!(
+        revealed.to_bytes() == committed,
+        GameError::HandRevealMismatch
+    );
+
+    // ...and be the cards the deck dealt this seat (in either order)
+    let dealt = state.dealt_hole_cards(player_index);
+    require!(
+        hole == dealt || hole == [dealt[1], dealt[0]],
+        GameError::HandRevealMismatch
+    );
+    Ok(())
+}
+
+/// Read a dealer deck key from the payload
+pub fn read_deck_key(payload: &[u8]) -> Result<[u8; 32]> {
+    require!(payload.len() >= 32, GameError::InvalidPayload);
+    let key: [u8; 32] = payload[0..32]
+        .try_into()
+        .map_err(|_| GameError::InvalidPayload)?;
+    Ok(key)
+}
+
+/// Read a little-endian u32 raise-to amount from the payload
+pub fn read_bet_amount(payload: &[u8]) -> Result<u32> {
+    require!(payload.len() >= 4, GameError::InvalidPayload);
+    let bytes: [u8; 4] = payload[0..4]
+        .try_into()
+        .map_err(|_| GameError::InvalidPayload)?;
+    Ok(u32::from_le_bytes(bytes))
+}
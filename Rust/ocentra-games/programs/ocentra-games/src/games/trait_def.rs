@@ -1,17 +1,30 @@
+use crate::error::GameError;
 use crate::state::Match;
 use anchor_lang::prelude::*;
 
-/// Trait for game-specific rules and validation
+/// Trait for game-specific rules and validation.
+/// Implemented by a unit struct per game and registered in `games::game_table::GAME_TABLE`.
+/// All hooks are associated functions (no `&self`) so the table holds plain fn pointers -
+/// no trait objects, no allocation.
 pub trait GameRules {
-    /// Maximum hand size for this game
-    fn max_hand_size(&self) -> u8;
+    /// Match.game_type / GameDefinition.game_id this implementation handles
+    const GAME_TYPE: u8;
+
+    /// Maximum hand size for this game (0 = game has no hands)
+    const MAX_HAND_SIZE: u8;
 
     /// Maximum action type value for this game
-    fn max_action_type(&self) -> u8;
+    const MAX_ACTION_TYPE: u8;
+
+    /// Whether play is turn-based (false = simultaneous play, the turn timer never applies)
+    const HAS_TURNS: bool = true;
+
+    /// Whether `score_match` measures who actually won, so the game can back paid matches and
+    /// tournaments (false = scores only rank free play)
+    const HAS_ON_CHAIN_SCORING: bool = true;
 
     /// Validate game-specific action
     fn validate_action(
-        &self,
         match_account: &Match,
         player_index: usize,
         action_type: u8,
@@ -22,11 +35,73 @@ pub trait GameRules {
     /// `advance_turn`: if true, advance turn after turn-based actions (for single moves)
     ///                 if false, don't advance turn (for batch moves)
     fn apply_action_state(
-        &self,
         match_account: &mut Match,
         player_index: usize,
         action_type: u8,
         payload: &[u8],
         advance_turn: bool,
     ) -> Result<()>;
+
+    /// Initialize game-specific state when the match enters the Playing phase
+    fn on_match_start(_match_account: &mut Match) -> Result<()> {
+        Ok(())
+    }
+
+    /// Rebuild the start-of-play state on a scratch match for score replay.
+    /// Called after the generic Match fields are reset; the default re-runs `on_match_start`
+    /// on a cleared game state. Override when the start state can't be rebuilt that way.
+    fn on_replay_start(match_account: &mut Match) -> Result<()> {
+        match_account.game_state = [0u8; Match::GAME_STATE_SIZE];
+        Self::on_match_start(match_account)
+    }
+
+    /// Record the dealer's deck commitment during the Dealing phase (commit_deck).
+    /// Games that don't deal from a dealer deck reject it.
+    fn on_deck_commit(_match_account: &mut Match, _deck_commitment: [u8; 32]) -> Result<()> {
+        Err(GameError::InvalidAction.into())
+    }
+
+    /// Advance the turn once after a batch of moves (batch moves apply with advance_turn = false)
+    fn advance_turn_after_batch(
+        _match_account: &mut Match,
+        _player_index: usize,
+        _last_action: u8,
+    ) {
+    }
+
+    /// Whether play is waiting on the dealer's next deck key rather than on a seat
+    /// (games dealt from a dealer deck - cancel_match's dealer deadline applies)
+    fn awaiting_dealer(_match_account: &Match) -> bool {
+        false
+    }
+
+    /// Seat the turn timer is waiting on (None = no seat is holding up play, e.g. Poker
+    /// between betting rounds while any seat may deal the next street)
+    fn stalled_player(match_account: &Match) -> Option<usize> {
+        Some(match_account.current_player as usize)
+    }
+
+    /// Pass a stalled player's turn (SKIP timeout policy, or AUTO_DECLINE without a default
+    /// action). The default hands the turn to the next seat; games whose turn order is more
+    /// than a plain rotation override it.
+    fn skip_turn(match_account: &mut Match, player_index: usize) -> Result<()> {
+        match_account.current_player =
+            ((player_index + 1) % match_account.player_count as usize) as u8;
+        Ok(())
+    }
+
+    /// Action played for a stalled player under the AUTO_DECLINE timeout policy
+    /// (None = game has no default action, the turn is skipped instead)
+    fn timeout_action(_match_account: &Match, _player_index: usize) -> Option<u8> {
+        None
+    }
+
+    /// Remove a forfeited player from game-specific state (e.g. Poker folds the seat).
+    /// Turn order skips forfeited seats generically after this hook runs.
+    fn on_player_forfeit(_match_account: &mut Match, _player_index: usize) -> Result<()> {
+        Ok(())
+    }
+
+    /// End-of-match scores per player slot (index = player index)
+    fn score_match(match_account: &Match) -> [i32; 10];
 }
@@ -0,0 +1,280 @@
+use crate::error::GameError;
+use crate::games::word_search::board::{Board, WORD_LIST};
+use crate::games::word_search::rules::{word_search_action, WordSearchRules};
+use crate::games::word_search::state::WordSearchState;
+use crate::state::Match;
+use anchor_lang::prelude::*;
+
+/// Generate the board at start_match and clear any pending claims
+pub fn start_word_search(match_account: &mut Match) -> Result<()> {
+    let mut state: WordSearchState = bytemuck::Zeroable::zeroed();
+    Board::generate(match_account.seed, &mut state);
+    state.store(match_account);
+
+    // committed_hand_hashes hold pending claim commitments for this game
+    match_account.committed_hand_hashes = [0u8; 320];
+    Ok(())
+}
+
+/// Word search action state updates
+/// `advance_turn` is ignored: all players search simultaneously
+pub fn apply_word_search_action(
+    match_account: &mut Match,
+    player_index: usize,
+    action_type: u8,
+    payload: &[u8],
+    _advance_turn: bool,
+) -> Result<()> {
+    let mut state = WordSearchState::load(match_account);
+
+    match action_type {
+        word_search_action::COMMIT_CLAIM => {
+            let mut claim_hash = [0u8; 32];
+            claim_hash.copy_from_slice(&payload[..32]);
+            match_account.set_committed_hand_hash(player_index, claim_hash);
+            // Moves are sequenced by move_count - an earlier commitment has a lower sequence
+            state.commit_seqs[player_index] = match_account.move_count;
+        }
+        word_search_action::REVEAL_CLAIM => {
+            let word =
+                Board::find_placed_word(&state, payload[0], payload[1], payload[2], payload[3])
+                    .ok_or(GameError::WordNotOnBoard)?;
+            award_word(&mut state, word, player_index);
+            match_account.set_committed_hand_hash(player_index, [0u8; 32]);
+        }
+        word_search_action::FINISH => {
+            state.finished |= 1 << player_index;
+        }
+        _ => return Err(GameError::InvalidAction.into()),
+    }
+
+    // Match ends when every word is found or every player is done
+    let all_finished = (0..match_account.player_count as usize).all(|i| state.has_finished(i));
+    if state.found_count >= state.word_count || all_finished {
+        match_account.phase = 2; // Ended - ended_at set by instruction handler with clock
+    }
+
+    state.store(match_account);
+    Ok(())
+}
+
+/// Score a found word. The earliest commitment owns the word: a later reveal backed by an
+/// earlier commitment takes the word (and its points) from the current finder.
+fn award_word(state: &mut WordSearchState, word: usize, player_index: usize) {
+    let seq = state.commit_seqs[player_index];
+    let points = WORD_LIST[state.placed[word].word_index as usize].len() as u16
+        * WordSearchRules::POINTS_PER_LETTER;
+
+    if !state.is_found(word) {
+        state.found_count += 1;
+    } else if seq < state.found_seqs[word] {
+        let previous = state.finders[word] as usize;
+        state.scores[previous] = state.scores[previous].saturating_sub(points);
+    } else {
+        return; // Already found by an earlier claim - no points
+    }
+
+    state.finders[word] = player_index as u8;
+    state.found_seqs[word] = seq;
+    state.scores[player_index] = state.scores[player_index].saturating_add(points);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::games::trait_def::GameRules;
+    use crate::games::word_search::validation::claim_hash;
+    use crate::games::{apply_action_state, resolve_turn_timeout, start_game, validate_move};
+    use crate::state::{game_phase, timeout_policy};
+
+    fn started_match() -> Box<Match> {
+        let mut match_account: Box<Match> = Box::new(bytemuck::Zeroable::zeroed());
+        match_account.game_type = WordSearchRules::GAME_TYPE;
+        match_account.player_count = 3;
+        match_account.phase = game_phase::PLAYING;
+        match_account.seed = 7;
+        start_game(&mut match_account).unwrap();
+        match_account
+    }
+
+    /// Submit a move as submit_move does: validate, apply, then bump the move sequence
+    fn submit(
+        match_account: &mut Match,
+        seat: usize,
+        action_type: u8,
+        payload: &[u8],
+    ) -> Result<()> {
+        validate_move(match_account, seat, action_type, payload)?;
+        apply_action_state(match_account, seat, action_type, payload, true)?;
+        match_account.move_count += 1;
+        Ok(())
+    }
+
+    /// Reveal payload (row, col, dir, len | salt) for a hidden word
+    fn claim(match_account: &Match, word: usize, salt: u8) -> Vec<u8> {
+        let state = WordSearchState::load(match_account);
+        let placed = state.placed[word];
+        let len = WORD_LIST[placed.word_index as usize].len() as u8;
+        let mut payload = vec![placed.row, placed.col, placed.dir, len];
+        payload.extend_from_slice(&[salt; WordSearchRules::CLAIM_SALT_SIZE]);
+        payload
+    }
+
+    fn word_points(match_account: &Match, word: usize) -> u16 {
+        let state = WordSearchState::load(match_account);
+        WORD_LIST[state.placed[word].word_index as usize].len() as u16
+            * WordSearchRules::POINTS_PER_LETTER
+    }
+
+    fn commit(match_account: &mut Match, seat: usize, reveal: &[u8]) {
+        let hash = claim_hash(seat, reveal);
+        submit(match_account, seat, word_search_action::COMMIT_CLAIM, &hash).unwrap();
+    }
+
+    fn reveal(match_account: &mut Match, seat: usize, reveal: &[u8]) {
+        submit(
+            match_account,
+            seat,
+            word_search_action::REVEAL_CLAIM,
+            reveal,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn committed_claim_scores_on_reveal() {
+        let mut match_account = started_match();
+        let payload = claim(&match_account, 0, 1);
+
+        commit(&mut match_account, 1, &payload);
+        reveal(&mut match_account, 1, &payload);
+
+        let state = WordSearchState::load(&match_account);
+        assert_eq!(state.finders[0], 1);
+        assert_eq!(state.found_count, 1);
+        assert_eq!(state.scores[1], word_points(&match_account, 0));
+        assert!(match_account.get_committed_hand_hash(1).is_none());
+    }
+
+    #[test]
+    fn reveal_must_match_the_seats_own_commitment() {
+        let mut match_account = started_match();
+        let payload = claim(&match_account, 0, 1);
+
+        // No commitment yet
+        let err = submit(
+            &mut match_account,
+            0,
+            word_search_action::REVEAL_CLAIM,
+            &payload,
+        );
+        assert_eq!(err.unwrap_err(), GameError::ClaimNotCommitted.into());
+
+        // Seat 2 copies seat 0's commitment - the hash binds the seat, so it can't reveal it
+        let copied = claim_hash(0, &payload);
+        submit(
+            &mut match_account,
+            2,
+            word_search_action::COMMIT_CLAIM,
+            &copied,
+        )
+        .unwrap();
+        let err = submit(
+            &mut match_account,
+            2,
+            word_search_action::REVEAL_CLAIM,
+            &payload,
+        );
+        assert_eq!(err.unwrap_err(), GameError::ClaimRevealMismatch.into());
+
+        // A different salt doesn't open the commitment either
+        commit(&mut match_account, 0, &payload);
+        let wrong_salt = claim(&match_account, 0, 2);
+        let err = submit(
+            &mut match_account,
+            0,
+            word_search_action::REVEAL_CLAIM,
+            &wrong_salt,
+        );
+        assert_eq!(err.unwrap_err(), GameError::ClaimRevealMismatch.into());
+
+        // One pending claim per seat
+        let next = claim_hash(0, &claim(&match_account, 1, 3));
+        let err = submit(
+            &mut match_account,
+            0,
+            word_search_action::COMMIT_CLAIM,
+            &next,
+        );
+        assert_eq!(err.unwrap_err(), GameError::InvalidAction.into());
+    }
+
+    #[test]
+    fn earliest_commit_wins_even_when_revealed_last() {
+        let mut match_account = started_match();
+        let early = claim(&match_account, 0, 1);
+        let late = claim(&match_account, 0, 2);
+        let points = word_points(&match_account, 0);
+
+        commit(&mut match_account, 0, &early);
+        commit(&mut match_account, 1, &late);
+
+        // The later commitment reveals first and holds the word for now
+        reveal(&mut match_account, 1, &late);
+        let state = WordSearchState::load(&match_account);
+        assert_eq!(state.finders[0], 1);
+        assert_eq!(state.scores[1], points);
+
+        // The earlier commitment takes the word and its points
+        reveal(&mut match_account, 0, &early);
+        let state = WordSearchState::load(&match_account);
+        assert_eq!(state.finders[0], 0);
+        assert_eq!(state.found_seqs[0], 0);
+        assert_eq!(state.scores[0], points);
+        assert_eq!(state.scores[1], 0);
+        assert_eq!(state.found_count, 1);
+    }
+
+    #[test]
+    fn later_commit_scores_nothing_for_a_found_word() {
+        let mut match_account = started_match();
+        let early = claim(&match_account, 0, 1);
+        let late = claim(&match_account, 0, 2);
+
+        commit(&mut match_account, 2, &early);
+        reveal(&mut match_account, 2, &early);
+        commit(&mut match_account, 1, &late);
+        reveal(&mut match_account, 1, &late);
+
+        let state = WordSearchState::load(&match_account);
+        assert_eq!(state.finders[0], 2);
+        assert_eq!(state.scores[1], 0);
+        assert_eq!(state.scores[2], word_points(&match_account, 0));
+    }
+
+    #[test]
+    fn turn_timeouts_never_touch_pending_claims() {
+        let mut match_account = started_match();
+        let payload = claim(&match_account, 0, 1);
+        commit(&mut match_account, 0, &payload);
+        let before = *match_account;
+
+        // No seat is ever "in turn" - seat 0 (current_player stays 0) can't be skipped or forfeited
+        for policy in [
+            timeout_policy::AUTO_DECLINE,
+            timeout_policy::SKIP,
+            timeout_policy::FORFEIT,
+        ] {
+            let err = resolve_turn_timeout(&mut match_account, policy).unwrap_err();
+            assert_eq!(err, GameError::TurnTimerDisabled.into());
+        }
+        assert_eq!(match_account.forfeited_players, before.forfeited_players);
+        assert_eq!(match_account.game_state, before.game_state);
+
+        // The pending claim still reveals and keeps its commit sequence
+        reveal(&mut match_account, 0, &payload);
+        let state = WordSearchState::load(&match_account);
+        assert_eq!(state.finders[0], 0);
+        assert_eq!(state.scores[0], word_points(&match_account, 0));
+    }
+}
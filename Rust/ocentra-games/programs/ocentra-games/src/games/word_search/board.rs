@@ -0,0 +1,186 @@
+use crate::games::word_search::rules::WordSearchRules;
+use crate::games::word_search::state::{PlacedWord, WordSearchState};
+use solana_sha256_hasher::hashv;
+
+/// Words that can be hidden on a board (uppercase ASCII, at most GRID_SIZE letters)
+pub const WORD_LIST: [&[u8]; 40] = [
+    b"SOLANA",
+    b"TOKEN",
+    b"CHAIN",
+    b"BLOCK",
+    b"LEDGER",
+    b"WALLET",
+    b"ORACLE",
+    b"ANCHOR",
+    b"PUZZLE",
+    b"SEARCH",
+    b"LETTER",
+    b"MATCH",
+    b"PLAYER",
+    b"SCORE",
+    b"WINNER",
+    b"REWARD",
+    b"STAKE",
+    b"VOTE",
+    b"PRIZE",
+    b"GAME",
+    b"QUEST",
+    b"RIDDLE",
+    b"CIPHER",
+    b"VECTOR",
+    b"SIGNAL",
+    b"ROCKET",
+    b"PLANET",
+    b"GALAXY",
+    b"COMET",
+    b"ORBIT",
+    b"FOREST",
+    b"RIVER",
+    b"MOUNTAIN",
+    b"CASTLE",
+    b"DRAGON",
+    b"KNIGHT",
+    b"WIZARD",
+    b"POTION",
+    b"SHIELD",
+    b"SWORD",
+];
+
+/// Row/column steps per direction: 0=E, 1=SE, 2=S, 3=SW, 4=W, 5=NW, 6=N, 7=NE
+/// Opposite direction of `d` is `(d + 4) % 8`
+pub const DIRECTIONS: [(i8, i8); 8] = [
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+];
+
+/// Filler letters weighted roughly by English frequency
+const FILLER: &[u8] = b"EEEEEEEEEEEETTTTTTTTTAAAAAAAAOOOOOOOIIIIIIINNNNNNNSSSSSSHHHHHHRRRRRRDDDDLLLLCCCUUUMMMWWFFGGYYPPBVKJXQZ";
+
+/// Tries per word to find a position that fits before skipping it
+const PLACEMENT_ATTEMPTS: usize = 50;
+
+/// Deterministic board generation - clients regenerate the identical board from Match.seed
+pub struct Board;
+
+impl Board {
+    /// Build the grid and hidden word placements for a match seed
+    pub fn generate(seed: u32, state: &mut WordSearchState) {
+        let mut rng = BoardRng::from_match_seed(seed);
+        let size = WordSearchRules::GRID_SIZE;
+
+        state.grid = [0u8; 100]; // 0 = empty cell until filled
+        state.finders = [WordSearchState::NOT_FOUND; 8];
+        state.word_count = 0;
+
+        let mut used_words: u64 = 0;
+        for _ in 0..WordSearchRules::MAX_WORDS {
+            // Pick a word not used yet (linear probe keeps it deterministic and bounded)
+            let mut word_index = rng.below(WORD_LIST.len());
+            while used_words & (1 << word_index) != 0 {
+                word_index = (word_index + 1) % WORD_LIST.len();
+            }
+            used_words |= 1 << word_index;
+            let word = WORD_LIST[word_index];
+
+            for _ in 0..PLACEMENT_ATTEMPTS {
+                let dir = rng.below(DIRECTIONS.len()) as u8;
+                let row = rng.below(size) as u8;
+                let col = rng.below(size) as u8;
+                if Self::fits(&state.grid, word, row, col, dir) {
+                    for (step, &letter) in word.iter().enumerate() {
+                        // fits() guarantees every cell is on the board
+                        if let Some(cell) = Self::cell(row, col, dir, step) {
+                            state.grid[cell] = letter;
+                        }
+                    }
+                    state.placed[state.word_count as usize] = PlacedWord {
+                        word_index: word_index as u8,
+                        row,
+                        col,
+                        dir,
+                    };
+                    state.word_count += 1;
+                    break;
+                }
+            }
+        }
+
+        // Fill the remaining cells with random letters
+        for cell in state.grid.iter_mut().filter(|c| **c == 0) {
+            *cell = FILLER[rng.below(FILLER.len())];
+        }
+    }
+
+    /// Grid index of the cell `step` letters from (row, col) in `dir` (None if off the board)
+    pub fn cell(row: u8, col: u8, dir: u8, step: usize) -> Option<usize> {
+        let (dr, dc) = *DIRECTIONS.get(dir as usize)?;
+        let size = WordSearchRules::GRID_SIZE as i32;
+        let r = row as i32 + dr as i32 * step as i32;
+        let c = col as i32 + dc as i32 * step as i32;
+        if r < 0 || c < 0 || r >= size || c >= size {
+            return None;
+        }
+        Some((r * size + c) as usize)
+    }
+
+    /// Find the hidden word exactly covered by a claimed path (either reading direction)
+    pub fn find_placed_word(
+        state: &WordSearchState,
+        row: u8,
+        col: u8,
+        dir: u8,
+        len: u8,
+    ) -> Option<usize> {
+        (0..state.word_count as usize).find(|&i| {
+            let placed = state.placed[i];
+            let word_len = WORD_LIST[placed.word_index as usize].len();
+            if len as usize != word_len {
+                return false;
+            }
+            let forward = row == placed.row && col == placed.col && dir == placed.dir;
+            // Reverse reading starts at the last letter and walks the opposite direction
+            let reverse = dir == (placed.dir + 4) % 8
+                && Self::cell(placed.row, placed.col, placed.dir, word_len - 1)
+                    == Self::cell(row, col, 0, 0);
+            forward || reverse
+        })
+    }
+
+    fn fits(grid: &[u8; 100], word: &[u8], row: u8, col: u8, dir: u8) -> bool {
+        word.iter().enumerate().all(|(step, &letter)| {
+            Self::cell(row, col, dir, step)
+                .map(|cell| grid[cell] == 0 || grid[cell] == letter)
+                .unwrap_or(false)
+        })
+    }
+}
+
+/// splitmix64 PRNG seeded from sha256("word_search" | Match.seed)
+struct BoardRng(u64);
+
+impl BoardRng {
+    fn from_match_seed(seed: u32) -> Self {
+        let digest = hashv(&[b"word_search", &seed.to_le_bytes()]).to_bytes();
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&digest[..8]);
+        Self(u64::from_le_bytes(bytes))
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next() % bound as u64) as usize
+    }
+}
@@ -0,0 +1,9 @@
+// Word search game-specific implementation (non-card game - does not use card_games helpers)
+
+pub mod actions;
+pub mod board;
+pub mod rules;
+pub mod state;
+pub mod validation;
+
+pub use rules::WordSearchRules;
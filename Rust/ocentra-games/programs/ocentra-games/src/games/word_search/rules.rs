@@ -0,0 +1,75 @@
+use crate::games::trait_def::GameRules;
+use crate::state::Match;
+use anchor_lang::prelude::*;
+
+/// Word search action type constants
+pub mod word_search_action {
+    pub const COMMIT_CLAIM: u8 = 0; // Commit a word claim - payload: [claim_hash(32)]
+    pub const REVEAL_CLAIM: u8 = 1; // Reveal committed claim - payload: [row, col, dir, len | salt(32)]
+    pub const FINISH: u8 = 2; // Player is done searching
+}
+
+/// Word search constants
+/// Players race to find the hidden words on a board generated from Match.seed.
+/// Claims are committed as hashes first so a revealed answer cannot be copied:
+/// when two players claim the same word, the earlier commitment wins it.
+pub struct WordSearchRules;
+
+impl WordSearchRules {
+    pub const GRID_SIZE: usize = 10; // 10 × 10 letter grid
+    pub const MAX_WORDS: usize = 8; // Hidden words per board
+    pub const POINTS_PER_LETTER: u16 = 10; // Word score = length × points per letter
+    pub const CLAIM_SALT_SIZE: usize = 32; // Salt appended to the claim in the committed hash
+}
+
+impl GameRules for WordSearchRules {
+    const GAME_TYPE: u8 = 2;
+    const MAX_HAND_SIZE: u8 = 0; // No hands
+    const MAX_ACTION_TYPE: u8 = 2; // 0=commit_claim, 1=reveal_claim, 2=finish
+    const HAS_TURNS: bool = false; // Everyone searches at once - no turn timer
+
+    fn validate_action(
+        match_account: &Match,
+        player_index: usize,
+        action_type: u8,
+        payload: &[u8],
+    ) -> Result<()> {
+        crate::games::word_search::validation::validate_word_search_action(
+            match_account,
+            player_index,
+            action_type,
+            payload,
+        )
+    }
+
+    fn apply_action_state(
+        match_account: &mut Match,
+        player_index: usize,
+        action_type: u8,
+        payload: &[u8],
+        advance_turn: bool,
+    ) -> Result<()> {
+        crate::games::word_search::actions::apply_word_search_action(
+            match_account,
+            player_index,
+            action_type,
+            payload,
+            advance_turn,
+        )
+    }
+
+    fn on_match_start(match_account: &mut Match) -> Result<()> {
+        crate::games::word_search::actions::start_word_search(match_account)
+    }
+
+    // advance_turn_after_batch: default no-op - all players search simultaneously
+
+    fn score_match(match_account: &Match) -> [i32; 10] {
+        let state = crate::games::word_search::state::WordSearchState::load(match_account);
+        let mut scores = [0i32; 10];
+        for (score, &points) in scores.iter_mut().zip(state.scores.iter()) {
+            *score = points as i32;
+        }
+        scores
+    }
+}
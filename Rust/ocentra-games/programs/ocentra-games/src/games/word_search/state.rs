@@ -0,0 +1,63 @@
+use crate::games::word_search::rules::WordSearchRules;
+use crate::state::Match;
+
+/// A hidden word placed on the board
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct PlacedWord {
+    pub word_index: u8, // Index into board::WORD_LIST
+    pub row: u8,        // Start cell row
+    pub col: u8,        // Start cell column
+    pub dir: u8,        // Direction (see board::DIRECTIONS)
+}
+
+/// Word search state - stored in Match.game_state (200 of 512 bytes).
+/// Pending claim commitments live in Match.committed_hand_hashes (one per player).
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct WordSearchState {
+    pub grid: [u8; 100], // Row-major uppercase ASCII letters (GRID_SIZE × GRID_SIZE)
+    pub placed: [PlacedWord; 8], // Hidden words (valid up to word_count)
+    pub finders: [u8; 8], // Seat that found each word (NOT_FOUND = not found)
+    pub found_seqs: [u16; 8], // Commit sequence of the winning claim per word
+    pub commit_seqs: [u16; 10], // Move count when each player's pending claim was committed
+    pub scores: [u16; 10], // Points per player
+    pub word_count: u8,  // Words actually placed (≤ MAX_WORDS)
+    pub found_count: u8, // Words found so far
+    pub finished: u16,   // Bitmask: players that called finish
+}
+
+// Layout must fit in Match.game_state and match SIZE (no implicit padding)
+const _: () = assert!(
+    WordSearchState::SIZE == core::mem::size_of::<WordSearchState>()
+        && WordSearchState::SIZE <= Match::GAME_STATE_SIZE
+        && WordSearchRules::GRID_SIZE * WordSearchRules::GRID_SIZE == 100
+);
+
+impl WordSearchState {
+    pub const SIZE: usize = 100 + // grid
+        (4 * 8) +                 // placed
+        8 + (2 * 8) +             // finders + found_seqs
+        (2 * 10) + (2 * 10) +     // commit_seqs + scores
+        1 + 1 + 2; // word_count + found_count + finished
+
+    pub const NOT_FOUND: u8 = 0xFF;
+
+    /// Read word search state from the match's game state blob
+    pub fn load(match_account: &Match) -> Self {
+        bytemuck::pod_read_unaligned(&match_account.game_state[..Self::SIZE])
+    }
+
+    /// Write word search state back to the match's game state blob
+    pub fn store(&self, match_account: &mut Match) {
+        match_account.game_state[..Self::SIZE].copy_from_slice(bytemuck::bytes_of(self));
+    }
+
+    pub fn is_found(&self, word: usize) -> bool {
+        self.finders[word] != Self::NOT_FOUND
+    }
+
+    pub fn has_finished(&self, player_index: usize) -> bool {
+        self.finished & (1 << player_index) != 0
+    }
+}
@@ -0,0 +1,85 @@
+use crate::common::validation_base::CommonValidation;
+use crate::error::GameError;
+use crate::games::word_search::board::Board;
+use crate::games::word_search::rules::{word_search_action, WordSearchRules};
+use crate::games::word_search::state::WordSearchState;
+use crate::state::Match;
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+/// Word search validation logic (simultaneous play - no turn order)
+pub fn validate_word_search_action(
+    match_account: &Match,
+    player_index: usize,
+    action_type: u8,
+    payload: &[u8],
+) -> Result<()> {
+    CommonValidation::validate_phase(match_account, 1)?;
+    let state = WordSearchState::load(match_account);
+    require!(!state.has_finished(player_index), GameError::InvalidAction);
+
+    match action_type {
+        word_search_action::COMMIT_CLAIM => {
+            validate_commit_claim(match_account, player_index, payload)
+        }
+        word_search_action::REVEAL_CLAIM => {
+            validate_reveal_claim(match_account, &state, player_index, payload)
+        }
+        word_search_action::FINISH => Ok(()),
+        _ => Err(GameError::InvalidAction.into()),
+    }
+}
+
+fn validate_commit_claim(match_account: &Match, player_index: usize, payload: &[u8]) -> Result<()> {
+    // One pending claim per player - reveal it before committing the next
+    require!(
+        match_account
+            .get_committed_hand_hash(player_index)
+            .is_none(),
+        GameError::InvalidAction
+    );
+    require!(payload.len() >= 32, GameError::InvalidPayload);
+    require!(
+        !payload[..32].iter().all(|&b| b == 0),
+        GameError::InvalidPayload
+    );
+    Ok(())
+}
+
+fn validate_reveal_claim(
+    match_account: &Match,
+    state: &WordSearchState,
+    player_index: usize,
+    payload: &[u8],
+) -> Result<()> {
+    require!(
+        payload.len() >= 4 + WordSearchRules::CLAIM_SALT_SIZE,
+        GameError::InvalidPayload
+    );
+    let committed = match_account
+        .get_committed_hand_hash(player_index)
+        .ok_or(GameError::ClaimNotCommitted)?;
+
+    // Claim hash binds the seat so a copied commitment is useless to another player
+    require!(
+        claim_hash(player_index, payload) == committed,
+        GameError::ClaimRevealMismatch
+    );
+
+    let (row, col, dir, len) = (payload[0], payload[1], payload[2], payload[3]);
+    require!(
+        Board::find_placed_word(state, row, col, dir, len).is_some(),
+        GameError::WordNotOnBoard
+    );
+    Ok(())
+}
+
+/// Commitment for a claim: sha256(seat | row | col | dir | len | salt)
+pub fn claim_hash(player_index: usize, payload: &[u8]) -> [u8; 32] {
+    hashv(&[
+        &[player_index as u8],
+        &payload[..4],
+        &payload[4..4 + WordSearchRules::CLAIM_SALT_SIZE],
+    ])
+    .to_bytes()
+}
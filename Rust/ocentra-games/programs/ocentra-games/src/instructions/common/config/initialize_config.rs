@@ -42,6 +42,7 @@ pub fn handler(ctx: Context<InitializeConfig>, treasury_multisig: Pubkey) -> Res
     config.kyc_tier_platform = crate::state::enums::kyc_tier::NONE; // No KYC required by default
     config.supported_payment_methods = 0x03; // Enable both WALLET (0x01) and PLATFORM (0x02) by default
     config.cancellation_fee_bps = 250; // 2.5% default cancellation fee
+    config.forfeit_routing = crate::state::enums::forfeit_routing::TREASURY; // Forfeits go to treasury by default
 
     // Set timestamps
     config.created_at = clock.unix_timestamp;
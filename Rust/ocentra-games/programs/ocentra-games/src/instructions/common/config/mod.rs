@@ -1,5 +1,7 @@
 pub mod initialize_config;
 pub mod pause_program;
+pub mod set_forfeit_routing;
+pub mod set_supported_mint;
 pub mod unpause_program;
 pub mod update_config;
 
@@ -8,6 +10,10 @@ pub use initialize_config::*;
 #[allow(ambiguous_glob_reexports)]
 pub use pause_program::*;
 #[allow(ambiguous_glob_reexports)]
+pub use set_forfeit_routing::*;
+#[allow(ambiguous_glob_reexports)]
+pub use set_supported_mint::*;
+#[allow(ambiguous_glob_reexports)]
 pub use unpause_program::*;
 #[allow(ambiguous_glob_reexports)]
 pub use update_config::*;
@@ -0,0 +1,42 @@
+use crate::error::GameError;
+use crate::state::enums::forfeit_routing;
+use crate::state::ConfigAccount;
+use anchor_lang::prelude::*;
+
+/// Sets where refund_escrow sends the stake forfeited by an abandoning player:
+/// the TreasuryVault, or pro rata (by stake) to the players being refunded.
+/// Only the treasury multisig can call this instruction.
+pub fn handler(ctx: Context<SetForfeitRouting>, routing: u8) -> Result<()> {
+    let clock = Clock::get()?;
+    let config = &mut ctx.accounts.config_account;
+
+    // Validate authority is treasury multisig
+    require!(
+        ctx.accounts.authority.key() == config.treasury_multisig,
+        GameError::Unauthorized
+    );
+
+    require!(
+        routing == forfeit_routing::TREASURY || routing == forfeit_routing::REMAINING_PLAYERS,
+        GameError::InvalidFeeParameter
+    );
+
+    config.forfeit_routing = routing;
+    config.last_updated = clock.unix_timestamp;
+
+    msg!("Forfeit routing updated to: {}", routing);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetForfeitRouting<'info> {
+    #[account(
+        mut,
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+
+    /// CHECK: Treasury multisig authority (must match config_account.treasury_multisig)
+    pub authority: Signer<'info>,
+}
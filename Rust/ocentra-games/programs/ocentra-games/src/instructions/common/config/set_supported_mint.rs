@@ -0,0 +1,78 @@
+use crate::error::GameError;
+use crate::state::{ConfigAccount, SupportedMint};
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+/// Allow-lists an SPL token mint for paid matches and platform deposits, or updates its
+/// entry-fee bounds, withdrawal fee and enabled flag (all in the mint's base units).
+/// Disabling a mint blocks new token matches and deposits; withdrawals stay open.
+/// Only the treasury multisig can call this instruction.
+pub fn handler(
+    ctx: Context<SetSupportedMint>,
+    min_entry_fee: u64,
+    max_entry_fee: u64,
+    withdrawal_fee: u64,
+    enabled: bool,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let config = &ctx.accounts.config_account;
+
+    // Validate authority is treasury multisig
+    require!(
+        ctx.accounts.authority.key() == config.treasury_multisig,
+        GameError::Unauthorized
+    );
+
+    require!(
+        max_entry_fee > 0 && min_entry_fee <= max_entry_fee,
+        GameError::InvalidFeeParameter
+    );
+
+    let supported_mint = &mut ctx.accounts.supported_mint;
+    if supported_mint.created_at == 0 {
+        supported_mint.mint = ctx.accounts.mint.key();
+        supported_mint.bump = ctx.bumps.supported_mint;
+        supported_mint.created_at = clock.unix_timestamp;
+    }
+    supported_mint.min_entry_fee = min_entry_fee;
+    supported_mint.max_entry_fee = max_entry_fee;
+    supported_mint.withdrawal_fee = withdrawal_fee;
+    supported_mint.enabled = enabled;
+    supported_mint.last_updated = clock.unix_timestamp;
+
+    msg!(
+        "Supported mint {} updated: entry fee {}-{}, withdrawal fee {}, enabled: {}",
+        supported_mint.mint,
+        min_entry_fee,
+        max_entry_fee,
+        withdrawal_fee,
+        enabled
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetSupportedMint<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = SupportedMint::MAX_SIZE,
+        seeds = [b"supported_mint", mint.key().as_ref()],
+        bump
+    )]
+    pub supported_mint: Account<'info, SupportedMint>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+
+    /// Treasury multisig authority (must match config_account.treasury_multisig)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,77 @@
+use crate::error::GameError;
+use crate::state::{ConfigAccount, EscrowAccount, Match};
+use anchor_lang::prelude::*;
+
+/// Records corrected final scores for a paid match after a dispute on it was upheld
+/// (RESOLVED_IN_FAVOR_OF_FLAGGER). finalize_dispute holds the escrow until this runs;
+/// distribute_prizes then pays out from the corrected Match.scores.
+/// Only the config authority can correct scores, once per upheld dispute.
+pub fn handler(ctx: Context<CorrectMatchScores>, match_id: String, scores: Vec<i32>) -> Result<()> {
+    let mut match_account = ctx.accounts.match_account.load_mut()?;
+    let mut escrow_account = ctx.accounts.escrow_account.load_mut()?;
+
+    // Security: Only the config authority corrects results
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.config_account.authority,
+        GameError::Unauthorized
+    );
+
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36
+            && match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::InvalidPayload
+    );
+    require!(
+        escrow_account.match_pda == ctx.accounts.match_account.key(),
+        GameError::InvalidPayload
+    );
+    require!(
+        escrow_account.is_score_correction_pending(),
+        GameError::NoScoreCorrectionPending
+    );
+
+    // One score per seat
+    let player_count = (match_account.player_count as usize).min(10);
+    require!(scores.len() == player_count, GameError::InvalidPayload);
+    let mut corrected = [0i32; 10];
+    corrected[..player_count].copy_from_slice(&scores);
+
+    match_account.record_scores(corrected);
+    match_account.set_scores_finalized(true);
+    escrow_account.set_score_correction_pending(false);
+
+    msg!(
+        "Corrected scores for match {}: {:?}",
+        match_id,
+        &corrected[..player_count]
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct CorrectMatchScores<'info> {
+    #[account(
+        mut,
+        seeds = [b"m", &match_id.as_bytes()[..31.min(match_id.len())]],
+        bump
+    )]
+    pub match_account: AccountLoader<'info, Match>,
+
+    #[account(
+        mut,
+        seeds = [b"escrow", match_account.key().as_ref()],
+        bump = escrow_account.load()?.bump
+    )]
+    pub escrow_account: AccountLoader<'info, EscrowAccount>,
+
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+
+    pub authority: Signer<'info>,
+}
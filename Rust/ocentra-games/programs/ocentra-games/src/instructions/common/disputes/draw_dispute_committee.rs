@@ -0,0 +1,125 @@
+use crate::error::GameError;
+use crate::state::{
+    first_slot_hash_after, signer_role, Dispute, Match, SignerRegistry, ValidatorReputation,
+    MIN_VALIDATOR_STAKE_LAMPORTS,
+};
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+/// Draws a flagged dispute's committee of up to DISPUTE_COMMITTEE_SIZE validators, by stake
+/// times reputation, from the staked VALIDATOR-role signers (excluding the flagger and the
+/// match's players).
+/// The draw is seeded by the hash of the first slot after the dispute's flag slot and the
+/// dispute PDA only - nothing the flagger chose, and a hash that did not exist when the dispute
+/// was flagged. The draw must be cranked while that slot is still in the SlotHashes sysvar
+/// (about 512 slots); after that it can never be drawn, and finalize_dispute dismisses the
+/// committee-less dispute at its voting deadline. There is no re-draw from a later slot, so
+/// whoever times the crank still gets exactly one possible committee.
+/// Remaining accounts: the ValidatorReputation PDA of every VALIDATOR-role signer in the
+/// SignerRegistry, in registry order (uninitialized for validators that never staked).
+/// Permissionless - the seed is fixed once the slot after the flag is produced, so anyone may
+/// crank it.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DrawDisputeCommittee<'info>>,
+    match_id: String,
+    flagger: Pubkey,
+) -> Result<()> {
+    let dispute_key = ctx.accounts.dispute.key();
+    let mut dispute = ctx.accounts.dispute.load_mut()?;
+    let clock = Clock::get()?;
+
+    require!(dispute.flagger == flagger, GameError::InvalidPayload);
+    require!(!dispute.is_resolved(), GameError::DisputeAlreadyResolved);
+    require!(
+        dispute.committee_size == 0,
+        GameError::CommitteeAlreadyDrawn
+    );
+    require!(
+        clock.unix_timestamp <= dispute.voting_deadline,
+        GameError::DisputeVotingClosed
+    );
+
+    let slot_hash = {
+        let slot_hashes = ctx.accounts.recent_slothashes.try_borrow_data()?;
+        first_slot_hash_after(&slot_hashes, dispute.flag_slot)?
+    };
+    let slot_hash = slot_hash.ok_or(GameError::CommitteeDrawExpired)?;
+
+    // Every staked validator is a candidate - the registry fixes the candidate set, so the
+    // caller cannot leave validators out
+    let validators = ctx
+        .accounts
+        .registry
+        .load()?
+        .signers_with_role(signer_role::VALIDATOR);
+    require!(
+        ctx.remaining_accounts.len() == validators.len(),
+        GameError::InvalidPayload
+    );
+    let match_account = ctx.accounts.match_account.load()?;
+    let mut candidates = Vec::with_capacity(validators.len());
+    for (validator, reputation_info) in validators.iter().zip(ctx.remaining_accounts.iter()) {
+        let (expected_reputation, _) =
+            Pubkey::find_program_address(&[b"validator", validator.as_ref()], ctx.program_id);
+        require!(
+            reputation_info.key() == expected_reputation,
+            GameError::InvalidPayload
+        );
+        // Parties to the dispute and validators that never staked are not eligible
+        if *validator == dispute.flagger
+            || match_account.has_player_wallet(validator)
+            || reputation_info.data_is_empty()
+        {
+            continue;
+        }
+        let reputation = Account::<ValidatorReputation>::try_from(reputation_info)?;
+        let weight = reputation.vote_weight();
+        if reputation.stake >= MIN_VALIDATOR_STAKE_LAMPORTS && weight > 0 {
+            candidates.push((*validator, weight));
+        }
+    }
+    require!(!candidates.is_empty(), GameError::NoEligibleValidators);
+
+    let seed = hashv(&[b"dispute_committee", &slot_hash, dispute_key.as_ref()]).to_bytes();
+    dispute.draw_committee(&seed, &candidates);
+
+    msg!(
+        "Dispute committee drawn: match {}, flagger {}, {} members from {} candidates",
+        match_id,
+        flagger,
+        dispute.committee_size,
+        candidates.len()
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String, flagger: Pubkey)]
+pub struct DrawDisputeCommittee<'info> {
+    #[account(
+        mut,
+        seeds = [b"dispute", match_account.key().as_ref(), flagger.as_ref()],
+        bump
+    )]
+    pub dispute: AccountLoader<'info, Dispute>,
+
+    #[account(
+        seeds = [b"m", &match_id.as_bytes()[..31.min(match_id.len())]],
+        bump
+    )]
+    pub match_account: AccountLoader<'info, Match>,
+
+    /// SignerRegistry listing the validators the committee is drawn from
+    #[account(
+        seeds = [b"signer_registry"],
+        bump
+    )]
+    pub registry: AccountLoader<'info, SignerRegistry>,
+
+    /// CHECK: SlotHashes sysvar - the hash of the first slot after the flag seeds the draw
+    #[account(address = anchor_lang::solana_program::sysvar::slot_hashes::ID)]
+    pub recent_slothashes: UncheckedAccount<'info>,
+
+    /// Anyone may crank the draw
+    pub caller: Signer<'info>,
+}
@@ -0,0 +1,169 @@
+use crate::error::GameError;
+use crate::state::enums::{cancellation_reason, slash_reason};
+use crate::state::{
+    dispute_resolution, Dispute, EscrowAccount, Match, MatchDisputes, ValidatorReputation,
+    DISPUTE_COMMITTEE_SIZE,
+};
+use anchor_lang::prelude::*;
+
+/// Resolves a dispute from its validator votes and records the GP deposit decision.
+/// Resolves as soon as the votes reach quorum (see Dispute::has_quorum); once the voting window
+/// has closed, the resolution with the most vote weight wins, and a dispute without a clear
+/// leader - or without a committee, when nobody cranked the draw in time - is dismissed in
+/// favor of the defendant (see Dispute::final_resolution). Every voter's reputation is then
+/// updated by whether they voted with the outcome. Committee members who never voted take the
+/// inactivity reputation penalty (see slash_reason::INACTIVITY) only when the voting window ran
+/// out: a quorum reached early closes voting while they still had time left to vote, so not
+/// having voted yet is not inactivity.
+/// GP deposit is refunded if the dispute is upheld, forfeited otherwise (applied off-chain).
+/// The dispute is removed from its match's open-dispute index.
+/// Paid matches apply the outcome to the escrow: MATCH_VOIDED and PARTIAL_REFUND cancel it for
+/// refund_escrow (full refunds, or refunds minus the cancellation fee), and an upheld dispute
+/// holds prizes until correct_match_scores records corrected scores. The escrow is unfrozen
+/// once no dispute on the match is open; the first refund outcome is final.
+/// Remaining accounts: the ValidatorReputation PDA of every committee member, in committee order.
+/// Permissionless - the votes are on-chain, so anyone may crank it.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FinalizeDispute<'info>>,
+    match_id: String,
+    flagger: Pubkey,
+) -> Result<()> {
+    let mut dispute = ctx.accounts.dispute.load_mut()?;
+    let clock = Clock::get()?;
+
+    require!(!dispute.is_resolved(), GameError::DisputeAlreadyResolved);
+
+    let voting_closed = clock.unix_timestamp > dispute.voting_deadline;
+    let resolution = dispute.final_resolution(voting_closed)?;
+
+    let vote_count = (dispute.vote_count as usize).min(10);
+    let committee_size = (dispute.committee_size as usize).min(DISPUTE_COMMITTEE_SIZE);
+    require!(
+        ctx.remaining_accounts.len() == committee_size,
+        GameError::InvalidPayload
+    );
+
+    // Security: Each account must be the reputation PDA of the matching committee member
+    for (member, reputation_info) in dispute.committee[..committee_size]
+        .iter()
+        .zip(ctx.remaining_accounts.iter())
+    {
+        let (expected_reputation, _) =
+            Pubkey::find_program_address(&[b"validator", member.as_ref()], ctx.program_id);
+        require!(
+            reputation_info.key() == expected_reputation,
+            GameError::InvalidPayload
+        );
+        let mut reputation = Account::<ValidatorReputation>::try_from(reputation_info)?;
+        match dispute.validator_votes[..vote_count]
+            .iter()
+            .find(|vote| vote.validator == *member)
+        {
+            Some(vote) => reputation.update_reputation(vote.resolution == resolution),
+            // Early quorum: voting closed before the deadline, so silence isn't inactivity
+            None if voting_closed => reputation.apply_slash_penalty(slash_reason::INACTIVITY),
+            None => {}
+        }
+        reputation.exit(ctx.program_id)?;
+    }
+
+    let mut match_disputes = ctx.accounts.match_disputes.load_mut()?;
+    match_disputes.remove_open(&ctx.accounts.dispute.key())?;
+
+    // Paid matches: execute the outcome on the escrow
+    if ctx.accounts.match_account.load()?.is_paid_match() {
+        let escrow_loader = ctx
+            .accounts
+            .escrow_account
+            .as_ref()
+            .ok_or(GameError::InvalidPayload)?;
+        let mut escrow_account = escrow_loader.load_mut()?;
+        require!(
+            escrow_account.match_pda == ctx.accounts.match_account.key(),
+            GameError::InvalidPayload
+        );
+
+        if !escrow_account.is_distributed() && !escrow_account.is_cancelled() {
+            match resolution {
+                dispute_resolution::MATCH_VOIDED | dispute_resolution::PARTIAL_REFUND => {
+                    let reason = if resolution == dispute_resolution::MATCH_VOIDED {
+                        cancellation_reason::DISPUTE_VOIDED
+                    } else {
+                        cancellation_reason::DISPUTE_PARTIAL_REFUND
+                    };
+                    escrow_account.set_cancelled(true);
+                    escrow_account.set_cancellation_reason(reason);
+                    escrow_account.abandoned_player_index = 255;
+                    escrow_account.set_score_correction_pending(false);
+                }
+                dispute_resolution::RESOLVED_IN_FAVOR_OF_FLAGGER => {
+                    escrow_account.set_score_correction_pending(true);
+                }
+                _ => {}
+            }
+        }
+        if !match_disputes.is_contested() {
+            escrow_account.set_contested(false);
+        }
+    }
+
+    // Record resolution
+    dispute.resolution = resolution;
+    dispute.resolved_at = clock.unix_timestamp;
+
+    // Resolution 1 = ResolvedInFavorOfFlagger (dispute valid) → refund GP
+    // Resolution 2, 3, 4 = Invalid → forfeit GP (gp_refunded stays false)
+    if resolution == dispute_resolution::RESOLVED_IN_FAVOR_OF_FLAGGER {
+        dispute.gp_refunded = 1; // 1 = true (u8 for zero-copy)
+    }
+
+    msg!(
+        "Dispute resolved: {}/{} with resolution {} from {} votes (GP {}: {})",
+        match_id,
+        flagger,
+        resolution,
+        vote_count,
+        if dispute.gp_refunded == 1 {
+            "refunded"
+        } else {
+            "forfeited"
+        },
+        dispute.gp_deposit
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String, flagger: Pubkey)]
+pub struct FinalizeDispute<'info> {
+    #[account(
+        mut,
+        seeds = [b"dispute", match_account.key().as_ref(), flagger.as_ref()],
+        bump
+    )]
+    pub dispute: AccountLoader<'info, Dispute>,
+
+    #[account(
+        mut,
+        seeds = [b"match_disputes", match_account.key().as_ref()],
+        bump
+    )]
+    pub match_disputes: AccountLoader<'info, MatchDisputes>,
+
+    #[account(
+        seeds = [b"m", &match_id.as_bytes()[..31.min(match_id.len())]],
+        bump
+    )]
+    pub match_account: AccountLoader<'info, Match>,
+
+    /// Escrow account (only required for paid matches)
+    #[account(
+        mut,
+        seeds = [b"escrow", match_account.key().as_ref()],
+        bump
+    )]
+    pub escrow_account: Option<AccountLoader<'info, EscrowAccount>>,
+
+    /// Anyone may crank a dispute once it can resolve
+    pub caller: Signer<'info>,
+}
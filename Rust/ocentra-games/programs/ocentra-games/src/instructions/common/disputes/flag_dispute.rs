@@ -1,11 +1,24 @@
 use crate::error::GameError;
-use crate::state::{ConfigAccount, Dispute, ValidatorVote};
+use crate::state::{
+    post_match_dispute_window_open, ConfigAccount, Dispute, EscrowAccount, Match, MatchDisputes,
+    ValidatorVote, DISPUTE_VOTING_WINDOW_SECONDS,
+};
 use anchor_lang::prelude::*;
 
 /// Flags a dispute with GP deposit.
 /// Per spec Section 23: Dispute deposit system using GP (Game Points) instead of SOL.
 /// GP is deducted off-chain in database before calling this instruction.
 /// This instruction records the GP deposit on-chain for tracking.
+/// The dispute lives at the canonical PDA [b"dispute", match, flagger] and is added to the
+/// match's MatchDisputes index (created by the first dispute on the match). Only wallets
+/// seated in the match can flag it.
+/// On a paid match the escrow is marked contested, which blocks distribute_prizes and
+/// refund_escrow until every dispute on the match is finalized. Paid matches can only be
+/// disputed before their prizes are distributed and within POST_MATCH_DISPUTE_WINDOW_SECONDS
+/// of ending.
+/// The flag slot is recorded for draw_dispute_committee, which draws the dispute's validator
+/// committee from the hash of a slot after the flag - a hash the flagger cannot know when
+/// flagging; only committee members can vote on the dispute.
 pub fn handler(
     ctx: Context<FlagDispute>,
     match_id: String,
@@ -21,8 +34,48 @@ pub fn handler(
     // Security: Validate flagger is signer
     require!(ctx.accounts.flagger.is_signer, GameError::Unauthorized);
 
-    // Security: Validate match_id is valid UUID
+    // Security: Validate match_id is valid UUID and matches the match account
     require!(match_id.len() == 36, GameError::InvalidPayload);
+    {
+        let match_account = ctx.accounts.match_account.load()?;
+        require!(
+            match_id.as_bytes() == &match_account.match_id[..],
+            GameError::InvalidPayload
+        );
+
+        // Security: Only seated players can dispute their match - one dispute PDA per flagger
+        // means at most one per seat, so outside wallets cannot freeze the escrow or fill the
+        // MatchDisputes index
+        require!(
+            match_account.has_player_wallet(&ctx.accounts.flagger.key()),
+            GameError::PlayerNotInMatch
+        );
+
+        // Paid matches: freeze escrow settlement while the dispute is open
+        if match_account.is_paid_match() {
+            if match_account.is_ended() {
+                require!(
+                    post_match_dispute_window_open(match_account.ended_at, clock.unix_timestamp)?,
+                    GameError::DisputeWindowClosed
+                );
+            }
+            let escrow_loader = ctx
+                .accounts
+                .escrow_account
+                .as_ref()
+                .ok_or(GameError::InvalidPayload)?;
+            let mut escrow_account = escrow_loader.load_mut()?;
+            require!(
+                escrow_account.match_pda == ctx.accounts.match_account.key(),
+                GameError::InvalidPayload
+            );
+            require!(
+                !escrow_account.is_distributed(),
+                GameError::EscrowAlreadyDistributed
+            );
+            escrow_account.set_contested(true);
+        }
+    }
 
     // Security: Validate reason bounds (0-4, see dispute_reason module)
     require!(
@@ -71,13 +124,36 @@ pub fn handler(
         timestamp: 0,
     }; 10]; // Initialize with default values
     dispute.vote_count = 0;
+    dispute.vote_weights = [0; 4];
+    dispute.voting_deadline = clock
+        .unix_timestamp
+        .checked_add(DISPUTE_VOTING_WINDOW_SECONDS)
+        .ok_or(GameError::Overflow)?;
+    dispute.flag_slot = clock.slot;
+
+    // Index the dispute under its match (check discriminator before loading)
+    let index_info = ctx.accounts.match_disputes.to_account_info();
+    let index_data = index_info.try_borrow_data()?;
+    let is_initialized = index_data.len() >= 8 && index_data[0..8] != [0u8; 8];
+    drop(index_data);
+    let mut match_disputes = if is_initialized {
+        ctx.accounts.match_disputes.load_mut()?
+    } else {
+        let mut match_disputes = ctx.accounts.match_disputes.load_init()?;
+        match_disputes.match_account = ctx.accounts.match_account.key();
+        match_disputes.bump = ctx.bumps.match_disputes;
+        match_disputes
+    };
+    match_disputes.add_open(ctx.accounts.dispute.key())?;
 
     msg!(
-        "Dispute flagged: match {}, reason {}, by {} (GP deposit: {})",
+        "Dispute flagged: match {}, reason {}, by {} (GP deposit: {}, {} open, slot {})",
         match_id,
         reason,
         user_id,
-        gp_deposit
+        gp_deposit,
+        match_disputes.open_count,
+        dispute.flag_slot
     );
     Ok(())
 }
@@ -89,11 +165,34 @@ pub struct FlagDispute<'info> {
         init,
         payer = flagger,
         space = Dispute::MAX_SIZE,
-        seeds = [b"dispute", match_id.as_bytes(), flagger.key().as_ref()],
+        seeds = [b"dispute", match_account.key().as_ref(), flagger.key().as_ref()],
         bump
     )]
     pub dispute: AccountLoader<'info, Dispute>,
 
+    #[account(
+        init_if_needed,
+        payer = flagger,
+        space = MatchDisputes::MAX_SIZE,
+        seeds = [b"match_disputes", match_account.key().as_ref()],
+        bump
+    )]
+    pub match_disputes: AccountLoader<'info, MatchDisputes>,
+
+    #[account(
+        seeds = [b"m", &match_id.as_bytes()[..31.min(match_id.len())]],
+        bump
+    )]
+    pub match_account: AccountLoader<'info, Match>,
+
+    /// Escrow account (only required for paid matches)
+    #[account(
+        mut,
+        seeds = [b"escrow", match_account.key().as_ref()],
+        bump
+    )]
+    pub escrow_account: Option<AccountLoader<'info, EscrowAccount>>,
+
     /// ConfigAccount to check dispute_deposit_gp requirement
     pub config_account: Account<'info, ConfigAccount>,
 
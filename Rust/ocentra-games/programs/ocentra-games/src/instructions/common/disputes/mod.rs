@@ -1,8 +1,17 @@
+pub mod correct_match_scores;
+pub mod draw_dispute_committee;
+pub mod finalize_dispute;
 pub mod flag_dispute;
 pub mod resolve_dispute;
 
 // Re-export for Anchor's #[program] macro
 // Ambiguous re-exports warning is acceptable because handlers use full paths
 #[allow(ambiguous_glob_reexports)]
+pub use correct_match_scores::*;
+#[allow(ambiguous_glob_reexports)]
+pub use draw_dispute_committee::*;
+#[allow(ambiguous_glob_reexports)]
+pub use finalize_dispute::*;
+#[allow(ambiguous_glob_reexports)]
 pub use flag_dispute::*;
 pub use resolve_dispute::*;
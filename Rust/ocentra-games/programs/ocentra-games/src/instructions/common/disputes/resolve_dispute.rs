@@ -1,19 +1,63 @@
 use crate::error::GameError;
-use crate::state::Dispute;
+use crate::state::{
+    signer_role, Dispute, Match, SignerRegistry, ValidatorReputation, MIN_VALIDATOR_STAKE_LAMPORTS,
+};
 use anchor_lang::prelude::*;
 
-/// Resolves a dispute and handles GP deposit refund/forfeit.
-/// Per spec Section 23: GP deposit is refunded if dispute is valid, forfeited if invalid.
-/// Actual GP refund/forfeit happens off-chain in database. This instruction records the decision.
-pub fn handler(ctx: Context<ResolveDispute>, dispute_id: String, resolution: u8) -> Result<()> {
+/// Records a validator's vote on a dispute, addressed by its match and flagger.
+/// Only members of the dispute's committee (drawn by draw_dispute_committee) that are still VALIDATOR-role
+/// signers in the SignerRegistry with at least MIN_VALIDATOR_STAKE_LAMPORTS staked may vote,
+/// once each, until the dispute's voting deadline. The vote carries the
+/// validator's weight (stake scaled by reputation); finalize_dispute tallies the votes.
+pub fn handler(
+    ctx: Context<ResolveDispute>,
+    match_id: String,
+    flagger: Pubkey,
+    resolution: u8,
+) -> Result<()> {
     let mut dispute = ctx.accounts.dispute.load_mut()?;
+    let registry = ctx.accounts.registry.load()?;
+    let validator_reputation = &mut ctx.accounts.validator_reputation;
+    let validator = ctx.accounts.validator.key();
     let clock = Clock::get()?;
 
     // Security: Validate validator is signer
     require!(ctx.accounts.validator.is_signer, GameError::Unauthorized);
 
+    // Security: Only registered validators may vote
+    require!(
+        registry.get_role(&validator) == Some(signer_role::VALIDATOR),
+        GameError::Unauthorized
+    );
+    require!(
+        validator_reputation.validator == validator,
+        GameError::InvalidPayload
+    );
+    require!(
+        validator_reputation.stake >= MIN_VALIDATOR_STAKE_LAMPORTS,
+        GameError::InsufficientValidatorStake
+    );
+
+    // Security: Only the drawn committee votes
+    require!(
+        dispute.committee_size > 0,
+        GameError::DisputeCommitteeNotDrawn
+    );
+    require!(
+        dispute.is_committee_member(&validator),
+        GameError::NotDisputeCommitteeMember
+    );
+
     // Security: Validate dispute exists and is not already resolved
     require!(!dispute.is_resolved(), GameError::DisputeAlreadyResolved);
+    require!(
+        clock.unix_timestamp <= dispute.voting_deadline,
+        GameError::DisputeVotingClosed
+    );
+    require!(
+        !dispute.has_voted(&validator),
+        GameError::ValidatorAlreadyVoted
+    );
 
     // Security: Validate resolution bounds (1-4, not 0)
     require!(
@@ -21,52 +65,52 @@ pub fn handler(ctx: Context<ResolveDispute>, dispute_id: String, resolution: u8)
         GameError::InvalidAction
     );
 
-    // Security: Validate GP deposit not already processed
-    require!(
-        dispute.gp_refunded == 0 || dispute.resolution == 0, // Allow if not resolved yet
-        GameError::GPDepositAlreadyProcessed
-    );
-
-    // Record resolution
-    dispute.resolution = resolution;
-    dispute.resolved_at = clock.unix_timestamp;
-
-    // Determine if GP should be refunded based on resolution
-    // Resolution 1 = ResolvedInFavorOfFlagger (dispute valid) → refund GP
-    // Resolution 2, 3, 4 = Invalid → forfeit GP (gp_refunded stays false)
-    // If dispute is valid (resolved in favor of flagger), refund GP
-    if resolution == 1 {
-        dispute.gp_refunded = 1; // 1 = true (u8 for zero-copy)
-    }
-    // Otherwise, GP is forfeited (gp_refunded = 0, which is already set)
-
-    // Add validator vote
+    let weight = validator_reputation.vote_weight();
     let timestamp = clock.unix_timestamp as u32; // Convert i64 to u32 for zero-copy
-    dispute.add_vote(ctx.accounts.validator.key(), resolution, timestamp)?;
+    dispute.add_vote(validator, resolution, timestamp, weight)?;
+    validator_reputation.last_active = clock.unix_timestamp;
 
     msg!(
-        "Dispute resolved: {} with resolution {} (GP {}: {})",
-        dispute_id,
+        "Dispute {}/{}: validator {} voted {} (weight {}, {} votes, quorum {})",
+        match_id,
+        flagger,
+        validator,
         resolution,
-        if dispute.gp_refunded == 1 {
-            "refunded"
-        } else {
-            "forfeited"
-        },
-        dispute.gp_deposit
+        weight,
+        dispute.vote_count,
+        dispute.has_quorum()
     );
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(dispute_id: String)]
+#[instruction(match_id: String, flagger: Pubkey)]
 pub struct ResolveDispute<'info> {
     #[account(
         mut,
-        seeds = [b"dispute", dispute_id.as_bytes()],
+        seeds = [b"dispute", match_account.key().as_ref(), flagger.as_ref()],
         bump
     )]
     pub dispute: AccountLoader<'info, Dispute>,
 
+    #[account(
+        seeds = [b"m", &match_id.as_bytes()[..31.min(match_id.len())]],
+        bump
+    )]
+    pub match_account: AccountLoader<'info, Match>,
+
+    #[account(
+        seeds = [b"signer_registry"],
+        bump
+    )]
+    pub registry: AccountLoader<'info, SignerRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"validator", validator.key().as_ref()],
+        bump
+    )]
+    pub validator_reputation: Account<'info, ValidatorReputation>,
+
     pub validator: Signer<'info>,
 }
@@ -0,0 +1,115 @@
+use crate::common::token_escrow::EscrowVault;
+use crate::error::GameError;
+use crate::state::{EscrowAccount, Match};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+/// Closes a settled EscrowAccount and returns its rent to the match creator.
+/// The escrow must have been fully paid out - by distribute_prizes, or by refund_escrow after
+/// cancel_match - so no stake remains on its ledger.
+/// SPL token escrows also close their (empty) escrow vault.
+/// Permissionless - the rent can only go to match_account.authority.
+pub fn handler(ctx: Context<CloseEscrow>, match_id: String) -> Result<()> {
+    let match_account = ctx.accounts.match_account.load()?;
+    let escrow_account = ctx.accounts.escrow_account.load()?;
+
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36
+            && match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::InvalidPayload
+    );
+    require!(
+        escrow_account.match_pda == ctx.accounts.match_account.key(),
+        GameError::InvalidPayload
+    );
+
+    // Only settled escrows can be closed - nothing may be left to pay out
+    require!(
+        escrow_account.is_distributed() || escrow_account.is_cancelled(),
+        GameError::IncompleteSettlement
+    );
+    require!(
+        escrow_account.total_entry_lamports == 0 && escrow_account.total_player_stakes() == 0,
+        GameError::IncompleteSettlement
+    );
+
+    // Security: Rent goes back to the creator that paid for the escrow
+    require!(
+        ctx.accounts.rent_recipient.key() == match_account.authority,
+        GameError::Unauthorized
+    );
+
+    // Token escrow: the emptied vault is closed with the escrow
+    if escrow_account.is_token_escrow() {
+        let escrow_vault = ctx
+            .accounts
+            .escrow_vault
+            .as_ref()
+            .ok_or(GameError::InvalidPayload)?;
+        require!(escrow_vault.amount == 0, GameError::IncompleteSettlement);
+        let token_program = ctx
+            .accounts
+            .token_program
+            .as_ref()
+            .ok_or(GameError::InvalidPayload)?
+            .to_account_info();
+        let escrow_vault_info = escrow_vault.to_account_info();
+        let escrow_account_info = ctx.accounts.escrow_account.to_account_info();
+        let vault = EscrowVault {
+            token_program: &token_program,
+            vault: &escrow_vault_info,
+            escrow: &escrow_account_info,
+            match_pda: ctx.accounts.match_account.key(),
+            escrow_bump: escrow_account.bump,
+            mint: escrow_account.entry_mint,
+        };
+        vault.validate(ctx.program_id)?;
+        vault.close(&ctx.accounts.rent_recipient.to_account_info())?;
+    }
+
+    msg!(
+        "Escrow closed for match {}: {} lamports rent returned to {}",
+        match_id,
+        ctx.accounts.escrow_account.to_account_info().lamports(),
+        ctx.accounts.rent_recipient.key()
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct CloseEscrow<'info> {
+    #[account(
+        mut,
+        seeds = [b"escrow", match_account.key().as_ref()],
+        bump = escrow_account.load()?.bump,
+        close = rent_recipient
+    )]
+    pub escrow_account: AccountLoader<'info, EscrowAccount>,
+
+    #[account(
+        seeds = [b"m", &match_id.as_bytes()[..31.min(match_id.len())]],
+        bump
+    )]
+    pub match_account: AccountLoader<'info, Match>,
+
+    /// Escrow vault (only required for SPL token escrows) - closed with the escrow
+    #[account(
+        mut,
+        seeds = [b"escrow_vault", match_account.key().as_ref()],
+        bump
+    )]
+    pub escrow_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Match creator - receives the escrow rent
+    /// CHECK: Validated in handler - must equal match_account.authority
+    #[account(mut)]
+    pub rent_recipient: UncheckedAccount<'info>,
+
+    /// Anyone may close a settled escrow
+    pub caller: Signer<'info>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
@@ -0,0 +1,139 @@
+use crate::error::GameError;
+use crate::state::{ConfigAccount, SupportedMint, UserDepositAccount};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+/// Deposits an allow-listed SPL token from the user's token account into their token
+/// UserDepositAccount (one ledger per mint) for platform-funded token matches.
+/// Tokens are held in the ledger's deposit vault, a token account owned by the ledger PDA.
+pub fn handler(ctx: Context<DepositToken>, amount: u64) -> Result<()> {
+    // Validate amount is greater than zero
+    require!(amount > 0, GameError::InvalidPayload);
+
+    // Check if program is paused
+    let config = &ctx.accounts.config_account;
+    require!(!config.is_paused, GameError::ProgramPaused);
+
+    // Only enabled mints accept new deposits
+    require!(
+        ctx.accounts.supported_mint.enabled,
+        GameError::MintNotSupported
+    );
+
+    // 1. Move the tokens into the deposit vault (signed by the user)
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.user_token_account.to_account_info(),
+                to: ctx.accounts.deposit_vault.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    // 2. Now load/initialize the ledger
+    // Check if account is initialized by checking if discriminator is set
+    let account_info = ctx.accounts.user_deposit_account.to_account_info();
+    let account_data = account_info.try_borrow_data()?;
+    let is_initialized = account_data.len() >= 8 && account_data[0..8] != [0u8; 8];
+    drop(account_data);
+
+    let mut deposit_account = if is_initialized {
+        // Account already exists - load and validate
+        let account = ctx.accounts.user_deposit_account.load_mut()?;
+        require!(
+            account.authority == ctx.accounts.user.key(),
+            GameError::Unauthorized
+        );
+        require!(!account.is_frozen(), GameError::AccountFrozen);
+        account
+    } else {
+        // Account was just created by init_if_needed but not initialized - initialize it
+        let mut account = ctx.accounts.user_deposit_account.load_init()?;
+        account.authority = ctx.accounts.user.key();
+        account.bump = ctx.bumps.user_deposit_account;
+        account.total_deposited = 0;
+        account.available_lamports = 0;
+        account.in_play_lamports = 0;
+        account.withdrawn_lamports = 0;
+        account.locked_until = 0;
+        account.flags = 0;
+        account.mint = ctx.accounts.mint.key();
+        account
+    };
+
+    // 3. Update the ledger's internal balance tracking (mint base units)
+    deposit_account.total_deposited = deposit_account
+        .total_deposited
+        .checked_add(amount)
+        .ok_or(GameError::Overflow)?;
+    deposit_account.available_lamports = deposit_account
+        .available_lamports
+        .checked_add(amount)
+        .ok_or(GameError::Overflow)?;
+
+    msg!(
+        "Deposited {} of mint {} to token UserDepositAccount. Total deposited: {}, Available: {}",
+        amount,
+        deposit_account.mint,
+        deposit_account.total_deposited,
+        deposit_account.available_lamports
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DepositToken<'info> {
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserDepositAccount::MAX_SIZE,
+        seeds = [b"user_deposit", user.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub user_deposit_account: AccountLoader<'info, UserDepositAccount>,
+
+    /// Deposit vault holding the ledger's tokens, owned by the ledger PDA
+    #[account(
+        init_if_needed,
+        payer = user,
+        seeds = [b"deposit_vault", user_deposit_account.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = user_deposit_account,
+        token::token_program = token_program
+    )]
+    pub deposit_vault: Box<Account<'info, TokenAccount>>,
+
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        seeds = [b"supported_mint", mint.key().as_ref()],
+        bump = supported_mint.bump
+    )]
+    pub supported_mint: Box<Account<'info, SupportedMint>>,
+
+    /// User's token account (source of the deposit)
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = user
+    )]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -1,34 +1,41 @@
+use crate::common::prize_structure::PrizeStructure;
+use crate::common::token_escrow::{EscrowVault, TokenEscrow};
 use crate::error::GameError;
-use crate::state::{ConfigAccount, EscrowAccount, Match, UserDepositAccount};
+use crate::state::enums::fee_source;
+use crate::state::{
+    post_match_dispute_window_open, ConfigAccount, EscrowAccount, Match, SupportedMint,
+    TreasuryVault, UserDepositAccount,
+};
 use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
 
-/// Distributes prize pool from EscrowAccount to all winners atomically.
+/// Distributes prize pool from EscrowAccount to the seats atomically.
 /// Per Phase 03: Economic instructions for prize distribution.
 ///
 /// **Enterprise-grade features:**
 /// - Atomic operation: All prizes distributed or none (all-or-nothing)
-/// - Validates prize pool sum matches escrow balance minus platform fee
-/// - Transfers platform fee to treasury before prize distribution
-/// - Supports up to 10 winners (max players per match)
+/// - Payouts computed on-chain from Match.scores and the prize structure declared at
+///   create_match - no caller-supplied winners or amounts
+/// - Transfers platform fee to the TreasuryVault PDA (recorded by source and game type)
+///   before prize distribution
 /// - Prevents double distribution with escrow status flag
+/// - Waits until the post-match dispute window has closed, so every seat can flag_dispute first
+/// - Frozen while a dispute on the match is open, and after an upheld dispute until
+///   correct_match_scores records corrected scores (dispute-voided escrows are refund-only)
+/// - Platform payments settle every seat: each entry-fee lock leaves in_play, winners'
+///   prizes are credited to available (deposit ledgers reconcile with escrow lamports)
 /// - Comprehensive validation of all inputs
-pub fn handler(
-    ctx: Context<DistributePrizes>,
+///
+/// Every seat's payout account is passed as remaining_accounts, in seat order (winners and
+/// losers): the seat's wallet for WALLET matches, its UserDepositAccount PDA for PLATFORM.
+/// SPL token matches pay out of the escrow vault instead: each seat passes a token account
+/// owned by its wallet (WALLET), or its token ledger followed by its deposit vault (PLATFORM),
+/// and the platform fee goes to the treasury multisig's token account, recorded in the mint's
+/// SupportedMint fee ledger.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DistributePrizes<'info>>,
     match_id: String,
-    winner_indices: Vec<u8>,
-    prize_amounts: Vec<u64>,
 ) -> Result<()> {
-    // Validate inputs
-    require!(!winner_indices.is_empty(), GameError::InvalidPayload);
-    require!(
-        winner_indices.len() == prize_amounts.len(),
-        GameError::InvalidPayload
-    );
-    require!(
-        winner_indices.len() <= 10, // Max players
-        GameError::InvalidPayload
-    );
-
     // Validate match_id format
     let match_id_bytes = match_id.as_bytes();
     require!(match_id_bytes.len() == 36, GameError::InvalidPayload);
@@ -67,162 +74,228 @@ pub fn handler(
         GameError::EscrowAlreadyDistributed
     );
 
-    // Validate all winner indices are valid
-    for &winner_index in &winner_indices {
-        require!(
-            winner_index < match_account.player_count,
-            GameError::InvalidPayload
-        );
-        require!(winner_index < 10, GameError::InvalidPayload);
-    }
+    // Disputes: settlement waits for open disputes and for corrected scores
+    require!(!escrow_account.is_cancelled(), GameError::EscrowCancelled);
+    require!(!escrow_account.is_contested(), GameError::EscrowContested);
+    require!(
+        !escrow_account.is_score_correction_pending(),
+        GameError::ScoreCorrectionPending
+    );
 
-    // Validate all prize amounts are positive
-    for &amount in &prize_amounts {
-        require!(amount > 0, GameError::InvalidPayload);
-    }
+    // Seats get the whole post-match dispute window to flag_dispute before anything is paid
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        !post_match_dispute_window_open(match_account.ended_at, now)?,
+        GameError::DisputeWindowOpen
+    );
+
+    // Per-seat stakes must account for the whole escrow balance being settled
+    require!(
+        escrow_account.total_player_stakes() == escrow_account.total_entry_lamports,
+        GameError::EscrowLedgerMismatch
+    );
+    let player_stakes = escrow_account.player_stakes;
+    let entry_mint = escrow_account.entry_mint;
+    let escrow_bump = escrow_account.bump;
 
-    // Calculate platform fee
-    let platform_fee_bps = config.platform_fee_bps as u64;
+    // Calculate platform fee (rate fixed at create_match from the game's economics)
+    let platform_fee_bps = escrow_account.platform_fee_bps as u64;
     let platform_fee = escrow_account
         .total_entry_lamports
         .checked_mul(platform_fee_bps)
         .and_then(|x| x.checked_div(10000))
         .ok_or(GameError::Overflow)?;
 
-    // Calculate expected prize pool (total entry fees - platform fee)
-    let expected_prize_pool = escrow_account
+    // Prize pool = total entry fees - platform fee, split by the declared prize structure
+    let prize_pool = escrow_account
         .total_entry_lamports
         .checked_sub(platform_fee)
         .ok_or(GameError::Overflow)?;
+    let payouts = PrizeStructure::compute_payouts(&match_account, prize_pool)?;
 
-    // Validate prize amounts sum equals expected prize pool
-    let total_prizes: u64 = prize_amounts.iter().sum();
-    require!(
-        total_prizes == expected_prize_pool,
-        GameError::InvalidPayload
-    );
-
-    // Validate we have enough winner accounts
-    require!(
-        winner_indices.len() <= 10,
-        GameError::InvalidPayload
-    );
+    // One payout account per seat (token PLATFORM seats pass two: ledger and deposit vault)
+    let player_count = match_account.player_count as usize;
+    let payment_method = match_account.get_payment_method();
+    if entry_mint != Pubkey::default() {
+        let accounts_per_seat = TokenEscrow::accounts_per_seat(payment_method);
+        require!(
+            ctx.remaining_accounts.len() == player_count * accounts_per_seat,
+            GameError::InvalidPayload
+        );
+    } else {
+        require!(
+            ctx.remaining_accounts.len() == player_count,
+            GameError::InvalidPayload
+        );
+    }
 
     // Update escrow with platform fee info (before dropping borrow)
-    if platform_fee > 0 {
+    // Token fees are recorded in the mint's SupportedMint ledger, never as lamports
+    if platform_fee > 0 && entry_mint == Pubkey::default() {
         escrow_account.platform_fee_lamports = platform_fee;
-        escrow_account.treasury_due_lamports = platform_fee;
     }
-    
+
     // Drop mutable borrow before lamport transfers
     drop(escrow_account);
-    
+
     // Get account info for manual lamport transfers (after dropping mutable borrow)
     let escrow_account_info = ctx.accounts.escrow_account.to_account_info();
 
-    // Transfer platform fee to treasury first (before prize distribution)
-    if platform_fee > 0 {
+    if entry_mint != Pubkey::default() {
+        // SPL token match: every transfer comes out of the escrow vault
+        let escrow_vault = ctx
+            .accounts
+            .escrow_vault
+            .as_ref()
+            .ok_or(GameError::InvalidPayload)?
+            .to_account_info();
+        let token_program = ctx
+            .accounts
+            .token_program
+            .as_ref()
+            .ok_or(GameError::InvalidPayload)?
+            .to_account_info();
+        let vault = EscrowVault {
+            token_program: &token_program,
+            vault: &escrow_vault,
+            escrow: &escrow_account_info,
+            match_pda: ctx.accounts.match_account.key(),
+            escrow_bump,
+            mint: entry_mint,
+        };
+        vault.validate(ctx.program_id)?;
+
+        // Platform fee goes to the treasury multisig's token account for this mint
+        if platform_fee > 0 {
+            let treasury_token_account = ctx
+                .accounts
+                .treasury_token_account
+                .as_ref()
+                .ok_or(GameError::InvalidPayload)?
+                .to_account_info();
+            TokenEscrow::validate_token_account(
+                &treasury_token_account,
+                &entry_mint,
+                &config.treasury_multisig,
+            )?;
+            vault.transfer_out(&treasury_token_account, platform_fee)?;
+
+            let supported_mint = ctx
+                .accounts
+                .supported_mint
+                .as_mut()
+                .ok_or(GameError::InvalidPayload)?;
+            require!(supported_mint.mint == entry_mint, GameError::InvalidPayload);
+            supported_mint.record_fee(fee_source::PLATFORM_FEE, platform_fee)?;
+        }
+
+        let accounts_per_seat = TokenEscrow::accounts_per_seat(payment_method);
+        for seat in 0..player_count {
+            let first = seat * accounts_per_seat;
+            vault.settle_seat(
+                payment_method,
+                &match_account.player_wallets[seat],
+                &ctx.remaining_accounts[first..first + accounts_per_seat],
+                (player_stakes[seat], payouts[seat]),
+                ctx.program_id,
+            )?;
+            msg!(
+                "Seat {} ({}): score {}, prize {} tokens",
+                seat,
+                match_account.player_wallets[seat],
+                match_account.scores[seat],
+                payouts[seat]
+            );
+        }
+    }
+
+    // Transfer platform fee to the treasury vault first (before prize distribution)
+    if platform_fee > 0 && entry_mint == Pubkey::default() {
         // Transfer platform fee to treasury using manual lamport transfer
         // Cannot use system_program::transfer() because escrow_account carries data
-        let treasury_account_info = ctx.accounts.treasury.to_account_info();
+        let treasury_account_info = ctx.accounts.treasury_vault.to_account_info();
         **escrow_account_info.try_borrow_mut_lamports()? -= platform_fee;
         **treasury_account_info.try_borrow_mut_lamports()? += platform_fee;
-    }
 
-    // Determine payment method from match
-    let payment_method = match_account.get_payment_method();
+        let mut treasury_vault = ctx.accounts.treasury_vault.load_mut()?;
+        treasury_vault.record_fee(
+            fee_source::PLATFORM_FEE,
+            Some(match_account.game_type),
+            platform_fee,
+        )?;
+    }
 
-    // Distribute prizes to all winners atomically
+    // Pay every seat atomically
     // If any transfer fails, the entire transaction reverts (atomic)
-    for (i, &winner_index) in winner_indices.iter().enumerate() {
-        let prize_amount = prize_amounts[i];
-        require!(i < 10, GameError::InvalidPayload);
-
-        // Get winner account by index (accounts are in order: winner_0, winner_1, etc.)
-        let winner_account = match i {
-            0 => &ctx.accounts.winner_0,
-            1 => &ctx.accounts.winner_1,
-            2 => &ctx.accounts.winner_2,
-            3 => &ctx.accounts.winner_3,
-            4 => &ctx.accounts.winner_4,
-            5 => &ctx.accounts.winner_5,
-            6 => &ctx.accounts.winner_6,
-            7 => &ctx.accounts.winner_7,
-            8 => &ctx.accounts.winner_8,
-            9 => &ctx.accounts.winner_9,
-            _ => return Err(GameError::InvalidPayload.into()),
-        };
+    let lamport_payouts = if entry_mint == Pubkey::default() {
+        ctx.remaining_accounts
+    } else {
+        &[]
+    };
+    for (seat, payout_info) in lamport_payouts.iter().enumerate() {
+        let wallet = match_account.player_wallets[seat];
+        let prize_amount = payouts[seat];
 
         if payment_method == crate::state::enums::payment_method::WALLET {
-            // Transfer prize to winner wallet using manual lamport transfer
-            // Cannot use system_program::transfer() because escrow_account carries data
-            let winner_account_info = winner_account.to_account_info();
-            **escrow_account_info.try_borrow_mut_lamports()? -= prize_amount;
-            **winner_account_info.try_borrow_mut_lamports()? += prize_amount;
+            // Security: Must be the wallet that owns this seat
+            require!(payout_info.key() == wallet, GameError::InvalidPayload);
 
-            msg!(
-                "Distributed {} lamports to winner wallet {} (match player index: {})",
-                prize_amount,
-                winner_account.key(),
-                winner_index
-            );
+            // Transfer prize to the seat's wallet using manual lamport transfer
+            // Cannot use system_program::transfer() because escrow_account carries data
+            if prize_amount > 0 {
+                **escrow_account_info.try_borrow_mut_lamports()? -= prize_amount;
+                **payout_info.try_borrow_mut_lamports()? += prize_amount;
+            }
         } else {
-            // Platform payment: Transfer prize to winner's deposit account
-            let deposit_account = match i {
-                0 => ctx.accounts.winner_deposit_0.as_ref(),
-                1 => ctx.accounts.winner_deposit_1.as_ref(),
-                2 => ctx.accounts.winner_deposit_2.as_ref(),
-                3 => ctx.accounts.winner_deposit_3.as_ref(),
-                4 => ctx.accounts.winner_deposit_4.as_ref(),
-                5 => ctx.accounts.winner_deposit_5.as_ref(),
-                6 => ctx.accounts.winner_deposit_6.as_ref(),
-                7 => ctx.accounts.winner_deposit_7.as_ref(),
-                8 => ctx.accounts.winner_deposit_8.as_ref(),
-                9 => ctx.accounts.winner_deposit_9.as_ref(),
-                _ => return Err(GameError::InvalidPayload.into()),
-            };
-
-            let deposit_account = deposit_account
-                .ok_or(GameError::InvalidPaymentMethod)?;
-
-            // Transfer prize to winner's deposit account using manual lamport transfer
-            let deposit_account_info = deposit_account.to_account_info();
-            **escrow_account_info.try_borrow_mut_lamports()? -= prize_amount;
-            **deposit_account_info.try_borrow_mut_lamports()? += prize_amount;
-
-            // Update deposit account balances
-            let mut deposit_account_mut = deposit_account.load_mut()?;
-            deposit_account_mut.available_lamports = deposit_account_mut
+            // Security: Must be the deposit PDA of the wallet that owns this seat
+            let (expected_deposit, _) = Pubkey::find_program_address(
+                &[b"user_deposit", wallet.as_ref()],
+                ctx.program_id,
+            );
+            require!(
+                payout_info.key() == expected_deposit,
+                GameError::InvalidPayload
+            );
+            let deposit_loader = AccountLoader::<UserDepositAccount>::try_from(payout_info)?;
+
+            if prize_amount > 0 {
+                **escrow_account_info.try_borrow_mut_lamports()? -= prize_amount;
+                **payout_info.try_borrow_mut_lamports()? += prize_amount;
+            }
+
+            let mut deposit_account = deposit_loader.load_mut()?;
+            // Credit the prize (backed by the lamports just moved into the deposit account)
+            deposit_account.available_lamports = deposit_account
                 .available_lamports
                 .checked_add(prize_amount)
                 .ok_or(GameError::Overflow)?;
-            // Unlock entry fee from in_play (it was locked when joining)
-            let entry_fee = match_account.entry_fee_lamports;
-            if deposit_account_mut.in_play_lamports >= entry_fee {
-                deposit_account_mut.in_play_lamports = deposit_account_mut
-                    .in_play_lamports
-                    .checked_sub(entry_fee)
-                    .ok_or(GameError::Overflow)?;
-            }
-
-            msg!(
-                "Distributed {} lamports to winner deposit {} (match player index: {})",
-                prize_amount,
-                deposit_account_info.key(),
-                winner_index
-            );
+            // Unlock the entry fee locked in join_match - its lamports left escrow above
+            deposit_account.in_play_lamports = deposit_account
+                .in_play_lamports
+                .checked_sub(player_stakes[seat])
+                .ok_or(GameError::EscrowLedgerMismatch)?;
         }
+
+        msg!(
+            "Seat {} ({}): score {}, prize {} lamports",
+            seat,
+            payout_info.key(),
+            match_account.scores[seat],
+            prize_amount
+        );
     }
 
     // Re-borrow escrow to mark as distributed and clear total_entry_lamports (only after all transfers succeed)
     let mut escrow_account = ctx.accounts.escrow_account.load_mut()?;
     escrow_account.set_distributed(true);
     escrow_account.total_entry_lamports = 0; // Clear entry lamports after distribution
+    escrow_account.player_stakes = [0u64; crate::state::escrow::MAX_PLAYERS]; // All stakes settled
 
     msg!(
-        "Prize distribution complete: {} lamports distributed to {} winners, platform fee: {}",
-        total_prizes,
-        winner_indices.len(),
+        "Prize distribution complete: {} lamports distributed to {} seats (prize structure {}), platform fee: {}",
+        prize_pool,
+        player_count,
+        match_account.prize_structure,
         platform_fee
     );
 
@@ -251,122 +324,37 @@ pub struct DistributePrizes<'info> {
     )]
     pub config_account: Account<'info, ConfigAccount>,
 
-    /// Treasury account (receives platform fee)
-    /// CHECK: Validated in handler - must be config.treasury_multisig
-    #[account(mut)]
-    pub treasury: AccountInfo<'info>,
-
-    /// Winner accounts (up to 10, fixed accounts for Anchor compatibility)
-    /// CHECK: Validated in handler - indices must match winner_indices parameter
-    #[account(mut)]
-    pub winner_0: AccountInfo<'info>,
-
-    /// CHECK: Validated in handler - indices must match winner_indices parameter
-    #[account(mut)]
-    pub winner_1: AccountInfo<'info>,
-
-    /// CHECK: Validated in handler - indices must match winner_indices parameter
-    #[account(mut)]
-    pub winner_2: AccountInfo<'info>,
-
-    /// CHECK: Validated in handler - indices must match winner_indices parameter
-    #[account(mut)]
-    pub winner_3: AccountInfo<'info>,
-
-    /// CHECK: Validated in handler - indices must match winner_indices parameter
-    #[account(mut)]
-    pub winner_4: AccountInfo<'info>,
-
-    /// CHECK: Validated in handler - indices must match winner_indices parameter
-    #[account(mut)]
-    pub winner_5: AccountInfo<'info>,
-
-    /// CHECK: Validated in handler - indices must match winner_indices parameter
-    #[account(mut)]
-    pub winner_6: AccountInfo<'info>,
-
-    /// CHECK: Validated in handler - indices must match winner_indices parameter
-    #[account(mut)]
-    pub winner_7: AccountInfo<'info>,
-
-    /// CHECK: Validated in handler - indices must match winner_indices parameter
-    #[account(mut)]
-    pub winner_8: AccountInfo<'info>,
-
-    /// CHECK: Validated in handler - indices must match winner_indices parameter
-    #[account(mut)]
-    pub winner_9: AccountInfo<'info>,
-
-    /// Winner deposit accounts (only needed for platform payment method)
+    /// Treasury vault (receives platform fee)
     #[account(
         mut,
-        seeds = [b"user_deposit", winner_0.key().as_ref()],
-        bump
-    )]
-    pub winner_deposit_0: Option<AccountLoader<'info, UserDepositAccount>>,
-
-    #[account(
-        mut,
-        seeds = [b"user_deposit", winner_1.key().as_ref()],
-        bump
+        seeds = [b"treasury_vault"],
+        bump = treasury_vault.load()?.bump
     )]
-    pub winner_deposit_1: Option<AccountLoader<'info, UserDepositAccount>>,
+    pub treasury_vault: AccountLoader<'info, TreasuryVault>,
 
+    /// Escrow vault (only required for SPL token matches)
     #[account(
         mut,
-        seeds = [b"user_deposit", winner_2.key().as_ref()],
+        seeds = [b"escrow_vault", match_account.key().as_ref()],
         bump
     )]
-    pub winner_deposit_2: Option<AccountLoader<'info, UserDepositAccount>>,
+    pub escrow_vault: Option<Box<Account<'info, TokenAccount>>>,
 
-    #[account(
-        mut,
-        seeds = [b"user_deposit", winner_3.key().as_ref()],
-        bump
-    )]
-    pub winner_deposit_3: Option<AccountLoader<'info, UserDepositAccount>>,
-
-    #[account(
-        mut,
-        seeds = [b"user_deposit", winner_4.key().as_ref()],
-        bump
-    )]
-    pub winner_deposit_4: Option<AccountLoader<'info, UserDepositAccount>>,
-
-    #[account(
-        mut,
-        seeds = [b"user_deposit", winner_5.key().as_ref()],
-        bump
-    )]
-    pub winner_deposit_5: Option<AccountLoader<'info, UserDepositAccount>>,
-
-    #[account(
-        mut,
-        seeds = [b"user_deposit", winner_6.key().as_ref()],
-        bump
-    )]
-    pub winner_deposit_6: Option<AccountLoader<'info, UserDepositAccount>>,
-
-    #[account(
-        mut,
-        seeds = [b"user_deposit", winner_7.key().as_ref()],
-        bump
-    )]
-    pub winner_deposit_7: Option<AccountLoader<'info, UserDepositAccount>>,
+    /// Treasury multisig's token account for the escrow mint (SPL token matches with a platform fee)
+    /// CHECK: Validated in handler - owner must be config_account.treasury_multisig
+    #[account(mut)]
+    pub treasury_token_account: Option<Box<Account<'info, TokenAccount>>>,
 
+    /// SupportedMint of the escrow mint (SPL token matches with a platform fee) - its ledger
+    /// records the token platform fee
     #[account(
         mut,
-        seeds = [b"user_deposit", winner_8.key().as_ref()],
-        bump
+        seeds = [b"supported_mint", supported_mint.mint.as_ref()],
+        bump = supported_mint.bump
     )]
-    pub winner_deposit_8: Option<AccountLoader<'info, UserDepositAccount>>,
+    pub supported_mint: Option<Box<Account<'info, SupportedMint>>>,
 
-    #[account(
-        mut,
-        seeds = [b"user_deposit", winner_9.key().as_ref()],
-        bump
-    )]
-    pub winner_deposit_9: Option<AccountLoader<'info, UserDepositAccount>>,
+    pub token_program: Option<Program<'info, Token>>,
 
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
@@ -15,7 +15,7 @@ pub fn handler(ctx: Context<StartGameWithGP>, match_id: String, user_id: String)
     require!(user_id_bytes.len() <= 64, GameError::InvalidPayload);
 
     let user_account = &mut ctx.accounts.user_account;
-    let _config = &ctx.accounts.config_account;
+    let config = &ctx.accounts.config_account;
 
     // Update stats (GP balance deducted in database before calling this)
     user_account.games_played = user_account
@@ -23,21 +23,10 @@ pub fn handler(ctx: Context<StartGameWithGP>, match_id: String, user_id: String)
         .checked_add(1)
         .ok_or(GameError::Overflow)?;
 
-    // Update season stats
-    let clock = Clock::get()?;
-    let current_season_id = (clock.unix_timestamp / 604800) as u64; // 7 days in seconds
-
-    // Reset season stats if new season
-    if user_account.current_season_id != current_season_id {
-        user_account.current_season_id = current_season_id;
-        user_account.season_games = 1;
-        user_account.season_wins = 0;
-        user_account.season_score = 0;
-    } else {
-        user_account.season_games = user_account
-            .season_games
-            .checked_add(1)
-            .ok_or(GameError::Overflow)?;
+    // Reset season stats once the config has rolled over to a new season
+    // (season games and wins are counted by record_result)
+    if user_account.current_season_id != config.current_season_id {
+        user_account.start_season(config.current_season_id);
     }
 
     msg!(
@@ -0,0 +1,52 @@
+use crate::error::GameError;
+use crate::state::{ConfigAccount, TreasuryVault};
+use anchor_lang::prelude::*;
+
+/// Initializes the program-owned TreasuryVault PDA.
+/// Must be called once before any instruction that collects fees.
+/// Only the treasury multisig can call this instruction.
+pub fn handler(ctx: Context<InitializeTreasury>) -> Result<()> {
+    let config = &ctx.accounts.config_account;
+
+    // Validate authority is treasury multisig
+    require!(
+        ctx.accounts.authority.key() == config.treasury_multisig,
+        GameError::Unauthorized
+    );
+
+    let clock = Clock::get()?;
+    let mut treasury_vault = ctx.accounts.treasury_vault.load_init()?;
+    treasury_vault.bump = ctx.bumps.treasury_vault;
+    treasury_vault.created_at = clock.unix_timestamp;
+
+    msg!(
+        "TreasuryVault initialized: {} (withdrawals signed by {})",
+        ctx.accounts.treasury_vault.key(),
+        config.treasury_multisig
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeTreasury<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = TreasuryVault::MAX_SIZE,
+        seeds = [b"treasury_vault"],
+        bump
+    )]
+    pub treasury_vault: AccountLoader<'info, TreasuryVault>,
+
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+
+    /// CHECK: Treasury multisig authority (must match config_account.treasury_multisig)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
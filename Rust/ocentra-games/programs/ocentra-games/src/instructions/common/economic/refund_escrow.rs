@@ -1,6 +1,9 @@
+use crate::common::token_escrow::{EscrowVault, TokenEscrow};
 use crate::error::GameError;
-use crate::state::{EscrowAccount, Match, UserDepositAccount};
+use crate::state::enums::fee_source;
+use crate::state::{EscrowAccount, Match, SupportedMint, TreasuryVault, UserDepositAccount};
 use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
 
 /// Refunds entry fees from EscrowAccount to all players atomically.
 /// Per Phase 03: Economic instructions for escrow refunds.
@@ -8,18 +11,31 @@ use anchor_lang::prelude::*;
 /// **Enterprise-grade features:**
 /// - Atomic operation: All refunds processed or none (all-or-nothing)
 /// - Supports both wallet and platform payment methods
-/// - Validates match is cancelled before refunding
-/// - Prevents double refunds with escrow status flag
+/// - Validates match was cancelled by cancel_match (or voided by a dispute) before refunding
+/// - Frozen while a dispute on the match is open
+/// - Reason and abandoned player come from the cancellation recorded in escrow
+/// - Settles every staked seat in one call - the escrow is left empty and closable
 /// - Updates UserDepositAccount balances for platform payments
 /// - Penalty system: Abandoned players forfeit entry fee (prevents exploitation)
+/// - Cancellation fee is deducted from each refund and paid into the TreasuryVault PDA
+/// - Forfeited stakes follow ConfigAccount.forfeit_routing: TreasuryVault, or pro rata to the
+///   refunded players
 /// - Comprehensive validation of all inputs
 ///
 /// **Cancellation Policy (Industry Standard):**
 /// - PLATFORM_FAULT: All players get full refunds, no platform fee
-/// - PLAYER_ABANDONMENT/TIMEOUT/GRACE_PERIOD_EXPIRED: Abandoned player forfeits entry fee, others get full refunds
-/// - INSUFFICIENT_PLAYERS: All players get full refunds, small platform cancellation fee
-pub fn handler(
-    ctx: Context<RefundEscrow>,
+/// - PLAYER_ABANDONMENT/TIMEOUT/GRACE_PERIOD_EXPIRED: Abandoned player forfeits entry fee, others
+///   are refunded minus the cancellation fee
+/// - INSUFFICIENT_PLAYERS: All players are refunded minus a small platform cancellation fee
+/// - DISPUTE_VOIDED: All players get full refunds, no platform fee
+/// - DISPUTE_PARTIAL_REFUND: All players are refunded minus the cancellation fee
+///
+/// SPL token matches refund out of the escrow vault: remaining_accounts carry each refunded
+/// seat's token payout accounts in player_indices order (a token account owned by the seat's
+/// wallet, or its token ledger followed by its deposit vault), and the treasury's share goes to
+/// the treasury multisig's token account, recorded by source in the mint's SupportedMint ledger.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RefundEscrow<'info>>,
     match_id: String,
     player_indices: Vec<u8>,
     cancellation_reason: u8,
@@ -49,13 +65,19 @@ pub fn handler(
         GameError::InvalidPayload
     );
 
-    // Validate match is cancelled (escrow cancelled flag or match ended prematurely)
-    // Note: Phase 04 will add explicit cancelled flag to Match struct
+    // Validate match was cancelled (cancel_match sets both the match phase and escrow flag;
+    // finalize_dispute cancels only the escrow of an ended match, with a dispute reason)
+    let voided_by_dispute = match_account.phase == crate::state::game_phase::ENDED
+        && matches!(
+            escrow_account.get_cancellation_reason(),
+            crate::state::enums::cancellation_reason::DISPUTE_VOIDED
+                | crate::state::enums::cancellation_reason::DISPUTE_PARTIAL_REFUND
+        );
     require!(
-        escrow_account.is_cancelled()
-            || match_account.phase != crate::state::match_state::game_phase::ENDED,
+        escrow_account.is_cancelled() && (match_account.is_cancelled() || voided_by_dispute),
         GameError::MatchNotCancelled
     );
+    require!(!escrow_account.is_contested(), GameError::EscrowContested);
 
     // Validate escrow not already distributed
     require!(
@@ -66,6 +88,16 @@ pub fn handler(
     // Validate cancellation reason is valid (0-7, 3 bits)
     require!(cancellation_reason <= 7, GameError::InvalidPayload);
 
+    // Reason and abandoned player must match the cancellation recorded by cancel_match
+    require!(
+        cancellation_reason == escrow_account.get_cancellation_reason(),
+        GameError::InvalidPayload
+    );
+    require!(
+        abandoned_player_index.unwrap_or(255) == escrow_account.abandoned_player_index,
+        GameError::InvalidPayload
+    );
+
     // Validate abandoned_player_index if provided
     if let Some(abandoned_idx) = abandoned_player_index {
         require!(abandoned_idx < 10, GameError::InvalidPayload);
@@ -93,10 +125,6 @@ pub fn handler(
     // Determine payment method from match
     let payment_method = match_account.get_payment_method();
 
-    // Store cancellation reason and abandoned player index in escrow
-    escrow_account.set_cancellation_reason(cancellation_reason);
-    escrow_account.abandoned_player_index = abandoned_player_index.unwrap_or(255);
-
     // Determine if abandoned player should forfeit entry fee
     let abandoned_forfeits = matches!(
         cancellation_reason,
@@ -105,82 +133,150 @@ pub fn handler(
             | crate::state::enums::cancellation_reason::GRACE_PERIOD_EXPIRED
     );
 
-    // Calculate cancellation fee (if applicable)
-    let cancellation_fee = if cancellation_reason
-        == crate::state::enums::cancellation_reason::PLATFORM_FAULT
-    {
-        // No cancellation fee for platform fault
+    // Cancellation fee rate (charged on every refunded stake, none for platform fault)
+    let cancellation_fee_bps = if matches!(
+        cancellation_reason,
+        crate::state::enums::cancellation_reason::PLATFORM_FAULT
+            | crate::state::enums::cancellation_reason::DISPUTE_VOIDED
+    ) {
         0
     } else {
-        // Calculate cancellation fee as percentage of total entry fees
-        let cancellation_fee_bps = config.cancellation_fee_bps as u64;
-        escrow_account
-            .total_entry_lamports
-            .checked_mul(cancellation_fee_bps)
-            .and_then(|x| x.checked_div(10000))
-            .ok_or(GameError::Overflow)?
+        config.cancellation_fee_bps as u64
     };
 
-    // Track total refunded amount and abandoned player's stake
-    let mut total_refunded = 0u64;
-    let abandoned_stake = if let Some(abandoned_idx) = abandoned_player_index {
-        escrow_account.get_player_stake(abandoned_idx as usize)
-    } else {
-        0
-    };
+    // Every staked seat is settled in this call so the escrow ends up empty (closable)
+    for seat in 0..match_account.player_count {
+        if escrow_account.get_player_stake(seat as usize) > 0 {
+            require!(
+                player_indices.contains(&seat),
+                GameError::IncompleteSettlement
+            );
+        }
+    }
+    require!(
+        escrow_account.total_player_stakes() == escrow_account.total_entry_lamports,
+        GameError::EscrowLedgerMismatch
+    );
 
-    // Collect refund data first (while escrow is mutably borrowed)
-    let mut refunds: Vec<(usize, u8, u64)> = Vec::new();
+    // Settlement plan: (position in player_indices, seat, stake, payout, forfeited)
+    let mut settlements: Vec<(usize, usize, u64, u64, bool)> = Vec::new();
+    let mut cancellation_fee = 0u64;
+    let mut forfeited_stake = 0u64;
+    let mut refunded_stakes = 0u64;
     for (i, &player_index) in player_indices.iter().enumerate() {
-        // Skip abandoned player if they forfeit entry fee
-        if abandoned_forfeits
-            && abandoned_player_index.is_some()
-            && player_index == abandoned_player_index.unwrap()
-        {
-            let forfeited_stake = escrow_account.get_player_stake(player_index as usize);
+        let seat = player_index as usize;
+        let stake = escrow_account.get_player_stake(seat);
+        // A zero stake here means the seat was listed twice (or never paid)
+        require!(stake > 0, GameError::InvalidPayload);
+        escrow_account.set_player_stake(seat, 0);
+
+        if abandoned_forfeits && abandoned_player_index == Some(player_index) {
             msg!(
                 "Player {} (index {}) forfeits {} lamports entry fee due to abandonment",
+                match_account.player_wallets[seat],
                 player_index,
-                player_index,
-                forfeited_stake
+                stake
             );
-            // Clear their stake from escrow (stake will go to treasury)
-            escrow_account.set_player_stake(player_index as usize, 0);
-            escrow_account.total_entry_lamports = escrow_account
-                .total_entry_lamports
-                .checked_sub(forfeited_stake)
-                .ok_or(GameError::Overflow)?;
+            forfeited_stake = stake;
+            settlements.push((i, seat, stake, 0, true));
             continue;
         }
 
-        let player_stake = escrow_account.get_player_stake(player_index as usize);
-        require!(player_stake > 0, GameError::InvalidPayload);
-        require!(i < 10, GameError::InvalidPayload);
-        
-        refunds.push((i, player_index, player_stake));
-        
-        // Update escrow account
-        escrow_account.set_player_stake(player_index as usize, 0);
-        escrow_account.total_entry_lamports = escrow_account
-            .total_entry_lamports
-            .checked_sub(player_stake)
+        let fee_share = stake
+            .checked_mul(cancellation_fee_bps)
+            .and_then(|x| x.checked_div(10000))
             .ok_or(GameError::Overflow)?;
-
-        total_refunded = total_refunded
-            .checked_add(player_stake)
+        cancellation_fee = cancellation_fee
+            .checked_add(fee_share)
+            .ok_or(GameError::Overflow)?;
+        refunded_stakes = refunded_stakes
+            .checked_add(stake)
             .ok_or(GameError::Overflow)?;
+        settlements.push((i, seat, stake, stake - fee_share, false));
     }
-    
+
+    // Route the forfeited stake: pro rata by stake to the refunded players, or to the treasury
+    // (always the treasury when nobody else is being refunded)
+    let forfeit_to_players = forfeited_stake > 0
+        && refunded_stakes > 0
+        && config.forfeit_routing == crate::state::enums::forfeit_routing::REMAINING_PLAYERS;
+    if forfeit_to_players {
+        let mut routed = 0u64;
+        for settlement in settlements.iter_mut().filter(|s| !s.4) {
+            let share = u64::try_from(
+                (forfeited_stake as u128) * (settlement.2 as u128) / (refunded_stakes as u128),
+            )
+            .map_err(|_| GameError::Overflow)?;
+            settlement.3 = settlement.3.checked_add(share).ok_or(GameError::Overflow)?;
+            routed = routed.checked_add(share).ok_or(GameError::Overflow)?;
+        }
+        // Rounding dust goes to the first refunded player
+        if let Some(first) = settlements.iter_mut().find(|s| !s.4) {
+            first.3 = first
+                .3
+                .checked_add(forfeited_stake - routed)
+                .ok_or(GameError::Overflow)?;
+        }
+    }
+    let treasury_forfeit = if forfeit_to_players {
+        0
+    } else {
+        forfeited_stake
+    };
+    let platform_receives = cancellation_fee
+        .checked_add(treasury_forfeit)
+        .ok_or(GameError::Overflow)?;
+
+    let entry_mint = escrow_account.entry_mint;
+    let escrow_bump = escrow_account.bump;
+
+    // Whole escrow ledger is settled below
+    escrow_account.total_entry_lamports = 0;
+
     // Drop mutable borrow before lamport transfers
     drop(escrow_account);
-    
+
     // Get account info for manual lamport transfers (after dropping mutable borrow)
     let escrow_account_info = ctx.accounts.escrow_account.to_account_info();
 
-    // Refund all players atomically (except abandoned player if they forfeit)
-    // If any refund fails, the entire transaction reverts (atomic)
-    for (i, player_index, player_stake) in refunds {
-        // Get player account by index (accounts are in order: player_0, player_1, etc.)
+    // SPL token match: every transfer comes out of the escrow vault
+    let accounts_per_seat = TokenEscrow::accounts_per_seat(payment_method);
+    let token_accounts = if entry_mint != Pubkey::default() {
+        require!(
+            ctx.remaining_accounts.len() == player_indices.len() * accounts_per_seat,
+            GameError::InvalidPayload
+        );
+        let escrow_vault = ctx
+            .accounts
+            .escrow_vault
+            .as_ref()
+            .ok_or(GameError::InvalidPayload)?;
+        let token_program = ctx
+            .accounts
+            .token_program
+            .as_ref()
+            .ok_or(GameError::InvalidPayload)?;
+        Some((escrow_vault.to_account_info(), token_program.to_account_info()))
+    } else {
+        None
+    };
+    let vault = token_accounts.as_ref().map(|(vault, token_program)| EscrowVault {
+        token_program,
+        vault,
+        escrow: &escrow_account_info,
+        match_pda: ctx.accounts.match_account.key(),
+        escrow_bump,
+        mint: entry_mint,
+    });
+    if let Some(vault) = &vault {
+        vault.validate(ctx.program_id)?;
+    }
+
+    // Settle every seat atomically
+    // If any transfer fails, the entire transaction reverts (atomic)
+    let mut total_refunded = 0u64;
+    for (i, seat, stake, payout, forfeited) in settlements {
+        // Get player account by position (accounts are in player_indices order: player_0, player_1, etc.)
         let player_account = match i {
             0 => &ctx.accounts.player_0,
             1 => &ctx.accounts.player_1,
@@ -195,12 +291,31 @@ pub fn handler(
             _ => return Err(GameError::InvalidPayload.into()),
         };
 
-        if payment_method == crate::state::enums::payment_method::WALLET {
+        // Security: Must be the wallet that owns this seat
+        require!(
+            player_account.key() == match_account.player_wallets[seat],
+            GameError::InvalidPayload
+        );
+
+        if let Some(vault) = &vault {
+            // Token refund to the seat's token account or deposit vault (forfeiters are paid 0
+            // but still have their stake unlocked from in_play)
+            let first = i * accounts_per_seat;
+            vault.settle_seat(
+                payment_method,
+                &match_account.player_wallets[seat],
+                &ctx.remaining_accounts[first..first + accounts_per_seat],
+                (stake, payout),
+                ctx.program_id,
+            )?;
+        } else if payment_method == crate::state::enums::payment_method::WALLET {
             // Refund to player's wallet using manual lamport transfer
             // Cannot use system_program::transfer() because escrow_account carries data
-            let player_account_info = player_account.to_account_info();
-            **escrow_account_info.try_borrow_mut_lamports()? -= player_stake;
-            **player_account_info.try_borrow_mut_lamports()? += player_stake;
+            if payout > 0 {
+                let player_account_info = player_account.to_account_info();
+                **escrow_account_info.try_borrow_mut_lamports()? -= payout;
+                **player_account_info.try_borrow_mut_lamports()? += payout;
+            }
         } else {
             // Refund to player's platform deposit account
             let deposit_account = match i {
@@ -222,49 +337,81 @@ pub fn handler(
 
             // Refund to player's platform deposit account using manual lamport transfer
             // Cannot use system_program::transfer() because escrow_account carries data
-            let deposit_account_info = deposit_account.to_account_info();
-            **escrow_account_info.try_borrow_mut_lamports()? -= player_stake;
-            **deposit_account_info.try_borrow_mut_lamports()? += player_stake;
+            if payout > 0 {
+                let deposit_account_info = deposit_account.to_account_info();
+                **escrow_account_info.try_borrow_mut_lamports()? -= payout;
+                **deposit_account_info.try_borrow_mut_lamports()? += payout;
+            }
 
-            // Update player deposit account balances
+            // Unlock the whole stake from in_play (forfeited stakes too - their lamports left
+            // escrow for the treasury or the other players) and credit what came back
             let mut deposit_account_mut = deposit_account.load_mut()?;
             deposit_account_mut.available_lamports = deposit_account_mut
                 .available_lamports
-                .checked_add(player_stake)
+                .checked_add(payout)
                 .ok_or(GameError::Overflow)?;
             deposit_account_mut.in_play_lamports = deposit_account_mut
                 .in_play_lamports
-                .checked_sub(player_stake)
+                .checked_sub(stake)
+                .ok_or(GameError::EscrowLedgerMismatch)?;
+        }
+
+        if !forfeited {
+            total_refunded = total_refunded
+                .checked_add(payout)
                 .ok_or(GameError::Overflow)?;
+            msg!(
+                "Refunded {} of {} lamports stake to player {} (match player index: {})",
+                payout,
+                stake,
+                player_account.key(),
+                seat
+            );
+        }
+    }
+
+    // Token matches: the treasury's share goes to the treasury multisig's token account
+    if let Some(vault) = vault.as_ref().filter(|_| platform_receives > 0) {
+        let treasury_token_account = ctx
+            .accounts
+            .treasury_token_account
+            .as_ref()
+            .ok_or(GameError::InvalidPayload)?
+            .to_account_info();
+        TokenEscrow::validate_token_account(
+            &treasury_token_account,
+            &entry_mint,
+            &config.treasury_multisig,
+        )?;
+        vault.transfer_out(&treasury_token_account, platform_receives)?;
+
+        // Record each source separately in the mint's fee ledger
+        let supported_mint = ctx
+            .accounts
+            .supported_mint
+            .as_mut()
+            .ok_or(GameError::InvalidPayload)?;
+        require!(supported_mint.mint == entry_mint, GameError::InvalidPayload);
+        if cancellation_fee > 0 {
+            supported_mint.record_fee(fee_source::CANCELLATION_FEE, cancellation_fee)?;
+        }
+        if treasury_forfeit > 0 {
+            supported_mint.record_fee(fee_source::FORFEITED_STAKE, treasury_forfeit)?;
         }
 
         msg!(
-            "Refunded {} lamports to player {} (match player index: {})",
-            player_stake,
-            player_account.key(),
-            player_index
+            "Transferred {} tokens to treasury (cancellation fee: {}, forfeited stake: {})",
+            platform_receives,
+            cancellation_fee,
+            treasury_forfeit
         );
     }
-    
-    // Re-borrow escrow to mark as cancelled
-    let escrow_account = ctx.accounts.escrow_account.load_mut()?;
 
-    // Transfer cancellation fee + abandoned stake to treasury (if applicable)
-    let platform_receives = cancellation_fee
-        .checked_add(if abandoned_forfeits {
-            abandoned_stake
-        } else {
-            0
-        })
-        .ok_or(GameError::Overflow)?;
-
-    // Drop mutable borrow before treasury transfer
-    drop(escrow_account);
-    
-    if platform_receives > 0 {
+    // Transfer cancellation fee + forfeited stake (unless routed to players) to the treasury vault
+    if platform_receives > 0 && vault.is_none() {
         let treasury = ctx
             .accounts
-            .treasury
+            .treasury_vault
             .as_ref()
             .ok_or(GameError::InvalidPayload)?;
         let treasury_account_info = treasury.to_account_info();
@@ -274,36 +421,37 @@ pub fn handler(
         **escrow_account_info.try_borrow_mut_lamports()? -= platform_receives;
         **treasury_account_info.try_borrow_mut_lamports()? += platform_receives;
 
-        // Re-borrow escrow to update treasury_due_lamports
-        let mut escrow_account = ctx.accounts.escrow_account.load_mut()?;
-        escrow_account.treasury_due_lamports = escrow_account
-            .treasury_due_lamports
-            .checked_add(platform_receives)
-            .ok_or(GameError::Overflow)?;
-        drop(escrow_account);
+        // Record each source separately in the treasury ledger
+        let mut treasury_vault = treasury.load_mut()?;
+        if cancellation_fee > 0 {
+            treasury_vault.record_fee(
+                fee_source::CANCELLATION_FEE,
+                Some(match_account.game_type),
+                cancellation_fee,
+            )?;
+        }
+        if treasury_forfeit > 0 {
+            treasury_vault.record_fee(
+                fee_source::FORFEITED_STAKE,
+                Some(match_account.game_type),
+                treasury_forfeit,
+            )?;
+        }
 
         msg!(
-            "Transferred {} lamports to treasury (cancellation fee: {}, abandoned stake: {})",
+            "Transferred {} lamports to treasury (cancellation fee: {}, forfeited stake: {})",
             platform_receives,
             cancellation_fee,
-            if abandoned_forfeits {
-                abandoned_stake
-            } else {
-                0
-            }
+            treasury_forfeit
         );
     }
 
-    // Re-borrow escrow to mark as cancelled and clear total_entry_lamports after all refunds complete
-    let mut escrow_account = ctx.accounts.escrow_account.load_mut()?;
-    escrow_account.set_cancelled(true);
-    escrow_account.total_entry_lamports = 0; // Clear entry lamports after refund
-
     msg!(
-        "Escrow refund complete: {} players refunded (total: {} lamports), platform receives: {} lamports",
+        "Escrow refund complete: {} players refunded (total: {} lamports), platform receives: {} lamports, forfeit routed to players: {}",
         player_indices.len(),
         total_refunded,
-        platform_receives
+        platform_receives,
+        if forfeit_to_players { forfeited_stake } else { 0 }
     );
 
     Ok(())
@@ -326,7 +474,7 @@ pub struct RefundEscrow<'info> {
     pub match_account: AccountLoader<'info, Match>,
 
     /// Player accounts (up to 10, fixed accounts for Anchor compatibility)
-    /// CHECK: Validated in handler - indices must match player_indices parameter
+    /// CHECK: Validated in handler - must be the wallet of the seat at the same position in player_indices
     #[account(mut)]
     pub player_0: AccountInfo<'info>,
 
@@ -444,10 +592,38 @@ pub struct RefundEscrow<'info> {
     )]
     pub config_account: Account<'info, crate::state::ConfigAccount>,
 
-    /// Treasury account (optional - only needed if cancellation fee > 0 or abandoned player forfeits)
-    /// CHECK: Validated in handler
+    /// Treasury vault (optional - only needed if cancellation fee > 0 or abandoned player forfeits)
+    #[account(
+        mut,
+        seeds = [b"treasury_vault"],
+        bump
+    )]
+    pub treasury_vault: Option<AccountLoader<'info, TreasuryVault>>,
+
+    /// Escrow vault (only required for SPL token matches)
+    #[account(
+        mut,
+        seeds = [b"escrow_vault", match_account.key().as_ref()],
+        bump
+    )]
+    pub escrow_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Treasury multisig's token account for the escrow mint (SPL token matches with a
+    /// cancellation fee or a forfeited stake routed to the treasury)
+    /// CHECK: Validated in handler - owner must be config_account.treasury_multisig
     #[account(mut)]
-    pub treasury: Option<AccountInfo<'info>>,
+    pub treasury_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// SupportedMint of the escrow mint (SPL token matches paying the treasury) - its ledger
+    /// records the token cancellation fee and forfeited stake
+    #[account(
+        mut,
+        seeds = [b"supported_mint", supported_mint.mint.as_ref()],
+        bump = supported_mint.bump
+    )]
+    pub supported_mint: Option<Box<Account<'info, SupportedMint>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
 
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
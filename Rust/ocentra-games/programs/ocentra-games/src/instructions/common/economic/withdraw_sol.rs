@@ -1,10 +1,11 @@
 use crate::error::GameError;
-use crate::state::{ConfigAccount, UserDepositAccount};
+use crate::state::enums::fee_source;
+use crate::state::{ConfigAccount, TreasuryVault, UserDepositAccount};
 use anchor_lang::prelude::*;
 
 /// Withdraws SOL from UserDepositAccount back to user wallet (with fee deduction).
 /// Per Phase 03: Economic instructions for platform withdrawals.
-/// Deducts withdrawal fee and transfers remaining amount to user.
+/// Deducts withdrawal fee (paid into the TreasuryVault) and transfers remaining amount to user.
 pub fn handler(ctx: Context<WithdrawSol>, amount: u64) -> Result<()> {
     // Validate amount is greater than zero
     require!(amount > 0, GameError::InvalidPayload);
@@ -46,11 +47,11 @@ pub fn handler(ctx: Context<WithdrawSol>, amount: u64) -> Result<()> {
     let deposit_account_info = ctx.accounts.user_deposit_account.to_account_info();
     let user_account_info = ctx.accounts.user.to_account_info();
 
-    // Transfer withdrawal fee to treasury (if fee > 0)
+    // Transfer withdrawal fee to the treasury vault (if fee > 0)
     if withdrawal_fee > 0 {
         let treasury = ctx
             .accounts
-            .treasury
+            .treasury_vault
             .as_ref()
             .ok_or(GameError::InvalidPayload)?;
         let treasury_account_info = treasury.to_account_info();
@@ -69,6 +70,9 @@ pub fn handler(ctx: Context<WithdrawSol>, amount: u64) -> Result<()> {
         // Assign new balances
         **deposit_account_info.lamports.borrow_mut() = new_deposit_balance;
         **treasury_account_info.lamports.borrow_mut() = new_treasury_balance;
+
+        let mut treasury_vault = treasury.load_mut()?;
+        treasury_vault.record_fee(fee_source::WITHDRAWAL_FEE, None, withdrawal_fee)?;
     }
 
     // Transfer amount to user
@@ -127,10 +131,13 @@ pub struct WithdrawSol<'info> {
     )]
     pub config_account: Account<'info, ConfigAccount>,
 
-    /// Treasury account (optional - only needed if withdrawal_fee > 0)
-    /// CHECK: Validated in handler
-    #[account(mut)]
-    pub treasury: Option<AccountInfo<'info>>,
+    /// Treasury vault (optional - only needed if withdrawal_fee > 0)
+    #[account(
+        mut,
+        seeds = [b"treasury_vault"],
+        bump
+    )]
+    pub treasury_vault: Option<AccountLoader<'info, TreasuryVault>>,
 
     pub system_program: Program<'info, System>,
 }
@@ -0,0 +1,162 @@
+use crate::common::token_escrow::TokenEscrow;
+use crate::error::GameError;
+use crate::state::enums::fee_source;
+use crate::state::{ConfigAccount, SupportedMint, UserDepositAccount};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+/// Withdraws an SPL token from the user's token UserDepositAccount back to their token account.
+/// Deducts the mint's withdrawal fee (SupportedMint.withdrawal_fee, paid to the treasury
+/// multisig's token account and recorded in the SupportedMint fee ledger) and transfers the
+/// remaining amount to the user.
+/// Withdrawals stay open when the mint is disabled so balances can always be recovered.
+pub fn handler(ctx: Context<WithdrawToken>, amount: u64) -> Result<()> {
+    // Validate amount is greater than zero
+    require!(amount > 0, GameError::InvalidPayload);
+
+    let config = &ctx.accounts.config_account;
+
+    // Check if program is paused
+    require!(!config.is_paused, GameError::ProgramPaused);
+
+    let clock = Clock::get()?;
+    let mint = ctx.accounts.mint.key();
+    let withdrawal_fee = ctx.accounts.supported_mint.withdrawal_fee;
+    let total_required = amount
+        .checked_add(withdrawal_fee)
+        .ok_or(GameError::Overflow)?;
+
+    // Validate and update the ledger before the vault transfers
+    let bump = {
+        let mut deposit_account = ctx.accounts.user_deposit_account.load_mut()?;
+
+        require!(
+            deposit_account.authority == ctx.accounts.user.key(),
+            GameError::Unauthorized
+        );
+        require!(deposit_account.mint == mint, GameError::InvalidTokenAccount);
+        require!(!deposit_account.is_frozen(), GameError::AccountFrozen);
+        if deposit_account.is_locked() {
+            require!(
+                clock.unix_timestamp >= deposit_account.locked_until,
+                GameError::AccountLocked
+            );
+        }
+        require!(
+            deposit_account.available_lamports >= total_required,
+            GameError::InsufficientFunds
+        );
+
+        deposit_account.available_lamports = deposit_account
+            .available_lamports
+            .checked_sub(total_required)
+            .ok_or(GameError::Overflow)?;
+        deposit_account.withdrawn_lamports = deposit_account
+            .withdrawn_lamports
+            .checked_add(amount)
+            .ok_or(GameError::Overflow)?;
+
+        msg!(
+            "Withdrew {} of mint {} (fee: {}). Available: {}, Total withdrawn: {}",
+            amount,
+            mint,
+            withdrawal_fee,
+            deposit_account.available_lamports,
+            deposit_account.withdrawn_lamports
+        );
+        deposit_account.bump
+    }; // Borrow on deposit_account is dropped here
+
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let deposit_vault = ctx.accounts.deposit_vault.to_account_info();
+    let ledger = ctx.accounts.user_deposit_account.to_account_info();
+    let user = ctx.accounts.user.key();
+
+    // Transfer withdrawal fee to the treasury multisig's token account (if fee > 0)
+    if withdrawal_fee > 0 {
+        let treasury_token_account = ctx
+            .accounts
+            .treasury_token_account
+            .as_ref()
+            .ok_or(GameError::InvalidPayload)?
+            .to_account_info();
+        TokenEscrow::validate_token_account(
+            &treasury_token_account,
+            &mint,
+            &config.treasury_multisig,
+        )?;
+        TokenEscrow::transfer_from_deposit(
+            &token_program,
+            &deposit_vault,
+            &treasury_token_account,
+            &ledger,
+            (&user, &mint, bump),
+            withdrawal_fee,
+        )?;
+        ctx.accounts
+            .supported_mint
+            .record_fee(fee_source::WITHDRAWAL_FEE, withdrawal_fee)?;
+    }
+
+    // Transfer amount to user
+    TokenEscrow::transfer_from_deposit(
+        &token_program,
+        &deposit_vault,
+        &ctx.accounts.user_token_account.to_account_info(),
+        &ledger,
+        (&user, &mint, bump),
+        amount,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawToken<'info> {
+    #[account(
+        mut,
+        seeds = [b"user_deposit", user.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub user_deposit_account: AccountLoader<'info, UserDepositAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"deposit_vault", user_deposit_account.key().as_ref()],
+        bump
+    )]
+    pub deposit_vault: Box<Account<'info, TokenAccount>>,
+
+    pub mint: Box<Account<'info, Mint>>,
+
+    /// SupportedMint of the withdrawn mint (its ledger records the withdrawal fee)
+    #[account(
+        mut,
+        seeds = [b"supported_mint", mint.key().as_ref()],
+        bump = supported_mint.bump
+    )]
+    pub supported_mint: Box<Account<'info, SupportedMint>>,
+
+    /// User's token account (receives the withdrawal)
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = user
+    )]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Treasury multisig's token account for this mint (optional - only needed if withdrawal_fee > 0)
+    /// CHECK: Validated in handler - owner must be config_account.treasury_multisig
+    #[account(mut)]
+    pub treasury_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
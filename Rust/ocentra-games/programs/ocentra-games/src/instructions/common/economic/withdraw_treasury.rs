@@ -0,0 +1,75 @@
+use crate::error::GameError;
+use crate::state::{ConfigAccount, TreasuryVault};
+use anchor_lang::prelude::*;
+
+/// Withdraws collected fees from the TreasuryVault to a destination chosen by the multisig.
+/// Only the treasury multisig can call this instruction.
+/// The vault keeps its rent-exempt balance - only collected, not yet withdrawn lamports can leave.
+pub fn handler(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    require!(amount > 0, GameError::InvalidPayload);
+
+    let config = &ctx.accounts.config_account;
+
+    // Validate authority is treasury multisig
+    require!(
+        ctx.accounts.authority.key() == config.treasury_multisig,
+        GameError::Unauthorized
+    );
+
+    let clock = Clock::get()?;
+
+    // Update the ledger first (checks amount against collected - withdrawn)
+    {
+        let mut treasury_vault = ctx.accounts.treasury_vault.load_mut()?;
+        treasury_vault.record_withdrawal(amount, clock.unix_timestamp)?;
+    } // Borrow released here
+
+    // Manual lamport transfer - treasury_vault carries data
+    let vault_account_info = ctx.accounts.treasury_vault.to_account_info();
+    let destination_account_info = ctx.accounts.destination.to_account_info();
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(TreasuryVault::MAX_SIZE);
+    let remaining = vault_account_info
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(GameError::InsufficientFunds)?;
+    require!(
+        remaining >= rent_exempt_minimum,
+        GameError::InsufficientFunds
+    );
+    **vault_account_info.try_borrow_mut_lamports()? -= amount;
+    **destination_account_info.try_borrow_mut_lamports()? += amount;
+
+    let treasury_vault = ctx.accounts.treasury_vault.load()?;
+    msg!(
+        "Treasury withdrew {} lamports to {}. Available: {}, Total withdrawn: {}",
+        amount,
+        ctx.accounts.destination.key(),
+        treasury_vault.available_lamports(),
+        treasury_vault.total_withdrawn_lamports
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    #[account(
+        mut,
+        seeds = [b"treasury_vault"],
+        bump = treasury_vault.load()?.bump
+    )]
+    pub treasury_vault: AccountLoader<'info, TreasuryVault>,
+
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+
+    /// Receives the withdrawn lamports
+    /// CHECK: Any destination chosen by the treasury multisig
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    /// CHECK: Treasury multisig authority (must match config_account.treasury_multisig)
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,124 @@
+use crate::error::GameError;
+use crate::state::{ConfigAccount, SeasonArchive, SeasonPrizeVault, UserAccount};
+use anchor_lang::prelude::*;
+
+/// Pays an archived season reward to the user at `rank` (1-based).
+/// Leaderboard entries are platform user IDs, so the platform claims on the user's behalf:
+/// the lamport reward goes only to the wallet bound to the user's UserAccount (the wallet its
+/// recorded results were played from) and the GP reward is added to the user's
+/// lifetime_gp_earned (GP balances are credited in the database).
+/// Each rank claims once. Only the config authority can call this instruction.
+pub fn handler(
+    ctx: Context<ClaimSeasonReward>,
+    game_type: u8,
+    _season_id: u64,
+    rank: u16,
+) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.config_account.authority,
+        GameError::Unauthorized
+    );
+
+    let mut archive = ctx.accounts.archive.load_mut()?;
+    require!(
+        rank >= 1 && rank <= archive.reward_count as u16,
+        GameError::InvalidPayload
+    );
+    require!(!archive.is_claimed(rank), GameError::SeasonRewardClaimed);
+
+    let place = (rank - 1) as usize;
+    let user_account = &mut ctx.accounts.user_account;
+    require!(
+        user_account.user_id == archive.entries[place].user_id,
+        GameError::InvalidPayload
+    );
+
+    let reward_lamports = archive.reward_lamports[place];
+    let reward_gp = archive.reward_gp[place];
+    archive.set_claimed(rank);
+
+    if reward_lamports > 0 {
+        // Security: Lamports only go to the wallet the user_id is bound to
+        require!(
+            user_account.wallet != Pubkey::default()
+                && ctx.accounts.recipient.key() == user_account.wallet,
+            GameError::UserWalletMismatch
+        );
+
+        let vault_loader = ctx
+            .accounts
+            .prize_vault
+            .as_ref()
+            .ok_or(GameError::InvalidPayload)?;
+        let (expected_vault, _) =
+            Pubkey::find_program_address(&[b"season_prize_vault", &[game_type]], ctx.program_id);
+        require!(
+            vault_loader.key() == expected_vault,
+            GameError::InvalidPayload
+        );
+
+        {
+            let mut vault = vault_loader.load_mut()?;
+            vault.reserved_lamports = vault
+                .reserved_lamports
+                .checked_sub(reward_lamports)
+                .ok_or(GameError::Overflow)?;
+            vault.total_paid_lamports = vault
+                .total_paid_lamports
+                .checked_add(reward_lamports)
+                .ok_or(GameError::Overflow)?;
+        }
+
+        // Manual lamport transfer - the vault carries data
+        let vault_info = vault_loader.to_account_info();
+        let recipient_info = ctx.accounts.recipient.to_account_info();
+        **vault_info.try_borrow_mut_lamports()? -= reward_lamports;
+        **recipient_info.try_borrow_mut_lamports()? += reward_lamports;
+    }
+
+    user_account.lifetime_gp_earned = user_account
+        .lifetime_gp_earned
+        .checked_add(reward_gp)
+        .ok_or(GameError::Overflow)?;
+
+    msg!(
+        "Season {} reward claimed: rank {}, {} lamports, {} GP",
+        archive.season_id,
+        rank,
+        reward_lamports,
+        reward_gp
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(game_type: u8, season_id: u64)]
+pub struct ClaimSeasonReward<'info> {
+    #[account(
+        mut,
+        seeds = [b"season_archive".as_ref(), &[game_type], &season_id.to_le_bytes()],
+        bump = archive.load()?.bump
+    )]
+    pub archive: AccountLoader<'info, SeasonArchive>,
+
+    /// UserAccount of the user at the claimed rank (validated in handler)
+    #[account(mut)]
+    pub user_account: Account<'info, UserAccount>,
+
+    /// Season prize vault of the game type (required for lamport rewards, validated in handler)
+    #[account(mut)]
+    pub prize_vault: Option<AccountLoader<'info, SeasonPrizeVault>>,
+
+    /// CHECK: Wallet bound to the user's UserAccount (validated in handler for lamport rewards)
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+
+    /// Config authority
+    pub authority: Signer<'info>,
+}
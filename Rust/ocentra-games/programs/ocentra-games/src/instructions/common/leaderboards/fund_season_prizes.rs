@@ -0,0 +1,75 @@
+use crate::error::GameError;
+use crate::state::{GameRegistry, SeasonPrizeVault};
+use anchor_lang::prelude::*;
+
+/// Adds lamports to a game type's season prize vault (created on first funding).
+/// Anyone may fund it; rollover_season reserves the unreserved balance for the top ranks.
+pub fn handler(ctx: Context<FundSeasonPrizes>, game_type: u8, amount: u64) -> Result<()> {
+    require!(amount > 0, GameError::InvalidPayload);
+    {
+        let registry = ctx.accounts.registry.load()?;
+        require!(
+            registry.find_game(game_type).is_some(),
+            GameError::InvalidPayload
+        );
+    }
+
+    // Check if vault exists (discriminator is set) before loading
+    let vault_info = ctx.accounts.prize_vault.to_account_info();
+    let vault_data = vault_info.try_borrow_data()?;
+    let is_initialized = vault_data.len() >= 8 && vault_data[0..8] != [0u8; 8];
+    drop(vault_data);
+
+    let cpi_accounts = anchor_lang::system_program::Transfer {
+        from: ctx.accounts.funder.to_account_info(),
+        to: ctx.accounts.prize_vault.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+    anchor_lang::system_program::transfer(cpi_ctx, amount)?;
+
+    let mut vault = if is_initialized {
+        ctx.accounts.prize_vault.load_mut()?
+    } else {
+        let mut vault = ctx.accounts.prize_vault.load_init()?;
+        vault.game_type = game_type;
+        vault.bump = ctx.bumps.prize_vault;
+        vault.created_at = Clock::get()?.unix_timestamp;
+        vault
+    };
+    vault.total_funded_lamports = vault
+        .total_funded_lamports
+        .checked_add(amount)
+        .ok_or(GameError::Overflow)?;
+
+    msg!(
+        "Season prize vault funded: game_type={}, amount={} lamports, total funded={}",
+        game_type,
+        amount,
+        vault.total_funded_lamports
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(game_type: u8)]
+pub struct FundSeasonPrizes<'info> {
+    #[account(
+        init_if_needed,
+        payer = funder,
+        space = SeasonPrizeVault::MAX_SIZE,
+        seeds = [b"season_prize_vault".as_ref(), &[game_type]],
+        bump
+    )]
+    pub prize_vault: AccountLoader<'info, SeasonPrizeVault>,
+
+    #[account(
+        seeds = [b"game_registry"],
+        bump
+    )]
+    pub registry: AccountLoader<'info, GameRegistry>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,52 @@
+use crate::error::GameError;
+use crate::state::{GameLeaderboard, GameRegistry};
+use anchor_lang::prelude::*;
+
+/// Creates the leaderboard of a registered game type for a season.
+/// One leaderboard per game type per season (PDA); record_result writes to the leaderboard of
+/// ConfigAccount.current_season_id. Any payer may create it.
+pub fn handler(ctx: Context<InitLeaderboard>, game_type: u8, season_id: u64) -> Result<()> {
+    let registry = ctx.accounts.registry.load()?;
+    require!(
+        registry.find_game(game_type).is_some(),
+        GameError::InvalidPayload
+    );
+
+    let clock = Clock::get()?;
+    let mut leaderboard = ctx.accounts.leaderboard.load_init()?;
+    leaderboard.game_type = game_type;
+    leaderboard.season_id = season_id;
+    leaderboard.entry_count = 0;
+    leaderboard.last_updated = clock.unix_timestamp;
+
+    msg!(
+        "Leaderboard created: game_type={}, season_id={}",
+        game_type,
+        season_id
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(game_type: u8, season_id: u64)]
+pub struct InitLeaderboard<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = GameLeaderboard::MAX_SIZE,
+        seeds = [b"leaderboard".as_ref(), &[game_type], &season_id.to_le_bytes()],
+        bump
+    )]
+    pub leaderboard: AccountLoader<'info, GameLeaderboard>,
+
+    #[account(
+        seeds = [b"game_registry"],
+        bump
+    )]
+    pub registry: AccountLoader<'info, GameRegistry>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,59 @@
+use crate::error::GameError;
+use crate::state::{ConfigAccount, UserAccount};
+use anchor_lang::prelude::*;
+
+/// Opens the UserAccount (stats and season standing) of a platform user.
+/// Any payer may fund it, but the user's wallet must sign: the user_id is bound to that wallet,
+/// and record_result only credits seats the bound wallet joined. The account starts empty and
+/// only verified results (record_result) and the economic instructions change it afterwards.
+/// Note: user_id is String in instruction data (Anchor requirement), but converted to fixed array immediately.
+pub fn handler(ctx: Context<InitUserAccount>, user_id: String) -> Result<()> {
+    // PDA seeds are limited to 32 bytes
+    let user_id_bytes = user_id.as_bytes();
+    require!(
+        !user_id_bytes.is_empty() && user_id_bytes.len() <= 32,
+        GameError::InvalidPayload
+    );
+    let mut user_id_array = [0u8; 64];
+    user_id_array[..user_id_bytes.len()].copy_from_slice(user_id_bytes);
+
+    let user_account = &mut ctx.accounts.user_account;
+    user_account.user_id = user_id_array;
+    user_account.current_season_id = ctx.accounts.config_account.current_season_id;
+    user_account.active_multiplier = UserAccount::calculate_multiplier(0);
+    user_account.wallet = ctx.accounts.wallet.key();
+
+    msg!(
+        "User account opened: user_id={}, wallet={}",
+        user_id,
+        user_account.wallet
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(user_id: String)]
+pub struct InitUserAccount<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = UserAccount::MAX_SIZE,
+        seeds = [b"user_account", user_id.as_bytes()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+
+    /// Wallet the user plays from - bound to the user_id
+    pub wallet: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,21 @@
+pub mod claim_season_reward;
+pub mod fund_season_prizes;
+pub mod init_leaderboard;
+pub mod init_user_account;
+pub mod record_result;
+pub mod rollover_season;
+
+// Re-export for Anchor's #[program] macro
+// Ambiguous re-exports warning is acceptable because handlers use full paths
+#[allow(ambiguous_glob_reexports)]
+pub use claim_season_reward::*;
+#[allow(ambiguous_glob_reexports)]
+pub use fund_season_prizes::*;
+#[allow(ambiguous_glob_reexports)]
+pub use init_leaderboard::*;
+#[allow(ambiguous_glob_reexports)]
+pub use init_user_account::*;
+#[allow(ambiguous_glob_reexports)]
+pub use record_result::*;
+#[allow(ambiguous_glob_reexports)]
+pub use rollover_season::*;
@@ -0,0 +1,163 @@
+use crate::error::GameError;
+use crate::state::{
+    game_phase, ConfigAccount, GameLeaderboard, LeaderboardEntry, Match, UserAccount,
+};
+use anchor_lang::prelude::*;
+
+/// Records an ended match in every participant's season stats and the game's leaderboard.
+/// Winners are the seats with the top recorded score (ties share the win; forfeited seats
+/// lose). Each participant's UserAccount gets its season games/wins, win streak and season
+/// score, is upserted into the GameLeaderboard of the current season, and gets its leaderboard
+/// rank and reward multiplier written back. Ranks of users outside the match refresh on their
+/// next result. Each match is recorded once.
+/// A seat's user_id is whatever the joiner typed, so every UserAccount must be bound to the
+/// wallet that took the seat - a match with a seat joined under someone else's user_id is
+/// never recorded.
+/// Remaining accounts: the UserAccount PDA of every seat, in seat order.
+/// Permissionless - the result is on-chain, so anyone may crank it.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RecordResult<'info>>,
+    match_id: String,
+) -> Result<()> {
+    let mut match_account = ctx.accounts.match_account.load_mut()?;
+    let mut leaderboard = ctx.accounts.leaderboard.load_mut()?;
+    let season_id = ctx.accounts.config_account.current_season_id;
+    let clock = Clock::get()?;
+
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36
+            && match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::InvalidPayload
+    );
+
+    // Only verified results: the match ended and its scores are recorded
+    require!(
+        match_account.phase == game_phase::ENDED,
+        GameError::MatchNotEnded
+    );
+    require!(
+        match_account.scores_recorded(),
+        GameError::ScoresNotRecorded
+    );
+    require!(
+        !match_account.results_recorded(),
+        GameError::ResultsAlreadyRecorded
+    );
+
+    // Leaderboard of this game type for the current season
+    require!(
+        leaderboard.game_type == match_account.game_type && leaderboard.season_id == season_id,
+        GameError::SeasonMismatch
+    );
+
+    let player_count = (match_account.player_count as usize).min(10);
+    require!(
+        ctx.remaining_accounts.len() == player_count,
+        GameError::InvalidPayload
+    );
+
+    // Top score among seats that finished the match
+    let top_score = (0..player_count)
+        .filter(|&seat| !match_account.is_forfeited(seat))
+        .map(|seat| match_account.scores[seat])
+        .max();
+
+    for (seat, user_info) in ctx.remaining_accounts.iter().enumerate() {
+        // Security: Must be the UserAccount PDA of the user in this seat
+        let user_id = match_account.player_ids[seat];
+        let id_len = user_id.iter().position(|&b| b == 0).unwrap_or(64);
+        require!(id_len > 0 && id_len <= 32, GameError::InvalidPayload);
+        let (expected_user, _) =
+            Pubkey::find_program_address(&[b"user_account", &user_id[..id_len]], ctx.program_id);
+        require!(user_info.key() == expected_user, GameError::InvalidPayload);
+        let mut user_account = Account::<UserAccount>::try_from(user_info)?;
+
+        // Security: The user_id's bound wallet must be the one that joined this seat
+        require!(
+            match_account.get_player_wallet(seat) == Some(user_account.wallet),
+            GameError::UserWalletMismatch
+        );
+
+        if user_account.current_season_id != season_id {
+            user_account.start_season(season_id);
+        }
+
+        let won =
+            !match_account.is_forfeited(seat) && Some(match_account.scores[seat]) == top_score;
+        user_account.season_games = user_account
+            .season_games
+            .checked_add(1)
+            .ok_or(GameError::Overflow)?;
+        if won {
+            user_account.season_wins = user_account
+                .season_wins
+                .checked_add(1)
+                .ok_or(GameError::Overflow)?;
+            user_account.games_won = user_account
+                .games_won
+                .checked_add(1)
+                .ok_or(GameError::Overflow)?;
+            user_account.win_streak = user_account
+                .win_streak
+                .checked_add(1)
+                .ok_or(GameError::Overflow)?;
+        } else {
+            user_account.win_streak = 0;
+        }
+        user_account.season_score =
+            UserAccount::calculate_score(user_account.season_wins, user_account.season_games);
+
+        leaderboard.insert_entry(LeaderboardEntry {
+            user_id,
+            score: user_account.season_score,
+            wins: user_account.season_wins,
+            games_played: user_account.season_games,
+            timestamp: clock.unix_timestamp as u32,
+            _padding: [0u8; 4],
+        });
+        let rank = leaderboard.get_user_rank(&user_id);
+        user_account.leaderboard_rank = rank;
+        user_account.active_multiplier = UserAccount::calculate_multiplier(rank);
+        user_account.exit(ctx.program_id)?;
+
+        msg!(
+            "Seat {}: won={}, season {}/{} wins, streak {}, rank {}",
+            seat,
+            won,
+            user_account.season_wins,
+            user_account.season_games,
+            user_account.win_streak,
+            rank
+        );
+    }
+
+    leaderboard.last_updated = clock.unix_timestamp;
+    match_account.set_results_recorded(true);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct RecordResult<'info> {
+    #[account(
+        mut,
+        seeds = [b"m", &match_id.as_bytes()[..31.min(match_id.len())]],
+        bump
+    )]
+    pub match_account: AccountLoader<'info, Match>,
+
+    /// Leaderboard of the match's game type for ConfigAccount.current_season_id
+    #[account(mut)]
+    pub leaderboard: AccountLoader<'info, GameLeaderboard>,
+
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+
+    /// Anyone may crank an ended match
+    pub caller: Signer<'info>,
+}
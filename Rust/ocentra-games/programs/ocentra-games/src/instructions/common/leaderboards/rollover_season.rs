@@ -0,0 +1,179 @@
+use crate::common::prize_structure::PrizeStructure;
+use crate::error::GameError;
+use crate::state::enums::prize_structure;
+use crate::state::{
+    ConfigAccount, GameLeaderboard, SeasonArchive, SeasonPrizeVault, MAX_SEASON_REWARD_RANKS,
+};
+use anchor_lang::prelude::*;
+
+/// Rewards opened for the top ranks of an archived season.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SeasonRewards {
+    pub lamport_split_bps: Vec<u16>, // Share of the prize vault's unreserved lamports per rank (empty = none)
+    pub gp_rewards: Vec<u64>,        // GP per rank (empty = none)
+}
+
+/// Ends a game type's season: freezes its GameLeaderboard into a SeasonArchive, closes the
+/// leaderboard and opens the season rewards for the top ranks. Lamport rewards split the prize
+/// vault's unreserved balance and stay reserved until paid to the ranked user's bound wallet
+/// (claim_season_reward); ranks nobody reached are not reserved. Standings only hold results
+/// of seats joined by each user_id's bound wallet (record_result). The first rollover after
+/// the season ends advances ConfigAccount.current_season_id
+/// (season IDs are unix_timestamp / season_duration_seconds); the other game types' leaderboards
+/// of the ended season roll over with the same instruction.
+/// Only the config authority can call this instruction.
+pub fn handler(
+    ctx: Context<RolloverSeason>,
+    game_type: u8,
+    season_id: u64,
+    rewards: SeasonRewards,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let config = &mut ctx.accounts.config_account;
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        GameError::Unauthorized
+    );
+    require!(
+        config.season_duration_seconds > 0,
+        GameError::InvalidPayload
+    );
+    let season_now = (clock.unix_timestamp / config.season_duration_seconds) as u64;
+    require!(season_id < season_now, GameError::SeasonNotEnded);
+
+    let leaderboard = ctx.accounts.leaderboard.load()?;
+    require!(
+        leaderboard.game_type == game_type && leaderboard.season_id == season_id,
+        GameError::SeasonMismatch
+    );
+
+    // Reward tables follow the TOP_N_SPLIT rules (non-increasing shares summing to 10,000 bps)
+    if !rewards.lamport_split_bps.is_empty() {
+        PrizeStructure::validate(
+            prize_structure::TOP_N_SPLIT,
+            &rewards.lamport_split_bps,
+            MAX_SEASON_REWARD_RANKS as u8,
+        )?;
+    }
+    require!(
+        rewards.gp_rewards.len() <= MAX_SEASON_REWARD_RANKS,
+        GameError::InvalidPayload
+    );
+
+    let entry_count = (leaderboard.entry_count as usize).min(100);
+    let reward_count = rewards
+        .lamport_split_bps
+        .len()
+        .max(rewards.gp_rewards.len())
+        .min(entry_count);
+
+    let mut archive = ctx.accounts.archive.load_init()?;
+    archive.season_id = season_id;
+    archive.archived_at = clock.unix_timestamp;
+    archive.entries.copy_from_slice(&leaderboard.entries);
+    archive.claimed_ranks = 0;
+    archive.game_type = game_type;
+    archive.entry_count = entry_count as u8;
+    archive.reward_count = reward_count as u8;
+    archive.bump = ctx.bumps.archive;
+    drop(leaderboard);
+
+    for rank in 0..reward_count {
+        archive.reward_gp[rank] = rewards.gp_rewards.get(rank).copied().unwrap_or(0);
+    }
+
+    // Lamport rewards: reserve shares of the vault's unreserved balance
+    if !rewards.lamport_split_bps.is_empty() && reward_count > 0 {
+        let vault_loader = ctx
+            .accounts
+            .prize_vault
+            .as_ref()
+            .ok_or(GameError::InvalidPayload)?;
+        let (expected_vault, _) =
+            Pubkey::find_program_address(&[b"season_prize_vault", &[game_type]], ctx.program_id);
+        require!(
+            vault_loader.key() == expected_vault,
+            GameError::InvalidPayload
+        );
+
+        let vault_info = vault_loader.to_account_info();
+        let rent_minimum = Rent::get()?.minimum_balance(vault_info.data_len());
+        let mut vault = vault_loader.load_mut()?;
+        let pool = vault_info
+            .lamports()
+            .checked_sub(rent_minimum)
+            .and_then(|x| x.checked_sub(vault.reserved_lamports))
+            .ok_or(GameError::Overflow)?;
+
+        let mut reserved = 0u64;
+        for rank in 0..reward_count {
+            let bps = rewards.lamport_split_bps.get(rank).copied().unwrap_or(0);
+            let share = (pool as u128)
+                .checked_mul(bps as u128)
+                .and_then(|x| x.checked_div(10_000))
+                .and_then(|x| u64::try_from(x).ok())
+                .ok_or(GameError::Overflow)?;
+            archive.reward_lamports[rank] = share;
+            reserved = reserved.checked_add(share).ok_or(GameError::Overflow)?;
+        }
+        vault.reserved_lamports = vault
+            .reserved_lamports
+            .checked_add(reserved)
+            .ok_or(GameError::Overflow)?;
+    }
+
+    // Advance the config to the season in progress
+    if config.current_season_id < season_now {
+        config.current_season_id = season_now;
+        config.last_updated = clock.unix_timestamp;
+    }
+
+    msg!(
+        "Season {} archived: game_type={}, entries={}, rewarded ranks={}, current season={}",
+        season_id,
+        game_type,
+        entry_count,
+        reward_count,
+        config.current_season_id
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(game_type: u8, season_id: u64)]
+pub struct RolloverSeason<'info> {
+    #[account(
+        mut,
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+
+    /// Leaderboard of the ended season - closed once archived
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"leaderboard".as_ref(), &[game_type], &season_id.to_le_bytes()],
+        bump
+    )]
+    pub leaderboard: AccountLoader<'info, GameLeaderboard>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = SeasonArchive::MAX_SIZE,
+        seeds = [b"season_archive".as_ref(), &[game_type], &season_id.to_le_bytes()],
+        bump
+    )]
+    pub archive: AccountLoader<'info, SeasonArchive>,
+
+    /// Season prize vault of the game type (required for lamport rewards, validated in handler)
+    #[account(mut)]
+    pub prize_vault: Option<AccountLoader<'info, SeasonPrizeVault>>,
+
+    /// Config authority
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -5,9 +5,12 @@ pub mod batches;
 pub mod config;
 pub mod disputes;
 pub mod economic;
+pub mod leaderboards;
+pub mod ratings;
 pub mod registry;
 pub mod scores;
 pub mod signers;
+pub mod tournaments;
 pub mod validators;
 
 // Re-export everything for Anchor's #[program] macro
@@ -21,8 +24,14 @@ pub use disputes::*;
 #[allow(ambiguous_glob_reexports)]
 pub use economic::*;
 #[allow(ambiguous_glob_reexports)]
+pub use leaderboards::*;
+#[allow(ambiguous_glob_reexports)]
+pub use ratings::*;
+#[allow(ambiguous_glob_reexports)]
 pub use registry::*;
 pub use scores::*;
 pub use signers::*;
 #[allow(ambiguous_glob_reexports)]
+pub use tournaments::*;
+#[allow(ambiguous_glob_reexports)]
 pub use validators::*;
@@ -0,0 +1,60 @@
+use crate::error::GameError;
+use crate::state::{GameRegistry, PlayerRating, INITIAL_RATING};
+use anchor_lang::prelude::*;
+
+/// Opens a wallet's rating for a registered game type at INITIAL_RATING.
+/// Any payer may open it (e.g. the platform for a new player); update_ratings needs one
+/// for every seat of a match.
+pub fn handler(ctx: Context<InitPlayerRating>, game_type: u8) -> Result<()> {
+    let registry = ctx.accounts.registry.load()?;
+    require!(
+        registry.find_game(game_type).is_some(),
+        GameError::InvalidPayload
+    );
+
+    let clock = Clock::get()?;
+    let mut player_rating = ctx.accounts.player_rating.load_init()?;
+    player_rating.wallet = ctx.accounts.wallet.key();
+    player_rating.created_at = clock.unix_timestamp;
+    player_rating.last_updated = 0;
+    player_rating.rating = INITIAL_RATING;
+    player_rating.peak_rating = INITIAL_RATING;
+    player_rating.games_rated = 0;
+    player_rating.game_type = game_type;
+    player_rating.bump = ctx.bumps.player_rating;
+
+    msg!(
+        "Player rating opened: wallet={}, game_type={}, rating={}",
+        player_rating.wallet,
+        game_type,
+        INITIAL_RATING
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(game_type: u8)]
+pub struct InitPlayerRating<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = PlayerRating::MAX_SIZE,
+        seeds = [b"player_rating", wallet.key().as_ref(), &[game_type]],
+        bump
+    )]
+    pub player_rating: AccountLoader<'info, PlayerRating>,
+
+    #[account(
+        seeds = [b"game_registry"],
+        bump
+    )]
+    pub registry: AccountLoader<'info, GameRegistry>,
+
+    /// CHECK: Any wallet - the rating is keyed by it, no signature needed to open it
+    pub wallet: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,9 @@
+pub mod init_player_rating;
+pub mod update_ratings;
+
+// Re-export for Anchor's #[program] macro
+// Ambiguous re-exports warning is acceptable because handlers use full paths
+#[allow(ambiguous_glob_reexports)]
+pub use init_player_rating::*;
+#[allow(ambiguous_glob_reexports)]
+pub use update_ratings::*;
@@ -0,0 +1,113 @@
+use crate::common::skill_rating::SkillRating;
+use crate::error::GameError;
+use crate::state::{game_phase, Match, PlayerRating};
+use anchor_lang::prelude::*;
+
+/// Applies an ended match to the skill rating of every seat (pairwise Elo, see
+/// common::skill_rating). Standings come from the match's recorded scores; forfeited seats
+/// rank below every seat that finished. Each match is rated once.
+/// Remaining accounts: the PlayerRating PDA of every seat, in seat order.
+/// Permissionless - the result is on-chain, so anyone may crank it.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UpdateRatings<'info>>,
+    match_id: String,
+) -> Result<()> {
+    let mut match_account = ctx.accounts.match_account.load_mut()?;
+    let clock = Clock::get()?;
+
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36
+            && match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::InvalidPayload
+    );
+
+    require!(
+        match_account.phase == game_phase::ENDED,
+        GameError::MatchNotEnded
+    );
+    require!(
+        match_account.scores_recorded(),
+        GameError::ScoresNotRecorded
+    );
+    require!(
+        !match_account.ratings_applied(),
+        GameError::RatingsAlreadyApplied
+    );
+
+    let player_count = (match_account.player_count as usize).min(10);
+    require!(player_count >= 2, GameError::InsufficientPlayers);
+    require!(
+        ctx.remaining_accounts.len() == player_count,
+        GameError::InvalidPayload
+    );
+
+    // Security: Each account must be the rating PDA of the seat's wallet for this game type
+    let game_type = match_account.game_type;
+    let mut loaders = Vec::with_capacity(player_count);
+    for (seat, rating_info) in ctx.remaining_accounts.iter().enumerate() {
+        let wallet = match_account.player_wallets[seat];
+        let (expected_rating, _) = Pubkey::find_program_address(
+            &[b"player_rating", wallet.as_ref(), &[game_type]],
+            ctx.program_id,
+        );
+        require!(
+            rating_info.key() == expected_rating,
+            GameError::InvalidPayload
+        );
+        loaders.push(AccountLoader::<PlayerRating>::try_from(rating_info)?);
+    }
+
+    let mut players = Vec::with_capacity(player_count);
+    for loader in loaders.iter() {
+        let rating = loader.load()?;
+        players.push((rating.rating, rating.games_rated));
+    }
+    let standings: Vec<i64> = (0..player_count)
+        .map(|seat| {
+            if match_account.is_forfeited(seat) {
+                i64::MIN
+            } else {
+                match_account.scores[seat] as i64
+            }
+        })
+        .collect();
+    let new_ratings = SkillRating::rate_match(&players, &standings)?;
+
+    for (seat, loader) in loaders.iter().enumerate() {
+        let mut rating = loader.load_mut()?;
+        let previous = rating.rating;
+        rating.rating = new_ratings[seat];
+        rating.peak_rating = rating.peak_rating.max(new_ratings[seat]);
+        rating.games_rated = rating
+            .games_rated
+            .checked_add(1)
+            .ok_or(GameError::Overflow)?;
+        rating.last_updated = clock.unix_timestamp;
+
+        msg!(
+            "Seat {}: rating {} -> {}",
+            seat,
+            previous,
+            new_ratings[seat]
+        );
+    }
+
+    match_account.set_ratings_applied(true);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct UpdateRatings<'info> {
+    #[account(
+        mut,
+        seeds = [b"m", &match_id.as_bytes()[..31.min(match_id.len())]],
+        bump
+    )]
+    pub match_account: AccountLoader<'info, Match>,
+
+    /// Anyone may crank an ended match
+    pub caller: Signer<'info>,
+}
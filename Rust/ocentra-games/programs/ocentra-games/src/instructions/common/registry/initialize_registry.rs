@@ -19,7 +19,15 @@ pub fn handler(ctx: Context<InitializeRegistry>) -> Result<()> {
         rule_engine_url: [0u8; 200],
         version: 0,
         enabled: 0,
-        _padding: [0; 6],
+        turn_timeout_secs: [0; 4],
+        timeout_policy: 0,
+        lobby_ttl_secs: [0; 4],
+        economics_overrides: 0,
+        platform_fee_bps: [0; 2],
+        allowed_payment_methods: 0,
+        min_entry_fee: [0; 8],
+        max_entry_fee: [0; 8],
+        _padding: [0; 1],
     }; 20];
     registry._padding1 = [0; 7];
     registry._padding2 = [0; 4];
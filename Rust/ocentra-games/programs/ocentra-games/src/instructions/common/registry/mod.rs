@@ -1,5 +1,8 @@
 pub mod initialize_registry;
 pub mod register_game;
+pub mod set_game_economics;
+pub mod set_lobby_ttl;
+pub mod set_turn_timeout;
 pub mod update_game;
 
 // Re-export for Anchor's #[program] macro
@@ -8,4 +11,10 @@ pub mod update_game;
 pub use initialize_registry::*;
 #[allow(ambiguous_glob_reexports)]
 pub use register_game::*;
+#[allow(ambiguous_glob_reexports)]
+pub use set_game_economics::*;
+#[allow(ambiguous_glob_reexports)]
+pub use set_lobby_ttl::*;
+#[allow(ambiguous_glob_reexports)]
+pub use set_turn_timeout::*;
 pub use update_game::*;
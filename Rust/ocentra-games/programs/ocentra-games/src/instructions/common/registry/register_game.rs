@@ -1,5 +1,5 @@
 use crate::error::GameError;
-use crate::state::{GameDefinition, GameRegistry};
+use crate::state::{GameDefinition, GameRegistry, DEFAULT_LOBBY_TTL_SECS};
 use anchor_lang::prelude::*;
 
 /// Registers a new game in the registry.
@@ -58,7 +58,15 @@ pub fn handler(
         rule_engine_url: url_array,
         version,
         enabled: 1, // 1 = enabled, 0 = disabled (u8 for zero-copy compatibility)
-        _padding: [0; 6],
+        turn_timeout_secs: [0; 4], // No turn timer until set_turn_timeout
+        timeout_policy: 0,
+        lobby_ttl_secs: DEFAULT_LOBBY_TTL_SECS.to_le_bytes(), // Stale lobbies expire after a day
+        economics_overrides: 0, // ConfigAccount economics until set_game_economics
+        platform_fee_bps: [0; 2],
+        allowed_payment_methods: 0,
+        min_entry_fee: [0; 8],
+        max_entry_fee: [0; 8],
+        _padding: [0; 1],
     };
 
     // Add to registry
@@ -0,0 +1,96 @@
+use crate::error::GameError;
+use crate::state::enums::economics_override;
+use crate::state::GameRegistry;
+use anchor_lang::prelude::*;
+
+/// Sets a game's economics overrides, e.g. high-stakes poker vs casual CLAIM tables.
+/// Each parameter replaces the ConfigAccount value for the game's matches; None clears the
+/// override so the game falls back to the global config. Entry fee bounds are in lamports
+/// (SPL token matches are bounded by their SupportedMint). Payment methods use the
+/// ConfigAccount bitmask (bit 0 = WALLET, bit 1 = PLATFORM) and can only narrow it.
+/// Admin-only instruction.
+pub fn handler(
+    ctx: Context<SetGameEconomics>,
+    game_id: u8,
+    platform_fee_bps: Option<u16>,
+    min_entry_fee: Option<u64>,
+    max_entry_fee: Option<u64>,
+    allowed_payment_methods: Option<u8>,
+) -> Result<()> {
+    let mut registry = ctx.accounts.registry.load_mut()?;
+    let clock = Clock::get()?;
+
+    // Validate authority
+    require!(
+        ctx.accounts.authority.key() == registry.authority,
+        GameError::Unauthorized
+    );
+
+    let game = registry
+        .find_game_mut(game_id)
+        .ok_or(GameError::InvalidPayload)?;
+
+    let mut overrides = 0u8;
+
+    if let Some(fee_bps) = platform_fee_bps {
+        require!(
+            fee_bps <= 10000, // Max 100% (10000 bps)
+            GameError::InvalidFeeParameter
+        );
+        game.platform_fee_bps = fee_bps.to_le_bytes();
+        overrides |= economics_override::PLATFORM_FEE;
+    } else {
+        game.platform_fee_bps = [0; 2];
+    }
+
+    // Entry fee bounds are overridden together
+    match (min_entry_fee, max_entry_fee) {
+        (Some(min_fee), Some(max_fee)) => {
+            require!(
+                min_fee > 0 && min_fee <= max_fee,
+                GameError::InvalidFeeParameter
+            );
+            game.min_entry_fee = min_fee.to_le_bytes();
+            game.max_entry_fee = max_fee.to_le_bytes();
+            overrides |= economics_override::ENTRY_FEE_BOUNDS;
+        }
+        (None, None) => {
+            game.min_entry_fee = [0; 8];
+            game.max_entry_fee = [0; 8];
+        }
+        _ => return Err(GameError::InvalidFeeParameter.into()),
+    }
+
+    if let Some(methods) = allowed_payment_methods {
+        require!(
+            methods > 0 && methods <= 0x03, // Only bits 0 and 1 are valid (WALLET and PLATFORM)
+            GameError::InvalidFeeParameter
+        );
+        game.allowed_payment_methods = methods;
+        overrides |= economics_override::PAYMENT_METHODS;
+    } else {
+        game.allowed_payment_methods = 0;
+    }
+
+    game.economics_overrides = overrides;
+    registry.last_updated = clock.unix_timestamp;
+
+    msg!(
+        "Game economics set: game_id={}, overrides=0x{:02x}",
+        game_id,
+        overrides
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetGameEconomics<'info> {
+    #[account(
+        mut,
+        seeds = [b"game_registry"],
+        bump
+    )]
+    pub registry: AccountLoader<'info, GameRegistry>,
+
+    pub authority: Signer<'info>,
+}
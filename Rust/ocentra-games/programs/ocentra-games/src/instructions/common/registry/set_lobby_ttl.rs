@@ -0,0 +1,43 @@
+use crate::error::GameError;
+use crate::state::GameRegistry;
+use anchor_lang::prelude::*;
+
+/// Sets how long a game's matches may wait in the Dealing phase.
+/// Once created_at + lobby_ttl_secs has passed, anyone may call expire_match.
+/// lobby_ttl_secs = 0 disables expiry.
+/// Admin-only instruction.
+pub fn handler(ctx: Context<SetLobbyTtl>, game_id: u8, lobby_ttl_secs: u32) -> Result<()> {
+    let mut registry = ctx.accounts.registry.load_mut()?;
+    let clock = Clock::get()?;
+
+    // Validate authority
+    require!(
+        ctx.accounts.authority.key() == registry.authority,
+        GameError::Unauthorized
+    );
+
+    let game = registry
+        .find_game_mut(game_id)
+        .ok_or(GameError::InvalidPayload)?;
+    game.set_lobby_ttl_secs(lobby_ttl_secs);
+    registry.last_updated = clock.unix_timestamp;
+
+    msg!(
+        "Lobby TTL set: game_id={}, lobby_ttl_secs={}",
+        game_id,
+        lobby_ttl_secs
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetLobbyTtl<'info> {
+    #[account(
+        mut,
+        seeds = [b"game_registry"],
+        bump
+    )]
+    pub registry: AccountLoader<'info, GameRegistry>,
+
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,63 @@
+use crate::error::GameError;
+use crate::games::has_turns;
+use crate::state::{timeout_policy, GameRegistry};
+use anchor_lang::prelude::*;
+
+/// Sets a game's turn timer and timeout policy.
+/// turn_timeout_secs = 0 disables the timer (claim_turn_timeout is rejected).
+/// Only turn-based games with on-chain rules can have a timer.
+/// Admin-only instruction.
+pub fn handler(
+    ctx: Context<SetTurnTimeout>,
+    game_id: u8,
+    turn_timeout_secs: u32,
+    policy: u8,
+) -> Result<()> {
+    let mut registry = ctx.accounts.registry.load_mut()?;
+    let clock = Clock::get()?;
+
+    // Validate authority
+    require!(
+        ctx.accounts.authority.key() == registry.authority,
+        GameError::Unauthorized
+    );
+
+    // Validate policy (see enums::timeout_policy)
+    require!(
+        policy <= timeout_policy::FORFEIT,
+        GameError::InvalidPayload
+    );
+
+    // Simultaneous-play games (and games without on-chain rules) have no turn to time out
+    require!(
+        turn_timeout_secs == 0 || has_turns(game_id),
+        GameError::TurnTimerDisabled
+    );
+
+    let game = registry
+        .find_game_mut(game_id)
+        .ok_or(GameError::InvalidPayload)?;
+    game.set_turn_timeout_secs(turn_timeout_secs);
+    game.timeout_policy = policy;
+    registry.last_updated = clock.unix_timestamp;
+
+    msg!(
+        "Turn timeout set: game_id={}, turn_timeout_secs={}, policy={}",
+        game_id,
+        turn_timeout_secs,
+        policy
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetTurnTimeout<'info> {
+    #[account(
+        mut,
+        seeds = [b"game_registry"],
+        bump
+    )]
+    pub registry: AccountLoader<'info, GameRegistry>,
+
+    pub authority: Signer<'info>,
+}
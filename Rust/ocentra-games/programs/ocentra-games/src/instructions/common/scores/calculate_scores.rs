@@ -1,87 +1,74 @@
+use crate::error::GameError;
+use crate::games::{
+    advance_turn_after_batch, apply_action_state, begin_replay, resolve_turn_timeout, score_match,
+    stalled_player, validate_move,
+};
 use crate::state::{Match, Move};
 use anchor_lang::prelude::*;
 
 /**
  * Calculates scores by replaying all moves from the match.
  * Per critique: full score calculation from moves, not simplified.
+ * `moves` must be the complete move log in move_index order.
  */
 pub fn calculate_scores_from_moves(match_account: &Match, moves: &[Move]) -> Result<[i32; 10]> {
-    let mut scores = [0i32; 10];
+    require!(
+        moves.len() == match_account.move_count as usize,
+        GameError::MoveOutOfOrder
+    );
 
-    // Track player hands (simplified - in production would use committed hands)
-    // For now, we calculate based on declared suits and move history
-    let mut player_declared_suits: [Option<u8>; 10] = [None; 10];
-    let mut player_move_counts: [u32; 10] = [0; 10];
+    // Scratch copy - the match itself is never modified (boxed: Match is too large for the stack)
+    let mut scratch = Box::new(*match_account);
+    begin_replay(&mut scratch)?;
 
-    // Replay moves to track game state
-    // TODO: Update Move struct to store user_id instead of player Pubkey
-    // For now, this function may not work correctly after the player_ids migration
-    // Move accounts still have player: Pubkey, but Match now has player_ids: [[u8; 64]; 10]
-    for move_account in moves {
-        // TODO: Need to update Move struct to store user_id, then use find_player_index
-        // For now, skip player index lookup since we can't match Pubkey to user_id
-        // This is a temporary workaround - Move struct needs to be updated too
-        let player_index = 10; // Invalid index - will skip processing
-                               // Old code (commented out):
-                               // let player_index = match_account.players
-                               //     .iter()
-                               //     .position(|&p| p == move_account.player)
-                               //     .unwrap_or(10);
-
-        if player_index >= 10 {
-            continue;
-        }
-
-        player_move_counts[player_index] += 1;
-
-        // Track declared suits
-        match move_account.action_type {
-            2 => {
-                // Declare intent
-                if move_account.get_payload_slice().len() >= 1 {
-                    let suit = move_account.get_payload_slice()[0];
-                    if suit < 4 {
-                        player_declared_suits[player_index] = Some(suit);
-                    }
-                }
-            }
-            _ => {}
-        }
+    for (expected_index, move_account) in moves.iter().enumerate() {
+        require!(
+            move_account.move_index as usize == expected_index,
+            GameError::MoveOutOfOrder
+        );
+        replay_move(&mut scratch, move_account)?;
     }
 
-    // Per critique Issue #2: Calculate scores based on CLAIM game rules
-    // Mirror TypeScript ScoreCalculator logic: sequence-based scoring with multipliers
-    for i in 0..match_account.player_count as usize {
-        if let Some(_declared_suit) = player_declared_suits[i] {
-            // Declared players: positive scoring
-            // Base score: 20 points for declaring a suit (matches end_match.rs)
-            let base_score = 20i32;
+    Ok(score_match(&scratch))
+}
 
-            // Activity score: move count as engagement indicator
-            let activity_score = player_move_counts[i] as i32;
+/**
+ * Replays one recorded move on a scratch match exactly as submit_move / submit_batch_moves
+ * applied it. The move's signer is mapped back to its seat through Match.player_wallets.
+ */
+pub fn replay_move(scratch: &mut Match, move_account: &Move) -> Result<()> {
+    require!(
+        move_account.match_id == scratch.match_id,
+        GameError::MoveAccountMismatch
+    );
+    let player_index = scratch
+        .find_player_index_by_wallet(&move_account.player)
+        .ok_or(GameError::PlayerNotInMatch)?;
 
-            // Declaration order bonus: first declarer gets bonus
-            let mut declaration_order = 0u32;
-            for j in 0..i {
-                if player_declared_suits[j].is_some() {
-                    declaration_order += 1;
-                }
-            }
-            let declaration_bonus = if declaration_order == 0 { 5i32 } else { 0i32 };
+    let action_type = move_account.action_type;
+    let payload = move_account.get_payload_slice();
+    let batched = move_account.is_batched();
 
-            scores[i] = base_score + activity_score + declaration_bonus;
-        } else {
-            // Undeclared players: penalty for not declaring
-            // Penalty increases with move count (more opportunities missed)
-            let penalty_per_move = 2i32;
-            scores[i] = -(player_move_counts[i] as i32 * penalty_per_move);
-        }
+    // Turn timeout: resolved for the stalled player under the recorded policy
+    if move_account.is_timeout() {
+        require!(
+            stalled_player(scratch).ok() == Some(player_index) && !payload.is_empty(),
+            GameError::MoveAccountMismatch
+        );
+        resolve_turn_timeout(scratch, payload[0])?;
+        scratch.move_count = move_account.move_index.saturating_add(1);
+        return Ok(());
     }
 
-    // Normalize scores to prevent overflow
-    for score in &mut scores {
-        *score = (*score).clamp(-100, 200);
-    }
+    validate_move(scratch, player_index, action_type, payload)?;
+    apply_action_state(scratch, player_index, action_type, payload, !batched)?;
 
-    Ok(scores)
+    // Batches update move_count and pass the turn once, after their last move
+    if !batched || move_account.is_batch_end() {
+        scratch.move_count = move_account.move_index.saturating_add(1);
+    }
+    if batched && move_account.is_batch_end() {
+        advance_turn_after_batch(scratch, player_index, action_type);
+    }
+    Ok(())
 }
@@ -0,0 +1,196 @@
+use crate::error::GameError;
+use crate::games::{begin_replay, score_match};
+use crate::instructions::common::scores::calculate_scores::replay_move;
+use crate::state::{game_phase, EscrowAccount, Match, Move, ScoreReplay};
+use anchor_lang::prelude::*;
+
+/// Replays an ended match's Move accounts (passed as remaining_accounts, in move_index order)
+/// through the game rules and records the exact scores on the match.
+/// Long matches are replayed in chunks: each call continues from the ScoreReplay cursor, and
+/// the call that replays the last move writes the scores. Permissionless - the replay is
+/// deterministic, so anyone may crank it - but only until the scores are final: once a replay
+/// or a dispute correction fixed them, or a paid match's escrow has settled, the crank refuses.
+pub fn handler(ctx: Context<FinalizeScores>, match_id: String) -> Result<()> {
+    let match_key = ctx.accounts.match_account.key();
+    let mut match_account = ctx.accounts.match_account.load_mut()?;
+
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36
+            && match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::InvalidPayload
+    );
+
+    // Only the complete move log of an ended match can be scored
+    require!(
+        match_account.phase == game_phase::ENDED,
+        GameError::MatchNotEnded
+    );
+
+    // Security: A completed replay or a dispute correction is authoritative
+    require!(
+        !match_account.scores_finalized(),
+        GameError::ScoresFinalized
+    );
+
+    // Security: Paid match scores are frozen once the escrow settles or a dispute owns them
+    if match_account.is_paid_match() {
+        let escrow_loader = ctx
+            .accounts
+            .escrow_account
+            .as_ref()
+            .ok_or(GameError::InvalidPayload)?;
+        let escrow_account = escrow_loader.load()?;
+        require!(
+            escrow_account.match_pda == match_key,
+            GameError::InvalidPayload
+        );
+        require!(
+            !escrow_account.is_distributed(),
+            GameError::EscrowAlreadyDistributed
+        );
+        require!(!escrow_account.is_cancelled(), GameError::EscrowCancelled);
+        require!(
+            !escrow_account.is_score_correction_pending(),
+            GameError::ScoreCorrectionPending
+        );
+    }
+
+    // Check if cursor exists (discriminator is set) before loading
+    let replay_info = ctx.accounts.score_replay.to_account_info();
+    let replay_data = replay_info.try_borrow_data()?;
+    let is_initialized = replay_data.len() >= 8 && replay_data[0..8] != [0u8; 8];
+    drop(replay_data);
+
+    let mut replay = if is_initialized {
+        ctx.accounts.score_replay.load_mut()?
+    } else {
+        let mut replay = ctx.accounts.score_replay.load_init()?;
+        replay.match_pda = match_key;
+        replay
+    };
+    require!(replay.match_pda == match_key, GameError::InvalidPayload);
+    require!(!replay.is_completed(), GameError::ScoreReplayCompleted);
+
+    // Scratch match: fixed fields (seats, seed, game type) from the match,
+    // replayed fields from the cursor (or the start-of-play state on the first chunk)
+    let mut scratch = Box::new(*match_account);
+    if replay.is_initialized() {
+        replay.restore_into(&mut scratch);
+    } else {
+        begin_replay(&mut scratch)?;
+        replay.set_initialized(true);
+    }
+
+    let match_id_array = match_account.match_id;
+    for move_info in ctx.remaining_accounts.iter() {
+        // Security: Only Move accounts created by this program
+        require!(
+            move_info.owner == ctx.program_id,
+            GameError::MoveAccountMismatch
+        );
+        let move_account = Move::try_deserialize(&mut &move_info.try_borrow_data()?[..])?;
+        require!(
+            move_account.match_id == match_id_array,
+            GameError::MoveAccountMismatch
+        );
+
+        // Security: Re-derive the Move PDA (same seeds as submit_move / submit_batch_moves,
+        // or claim_turn_timeout's move-index seeds for timeout Moves)
+        let (expected_key, _) = if move_account.is_timeout() {
+            Pubkey::find_program_address(
+                &[
+                    b"timeout_move",
+                    &match_id_array[..32],
+                    &match_id_array[32..],
+                    &move_account.move_index.to_le_bytes(),
+                ],
+                ctx.program_id,
+            )
+        } else {
+            Pubkey::find_program_address(
+                &[
+                    b"move",
+                    &match_id_array[..32],
+                    &match_id_array[32..],
+                    move_account.player.as_ref(),
+                    &move_account.nonce.to_le_bytes(),
+                ],
+                ctx.program_id,
+            )
+        };
+        require!(
+            move_info.key() == expected_key,
+            GameError::MoveAccountMismatch
+        );
+
+        // Moves must continue exactly where the cursor stopped
+        require!(
+            move_account.move_index == replay.next_move_index
+                && replay.next_move_index < match_account.move_count,
+            GameError::MoveOutOfOrder
+        );
+
+        replay_move(&mut scratch, &move_account)?;
+        replay.next_move_index += 1;
+    }
+
+    replay.save_from(&scratch);
+
+    if replay.next_move_index == match_account.move_count {
+        let scores = score_match(&scratch);
+        replay.scores = scores;
+        replay.set_completed(true);
+
+        // Replayed scores are exact - they supersede the state-based scores recorded at end
+        match_account.record_scores(scores);
+        match_account.set_scores_finalized(true);
+
+        msg!(
+            "Scores finalized from {} moves: match {}, scores {:?}",
+            replay.next_move_index,
+            match_id,
+            match_account.scores
+        );
+    } else {
+        msg!(
+            "Score replay progress: match {}, {}/{} moves",
+            match_id,
+            replay.next_move_index,
+            match_account.move_count
+        );
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct FinalizeScores<'info> {
+    #[account(
+        mut,
+        seeds = [b"m", &match_id.as_bytes()[..31.min(match_id.len())]],
+        bump
+    )]
+    pub match_account: AccountLoader<'info, Match>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = ScoreReplay::MAX_SIZE,
+        seeds = [b"score_replay", match_account.key().as_ref()],
+        bump
+    )]
+    pub score_replay: AccountLoader<'info, ScoreReplay>,
+
+    /// Escrow account (only required for paid matches)
+    #[account(
+        seeds = [b"escrow", match_account.key().as_ref()],
+        bump
+    )]
+    pub escrow_account: Option<AccountLoader<'info, EscrowAccount>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,3 +1,6 @@
 pub mod calculate_scores;
+pub mod finalize_scores;
 
 pub use calculate_scores::*;
+#[allow(ambiguous_glob_reexports)]
+pub use finalize_scores::*;
@@ -3,7 +3,18 @@ use crate::state::SignerRegistry;
 use anchor_lang::prelude::*;
 
 pub fn handler(ctx: Context<RegisterSigner>, pubkey: Pubkey, role: u8) -> Result<()> {
-    let mut registry = ctx.accounts.registry.load_mut()?;
+    // Check if account is initialized by checking if discriminator is set
+    let account_info = ctx.accounts.registry.to_account_info();
+    let account_data = account_info.try_borrow_data()?;
+    let is_initialized = account_data.len() >= 8 && account_data[0..8] != [0u8; 8];
+    drop(account_data);
+
+    let mut registry = if is_initialized {
+        ctx.accounts.registry.load_mut()?
+    } else {
+        // Account was just created by init_if_needed but not initialized - initialize it
+        ctx.accounts.registry.load_init()?
+    };
 
     // Initialize registry if it doesn't exist (check if authority is default/unset)
     if registry.authority == Pubkey::default() {
@@ -0,0 +1,67 @@
+use crate::error::GameError;
+use crate::state::enums::tournament_phase;
+use crate::state::Tournament;
+use anchor_lang::prelude::*;
+
+/// Cancels a tournament that has not started and refunds every buy-in in full.
+/// Remaining accounts: the wallet of every entrant, in seed (registration) order.
+/// Organizer-only instruction.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CancelTournament<'info>>,
+    _tournament_id: [u8; 16],
+) -> Result<()> {
+    let mut tournament = ctx.accounts.tournament.load_mut()?;
+
+    require!(
+        ctx.accounts.authority.key() == tournament.authority,
+        GameError::Unauthorized
+    );
+    require!(
+        tournament.phase == tournament_phase::REGISTRATION,
+        GameError::InvalidTournamentPhase
+    );
+    require!(
+        ctx.remaining_accounts.len() == tournament.entrant_count as usize,
+        GameError::InvalidPayload
+    );
+    for (seed, wallet) in ctx.remaining_accounts.iter().enumerate() {
+        require!(
+            wallet.key() == tournament.entrants[seed] && wallet.is_writable,
+            GameError::InvalidPayload
+        );
+    }
+
+    let buy_in = tournament.buy_in_lamports;
+    let refunded = tournament.prize_pool_lamports;
+    tournament.prize_pool_lamports = 0;
+    tournament.phase = tournament_phase::CANCELLED;
+    drop(tournament);
+
+    if buy_in > 0 {
+        let tournament_info = ctx.accounts.tournament.to_account_info();
+        for wallet in ctx.remaining_accounts.iter() {
+            **tournament_info.try_borrow_mut_lamports()? -= buy_in;
+            **wallet.try_borrow_mut_lamports()? += buy_in;
+        }
+    }
+
+    msg!(
+        "Tournament cancelled: {} lamports refunded to {} entrants",
+        refunded,
+        ctx.remaining_accounts.len()
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: [u8; 16])]
+pub struct CancelTournament<'info> {
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament_id.as_ref()],
+        bump = tournament.load()?.bump
+    )]
+    pub tournament: AccountLoader<'info, Tournament>,
+
+    pub authority: Signer<'info>,
+}
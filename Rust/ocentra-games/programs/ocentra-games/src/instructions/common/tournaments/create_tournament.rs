@@ -0,0 +1,168 @@
+use crate::common::prize_structure::PrizeStructure;
+use crate::error::GameError;
+use crate::games::has_on_chain_scoring;
+use crate::state::enums::{payment_method, prize_structure, tournament_format, tournament_phase};
+use crate::state::{
+    ConfigAccount, GameRegistry, Tournament, MAX_TOURNAMENT_ENTRANTS, MAX_TOURNAMENT_PAIRINGS,
+    MAX_TOURNAMENT_PAYOUTS, PAIRING_PENDING, TOURNAMENT_BYE,
+};
+use anchor_lang::prelude::*;
+
+/// Tournament parameters declared at create_tournament.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TournamentParams {
+    pub game_type: u8,
+    pub format: u8,
+    pub max_entrants: u8,
+    pub buy_in_lamports: u64,
+    pub registration_opens_at: i64,
+    pub registration_closes_at: i64,
+    pub payout_bps: Vec<u16>, // Share of the prize pool per place (sums to 10,000 bps)
+    pub no_show_secs: u32,    // Lobby time before a bracket match no-show can be reported
+}
+
+/// Creates a tournament for a registered game. Bracket matches are heads-up, so the game
+/// must allow two-player matches. Buy-ins are paid from entrants' wallets into the tournament
+/// account and are bounded like match entry fees (game override or ConfigAccount).
+pub fn handler(
+    ctx: Context<CreateTournament>,
+    tournament_id: [u8; 16],
+    params: TournamentParams,
+) -> Result<()> {
+    let registry = ctx.accounts.registry.load()?;
+    let clock = Clock::get()?;
+
+    // Tournament ID of all zeros means "not a tournament match" on Match
+    require!(
+        tournament_id.iter().any(|&b| b != 0),
+        GameError::InvalidPayload
+    );
+
+    // Game must exist, be enabled and support heads-up matches
+    let game_def = registry
+        .find_game(params.game_type)
+        .ok_or(GameError::InvalidPayload)?;
+    require!(game_def.enabled != 0, GameError::InvalidPayload);
+    require!(
+        game_def.min_players <= 2 && game_def.max_players >= 2,
+        GameError::InvalidPayload
+    );
+    // Bracket results come from the recorded scores - the game's rules must compute them
+    require!(
+        has_on_chain_scoring(params.game_type),
+        GameError::GameNotScorable
+    );
+
+    require!(
+        params.format <= tournament_format::ROUND_ROBIN,
+        GameError::InvalidPayload
+    );
+    require!(
+        params.max_entrants >= 2 && params.max_entrants as usize <= MAX_TOURNAMENT_ENTRANTS,
+        GameError::InvalidPayload
+    );
+    require!(
+        params.registration_closes_at > params.registration_opens_at
+            && params.registration_closes_at > clock.unix_timestamp,
+        GameError::InvalidTimestamp
+    );
+    // A bracket match nobody starts must be resolvable (report_tournament_no_show)
+    require!(params.no_show_secs > 0, GameError::InvalidPayload);
+
+    // Payout table follows the TOP_N_SPLIT rules: non-increasing shares summing to 10,000 bps
+    PrizeStructure::validate(
+        prize_structure::TOP_N_SPLIT,
+        &params.payout_bps,
+        MAX_TOURNAMENT_PAYOUTS as u8,
+    )?;
+
+    // Paid tournaments follow the game's economics
+    let mut platform_fee_bps = 0u16;
+    if params.buy_in_lamports > 0 {
+        let config = ctx
+            .accounts
+            .config_account
+            .as_ref()
+            .ok_or(GameError::InvalidPayload)?;
+        require!(!config.is_paused, GameError::ProgramPaused);
+        require!(
+            game_def.is_payment_method_allowed(config, payment_method::WALLET),
+            GameError::InvalidPaymentMethod
+        );
+        let (min_entry_fee, max_entry_fee) = game_def.effective_entry_fee_bounds(config);
+        require!(
+            params.buy_in_lamports >= min_entry_fee && params.buy_in_lamports <= max_entry_fee,
+            GameError::EntryFeeOutOfRange
+        );
+        platform_fee_bps = game_def.effective_platform_fee_bps(config);
+    }
+
+    let mut tournament = ctx.accounts.tournament.load_init()?;
+    tournament.tournament_id = tournament_id;
+    tournament.authority = ctx.accounts.authority.key();
+    tournament.buy_in_lamports = params.buy_in_lamports;
+    tournament.prize_pool_lamports = 0;
+    tournament.registration_opens_at = params.registration_opens_at;
+    tournament.registration_closes_at = params.registration_closes_at;
+    tournament.created_at = clock.unix_timestamp;
+    tournament.entrants = [Pubkey::default(); MAX_TOURNAMENT_ENTRANTS];
+    tournament.pairing_matches = [Pubkey::default(); MAX_TOURNAMENT_PAIRINGS];
+    tournament.points = [0u16; MAX_TOURNAMENT_ENTRANTS];
+    tournament.payout_bps = [0u16; MAX_TOURNAMENT_PAYOUTS];
+    tournament.payout_bps[..params.payout_bps.len()].copy_from_slice(&params.payout_bps);
+    tournament.platform_fee_bps = platform_fee_bps;
+    tournament.game_type = params.game_type;
+    tournament.format = params.format;
+    tournament.phase = tournament_phase::REGISTRATION;
+    tournament.max_entrants = params.max_entrants;
+    tournament.entrant_count = 0;
+    tournament.current_round = 0;
+    tournament.total_rounds = 0; // Set by start_tournament once the field is known
+    tournament.pairing_count = 0;
+    tournament.payout_count = params.payout_bps.len() as u8;
+    tournament.bump = ctx.bumps.tournament;
+    tournament.pairings = [[TOURNAMENT_BYE; 2]; MAX_TOURNAMENT_PAIRINGS];
+    tournament.pairing_results = [PAIRING_PENDING; MAX_TOURNAMENT_PAIRINGS];
+    tournament.placements = [TOURNAMENT_BYE; MAX_TOURNAMENT_ENTRANTS];
+    tournament.no_show_secs = params.no_show_secs;
+
+    msg!(
+        "Tournament created: game_type={}, format={}, max_entrants={}, buy_in={} lamports",
+        params.game_type,
+        params.format,
+        params.max_entrants,
+        params.buy_in_lamports
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: [u8; 16])]
+pub struct CreateTournament<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = Tournament::MAX_SIZE,
+        seeds = [b"tournament", tournament_id.as_ref()],
+        bump
+    )]
+    pub tournament: AccountLoader<'info, Tournament>,
+
+    #[account(
+        seeds = [b"game_registry"],
+        bump
+    )]
+    pub registry: AccountLoader<'info, GameRegistry>,
+
+    /// Config account (optional - required for paid tournaments)
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Option<Account<'info, ConfigAccount>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
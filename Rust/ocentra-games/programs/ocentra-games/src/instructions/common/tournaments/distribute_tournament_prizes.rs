@@ -0,0 +1,106 @@
+use crate::common::tournament_bracket::TournamentBracket;
+use crate::error::GameError;
+use crate::state::enums::{fee_source, tournament_phase};
+use crate::state::{Tournament, TreasuryVault};
+use anchor_lang::prelude::*;
+
+/// Pays out a completed tournament's prize pool by its payout table.
+/// The platform fee (fixed at create_tournament) goes to the treasury vault first; the rest is
+/// split between the top finishers. Remaining accounts: the wallet of each paid place, in
+/// placement order (first place first). Anyone may crank a completed tournament.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DistributeTournamentPrizes<'info>>,
+    _tournament_id: [u8; 16],
+) -> Result<()> {
+    let mut tournament = ctx.accounts.tournament.load_mut()?;
+
+    require!(
+        tournament.phase == tournament_phase::COMPLETED,
+        GameError::InvalidTournamentPhase
+    );
+
+    let total_pool = tournament.prize_pool_lamports;
+    let platform_fee = (total_pool as u128)
+        .checked_mul(tournament.platform_fee_bps as u128)
+        .and_then(|x| x.checked_div(10_000))
+        .and_then(|x| u64::try_from(x).ok())
+        .ok_or(GameError::Overflow)?;
+    let prize_pool = total_pool
+        .checked_sub(platform_fee)
+        .ok_or(GameError::Overflow)?;
+    let payouts = TournamentBracket::compute_payouts(&tournament, prize_pool)?;
+
+    // One wallet per paid place
+    let places = tournament
+        .get_payout_bps()
+        .len()
+        .min(tournament.entrant_count as usize);
+    require!(
+        ctx.remaining_accounts.len() == places,
+        GameError::InvalidPayload
+    );
+    for (place, wallet) in ctx.remaining_accounts.iter().enumerate() {
+        let entrant = tournament.placements[place] as usize;
+        require!(
+            wallet.key() == tournament.entrants[entrant] && wallet.is_writable,
+            GameError::InvalidPayload
+        );
+    }
+
+    tournament.prize_pool_lamports = 0;
+    tournament.phase = tournament_phase::SETTLED;
+    let game_type = tournament.game_type;
+    drop(tournament);
+
+    // Manual lamport transfers - the tournament account carries data
+    let tournament_info = ctx.accounts.tournament.to_account_info();
+    if platform_fee > 0 {
+        let treasury_info = ctx.accounts.treasury_vault.to_account_info();
+        **tournament_info.try_borrow_mut_lamports()? -= platform_fee;
+        **treasury_info.try_borrow_mut_lamports()? += platform_fee;
+
+        let mut treasury_vault = ctx.accounts.treasury_vault.load_mut()?;
+        treasury_vault.record_fee(fee_source::PLATFORM_FEE, Some(game_type), platform_fee)?;
+    }
+
+    for (place, wallet) in ctx.remaining_accounts.iter().enumerate() {
+        if payouts[place] > 0 {
+            **tournament_info.try_borrow_mut_lamports()? -= payouts[place];
+            **wallet.try_borrow_mut_lamports()? += payouts[place];
+        }
+        msg!(
+            "Place {} ({}): {} lamports",
+            place + 1,
+            wallet.key(),
+            payouts[place]
+        );
+    }
+
+    msg!(
+        "Tournament prizes distributed: pool {} lamports, platform fee {} lamports",
+        total_pool,
+        platform_fee
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: [u8; 16])]
+pub struct DistributeTournamentPrizes<'info> {
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament_id.as_ref()],
+        bump = tournament.load()?.bump
+    )]
+    pub tournament: AccountLoader<'info, Tournament>,
+
+    #[account(
+        mut,
+        seeds = [b"treasury_vault"],
+        bump = treasury_vault.load()?.bump
+    )]
+    pub treasury_vault: AccountLoader<'info, TreasuryVault>,
+
+    /// Anyone may crank a completed tournament
+    pub caller: Signer<'info>,
+}
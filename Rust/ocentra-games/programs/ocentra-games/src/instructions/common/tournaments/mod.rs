@@ -0,0 +1,27 @@
+pub mod cancel_tournament;
+pub mod create_tournament;
+pub mod distribute_tournament_prizes;
+pub mod register_tournament;
+pub mod report_tournament_match;
+pub mod report_tournament_no_show;
+pub mod reschedule_tournament_pairing;
+pub mod start_tournament;
+
+// Re-export for Anchor's #[program] macro
+// Ambiguous re-exports warning is acceptable because handlers use full paths
+#[allow(ambiguous_glob_reexports)]
+pub use cancel_tournament::*;
+#[allow(ambiguous_glob_reexports)]
+pub use create_tournament::*;
+#[allow(ambiguous_glob_reexports)]
+pub use distribute_tournament_prizes::*;
+#[allow(ambiguous_glob_reexports)]
+pub use register_tournament::*;
+#[allow(ambiguous_glob_reexports)]
+pub use report_tournament_match::*;
+#[allow(ambiguous_glob_reexports)]
+pub use report_tournament_no_show::*;
+#[allow(ambiguous_glob_reexports)]
+pub use reschedule_tournament_pairing::*;
+#[allow(ambiguous_glob_reexports)]
+pub use start_tournament::*;
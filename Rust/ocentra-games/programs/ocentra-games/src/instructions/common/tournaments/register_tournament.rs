@@ -0,0 +1,87 @@
+use crate::error::GameError;
+use crate::state::{ConfigAccount, Tournament, MAX_TOURNAMENT_ENTRANTS};
+use anchor_lang::prelude::*;
+
+/// Registers the signer for a tournament during its registration window.
+/// The buy-in moves from the entrant's wallet into the tournament account, which holds
+/// the prize pool until distribute_tournament_prizes (or cancel_tournament refunds it).
+/// Entrants are seeded in registration order.
+pub fn handler(ctx: Context<RegisterTournament>, _tournament_id: [u8; 16]) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let buy_in = {
+        let tournament = ctx.accounts.tournament.load()?;
+        require!(
+            tournament.is_registration_open(clock.unix_timestamp),
+            GameError::RegistrationNotOpen
+        );
+        require!(
+            tournament.entrant_count < tournament.max_entrants
+                && (tournament.entrant_count as usize) < MAX_TOURNAMENT_ENTRANTS,
+            GameError::TournamentFull
+        );
+        require!(
+            tournament
+                .find_entrant(&ctx.accounts.entrant.key())
+                .is_none(),
+            GameError::AlreadyRegistered
+        );
+        tournament.buy_in_lamports
+    };
+
+    if buy_in > 0 {
+        require!(
+            !ctx.accounts.config_account.is_paused,
+            GameError::ProgramPaused
+        );
+
+        // Buy-in: entrant wallet → tournament account
+        let cpi_accounts = anchor_lang::system_program::Transfer {
+            from: ctx.accounts.entrant.to_account_info(),
+            to: ctx.accounts.tournament.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+        anchor_lang::system_program::transfer(cpi_ctx, buy_in)?;
+    }
+
+    let mut tournament = ctx.accounts.tournament.load_mut()?;
+    let seed = tournament.entrant_count as usize;
+    tournament.entrants[seed] = ctx.accounts.entrant.key();
+    tournament.entrant_count += 1;
+    tournament.prize_pool_lamports = tournament
+        .prize_pool_lamports
+        .checked_add(buy_in)
+        .ok_or(GameError::Overflow)?;
+
+    msg!(
+        "Entrant {} registered as seed {} ({} of {}), prize pool {} lamports",
+        ctx.accounts.entrant.key(),
+        seed,
+        tournament.entrant_count,
+        tournament.max_entrants,
+        tournament.prize_pool_lamports
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: [u8; 16])]
+pub struct RegisterTournament<'info> {
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament_id.as_ref()],
+        bump = tournament.load()?.bump
+    )]
+    pub tournament: AccountLoader<'info, Tournament>,
+
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+
+    #[account(mut)]
+    pub entrant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,98 @@
+use crate::common::tournament_bracket::TournamentBracket;
+use crate::error::GameError;
+use crate::state::enums::{tournament_format, tournament_phase};
+use crate::state::{Match, Tournament, PAIRING_DRAW};
+use anchor_lang::prelude::*;
+
+/// Records the result of a finished bracket match and advances the tournament.
+/// The winner is read from the match's recorded scores: an entrant who never took a seat or
+/// forfeited loses, equal scores are a draw (single elimination: the better seed advances).
+/// Completing a round pairs the next one; completing the last round sets the final standings.
+/// Anyone may crank a finished match.
+pub fn handler(
+    ctx: Context<ReportTournamentMatch>,
+    tournament_id: [u8; 16],
+    match_id: String,
+) -> Result<()> {
+    let match_account = ctx.accounts.match_account.load()?;
+    let mut tournament = ctx.accounts.tournament.load_mut()?;
+
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36
+            && match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::InvalidPayload
+    );
+
+    require!(
+        tournament.phase == tournament_phase::IN_PROGRESS,
+        GameError::InvalidTournamentPhase
+    );
+    require!(
+        match_account.get_tournament_id() == Some(tournament_id),
+        GameError::NotInTournamentBracket
+    );
+    let pairing = tournament
+        .find_pairing_by_match(&ctx.accounts.match_account.key())
+        .ok_or(GameError::NotInTournamentBracket)?;
+
+    // Result comes from the match's authoritative scores
+    require!(match_account.is_ended(), GameError::MatchNotEnded);
+    require!(
+        match_account.scores_recorded(),
+        GameError::ScoresNotRecorded
+    );
+
+    let [a, b] = tournament.pairings[pairing];
+    let score = |entrant: u8| -> Option<i32> {
+        let seat =
+            match_account.find_player_index_by_wallet(&tournament.entrants[entrant as usize])?;
+        if match_account.is_forfeited(seat) {
+            None
+        } else {
+            Some(match_account.scores[seat])
+        }
+    };
+    let result = match (score(a), score(b)) {
+        (Some(score_a), Some(score_b)) if score_a > score_b => a,
+        (Some(score_a), Some(score_b)) if score_b > score_a => b,
+        (Some(_), None) => a,
+        (None, Some(_)) => b,
+        // Tie (or neither entrant played)
+        _ if tournament.format == tournament_format::SINGLE_ELIMINATION => a.min(b),
+        _ => PAIRING_DRAW,
+    };
+
+    TournamentBracket::report(&mut tournament, pairing, result)?;
+
+    msg!(
+        "Tournament pairing {} reported: result={} (round {} of {}, phase {})",
+        pairing,
+        result,
+        tournament.current_round + 1,
+        tournament.total_rounds,
+        tournament.phase
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: [u8; 16], match_id: String)]
+pub struct ReportTournamentMatch<'info> {
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament_id.as_ref()],
+        bump = tournament.load()?.bump
+    )]
+    pub tournament: AccountLoader<'info, Tournament>,
+
+    #[account(
+        seeds = [b"m", &match_id.as_bytes()[..31.min(match_id.len())]],
+        bump
+    )]
+    pub match_account: AccountLoader<'info, Match>,
+
+    /// Anyone may crank a finished match
+    pub caller: Signer<'info>,
+}
@@ -0,0 +1,101 @@
+use crate::common::tournament_bracket::TournamentBracket;
+use crate::error::GameError;
+use crate::state::enums::{tournament_format, tournament_phase};
+use crate::state::{game_phase, Match, Tournament, PAIRING_DRAW};
+use anchor_lang::prelude::*;
+
+/// Settles a bracket match that never started once its no-show deadline has passed
+/// (Match.created_at + Tournament.no_show_secs). An entrant who took a seat wins by walkover
+/// against one who didn't; when both or neither took a seat the pairing is settled like a tie
+/// (single elimination: the better seed advances, otherwise a draw).
+/// The lobby stays open until expire_match closes it - the pairing no longer holds it.
+/// Anyone may crank a stalled pairing.
+pub fn handler(
+    ctx: Context<ReportTournamentNoShow>,
+    tournament_id: [u8; 16],
+    match_id: String,
+) -> Result<()> {
+    let match_account = ctx.accounts.match_account.load()?;
+    let mut tournament = ctx.accounts.tournament.load_mut()?;
+    let clock = Clock::get()?;
+
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36
+            && match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::InvalidPayload
+    );
+
+    require!(
+        tournament.phase == tournament_phase::IN_PROGRESS,
+        GameError::InvalidTournamentPhase
+    );
+    require!(
+        match_account.get_tournament_id() == Some(tournament_id),
+        GameError::NotInTournamentBracket
+    );
+    let pairing = tournament
+        .find_pairing_by_match(&ctx.accounts.match_account.key())
+        .ok_or(GameError::NotInTournamentBracket)?;
+
+    // Started matches are reported from their scores (stalled turns use claim_turn_timeout)
+    require!(
+        match_account.phase == game_phase::DEALING,
+        GameError::InvalidPhase
+    );
+    let deadline = match_account
+        .created_at
+        .checked_add(tournament.no_show_secs as i64)
+        .ok_or(GameError::Overflow)?;
+    require!(
+        clock.unix_timestamp >= deadline,
+        GameError::NoShowDeadlineNotPassed
+    );
+
+    let [a, b] = tournament.pairings[pairing];
+    let seated = |entrant: u8| {
+        match_account
+            .find_player_index_by_wallet(&tournament.entrants[entrant as usize])
+            .is_some()
+    };
+    let result = match (seated(a), seated(b)) {
+        (true, false) => a,
+        (false, true) => b,
+        // Both or neither showed up
+        _ if tournament.format == tournament_format::SINGLE_ELIMINATION => a.min(b),
+        _ => PAIRING_DRAW,
+    };
+
+    TournamentBracket::report(&mut tournament, pairing, result)?;
+
+    msg!(
+        "Tournament pairing {} settled by no-show: result={} (round {} of {}, phase {})",
+        pairing,
+        result,
+        tournament.current_round + 1,
+        tournament.total_rounds,
+        tournament.phase
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: [u8; 16], match_id: String)]
+pub struct ReportTournamentNoShow<'info> {
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament_id.as_ref()],
+        bump = tournament.load()?.bump
+    )]
+    pub tournament: AccountLoader<'info, Tournament>,
+
+    #[account(
+        seeds = [b"m", &match_id.as_bytes()[..31.min(match_id.len())]],
+        bump
+    )]
+    pub match_account: AccountLoader<'info, Match>,
+
+    /// Anyone may crank a stalled pairing
+    pub caller: Signer<'info>,
+}
@@ -0,0 +1,82 @@
+use crate::error::GameError;
+use crate::state::enums::tournament_phase;
+use crate::state::{game_phase, Match, Tournament, PAIRING_PENDING};
+use anchor_lang::prelude::*;
+
+/// Frees a pending pairing of the current round from the match scheduled for it, so
+/// create_match can schedule a fresh one. The scheduled match must never have started and be
+/// past its no-show deadline - or already be closed (e.g. cancelled).
+/// Remaining accounts: the match scheduled for the pairing.
+/// Organizer-only instruction.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RescheduleTournamentPairing<'info>>,
+    _tournament_id: [u8; 16],
+    pairing: u8,
+) -> Result<()> {
+    let mut tournament = ctx.accounts.tournament.load_mut()?;
+    let clock = Clock::get()?;
+    let pairing = pairing as usize;
+
+    require!(
+        ctx.accounts.authority.key() == tournament.authority,
+        GameError::Unauthorized
+    );
+    require!(
+        tournament.phase == tournament_phase::IN_PROGRESS,
+        GameError::InvalidTournamentPhase
+    );
+    require!(
+        pairing < tournament.pairing_count as usize
+            && tournament.pairing_results[pairing] == PAIRING_PENDING,
+        GameError::NotInTournamentBracket
+    );
+    let scheduled = tournament.pairing_matches[pairing];
+    require!(scheduled != Pubkey::default(), GameError::InvalidPayload);
+
+    let match_info = ctx
+        .remaining_accounts
+        .first()
+        .ok_or(GameError::InvalidPayload)?;
+    require!(match_info.key() == scheduled, GameError::InvalidPayload);
+
+    // A match that is still open must be a lobby nobody started, past its no-show deadline
+    if !match_info.data_is_empty() {
+        let match_loader = AccountLoader::<Match>::try_from(match_info)?;
+        let match_account = match_loader.load()?;
+        require!(
+            match_account.phase == game_phase::DEALING,
+            GameError::InvalidPhase
+        );
+        let deadline = match_account
+            .created_at
+            .checked_add(tournament.no_show_secs as i64)
+            .ok_or(GameError::Overflow)?;
+        require!(
+            clock.unix_timestamp >= deadline,
+            GameError::NoShowDeadlineNotPassed
+        );
+    }
+
+    tournament.pairing_matches[pairing] = Pubkey::default();
+
+    msg!(
+        "Tournament pairing {} of round {} rescheduled (was match {})",
+        pairing,
+        tournament.current_round + 1,
+        scheduled
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: [u8; 16])]
+pub struct RescheduleTournamentPairing<'info> {
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament_id.as_ref()],
+        bump = tournament.load()?.bump
+    )]
+    pub tournament: AccountLoader<'info, Tournament>,
+
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,54 @@
+use crate::common::tournament_bracket::TournamentBracket;
+use crate::error::GameError;
+use crate::state::enums::tournament_phase;
+use crate::state::Tournament;
+use anchor_lang::prelude::*;
+
+/// Closes registration and pairs the first round.
+/// Allowed once the registration window has closed, or earlier when the field is full.
+/// Organizer-only instruction.
+pub fn handler(ctx: Context<StartTournament>, _tournament_id: [u8; 16]) -> Result<()> {
+    let mut tournament = ctx.accounts.tournament.load_mut()?;
+    let clock = Clock::get()?;
+
+    require!(
+        ctx.accounts.authority.key() == tournament.authority,
+        GameError::Unauthorized
+    );
+    require!(
+        tournament.phase == tournament_phase::REGISTRATION,
+        GameError::InvalidTournamentPhase
+    );
+    require!(
+        clock.unix_timestamp >= tournament.registration_closes_at
+            || tournament.entrant_count == tournament.max_entrants,
+        GameError::InvalidTournamentPhase
+    );
+    require!(
+        tournament.entrant_count >= 2,
+        GameError::InsufficientPlayers
+    );
+
+    TournamentBracket::start(&mut tournament)?;
+
+    msg!(
+        "Tournament started: {} entrants, {} rounds, {} pairings in round 1",
+        tournament.entrant_count,
+        tournament.total_rounds,
+        tournament.pairing_count
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tournament_id: [u8; 16])]
+pub struct StartTournament<'info> {
+    #[account(
+        mut,
+        seeds = [b"tournament", tournament_id.as_ref()],
+        bump = tournament.load()?.bump
+    )]
+    pub tournament: AccountLoader<'info, Tournament>,
+
+    pub authority: Signer<'info>,
+}
@@ -1,3 +1,14 @@
+pub mod register_validator;
 pub mod slash_validator;
+pub mod unstake;
+pub mod withdraw_stake;
 
+// Re-export for Anchor's #[program] macro
+// Ambiguous re-exports warning is acceptable because handlers use full paths
+#[allow(ambiguous_glob_reexports)]
+pub use register_validator::*;
+#[allow(ambiguous_glob_reexports)]
 pub use slash_validator::*;
+#[allow(ambiguous_glob_reexports)]
+pub use unstake::*;
+pub use withdraw_stake::*;
@@ -0,0 +1,91 @@
+use crate::error::GameError;
+use crate::state::{
+    signer_role, SignerRegistry, ValidatorReputation, INITIAL_VALIDATOR_REPUTATION,
+    MIN_VALIDATOR_STAKE_LAMPORTS,
+};
+use anchor_lang::prelude::*;
+
+/// Stakes SOL into the validator's stake vault (its ValidatorReputation PDA).
+/// The first call registers the validator with INITIAL_VALIDATOR_REPUTATION; later calls top
+/// up the stake. Only VALIDATOR-role signers in the SignerRegistry can stake, and the
+/// resulting stake must reach MIN_VALIDATOR_STAKE_LAMPORTS.
+pub fn handler(ctx: Context<RegisterValidator>, amount: u64) -> Result<()> {
+    require!(amount > 0, GameError::InvalidPayload);
+
+    let validator = ctx.accounts.validator.key();
+
+    // Security: Only registered validators may stake
+    {
+        let registry = ctx.accounts.registry.load()?;
+        require!(
+            registry.get_role(&validator) == Some(signer_role::VALIDATOR),
+            GameError::Unauthorized
+        );
+    }
+
+    // Move the stake into the vault
+    let cpi_accounts = anchor_lang::system_program::Transfer {
+        from: ctx.accounts.validator.to_account_info(),
+        to: ctx.accounts.validator_reputation.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.system_program.to_account_info();
+    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    anchor_lang::system_program::transfer(cpi_ctx, amount)?;
+
+    let clock = Clock::get()?;
+    let validator_reputation = &mut ctx.accounts.validator_reputation;
+    if validator_reputation.created_at == 0 {
+        // Account was just created by init_if_needed - register the validator
+        validator_reputation.validator = validator;
+        validator_reputation.reputation = INITIAL_VALIDATOR_REPUTATION;
+        validator_reputation.created_at = clock.unix_timestamp;
+        validator_reputation.last_active = clock.unix_timestamp;
+        validator_reputation.bump = ctx.bumps.validator_reputation;
+    } else {
+        require!(
+            validator_reputation.validator == validator,
+            GameError::Unauthorized
+        );
+    }
+
+    validator_reputation.stake = validator_reputation
+        .stake
+        .checked_add(amount)
+        .ok_or(GameError::Overflow)?;
+    require!(
+        validator_reputation.stake >= MIN_VALIDATOR_STAKE_LAMPORTS,
+        GameError::InsufficientValidatorStake
+    );
+
+    msg!(
+        "Validator {} staked {} lamports. Stake: {}, unbonding: {}",
+        validator,
+        amount,
+        validator_reputation.stake,
+        validator_reputation.unbonding_lamports
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RegisterValidator<'info> {
+    #[account(
+        init_if_needed,
+        payer = validator,
+        space = ValidatorReputation::MAX_SIZE,
+        seeds = [b"validator", validator.key().as_ref()],
+        bump
+    )]
+    pub validator_reputation: Account<'info, ValidatorReputation>,
+
+    #[account(
+        seeds = [b"signer_registry"],
+        bump
+    )]
+    pub registry: AccountLoader<'info, SignerRegistry>,
+
+    #[account(mut)]
+    pub validator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
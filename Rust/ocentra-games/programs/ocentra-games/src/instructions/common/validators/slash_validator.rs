@@ -1,13 +1,15 @@
 use crate::error::GameError;
-use crate::state::ValidatorReputation;
+use crate::state::enums::{fee_source, slash_reason};
+use crate::state::{signer_role, SignerRegistry, TreasuryVault, ValidatorReputation};
 use anchor_lang::prelude::*;
 
 /**
  * Slashes a validator's stake for malicious or negligent behavior.
  * Per critique Issue #3, #5, Spec Section 33.3: Validator slashing mechanism.
  *
- * Only the authority can slash validators.
- * Slashed amount is transferred to the authority or treasury.
+ * Only AUTHORITY-role signers in the SignerRegistry can slash validators.
+ * The slashed lamports leave the validator's stake vault (active stake first, then lamports
+ * still unbonding) and go to the harmed party when one is passed, otherwise to the TreasuryVault.
  */
 pub fn handler(
     ctx: Context<SlashValidator>,
@@ -18,11 +20,23 @@ pub fn handler(
     // Security: Validate authority is signer
     require!(ctx.accounts.authority.is_signer, GameError::Unauthorized);
 
+    // Security: Only registry authorities may slash
+    {
+        let registry = ctx.accounts.registry.load()?;
+        require!(
+            registry.get_role(&ctx.accounts.authority.key()) == Some(signer_role::AUTHORITY),
+            GameError::Unauthorized
+        );
+    }
+
     // Security: Validate amount is positive
     require!(amount > 0, GameError::InvalidPayload);
 
     // Security: Validate reason is valid
-    require!(reason <= 2, GameError::InvalidPayload);
+    require!(
+        reason <= slash_reason::INACTIVITY,
+        GameError::InvalidPayload
+    );
 
     // Get validator reputation account
     let validator_account = &mut ctx.accounts.validator_reputation;
@@ -33,37 +47,35 @@ pub fn handler(
         GameError::InvalidPayload
     );
 
-    // Security: Validate validator has sufficient stake
-    require!(
-        validator_account.stake >= amount,
-        GameError::InsufficientFunds
-    );
-
-    // Slash the stake
-    validator_account.stake = validator_account
-        .stake
-        .checked_sub(amount)
-        .ok_or(GameError::InsufficientFunds)?;
+    // Slash the bond (fails if the validator has less than amount bonded)
+    validator_account.slash(amount)?;
 
     // Update reputation (slash reduces reputation)
-    let reputation_penalty = match reason {
-        0 => 0.5, // Malicious: 50% reputation loss
-        1 => 0.2, // Negligent: 20% reputation loss
-        2 => 0.1, // Inactivity: 10% reputation loss
-        _ => 0.0,
-    };
-    validator_account.reputation =
-        (validator_account.reputation * (1.0 - reputation_penalty)).max(0.0);
+    validator_account.apply_slash_penalty(reason);
 
-    // Transfer slashed amount from validator stake to authority (or treasury in production)
-    // Note: In production, stake would be in a separate escrow account
-    // For now, we just update the reputation account's stake field
-    // The actual SOL transfer would happen when stake is withdrawn
+    // Transfer slashed lamports from the stake vault - manual transfer, the vault carries data
+    let vault_account_info = validator_account.to_account_info();
+    let recipient_account_info = match &ctx.accounts.harmed_party {
+        Some(harmed_party) => harmed_party.to_account_info(),
+        None => {
+            let treasury = ctx
+                .accounts
+                .treasury_vault
+                .as_ref()
+                .ok_or(GameError::InvalidPayload)?;
+            let mut treasury_vault = treasury.load_mut()?;
+            treasury_vault.record_fee(fee_source::SLASHED_STAKE, None, amount)?;
+            treasury.to_account_info()
+        }
+    };
+    **vault_account_info.try_borrow_mut_lamports()? -= amount;
+    **recipient_account_info.try_borrow_mut_lamports()? += amount;
 
     msg!(
-        "Slashed validator {}: {} lamports (reason: {})",
+        "Slashed validator {}: {} lamports to {} (reason: {})",
         validator_pubkey,
         amount,
+        recipient_account_info.key(),
         reason
     );
 
@@ -76,10 +88,29 @@ pub struct SlashValidator<'info> {
     #[account(
         mut,
         seeds = [b"validator", validator_pubkey.as_ref()],
-        bump
+        bump = validator_reputation.bump
     )]
     pub validator_reputation: Account<'info, ValidatorReputation>,
 
+    #[account(
+        seeds = [b"signer_registry"],
+        bump
+    )]
+    pub registry: AccountLoader<'info, SignerRegistry>,
+
+    /// Treasury vault (optional - receives the slashed lamports when no harmed party is passed)
+    #[account(
+        mut,
+        seeds = [b"treasury_vault"],
+        bump
+    )]
+    pub treasury_vault: Option<AccountLoader<'info, TreasuryVault>>,
+
+    /// Party harmed by the validator (optional - receives the slashed lamports directly)
+    /// CHECK: Any destination chosen by the slashing authority
+    #[account(mut)]
+    pub harmed_party: Option<UncheckedAccount<'info>>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 }
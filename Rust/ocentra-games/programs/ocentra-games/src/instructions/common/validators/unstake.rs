@@ -0,0 +1,56 @@
+use crate::error::GameError;
+use crate::state::{ValidatorReputation, VALIDATOR_UNBONDING_SECONDS};
+use anchor_lang::prelude::*;
+
+/// Starts unbonding part of a validator's stake.
+/// Unstaked lamports stop counting towards vote weight immediately but stay in the vault, and
+/// slashable, for VALIDATOR_UNBONDING_SECONDS before withdraw_stake releases them.
+/// Unstaking more while lamports are unbonding restarts the unbonding period for all of them.
+pub fn handler(ctx: Context<Unstake>, amount: u64) -> Result<()> {
+    require!(amount > 0, GameError::InvalidPayload);
+
+    let validator_reputation = &mut ctx.accounts.validator_reputation;
+
+    // Security: Only the validator can unstake its own bond
+    require!(
+        validator_reputation.validator == ctx.accounts.validator.key(),
+        GameError::Unauthorized
+    );
+    require!(
+        validator_reputation.stake >= amount,
+        GameError::InsufficientFunds
+    );
+
+    let clock = Clock::get()?;
+    validator_reputation.stake -= amount;
+    validator_reputation.unbonding_lamports = validator_reputation
+        .unbonding_lamports
+        .checked_add(amount)
+        .ok_or(GameError::Overflow)?;
+    validator_reputation.unbonding_until = clock
+        .unix_timestamp
+        .checked_add(VALIDATOR_UNBONDING_SECONDS)
+        .ok_or(GameError::Overflow)?;
+
+    msg!(
+        "Validator {} unstaked {} lamports. Stake: {}, unbonding: {} until {}",
+        validator_reputation.validator,
+        amount,
+        validator_reputation.stake,
+        validator_reputation.unbonding_lamports,
+        validator_reputation.unbonding_until
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    #[account(
+        mut,
+        seeds = [b"validator", validator.key().as_ref()],
+        bump = validator_reputation.bump
+    )]
+    pub validator_reputation: Account<'info, ValidatorReputation>,
+
+    pub validator: Signer<'info>,
+}
@@ -0,0 +1,70 @@
+use crate::error::GameError;
+use crate::state::ValidatorReputation;
+use anchor_lang::prelude::*;
+
+/// Pays a validator's unbonded lamports from its stake vault back to its wallet.
+/// Only runs once the unbonding period started by unstake has passed; whatever was slashed
+/// in the meantime is no longer there to withdraw.
+pub fn handler(ctx: Context<WithdrawStake>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Scope the validation to release the borrow before moving lamports
+    let amount = {
+        let validator_reputation = &mut ctx.accounts.validator_reputation;
+
+        // Security: Only the validator can withdraw its own bond
+        require!(
+            validator_reputation.validator == ctx.accounts.validator.key(),
+            GameError::Unauthorized
+        );
+        require!(
+            validator_reputation.unbonding_lamports > 0,
+            GameError::NoUnbondedStake
+        );
+        require!(
+            clock.unix_timestamp >= validator_reputation.unbonding_until,
+            GameError::StakeStillUnbonding
+        );
+
+        let amount = validator_reputation.unbonding_lamports;
+        validator_reputation.unbonding_lamports = 0;
+        validator_reputation.unbonding_until = 0;
+        amount
+    };
+
+    // Manual lamport transfer - the vault carries data
+    let vault_account_info = ctx.accounts.validator_reputation.to_account_info();
+    let validator_account_info = ctx.accounts.validator.to_account_info();
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(ValidatorReputation::MAX_SIZE);
+    let remaining = vault_account_info
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(GameError::InsufficientFunds)?;
+    require!(
+        remaining >= rent_exempt_minimum,
+        GameError::InsufficientFunds
+    );
+    **vault_account_info.try_borrow_mut_lamports()? -= amount;
+    **validator_account_info.try_borrow_mut_lamports()? += amount;
+
+    msg!(
+        "Validator {} withdrew {} unbonded lamports. Stake: {}",
+        ctx.accounts.validator.key(),
+        amount,
+        ctx.accounts.validator_reputation.stake
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawStake<'info> {
+    #[account(
+        mut,
+        seeds = [b"validator", validator.key().as_ref()],
+        bump = validator_reputation.bump
+    )]
+    pub validator_reputation: Account<'info, ValidatorReputation>,
+
+    #[account(mut)]
+    pub validator: Signer<'info>,
+}
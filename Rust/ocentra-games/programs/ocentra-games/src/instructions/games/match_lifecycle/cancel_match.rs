@@ -0,0 +1,133 @@
+use crate::card_games::deck::DEALER_KEY_TIMEOUT_SECONDS;
+use crate::error::GameError;
+use crate::games::awaiting_dealer;
+use crate::state::enums::cancellation_reason;
+use crate::state::{game_phase, EscrowAccount, Match};
+use anchor_lang::prelude::*;
+
+/// Cancels a match that has not ended and puts its escrow into the cancelled state.
+/// Authority-only; stale lobbies are cleaned up permissionlessly by expire_match instead.
+/// The one exception is a dealer (the match authority) that stops revealing deck keys: once
+/// play has waited DEALER_KEY_TIMEOUT_SECONDS on the next key, anyone may cancel, and the
+/// match is cancelled as a PLATFORM_FAULT (full refunds) whatever reason is passed.
+/// refund_escrow only refunds matches cancelled here, using the recorded reason and
+/// abandoned player.
+pub fn handler(
+    ctx: Context<CancelMatch>,
+    match_id: String,
+    reason: u8,
+    abandoned_player_index: Option<u8>,
+) -> Result<()> {
+    let mut match_account = ctx.accounts.match_account.load_mut()?;
+    let clock = Clock::get()?;
+
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36
+            && match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::InvalidPayload
+    );
+
+    // Only live matches can be cancelled (Ended and Cancelled are terminal)
+    require!(
+        match_account.phase == game_phase::DEALING || match_account.phase == game_phase::PLAYING,
+        GameError::MatchNotCancellable
+    );
+
+    // Security: Only the match authority may cancel - unless it is the dealer holding up play
+    let (reason, abandoned_player_index) = if ctx.accounts.caller.key() == match_account.authority {
+        (reason, abandoned_player_index)
+    } else {
+        require!(
+            match_account.phase == game_phase::PLAYING && awaiting_dealer(&match_account),
+            GameError::Unauthorized
+        );
+        let deadline = match_account
+            .turn_started_at
+            .checked_add(DEALER_KEY_TIMEOUT_SECONDS)
+            .ok_or(GameError::Overflow)?;
+        require!(
+            clock.unix_timestamp >= deadline,
+            GameError::DealerKeyNotExpired
+        );
+        (cancellation_reason::PLATFORM_FAULT, None)
+    };
+
+    // Validate cancellation reason (see enums::cancellation_reason)
+    require!(
+        reason <= cancellation_reason::GRACE_PERIOD_EXPIRED,
+        GameError::InvalidPayload
+    );
+
+    // TIMEOUT: default to the seat forfeited on-chain by claim_turn_timeout
+    let abandoned_player_index = match abandoned_player_index {
+        None if reason == cancellation_reason::TIMEOUT
+            && match_account.timed_out_player < match_account.player_count =>
+        {
+            Some(match_account.timed_out_player)
+        }
+        other => other,
+    };
+    if let Some(abandoned_idx) = abandoned_player_index {
+        require!(
+            abandoned_idx < match_account.player_count,
+            GameError::InvalidPayload
+        );
+    }
+
+    // Paid matches: record the cancellation in escrow for refund_escrow
+    if match_account.is_paid_match() {
+        let escrow_loader = ctx
+            .accounts
+            .escrow_account
+            .as_ref()
+            .ok_or(GameError::InvalidPayload)?;
+        let mut escrow_account = escrow_loader.load_mut()?;
+
+        require!(
+            escrow_account.match_pda == ctx.accounts.match_account.key(),
+            GameError::InvalidPayload
+        );
+        require!(
+            !escrow_account.is_distributed(),
+            GameError::EscrowAlreadyDistributed
+        );
+
+        escrow_account.set_cancelled(true);
+        escrow_account.set_cancellation_reason(reason);
+        escrow_account.abandoned_player_index = abandoned_player_index.unwrap_or(255);
+    }
+
+    match_account.phase = game_phase::CANCELLED;
+    match_account.ended_at = clock.unix_timestamp;
+
+    msg!(
+        "Match cancelled: {}, reason {}, abandoned player {:?}",
+        match_id,
+        reason,
+        abandoned_player_index
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct CancelMatch<'info> {
+    #[account(
+        mut,
+        seeds = [b"m", &match_id.as_bytes()[..31.min(match_id.len())]],
+        bump
+    )]
+    pub match_account: AccountLoader<'info, Match>,
+
+    /// Escrow account (only required for paid matches)
+    #[account(
+        mut,
+        seeds = [b"escrow", match_account.key().as_ref()],
+        bump
+    )]
+    pub escrow_account: Option<AccountLoader<'info, EscrowAccount>>,
+
+    pub caller: Signer<'info>,
+}
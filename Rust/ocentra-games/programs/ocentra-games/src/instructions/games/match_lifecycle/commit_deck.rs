@@ -0,0 +1,60 @@
+use crate::error::GameError;
+use crate::games::commit_deck;
+use crate::state::{game_phase, Match};
+use anchor_lang::prelude::*;
+
+/// Commit the dealer's deck during the Dealing phase (card games dealt on-chain, e.g. Poker).
+/// `deck_commitment` is the head of the dealer's hash chain (see card_games::deck): every deal
+/// reveals the next key down the chain, so no seat picks the cards it is dealt.
+/// Match authority only - the dealer cannot hold a seat. The dealer knows every card from the
+/// chain it commits, so seats trust it not to leak the deal (see card_games::deck::Deck).
+pub fn handler(
+    ctx: Context<CommitDeck>,
+    match_id: String,
+    deck_commitment: [u8; 32],
+) -> Result<()> {
+    let mut match_account = ctx.accounts.match_account.load_mut()?;
+
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36
+            && match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::InvalidPayload
+    );
+
+    // Security: Only the match authority deals
+    require!(
+        ctx.accounts.authority.key() == match_account.authority,
+        GameError::Unauthorized
+    );
+    require!(
+        !match_account.has_player_wallet(&ctx.accounts.authority.key()),
+        GameError::DealerSeated
+    );
+
+    // Security: The deck is fixed before play starts
+    require!(
+        match_account.phase == game_phase::DEALING,
+        GameError::InvalidPhase
+    );
+    require!(deck_commitment != [0u8; 32], GameError::InvalidPayload);
+
+    commit_deck(&mut match_account, deck_commitment)?;
+
+    msg!("Dealer committed the deck for match {}", match_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct CommitDeck<'info> {
+    #[account(
+        mut,
+        seeds = [b"m", &match_id.as_bytes()[..31.min(match_id.len())]],
+        bump
+    )]
+    pub match_account: AccountLoader<'info, Match>,
+
+    pub authority: Signer<'info>,
+}
@@ -1,4 +1,7 @@
+use crate::card_games::hand_management::HandManagement;
+use crate::common::validation_base::CommonValidation;
 use crate::error::GameError;
+use crate::games::max_hand_size;
 use crate::state::Match;
 use anchor_lang::prelude::*;
 
@@ -41,6 +44,13 @@ pub fn handler(
         .find_player_index(&user_id_array)
         .ok_or(GameError::PlayerNotInMatch)?;
 
+    // Security: Signer must own the seat (user_id alone is not proof of identity)
+    CommonValidation::validate_seat_signer(
+        &match_account,
+        player_index,
+        &ctx.accounts.player.key(),
+    )?;
+
     // Security: Validate hand hash is not all zeros (empty hash)
     require!(
         !hand_hash.iter().all(|&b| b == 0),
@@ -48,8 +58,12 @@ pub fn handler(
     );
 
     // Per critique Issue #1: Validate hand size is reasonable
-    // For CLAIM game, max hand size is 13, but allow up to 52 (full deck) for other games
-    require!(hand_size > 0 && hand_size <= 52, GameError::InvalidPayload);
+    // Games with on-chain rules bound it by their max hand size (e.g. CLAIM 13, Poker 2,
+    // word search 0 = no hands); other games allow up to 52 (full deck)
+    HandManagement::validate_hand_size_bounds(hand_size)?;
+    if let Some(max_hand_size) = max_hand_size(match_account.game_type) {
+        require!(hand_size <= max_hand_size, GameError::InvalidPayload);
+    }
 
     // Set committed hand hash for this player
     match_account.set_committed_hand_hash(player_index, hand_hash);
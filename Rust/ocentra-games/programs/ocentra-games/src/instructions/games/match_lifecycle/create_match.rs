@@ -1,17 +1,39 @@
+use crate::common::prize_structure::PrizeStructure;
 use crate::error::GameError;
-use crate::state::{ConfigAccount, EscrowAccount, GameRegistry, Match};
+use crate::games::has_on_chain_scoring;
+use crate::state::enums::tournament_phase;
+use crate::state::{ConfigAccount, EscrowAccount, GameRegistry, Match, SupportedMint, Tournament};
 use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+/// Economics, prize and tournament parameters declared at create_match.
+/// Every field is optional - leaving all of them None creates a free, winner-takes-all match
+/// outside any tournament.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct CreateMatchParams {
+    pub entry_fee: Option<u64>,
+    pub payment_method: Option<u8>,
+    pub match_type: Option<u8>,
+    pub tournament_id: Option<[u8; 16]>,
+    pub prize_structure: Option<u8>,
+    pub prize_split_bps: Option<Vec<u16>>,
+}
 
 pub fn handler(
     ctx: Context<CreateMatch>,
     match_id: String,
     game_type: u8,
     seed: u64,
-    entry_fee: Option<u64>,
-    payment_method: Option<u8>,
-    match_type: Option<u8>,
-    tournament_id: Option<[u8; 16]>,
+    params: CreateMatchParams,
 ) -> Result<()> {
+    let CreateMatchParams {
+        entry_fee,
+        payment_method,
+        match_type,
+        tournament_id,
+        prize_structure,
+        prize_split_bps,
+    } = params;
     let mut match_account = ctx.accounts.match_account.load_init()?;
     let registry = ctx.accounts.registry.load()?;
     let clock = Clock::get()?;
@@ -56,6 +78,7 @@ pub fn handler(
     match_account.phase = 0; // Dealing
     match_account.current_player = 0;
     match_account.player_ids = [[0u8; 64]; 10]; // Initialize all player_ids to empty
+    match_account.player_wallets = [Pubkey::default(); 10]; // All seats unbound
     match_account.player_count = 0;
     match_account.move_count = 0;
     match_account.created_at = clock.unix_timestamp;
@@ -69,6 +92,11 @@ pub fn handler(
     match_account.hand_sizes = [0u8; 10]; // All zeros = no hands committed yet - per critique Issue #1
     match_account.committed_hand_hashes = [0u8; 320]; // All zeros = not committed yet
     match_account.last_nonce = [0u64; 10]; // All zeros = no moves yet
+    match_account.game_state = [0u8; 512]; // Initialized by the game module at start_match
+    match_account.scores = [0i32; 10]; // Recorded when the match ends
+    match_account.turn_started_at = 0; // Turn timer starts at start_match
+    match_account.forfeited_players = 0;
+    match_account.timed_out_player = 255; // 255 = none
 
     // Phase 04: Paid match fields (backward compatible - defaults to FREE match)
     let entry_fee_lamports = entry_fee.unwrap_or(0);
@@ -76,11 +104,23 @@ pub fn handler(
     let payment_method_val = payment_method.unwrap_or(crate::state::enums::payment_method::WALLET);
     
     // Validate paid match parameters
+    let mut platform_fee_bps = 0u16;
     if entry_fee_lamports > 0 {
+        // Payouts follow the recorded scores - the game's rules must compute them on-chain
+        require!(
+            has_on_chain_scoring(game_type),
+            GameError::GameNotScorable
+        );
+
+        // Paid matches are bounded by the global config (or the game's overrides)
+        let config = ctx
+            .accounts
+            .config_account
+            .as_ref()
+            .ok_or(GameError::InvalidPayload)?;
+
         // Check if program is paused (only for paid matches)
-        if let Some(config) = &ctx.accounts.config_account {
-            require!(!config.is_paused, GameError::ProgramPaused);
-        }
+        require!(!config.is_paused, GameError::ProgramPaused);
 
         // If entry fee is set, match must be PAID
         require!(
@@ -93,6 +133,24 @@ pub fn handler(
                 || payment_method_val == crate::state::enums::payment_method::PLATFORM,
             GameError::InvalidPayload
         );
+        // ... and allowed for this game
+        require!(
+            game_def.is_payment_method_allowed(config, payment_method_val),
+            GameError::InvalidPaymentMethod
+        );
+
+        // Lamport bounds apply to SOL matches (token matches use their SupportedMint bounds)
+        if ctx.accounts.entry_mint.is_none() {
+            let (min_entry_fee, max_entry_fee) = game_def.effective_entry_fee_bounds(config);
+            require!(
+                entry_fee_lamports >= min_entry_fee && entry_fee_lamports <= max_entry_fee,
+                GameError::EntryFeeOutOfRange
+            );
+        }
+
+        // Fee charged by distribute_prizes is fixed now, so later config changes don't
+        // affect matches already open
+        platform_fee_bps = game_def.effective_platform_fee_bps(config);
     } else {
         // If no entry fee, match must be FREE
         require!(
@@ -103,6 +161,33 @@ pub fn handler(
         // payment_method_val is already set to default WALLET above
     }
 
+    // SPL token matches: entry fee is in the mint's base units, bounded by its SupportedMint
+    let entry_mint = match ctx.accounts.entry_mint.as_ref() {
+        Some(mint) => {
+            require!(entry_fee_lamports > 0, GameError::InvalidPayload);
+            let supported_mint = ctx
+                .accounts
+                .supported_mint
+                .as_ref()
+                .ok_or(GameError::MintNotSupported)?;
+            require!(
+                supported_mint.mint == mint.key() && supported_mint.enabled,
+                GameError::MintNotSupported
+            );
+            require!(
+                supported_mint.is_entry_fee_allowed(entry_fee_lamports),
+                GameError::EntryFeeOutOfRange
+            );
+            // The escrow vault is created by Anchor alongside the escrow account
+            require!(
+                ctx.accounts.escrow_vault.is_some(),
+                GameError::InvalidPayload
+            );
+            mint.key()
+        }
+        None => Pubkey::default(),
+    };
+
     // Set paid match fields
     match_account.entry_fee_lamports = entry_fee_lamports;
     match_account.match_type = match_type_val;
@@ -118,11 +203,59 @@ pub fn handler(
         0
     };
 
-    // Set tournament_id if provided
-    if let Some(tournament_id_bytes) = tournament_id {
-        match_account.tournament_id = tournament_id_bytes;
-    } else {
-        match_account.tournament_id = [0u8; 16]; // All zeros = not a tournament match
+    // Prize structure is fixed at creation - distribute_prizes derives payouts from it
+    let prize_structure_val =
+        prize_structure.unwrap_or(crate::state::enums::prize_structure::WINNER_TAKES_ALL);
+    let prize_split_bps = prize_split_bps.unwrap_or_default();
+    PrizeStructure::validate(prize_structure_val, &prize_split_bps, max_players as u8)?;
+    match_account.prize_structure = prize_structure_val;
+    match_account.prize_split_count = prize_split_bps.len() as u8;
+    match_account.prize_split_bps = [0u16; 10];
+    match_account.prize_split_bps[..prize_split_bps.len()].copy_from_slice(&prize_split_bps);
+
+    // Tournament matches must fill an open pairing of the tournament's current round
+    match tournament_id.filter(|id| id.iter().any(|&b| b != 0)) {
+        Some(tournament_id_bytes) => {
+            let tournament_loader = ctx
+                .accounts
+                .tournament
+                .as_ref()
+                .ok_or(GameError::NotInTournamentBracket)?;
+            let mut tournament = tournament_loader.load_mut()?;
+            require!(
+                tournament.tournament_id == tournament_id_bytes,
+                GameError::NotInTournamentBracket
+            );
+            require!(
+                tournament.phase == tournament_phase::IN_PROGRESS,
+                GameError::InvalidTournamentPhase
+            );
+            // Only the organizer schedules bracket matches
+            require!(
+                ctx.accounts.authority.key() == tournament.authority,
+                GameError::Unauthorized
+            );
+            require!(
+                game_type == tournament.game_type,
+                GameError::NotInTournamentBracket
+            );
+            // Buy-ins were paid at registration - bracket matches are free
+            require!(entry_fee_lamports == 0, GameError::InvalidPayload);
+
+            let pairing = tournament
+                .next_unscheduled_pairing()
+                .ok_or(GameError::NotInTournamentBracket)?;
+            tournament.pairing_matches[pairing] = ctx.accounts.match_account.key();
+            match_account.tournament_id = tournament_id_bytes;
+            msg!(
+                "Tournament match for pairing {} of round {}",
+                pairing,
+                tournament.current_round + 1
+            );
+        }
+        None => {
+            match_account.tournament_id = [0u8; 16]; // All zeros = not a tournament match
+        }
     }
 
     // Phase 04: Initialize escrow account for paid matches
@@ -152,11 +285,11 @@ pub fn handler(
             escrow_account.bump = bump;
             escrow_account.total_entry_lamports = 0; // Will be incremented as players join
             escrow_account.platform_fee_lamports = 0; // Calculated when distributing prizes
-            escrow_account.treasury_due_lamports = 0;
             escrow_account.player_stakes = [0u64; crate::state::escrow::MAX_PLAYERS];
             escrow_account.status_flags = 0; // Not funded yet
             escrow_account.abandoned_player_index = 255; // None/not applicable
-            escrow_account.reserved = [0u8; 32];
+            escrow_account.platform_fee_bps = platform_fee_bps;
+            escrow_account.entry_mint = entry_mint;
             
             msg!("Escrow account initialized for paid match: {}", match_id);
         } else {
@@ -168,6 +301,9 @@ pub fn handler(
          match_id, 
          if match_type_val == crate::state::enums::match_type::PAID { "PAID" } else { "FREE" },
          entry_fee_lamports);
+    if entry_mint != Pubkey::default() {
+        msg!("Entry fee denominated in SPL mint {}", entry_mint);
+    }
     Ok(())
 }
 
@@ -200,15 +336,41 @@ pub struct CreateMatch<'info> {
     )]
     pub escrow_account: Option<AccountLoader<'info, EscrowAccount>>,
 
-    /// Config account (optional - only needed for paid matches to check is_paused)
+    /// Config account (optional - required for paid matches: pause, entry fee bounds, payment methods)
     #[account(
         seeds = [b"config_account"],
         bump
     )]
     pub config_account: Option<Account<'info, ConfigAccount>>,
 
+    /// SPL token mint for the entry fee (optional - omit for SOL matches)
+    pub entry_mint: Option<Box<Account<'info, Mint>>>,
+
+    /// Allow-list entry for entry_mint (required with entry_mint)
+    /// CHECK: Validated in handler - must be for entry_mint and enabled
+    pub supported_mint: Option<Box<Account<'info, SupportedMint>>>,
+
+    /// Escrow vault holding token stakes, owned by the escrow PDA (required with entry_mint)
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"escrow_vault", match_account.key().as_ref()],
+        bump,
+        token::mint = entry_mint,
+        token::authority = escrow_account,
+        token::token_program = token_program
+    )]
+    pub escrow_vault: Option<Box<Account<'info, TokenAccount>>>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// Tournament the match belongs to (required with tournament_id)
+    /// CHECK: Validated in handler - tournament_id must match
+    #[account(mut)]
+    pub tournament: Option<AccountLoader<'info, Tournament>>,
+
     pub system_program: Program<'info, System>,
 }
@@ -1,4 +1,5 @@
 use crate::error::GameError;
+use crate::games::record_final_scores;
 use crate::state::{EscrowAccount, Match};
 use anchor_lang::prelude::*;
 
@@ -83,74 +84,19 @@ pub fn handler(
     }
 
     // Per critique Issue #2: Score calculation - compute scores on-chain
-    // Note: Full replay with all Move accounts requires off-chain querying (not possible in instruction)
-    // On-chain we calculate based on available state: declared suits, move patterns, and game outcomes
-    // This provides verifiable on-chain scores, with full detailed scoring done off-chain
-
-    // Use calculate_scores_from_moves if we had access to Move accounts
-    // Since we can't query Move accounts in an instruction, we calculate from match state
-    let mut scores: [i32; 10] = [0; 10];
-
-    // Count declarations and activity per player
-    let mut declarations_count = 0u32;
-    let total_activity = match_account.move_count as u32;
-
-    // Calculate scores for each player based on game state
-    // Per CLAIM game rules: declared players get positive scores, undeclared get penalties
-    for i in 0..match_account.player_count as usize {
-        if match_account.has_declared_suit(i) {
-            declarations_count += 1;
-
-            // Declared players: positive scoring based on CLAIM game rules
-            // Base score: 20 points for declaring a suit
-            let base_score = 20i32;
-
-            // Bonus: Activity points (more moves = more engagement)
-            // Normalize by player count to avoid bias
-            let avg_moves_per_player = if match_account.player_count > 0 {
-                total_activity / (match_account.player_count as u32)
-            } else {
-                0
-            };
-            let activity_score = avg_moves_per_player as i32;
-
-            // Bonus: Early declaration bonus (simplified - first declarer gets bonus)
-            // In full implementation, would track declaration order from Move accounts
-            let declaration_bonus = if declarations_count == 1 { 5i32 } else { 0i32 };
-
-            scores[i] = base_score + activity_score + declaration_bonus;
-        } else {
-            // Undeclared players: penalty for not declaring
-            // Penalty increases with game length (more opportunities missed)
-            let penalty_per_round = 2i32;
-            let rounds = if match_account.player_count > 0 {
-                (total_activity / match_account.player_count as u32).max(1) as i32
-            } else {
-                1
-            };
-            scores[i] = -(penalty_per_round * rounds);
-        }
-    }
-
-    // Additional scoring based on game outcomes
-    // If match ended via showdown (phase 2), give bonus to declarer who called showdown
-    // Note: We can't determine who called showdown without querying Move accounts
-    // Full detailed scoring (sequences, bonuses, penalties) is done off-chain in MatchCoordinator
-
-    // Normalize scores to ensure they're reasonable (prevent overflow)
-    for score in &mut scores {
-        *score = (*score).clamp(-100, 200); // Reasonable bounds
-    }
-
-    // Per critique Issue #2: Store scores in match account for on-chain verification
-    // Note: Match struct doesn't currently have scores field - would need to add it
-    // For now, scores are calculated but not stored (off-chain MatchCoordinator stores in match record)
+    // Scoring is game-specific (GameRules::score_match), computed from match state
+    // Stored in Match.scores as the authoritative result for prizes, leaderboards and disputes
+    record_final_scores(&mut match_account);
 
     // Finalize match
     match_account.phase = 2; // Ended
     match_account.ended_at = clock.unix_timestamp;
 
-    msg!("Match ended: {} with scores: {:?}", match_id, scores);
+    msg!(
+        "Match ended: {} with scores: {:?}",
+        match_id,
+        match_account.scores
+    );
     Ok(())
 }
 
@@ -0,0 +1,279 @@
+use crate::common::token_escrow::{EscrowVault, TokenEscrow};
+use crate::error::GameError;
+use crate::state::enums::cancellation_reason;
+use crate::state::{
+    game_phase, EscrowAccount, GameRegistry, Match, Tournament, UserDepositAccount,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+/// Expires a match that never left the Dealing phase within its game's lobby TTL.
+/// Every stake is refunded in full (INSUFFICIENT_PLAYERS, no cancellation fee), then the match
+/// and escrow accounts are closed and their rent returned to the match creator.
+/// Permissionless - anyone may crank it once created_at + lobby_ttl_secs has passed.
+/// A tournament match also passes its tournament and can only expire once its pairing has
+/// been settled (report_tournament_no_show) or rescheduled (reschedule_tournament_pairing).
+///
+/// Paid matches pass one refund account per seat as remaining_accounts, in seat order:
+/// the seat's wallet for WALLET matches, its UserDepositAccount PDA for PLATFORM matches.
+/// SPL token matches pass each seat's token payout accounts instead (a token account owned by
+/// the seat's wallet, or its token ledger followed by its deposit vault); the escrow vault is
+/// closed along with the escrow.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExpireMatch<'info>>,
+    match_id: String,
+) -> Result<()> {
+    let match_account = ctx.accounts.match_account.load()?;
+    let registry = ctx.accounts.registry.load()?;
+    let clock = Clock::get()?;
+
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36
+            && match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::InvalidPayload
+    );
+
+    // Only lobbies that never started can expire
+    require!(
+        match_account.phase == game_phase::DEALING,
+        GameError::InvalidPhase
+    );
+
+    // Tournament matches hold a pairing - expiring one still pending would strand the bracket
+    if let Some(tournament_id) = match_account.get_tournament_id() {
+        let tournament_loader = ctx
+            .accounts
+            .tournament
+            .as_ref()
+            .ok_or(GameError::NotInTournamentBracket)?;
+        let tournament = tournament_loader.load()?;
+        require!(
+            tournament.tournament_id == tournament_id,
+            GameError::NotInTournamentBracket
+        );
+        require!(
+            tournament
+                .find_pending_pairing_by_match(&ctx.accounts.match_account.key())
+                .is_none(),
+            GameError::TournamentPairingPending
+        );
+    }
+
+    // Lobby TTL comes from the game's registry entry (0 = never expires)
+    let game = registry
+        .find_game(match_account.game_type)
+        .ok_or(GameError::InvalidPayload)?;
+    let lobby_ttl_secs = game.get_lobby_ttl_secs();
+    require!(lobby_ttl_secs > 0, GameError::LobbyExpiryDisabled);
+    let expires_at = match_account
+        .created_at
+        .checked_add(lobby_ttl_secs as i64)
+        .ok_or(GameError::Overflow)?;
+    require!(
+        clock.unix_timestamp >= expires_at,
+        GameError::LobbyNotExpired
+    );
+
+    // Security: Rent goes back to the creator that paid for the match (and escrow)
+    require!(
+        ctx.accounts.rent_recipient.key() == match_account.authority,
+        GameError::Unauthorized
+    );
+
+    let mut total_refunded = 0u64;
+    if match_account.is_paid_match() {
+        let escrow_loader = ctx
+            .accounts
+            .escrow_account
+            .as_ref()
+            .ok_or(GameError::InvalidPayload)?;
+        let (entry_mint, escrow_bump) = {
+            let escrow_account = escrow_loader.load()?;
+            require!(
+                escrow_account.match_pda == ctx.accounts.match_account.key(),
+                GameError::InvalidPayload
+            );
+            require!(
+                !escrow_account.is_distributed(),
+                GameError::EscrowAlreadyDistributed
+            );
+            (escrow_account.entry_mint, escrow_account.bump)
+        };
+
+        let player_count = match_account.player_count as usize;
+        let method = match_account.get_payment_method();
+        let accounts_per_seat = if entry_mint != Pubkey::default() {
+            TokenEscrow::accounts_per_seat(method)
+        } else {
+            1
+        };
+        require!(
+            ctx.remaining_accounts.len() >= player_count * accounts_per_seat,
+            GameError::InvalidPayload
+        );
+
+        // SPL token match: refunds come out of the escrow vault, which is closed afterwards
+        let token_accounts = if entry_mint != Pubkey::default() {
+            let escrow_vault = ctx
+                .accounts
+                .escrow_vault
+                .as_ref()
+                .ok_or(GameError::InvalidPayload)?;
+            let token_program = ctx
+                .accounts
+                .token_program
+                .as_ref()
+                .ok_or(GameError::InvalidPayload)?;
+            Some((escrow_vault.to_account_info(), token_program.to_account_info()))
+        } else {
+            None
+        };
+        let escrow_account_info = escrow_loader.to_account_info();
+        let vault = token_accounts.as_ref().map(|(vault, token_program)| EscrowVault {
+            token_program,
+            vault,
+            escrow: &escrow_account_info,
+            match_pda: ctx.accounts.match_account.key(),
+            escrow_bump,
+            mint: entry_mint,
+        });
+        if let Some(vault) = &vault {
+            vault.validate(ctx.program_id)?;
+        }
+
+        // Manual lamport transfers - escrow_account carries data
+        for player_index in 0..player_count {
+            let stake = escrow_loader.load()?.get_player_stake(player_index);
+            if stake == 0 {
+                continue;
+            }
+            let wallet = match_account.player_wallets[player_index];
+            let refund_info = &ctx.remaining_accounts[player_index];
+
+            if let Some(vault) = &vault {
+                // Token refund to the seat's token account or deposit vault
+                let first = player_index * accounts_per_seat;
+                vault.settle_seat(
+                    method,
+                    &wallet,
+                    &ctx.remaining_accounts[first..first + accounts_per_seat],
+                    (stake, stake),
+                    ctx.program_id,
+                )?;
+            } else if match_account.is_wallet_payment() {
+                // WALLET: refund to the wallet that paid in join_match
+                require!(refund_info.key() == wallet, GameError::InvalidPayload);
+                **escrow_account_info.try_borrow_mut_lamports()? -= stake;
+                **refund_info.try_borrow_mut_lamports()? += stake;
+            } else if match_account.is_platform_payment() {
+                // PLATFORM: refund to the seat's deposit account and unlock it from in_play
+                let (expected_deposit, _) = Pubkey::find_program_address(
+                    &[b"user_deposit", wallet.as_ref()],
+                    ctx.program_id,
+                );
+                require!(
+                    refund_info.key() == expected_deposit,
+                    GameError::InvalidPayload
+                );
+                let deposit_loader = AccountLoader::<UserDepositAccount>::try_from(refund_info)?;
+                **escrow_account_info.try_borrow_mut_lamports()? -= stake;
+                **refund_info.try_borrow_mut_lamports()? += stake;
+
+                let mut deposit_account = deposit_loader.load_mut()?;
+                deposit_account.in_play_lamports = deposit_account
+                    .in_play_lamports
+                    .checked_sub(stake)
+                    .ok_or(GameError::Overflow)?;
+                deposit_account.available_lamports = deposit_account
+                    .available_lamports
+                    .checked_add(stake)
+                    .ok_or(GameError::Overflow)?;
+            } else {
+                return Err(GameError::InvalidPaymentMethod.into());
+            }
+
+            total_refunded = total_refunded
+                .checked_add(stake)
+                .ok_or(GameError::Overflow)?;
+            msg!(
+                "Refunded {} lamports to player {} (seat {})",
+                stake,
+                wallet,
+                player_index
+            );
+        }
+
+        // Record the outcome until the account is closed at the end of the instruction
+        let mut escrow_account = escrow_loader.load_mut()?;
+        escrow_account.player_stakes = [0u64; crate::state::escrow::MAX_PLAYERS];
+        escrow_account.total_entry_lamports = 0;
+        escrow_account.set_cancelled(true);
+        escrow_account.set_cancellation_reason(cancellation_reason::INSUFFICIENT_PLAYERS);
+        drop(escrow_account);
+
+        // Emptied escrow vault goes with the escrow - its rent also returns to the creator
+        if let Some(vault) = &vault {
+            vault.close(&ctx.accounts.rent_recipient.to_account_info())?;
+        }
+    }
+
+    msg!(
+        "Match expired: {} ({} players, {} lamports refunded, reason {})",
+        match_id,
+        match_account.player_count,
+        total_refunded,
+        cancellation_reason::INSUFFICIENT_PLAYERS
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct ExpireMatch<'info> {
+    #[account(
+        mut,
+        seeds = [b"m", &match_id.as_bytes()[..31.min(match_id.len())]],
+        bump,
+        close = rent_recipient
+    )]
+    pub match_account: AccountLoader<'info, Match>,
+
+    #[account(
+        seeds = [b"game_registry"],
+        bump
+    )]
+    pub registry: AccountLoader<'info, GameRegistry>,
+
+    /// Escrow account (only required for paid matches)
+    #[account(
+        mut,
+        seeds = [b"escrow", match_account.key().as_ref()],
+        bump,
+        close = rent_recipient
+    )]
+    pub escrow_account: Option<AccountLoader<'info, EscrowAccount>>,
+
+    /// Escrow vault (only required for SPL token matches) - closed with the escrow
+    #[account(
+        mut,
+        seeds = [b"escrow_vault", match_account.key().as_ref()],
+        bump
+    )]
+    pub escrow_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Tournament the match belongs to (only required for tournament matches)
+    /// CHECK: Validated in handler - tournament_id must match
+    pub tournament: Option<AccountLoader<'info, Tournament>>,
+
+    /// Match creator - receives the rent of the closed accounts
+    /// CHECK: Validated in handler - must equal match_account.authority
+    #[account(mut)]
+    pub rent_recipient: UncheckedAccount<'info>,
+
+    /// Anyone may crank an expired lobby
+    pub caller: Signer<'info>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
@@ -1,6 +1,10 @@
+use crate::common::token_escrow::TokenEscrow;
 use crate::error::GameError;
-use crate::state::{ConfigAccount, EscrowAccount, GameRegistry, Match, UserDepositAccount};
+use crate::state::{
+    ConfigAccount, EscrowAccount, GameRegistry, Match, Tournament, UserDepositAccount,
+};
 use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
 pub fn handler(ctx: Context<JoinMatch>, match_id: String, user_id: String) -> Result<()> {
     let mut match_account = ctx.accounts.match_account.load_mut()?;
@@ -35,6 +39,36 @@ pub fn handler(ctx: Context<JoinMatch>, match_id: String, user_id: String) -> Re
         GameError::PlayerNotInMatch
     );
 
+    // Security: One seat per wallet - the joining signer owns the seat it takes
+    require!(
+        !match_account.has_player_wallet(&ctx.accounts.player.key()),
+        GameError::WalletAlreadySeated
+    );
+
+    // Tournament matches only seat the two entrants of their bracket pairing
+    if let Some(tournament_id) = match_account.get_tournament_id() {
+        let tournament = ctx
+            .accounts
+            .tournament
+            .as_ref()
+            .ok_or(GameError::NotInTournamentBracket)?
+            .load()?;
+        require!(
+            tournament.tournament_id == tournament_id,
+            GameError::NotInTournamentBracket
+        );
+        let pairing = tournament
+            .find_pairing_by_match(&ctx.accounts.match_account.key())
+            .ok_or(GameError::NotInTournamentBracket)?;
+        let entrant = tournament
+            .find_entrant(&ctx.accounts.player.key())
+            .ok_or(GameError::NotInTournamentBracket)?;
+        require!(
+            tournament.pairings[pairing].contains(&(entrant as u8)),
+            GameError::NotInTournamentBracket
+        );
+    }
+
     // Security: Validate bounds before adding player
     let player_index = match_account.player_count as usize;
     let max_players = match_account.get_max_players(&registry)? as usize;
@@ -66,21 +100,98 @@ pub fn handler(ctx: Context<JoinMatch>, match_id: String, user_id: String) -> Re
             .ok_or(GameError::InvalidPayload)?;
         
         // Scope escrow validation to release borrow before payment transfers
-        {
+        let entry_mint = {
             let escrow_account = escrow_loader.load()?;
             // Validate escrow belongs to this match
             require!(
                 escrow_account.match_pda == ctx.accounts.match_account.key(),
                 GameError::InvalidPayload
             );
-        } // Borrow released here
+            escrow_account.entry_mint
+        }; // Borrow released here
 
         // Phase 04: Payment method validation
         // All players in a match MUST use the same payment method
         let match_payment_method = match_account.get_payment_method();
         
         // Handle payment based on match's payment method
-        if match_payment_method == crate::state::enums::payment_method::WALLET {
+        if entry_mint != Pubkey::default() {
+            // SPL token match: entry fee moves into the escrow vault owned by the escrow PDA
+            let escrow_vault = ctx.accounts.escrow_vault.as_ref()
+                .ok_or(GameError::InvalidPayload)?;
+            require!(escrow_vault.mint == entry_mint, GameError::InvalidTokenAccount);
+            let token_program = ctx.accounts.token_program.as_ref()
+                .ok_or(GameError::InvalidPayload)?;
+            let source = ctx.accounts.player_token_account.as_ref()
+                .ok_or(GameError::InvalidPayload)?;
+
+            if match_payment_method == crate::state::enums::payment_method::WALLET {
+                // Wallet payment: player's own token account, signed by the player
+                TokenEscrow::validate_token_account(
+                    &source.to_account_info(),
+                    &entry_mint,
+                    &ctx.accounts.player.key(),
+                )?;
+                token::transfer(
+                    CpiContext::new(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: source.to_account_info(),
+                            to: escrow_vault.to_account_info(),
+                            authority: ctx.accounts.player.to_account_info(),
+                        },
+                    ),
+                    entry_fee,
+                )?;
+
+                msg!("Player {} paid {} tokens from wallet to escrow vault", user_id, entry_fee);
+            } else if match_payment_method == crate::state::enums::payment_method::PLATFORM {
+                // Platform payment: the player's token ledger and its deposit vault (the source)
+                let ledger_loader = ctx.accounts.token_deposit_account.as_ref()
+                    .ok_or(GameError::InvalidPayload)?;
+                require!(
+                    ledger_loader.key()
+                        == TokenEscrow::deposit_ledger_address(
+                            &ctx.accounts.player.key(),
+                            &entry_mint,
+                            ctx.program_id,
+                        ),
+                    GameError::InvalidPayload
+                );
+                require!(
+                    source.key()
+                        == TokenEscrow::deposit_vault_address(&ledger_loader.key(), ctx.program_id),
+                    GameError::InvalidTokenAccount
+                );
+
+                // Entry fee comes out of available and is locked in in_play
+                let ledger_bump = {
+                    let mut ledger = ledger_loader.load_mut()?;
+                    require!(!ledger.is_frozen(), GameError::AccountFrozen);
+                    ledger.available_lamports = ledger
+                        .available_lamports
+                        .checked_sub(entry_fee)
+                        .ok_or(GameError::InsufficientFunds)?;
+                    ledger.in_play_lamports = ledger
+                        .in_play_lamports
+                        .checked_add(entry_fee)
+                        .ok_or(GameError::Overflow)?;
+                    ledger.bump
+                };
+                TokenEscrow::transfer_from_deposit(
+                    &token_program.to_account_info(),
+                    &source.to_account_info(),
+                    &escrow_vault.to_account_info(),
+                    &ledger_loader.to_account_info(),
+                    (&ctx.accounts.player.key(), &entry_mint, ledger_bump),
+                    entry_fee,
+                )?;
+
+                msg!("Player {} paid {} tokens from platform deposit to escrow vault", user_id, entry_fee);
+            } else {
+                return Err(GameError::InvalidPaymentMethod.into());
+            }
+        } else if match_payment_method == crate::state::enums::payment_method::WALLET {
             // Wallet payment: CPI transfer from player wallet → escrow PDA
             // For wallet payment, player signer IS the wallet
             let player_wallet = ctx.accounts.player_wallet.as_ref()
@@ -181,7 +292,9 @@ pub fn handler(ctx: Context<JoinMatch>, match_id: String, user_id: String) -> Re
     }
 
     // Add player to match (only after payment succeeds)
+    // The signer is bound to the seat; all per-player instructions must be signed by it
     match_account.set_player_id(player_index, user_id_array);
+    match_account.set_player_wallet(player_index, ctx.accounts.player.key());
     let new_player_count = match_account.player_count + 1;
     match_account.player_count = new_player_count;
 
@@ -292,7 +405,32 @@ pub struct JoinMatch<'info> {
     )]
     pub config_account: Option<Account<'info, ConfigAccount>>,
 
+    /// Escrow vault (only required for SPL token matches)
+    #[account(
+        mut,
+        seeds = [b"escrow_vault", match_account.key().as_ref()],
+        bump
+    )]
+    pub escrow_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Token source (only required for SPL token matches): the player's token account for
+    /// WALLET matches, the deposit vault of token_deposit_account for PLATFORM matches
+    /// CHECK: Validated in handler against the escrow mint and payment method
+    #[account(mut)]
+    pub player_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Player's token deposit ledger (only required for SPL token PLATFORM matches)
+    /// CHECK: Validated in handler - seeds [b"user_deposit", player, entry_mint]
+    #[account(mut)]
+    pub token_deposit_account: Option<AccountLoader<'info, UserDepositAccount>>,
+
     pub player: Signer<'info>,
 
+    pub token_program: Option<Program<'info, Token>>,
+
+    /// Tournament the match belongs to (required for tournament matches)
+    /// CHECK: Validated in handler - must be the match's tournament
+    pub tournament: Option<AccountLoader<'info, Tournament>>,
+
     pub system_program: Program<'info, System>,
 }
@@ -0,0 +1,262 @@
+use crate::common::token_escrow::EscrowVault;
+use crate::error::GameError;
+use crate::state::{
+    game_phase, ConfigAccount, EscrowAccount, GameRegistry, Match, UserDepositAccount,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+/// Leave a match before it starts (Dealing phase only).
+/// The signer's seat is removed and later seats shift down one index.
+/// For paid matches the player's stake is refunded in full from escrow - to the wallet for
+/// WALLET matches, to the UserDepositAccount (unlocked from in_play) for PLATFORM matches -
+/// and the escrow funded flag is recomputed for the remaining players.
+/// SPL token matches refund from the escrow vault to the player's token account (WALLET) or
+/// to the deposit vault of their token ledger (PLATFORM).
+pub fn handler(ctx: Context<LeaveMatch>, match_id: String) -> Result<()> {
+    let mut match_account = ctx.accounts.match_account.load_mut()?;
+    let registry = ctx.accounts.registry.load()?;
+
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36
+            && match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::InvalidPayload
+    );
+
+    // Security: Seats can only be vacated before start_match
+    require!(
+        match_account.phase == game_phase::DEALING,
+        GameError::InvalidPhase
+    );
+
+    // Security: The signer must own a seat
+    let player_key = ctx.accounts.player.key();
+    let player_index = match_account
+        .find_player_index_by_wallet(&player_key)
+        .ok_or(GameError::PlayerNotInMatch)?;
+
+    if match_account.is_paid_match() {
+        require!(
+            !ctx.accounts.config_account.is_paused,
+            GameError::ProgramPaused
+        );
+
+        let escrow_loader = ctx
+            .accounts
+            .escrow_account
+            .as_ref()
+            .ok_or(GameError::InvalidPayload)?;
+
+        // Remove the stake (compacting later seats) before moving lamports
+        let (stake, entry_mint, escrow_bump) = {
+            let mut escrow_account = escrow_loader.load_mut()?;
+            require!(
+                escrow_account.match_pda == ctx.accounts.match_account.key(),
+                GameError::InvalidPayload
+            );
+            require!(
+                !escrow_account.is_distributed(),
+                GameError::EscrowAlreadyDistributed
+            );
+            require!(!escrow_account.is_cancelled(), GameError::InvalidPhase);
+
+            let stake = escrow_account.remove_player_stake(player_index);
+            escrow_account.total_entry_lamports = escrow_account
+                .total_entry_lamports
+                .checked_sub(stake)
+                .ok_or(GameError::Overflow)?;
+            (stake, escrow_account.entry_mint, escrow_account.bump)
+        }; // Borrow released here
+
+        if stake > 0 && entry_mint != Pubkey::default() {
+            // SPL token match: refund out of the escrow vault
+            let escrow_vault = ctx
+                .accounts
+                .escrow_vault
+                .as_ref()
+                .ok_or(GameError::InvalidPayload)?;
+            let token_program = ctx
+                .accounts
+                .token_program
+                .as_ref()
+                .ok_or(GameError::InvalidPayload)?;
+            let vault = EscrowVault {
+                token_program: &token_program.to_account_info(),
+                vault: &escrow_vault.to_account_info(),
+                escrow: &escrow_loader.to_account_info(),
+                match_pda: ctx.accounts.match_account.key(),
+                escrow_bump,
+                mint: entry_mint,
+            };
+            vault.validate(ctx.program_id)?;
+            let refund_account = ctx
+                .accounts
+                .player_token_account
+                .as_ref()
+                .ok_or(GameError::InvalidPayload)?
+                .to_account_info();
+            if match_account.is_platform_payment() {
+                let ledger = ctx
+                    .accounts
+                    .token_deposit_account
+                    .as_ref()
+                    .ok_or(GameError::InvalidPaymentMethod)?;
+                vault.pay_deposit(
+                    ledger,
+                    &refund_account,
+                    &player_key,
+                    (stake, stake),
+                    ctx.program_id,
+                )?;
+            } else {
+                vault.pay_wallet(&refund_account, &player_key, stake)?;
+            }
+
+            msg!(
+                "Refunded {} tokens to player {} leaving match {}",
+                stake,
+                player_key,
+                match_id
+            );
+        } else if stake > 0 {
+            // Manual lamport transfer - escrow_account carries data
+            let escrow_account_info = escrow_loader.to_account_info();
+            if match_account.is_wallet_payment() {
+                // WALLET: the seat's wallet paid the entry fee in join_match
+                let player_account_info = ctx.accounts.player.to_account_info();
+                **escrow_account_info.try_borrow_mut_lamports()? -= stake;
+                **player_account_info.try_borrow_mut_lamports()? += stake;
+            } else if match_account.is_platform_payment() {
+                let deposit_loader = ctx
+                    .accounts
+                    .user_deposit_account
+                    .as_ref()
+                    .ok_or(GameError::InvalidPaymentMethod)?;
+                **escrow_account_info.try_borrow_mut_lamports()? -= stake;
+                **deposit_loader.to_account_info().try_borrow_mut_lamports()? += stake;
+
+                // Unlock the stake that join_match moved into in_play
+                let mut deposit_account = deposit_loader.load_mut()?;
+                require!(
+                    deposit_account.authority == player_key,
+                    GameError::Unauthorized
+                );
+                deposit_account.in_play_lamports = deposit_account
+                    .in_play_lamports
+                    .checked_sub(stake)
+                    .ok_or(GameError::Overflow)?;
+                deposit_account.available_lamports = deposit_account
+                    .available_lamports
+                    .checked_add(stake)
+                    .ok_or(GameError::Overflow)?;
+            } else {
+                return Err(GameError::InvalidPaymentMethod.into());
+            }
+
+            msg!(
+                "Refunded {} lamports to player {} leaving match {}",
+                stake,
+                player_key,
+                match_id
+            );
+        }
+    }
+
+    match_account.remove_seat(player_index);
+
+    // Recompute escrow funding for the remaining players (same rule as join_match)
+    if match_account.is_paid_match() {
+        let escrow_loader = ctx
+            .accounts
+            .escrow_account
+            .as_ref()
+            .ok_or(GameError::InvalidPayload)?;
+        let mut escrow_account = escrow_loader.load_mut()?;
+        let min_players = match_account.get_min_players(&registry)?;
+        let expected_total = match_account
+            .entry_fee_lamports
+            .checked_mul(match_account.player_count as u64)
+            .ok_or(GameError::Overflow)?;
+        let funded = match_account.player_count >= min_players
+            && escrow_account.total_entry_lamports == expected_total;
+        escrow_account.set_funded(funded);
+    }
+
+    msg!(
+        "Player {} left match {} (seat {}, {} players remain)",
+        player_key,
+        match_id,
+        player_index,
+        match_account.player_count
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String)]
+pub struct LeaveMatch<'info> {
+    #[account(
+        mut,
+        seeds = [b"m", &match_id.as_bytes()[..31.min(match_id.len())]],
+        bump
+    )]
+    pub match_account: AccountLoader<'info, Match>,
+
+    #[account(
+        seeds = [b"game_registry"],
+        bump
+    )]
+    pub registry: AccountLoader<'info, GameRegistry>,
+
+    /// Escrow account (only required for paid matches)
+    /// CHECK: Validated in handler - only required if match is paid
+    #[account(
+        mut,
+        seeds = [b"escrow", match_account.key().as_ref()],
+        bump
+    )]
+    pub escrow_account: Option<AccountLoader<'info, EscrowAccount>>,
+
+    /// User deposit account (only required for platform payment method)
+    /// CHECK: Validated in handler - only required if payment_method == PLATFORM
+    #[account(
+        mut,
+        seeds = [b"user_deposit", player.key().as_ref()],
+        bump
+    )]
+    pub user_deposit_account: Option<AccountLoader<'info, UserDepositAccount>>,
+
+    /// Config account (checked for is_paused on paid matches)
+    #[account(
+        seeds = [b"config_account"],
+        bump
+    )]
+    pub config_account: Account<'info, ConfigAccount>,
+
+    /// Escrow vault (only required for SPL token matches)
+    #[account(
+        mut,
+        seeds = [b"escrow_vault", match_account.key().as_ref()],
+        bump
+    )]
+    pub escrow_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Token refund destination (only required for SPL token matches): the player's token
+    /// account for WALLET matches, the deposit vault of token_deposit_account for PLATFORM
+    /// CHECK: Validated in handler against the escrow mint and payment method
+    #[account(mut)]
+    pub player_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Player's token deposit ledger (only required for SPL token PLATFORM matches)
+    /// CHECK: Validated in handler - seeds [b"user_deposit", player, entry_mint]
+    #[account(mut)]
+    pub token_deposit_account: Option<AccountLoader<'info, UserDepositAccount>>,
+
+    /// Seat owner - receives the refund for wallet-paid matches
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
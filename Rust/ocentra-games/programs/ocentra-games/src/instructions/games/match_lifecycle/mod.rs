@@ -1,8 +1,12 @@
 pub mod anchor_match_record;
+pub mod cancel_match;
+pub mod commit_deck;
 pub mod commit_hand;
 pub mod create_match;
 pub mod end_match;
+pub mod expire_match;
 pub mod join_match;
+pub mod leave_match;
 pub mod start_match;
 
 // Re-export for Anchor's #[program] macro
@@ -10,12 +14,20 @@ pub mod start_match;
 #[allow(ambiguous_glob_reexports)]
 pub use anchor_match_record::*;
 #[allow(ambiguous_glob_reexports)]
+pub use cancel_match::*;
+#[allow(ambiguous_glob_reexports)]
+pub use commit_deck::*;
+#[allow(ambiguous_glob_reexports)]
 pub use commit_hand::*;
 #[allow(ambiguous_glob_reexports)]
 pub use create_match::*;
 #[allow(ambiguous_glob_reexports)]
 pub use end_match::*;
 #[allow(ambiguous_glob_reexports)]
+pub use expire_match::*;
+#[allow(ambiguous_glob_reexports)]
 pub use join_match::*;
 #[allow(ambiguous_glob_reexports)]
+pub use leave_match::*;
+#[allow(ambiguous_glob_reexports)]
 pub use start_match::*;
@@ -1,4 +1,5 @@
 use crate::error::GameError;
+use crate::games::start_game;
 use crate::state::{EscrowAccount, GameRegistry, Match};
 use anchor_lang::prelude::*;
 
@@ -97,19 +98,18 @@ pub fn handler(ctx: Context<StartMatch>, match_id: String) -> Result<()> {
     match_account.phase = 1; // Playing
     match_account.set_all_players_joined(true);
 
-    // Per critique: initialize committed hand hashes
-    // In production, players would commit their hand hashes here
-    // For now, initialize to all zeros (will be set when hands are dealt)
-    match_account.committed_hand_hashes = [0u8; 320];
-
-    // Per critique Issue #1: Initialize hand sizes (will be set when hands are dealt)
-    // For CLAIM game, each player starts with 13 cards after dealing
-    // But we initialize to 0 here - will be set by commit_hand instruction
-    match_account.hand_sizes = [0u8; 10];
+    // Committed hand hashes and hand sizes are kept: players commit them via commit_hand
+    // during the Dealing phase (zeroed at create_match), and games verify reveals against them
 
     // Per critique Issue #1: Initialize floor card hash (no floor card yet)
     match_account.floor_card_hash = [0u8; 32];
 
+    // Game-specific setup (e.g. Poker: stacks, button and blinds)
+    start_game(&mut match_account)?;
+
+    // First turn starts now (turn timer)
+    match_account.turn_started_at = Clock::get()?.unix_timestamp;
+
     msg!(
         "Match started: {} with {} players",
         match_id,
@@ -0,0 +1,134 @@
+use crate::common::validation_base::CommonValidation;
+use crate::error::GameError;
+use crate::games::{record_final_scores, resolve_turn_timeout, stalled_player};
+use crate::state::{game_phase, GameRegistry, Match, Move};
+use anchor_lang::prelude::*;
+
+/// Resolves a stalled turn once the game's turn deadline has passed.
+/// Permissionless: anyone may call it (the caller pays for the timeout Move account).
+/// The stalled seat comes from the game's rules (games without turns have no timer).
+/// The game's timeout policy auto-declines, skips or forfeits that seat; the
+/// resolution is recorded as a Move (timeout flag) so finalize_scores replays it, and a
+/// forfeit is recorded on the match for TIMEOUT escrow refunds.
+/// move_index must be the match's next move index: timeout Moves are keyed by it rather than
+/// by a nonce, so a caller can never advance the stalled seat's submit_move nonce.
+pub fn handler(ctx: Context<ClaimTurnTimeout>, match_id: String, move_index: u16) -> Result<()> {
+    let mut match_account = ctx.accounts.match_account.load_mut()?;
+    let registry = ctx.accounts.registry.load()?;
+    let move_account = &mut ctx.accounts.move_account;
+    let clock = Clock::get()?;
+
+    // Security: Validate match_id matches
+    let match_id_bytes = match_id.as_bytes();
+    require!(
+        match_id_bytes.len() == 36
+            && match_id_bytes == &match_account.match_id[..match_id_bytes.len().min(36)],
+        GameError::InvalidPayload
+    );
+
+    CommonValidation::validate_not_ended(&match_account)?;
+    CommonValidation::validate_phase(&match_account, game_phase::PLAYING)?;
+
+    // Turn timer comes from the game definition (0 = disabled)
+    let game = registry
+        .find_game(match_account.game_type)
+        .ok_or(GameError::InvalidPayload)?;
+    let turn_timeout_secs = game.get_turn_timeout_secs();
+    let policy = game.timeout_policy;
+    require!(turn_timeout_secs > 0, GameError::TurnTimerDisabled);
+
+    let deadline = match_account
+        .turn_started_at
+        .checked_add(turn_timeout_secs as i64)
+        .ok_or(GameError::Overflow)?;
+    require!(clock.unix_timestamp >= deadline, GameError::TurnNotExpired);
+
+    // Security: Move PDA is derived from the stalled seat's wallet (same scheme as submit_move)
+    let player_index = stalled_player(&match_account)?;
+    let stalled_wallet = match_account
+        .get_player_wallet(player_index)
+        .ok_or(GameError::PlayerNotInMatch)?;
+    require!(
+        ctx.accounts.stalled_player.key() == stalled_wallet,
+        GameError::InvalidPayload
+    );
+
+    // Timeout Moves sit in their own PDA space keyed by move index (seat nonces untouched)
+    require!(
+        move_index == match_account.move_count,
+        GameError::MoveOutOfOrder
+    );
+
+    // Record the resolution as a Move (payload[0] = policy applied)
+    move_account.match_id = match_account.match_id;
+    move_account.player = stalled_wallet;
+    move_account.move_index = move_index;
+    move_account.action_type = 0;
+    move_account.set_payload(&[policy])?;
+    move_account.timestamp = clock.unix_timestamp as u32; // Convert i64 to u32
+    move_account.nonce = 0; // Timeout Moves take no seat nonce
+    move_account.set_timeout();
+
+    resolve_turn_timeout(&mut match_account, policy)?;
+
+    // Update ended_at timestamp if the forfeit ended the match
+    if match_account.phase == game_phase::ENDED && match_account.ended_at == 0 {
+        match_account.ended_at = clock.unix_timestamp;
+    }
+
+    match_account.move_count = match_account.move_count.saturating_add(1);
+
+    if match_account.phase == game_phase::ENDED {
+        record_final_scores(&mut match_account);
+    }
+
+    // Next turn starts now
+    match_account.turn_started_at = clock.unix_timestamp;
+
+    msg!(
+        "Turn timeout: player {}, policy {}, match {}, next player {}",
+        player_index,
+        policy,
+        match_id,
+        match_account.current_player
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(match_id: String, move_index: u16)]
+pub struct ClaimTurnTimeout<'info> {
+    #[account(
+        mut,
+        seeds = [b"m", &match_id.as_bytes()[..31.min(match_id.len())]],
+        bump
+    )]
+    pub match_account: AccountLoader<'info, Match>,
+
+    #[account(
+        seeds = [b"game_registry"],
+        bump
+    )]
+    pub registry: AccountLoader<'info, GameRegistry>,
+
+    /// CHECK: Wallet bound to the seat in turn - validated in handler against player_wallets
+    pub stalled_player: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = caller,
+        space = Move::MAX_SIZE,
+        seeds = [
+            b"timeout_move",
+            &match_id.as_bytes()[..32.min(match_id.len())],
+            &match_id.as_bytes()[32.min(match_id.len())..],
+            &move_index.to_le_bytes()
+        ],
+        bump
+    )]
+    pub move_account: Account<'info, Move>,
+
+    #[account(mut)]
+    pub caller: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
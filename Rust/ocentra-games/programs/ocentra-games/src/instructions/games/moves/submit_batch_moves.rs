@@ -1,6 +1,9 @@
 use crate::common::{replay_protection::ReplayProtection, validation_base::CommonValidation};
 use crate::error::GameError;
-use crate::games::{apply_action_state, validate_move as validate_game_move};
+use crate::games::{
+    advance_turn_after_batch, apply_action_state, record_final_scores,
+    validate_move as validate_game_move,
+};
 use crate::state::{GameRegistry, Match, Move};
 use anchor_lang::prelude::*;
 
@@ -69,7 +72,14 @@ pub fn handler(
     let player_index = match_account
         .find_player_index(&user_id_array)
         .ok_or(GameError::PlayerNotInMatch)?;
-    CommonValidation::validate_player_in_match(&*match_account, player_index)?;
+    CommonValidation::validate_player_in_match(&match_account, player_index)?;
+
+    // Security: Signer must own the seat (user_id alone is not proof of identity)
+    CommonValidation::validate_seat_signer(
+        &match_account,
+        player_index,
+        &ctx.accounts.player.key(),
+    )?;
 
     // CRITICAL: Deadlock prevention - ALL moves must be from the same player AND only in their turn
     // Validate it's the player's turn BEFORE processing any moves
@@ -119,6 +129,8 @@ pub fn handler(
         move_account.action_type = batch_move.action_type;
         move_account.set_payload(&batch_move.payload)?;
         move_account.timestamp = clock.unix_timestamp as u32; // Convert i64 to u32
+        move_account.nonce = batch_move.nonce;
+        move_account.set_batch_position(true, batch_idx + 1 == moves.len());
 
         // Apply game-specific action state updates
         // CRITICAL: All state updates happen for the same player (player_index) - no turn advancement during batch
@@ -143,19 +155,22 @@ pub fn handler(
     // Update match state after all moves processed
     match_account.move_count = current_move_index;
 
+    // Game-ending move: persist final scores (after move_count so scoring sees the batch)
+    if match_account.phase == 2 {
+        record_final_scores(&mut match_account);
+    }
+
     // CRITICAL: Turn advancement happens ONCE after all moves are processed
     // Only advance turn if the last move was a turn-based move (pick_up or decline)
     // This prevents deadlocks and ensures all moves in batch are from the same turn
+    // Which actions pass the turn is game-specific (e.g. CLAIM: pick_up or decline)
     if let Some(last_move) = moves.last() {
-        if last_move.action_type == 0 || last_move.action_type == 1 {
-            // Pick up or decline: advance to next player
-            // Use player_count (actual players in match) not max_players (max allowed)
-            let next_player = ((player_index + 1) % match_account.player_count as usize) as u8;
-            match_account.current_player = next_player;
-        }
-        // For other moves (declare intent, rebuttal, call showdown), turn doesn't advance
+        advance_turn_after_batch(&mut match_account, player_index, last_move.action_type);
     }
 
+    // Turn timer restarts - batches are only submitted by the player in turn
+    match_account.turn_started_at = clock.unix_timestamp;
+
     msg!(
         "Batch moves submitted: match_id={}, count={}",
         match_id,
@@ -164,6 +179,16 @@ pub fn handler(
     Ok(())
 }
 
+/// Move PDA nonce seed for a batch slot: the move's own nonce (same address scheme as
+/// submit_move, so moves can be re-derived for score replay). Unused slots keep the
+/// legacy slot-index seed.
+pub fn batch_move_seed(moves: &[BatchMove], slot: usize) -> Vec<u8> {
+    match moves.get(slot) {
+        Some(batch_move) => batch_move.nonce.to_le_bytes().to_vec(),
+        None => (slot as u32).to_le_bytes().to_vec(),
+    }
+}
+
 #[derive(Accounts)]
 #[instruction(match_id: String, user_id: String, moves: Vec<BatchMove>)]
 pub struct SubmitBatchMoves<'info> {
@@ -191,7 +216,7 @@ pub struct SubmitBatchMoves<'info> {
             &match_id.as_bytes()[..32.min(match_id.len())],
             &match_id.as_bytes()[32.min(match_id.len())..],
             player.key().as_ref(),
-            &0u32.to_le_bytes()
+            &batch_move_seed(&moves, 0)
         ],
         bump
     )]
@@ -206,7 +231,7 @@ pub struct SubmitBatchMoves<'info> {
             &match_id.as_bytes()[..32.min(match_id.len())],
             &match_id.as_bytes()[32.min(match_id.len())..],
             player.key().as_ref(),
-            &1u32.to_le_bytes()
+            &batch_move_seed(&moves, 1)
         ],
         bump
     )]
@@ -221,7 +246,7 @@ pub struct SubmitBatchMoves<'info> {
             &match_id.as_bytes()[..32.min(match_id.len())],
             &match_id.as_bytes()[32.min(match_id.len())..],
             player.key().as_ref(),
-            &2u32.to_le_bytes()
+            &batch_move_seed(&moves, 2)
         ],
         bump
     )]
@@ -236,7 +261,7 @@ pub struct SubmitBatchMoves<'info> {
             &match_id.as_bytes()[..32.min(match_id.len())],
             &match_id.as_bytes()[32.min(match_id.len())..],
             player.key().as_ref(),
-            &3u32.to_le_bytes()
+            &batch_move_seed(&moves, 3)
         ],
         bump
     )]
@@ -251,7 +276,7 @@ pub struct SubmitBatchMoves<'info> {
             &match_id.as_bytes()[..32.min(match_id.len())],
             &match_id.as_bytes()[32.min(match_id.len())..],
             player.key().as_ref(),
-            &4u32.to_le_bytes()
+            &batch_move_seed(&moves, 4)
         ],
         bump
     )]
@@ -1,6 +1,8 @@
 use crate::common::{replay_protection::ReplayProtection, validation_base::CommonValidation};
 use crate::error::GameError;
-use crate::games::{apply_action_state, validate_move as validate_game_move};
+use crate::games::{
+    apply_action_state, record_final_scores, validate_move as validate_game_move,
+};
 use crate::state::{GameRegistry, Match, Move};
 use anchor_lang::prelude::*;
 
@@ -48,7 +50,14 @@ pub fn handler(
     let player_index = match_account
         .find_player_index(&user_id_array)
         .ok_or(GameError::PlayerNotInMatch)?;
-    CommonValidation::validate_player_in_match(&*match_account, player_index)?;
+    CommonValidation::validate_player_in_match(&match_account, player_index)?;
+
+    // Security: Signer must own the seat (user_id alone is not proof of identity)
+    CommonValidation::validate_seat_signer(
+        &match_account,
+        player_index,
+        &ctx.accounts.player.key(),
+    )?;
 
     // Turn validation: Only required for turn-based actions (pick_up=0, decline=1)
     // Other actions (declare_intent=2, call_showdown=3, rebuttal=4) don't require turn validation
@@ -85,9 +94,12 @@ pub fn handler(
     move_account.action_type = action_type;
     move_account.set_payload(&payload)?; // Uses fixed-size array
     move_account.timestamp = clock.unix_timestamp as u32; // Convert i64 to u32
+    move_account.nonce = nonce;
+    move_account.set_batch_position(false, false);
 
     // Apply game-specific action state updates (delegates to appropriate game rules)
     // advance_turn = true for single moves
+    let turn_before = match_account.current_player;
     apply_action_state(
         &mut match_account,
         player_index,
@@ -103,6 +115,16 @@ pub fn handler(
 
     match_account.move_count = match_account.move_count.saturating_add(1);
 
+    // Turn timer restarts when the player in turn acts or the turn passes
+    if player_index == turn_before as usize || match_account.current_player != turn_before {
+        match_account.turn_started_at = clock.unix_timestamp;
+    }
+
+    // Game-ending move: persist final scores (after move_count so scoring sees this move)
+    if match_account.phase == 2 {
+        record_final_scores(&mut match_account);
+    }
+
     msg!(
         "Move submitted: player {}, action {}, match {}",
         ctx.accounts.player.key(),
@@ -24,20 +24,10 @@ pub mod ocentra_games {
         match_id: String,
         game_type: u8,
         seed: u64,
-        entry_fee: Option<u64>,
-        payment_method: Option<u8>,
-        match_type: Option<u8>,
-        tournament_id: Option<[u8; 16]>,
+        params: CreateMatchParams,
     ) -> Result<()> {
         instructions::games::match_lifecycle::create_match::handler(
-            ctx,
-            match_id,
-            game_type,
-            seed,
-            entry_fee,
-            payment_method,
-            match_type,
-            tournament_id,
+            ctx, match_id, game_type, seed, params,
         )
     }
 
@@ -45,6 +35,17 @@ pub mod ocentra_games {
         instructions::games::match_lifecycle::join_match::handler(ctx, match_id, user_id)
     }
 
+    pub fn leave_match(ctx: Context<LeaveMatch>, match_id: String) -> Result<()> {
+        instructions::games::match_lifecycle::leave_match::handler(ctx, match_id)
+    }
+
+    pub fn expire_match<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExpireMatch<'info>>,
+        match_id: String,
+    ) -> Result<()> {
+        instructions::games::match_lifecycle::expire_match::handler(ctx, match_id)
+    }
+
     pub fn start_match(ctx: Context<StartMatch>, match_id: String) -> Result<()> {
         instructions::games::match_lifecycle::start_match::handler(ctx, match_id)
     }
@@ -61,6 +62,14 @@ pub mod ocentra_games {
         )
     }
 
+    pub fn commit_deck(
+        ctx: Context<CommitDeck>,
+        match_id: String,
+        deck_commitment: [u8; 32],
+    ) -> Result<()> {
+        instructions::games::match_lifecycle::commit_deck::handler(ctx, match_id, deck_commitment)
+    }
+
     pub fn submit_move(
         ctx: Context<SubmitMove>,
         match_id: String,
@@ -79,6 +88,28 @@ pub mod ocentra_games {
         )
     }
 
+    pub fn claim_turn_timeout(
+        ctx: Context<ClaimTurnTimeout>,
+        match_id: String,
+        move_index: u16,
+    ) -> Result<()> {
+        instructions::games::moves::claim_turn_timeout::handler(ctx, match_id, move_index)
+    }
+
+    pub fn cancel_match(
+        ctx: Context<CancelMatch>,
+        match_id: String,
+        reason: u8,
+        abandoned_player_index: Option<u8>,
+    ) -> Result<()> {
+        instructions::games::match_lifecycle::cancel_match::handler(
+            ctx,
+            match_id,
+            reason,
+            abandoned_player_index,
+        )
+    }
+
     pub fn end_match(
         ctx: Context<EndMatch>,
         match_id: String,
@@ -99,6 +130,10 @@ pub mod ocentra_games {
         )
     }
 
+    pub fn finalize_scores(ctx: Context<FinalizeScores>, match_id: String) -> Result<()> {
+        instructions::common::scores::finalize_scores::handler(ctx, match_id)
+    }
+
     pub fn register_signer(ctx: Context<RegisterSigner>, pubkey: Pubkey, role: u8) -> Result<()> {
         instructions::common::signers::register_signer::handler(ctx, pubkey, role)
     }
@@ -139,18 +174,55 @@ pub mod ocentra_games {
         )
     }
 
+    pub fn draw_dispute_committee<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DrawDisputeCommittee<'info>>,
+        match_id: String,
+        flagger: Pubkey,
+    ) -> Result<()> {
+        instructions::common::disputes::draw_dispute_committee::handler(ctx, match_id, flagger)
+    }
+
     pub fn resolve_dispute(
         ctx: Context<ResolveDispute>,
-        dispute_id: String,
+        match_id: String,
+        flagger: Pubkey,
         resolution: u8,
     ) -> Result<()> {
-        instructions::common::disputes::resolve_dispute::handler(ctx, dispute_id, resolution)
+        instructions::common::disputes::resolve_dispute::handler(ctx, match_id, flagger, resolution)
+    }
+
+    pub fn finalize_dispute<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizeDispute<'info>>,
+        match_id: String,
+        flagger: Pubkey,
+    ) -> Result<()> {
+        instructions::common::disputes::finalize_dispute::handler(ctx, match_id, flagger)
+    }
+
+    pub fn correct_match_scores(
+        ctx: Context<CorrectMatchScores>,
+        match_id: String,
+        scores: Vec<i32>,
+    ) -> Result<()> {
+        instructions::common::disputes::correct_match_scores::handler(ctx, match_id, scores)
     }
 
     pub fn close_match_account(ctx: Context<CloseMatchAccount>, match_id: String) -> Result<()> {
         instructions::common::accounts::close_match_account::handler(ctx, match_id)
     }
 
+    pub fn register_validator(ctx: Context<RegisterValidator>, amount: u64) -> Result<()> {
+        instructions::common::validators::register_validator::handler(ctx, amount)
+    }
+
+    pub fn unstake(ctx: Context<Unstake>, amount: u64) -> Result<()> {
+        instructions::common::validators::unstake::handler(ctx, amount)
+    }
+
+    pub fn withdraw_stake(ctx: Context<WithdrawStake>) -> Result<()> {
+        instructions::common::validators::withdraw_stake::handler(ctx)
+    }
+
     pub fn slash_validator(
         ctx: Context<SlashValidator>,
         validator_pubkey: Pubkey,
@@ -262,6 +334,46 @@ pub mod ocentra_games {
         )
     }
 
+    pub fn set_lobby_ttl(
+        ctx: Context<SetLobbyTtl>,
+        game_id: u8,
+        lobby_ttl_secs: u32,
+    ) -> Result<()> {
+        instructions::common::registry::set_lobby_ttl::handler(ctx, game_id, lobby_ttl_secs)
+    }
+
+    pub fn set_game_economics(
+        ctx: Context<SetGameEconomics>,
+        game_id: u8,
+        platform_fee_bps: Option<u16>,
+        min_entry_fee: Option<u64>,
+        max_entry_fee: Option<u64>,
+        allowed_payment_methods: Option<u8>,
+    ) -> Result<()> {
+        instructions::common::registry::set_game_economics::handler(
+            ctx,
+            game_id,
+            platform_fee_bps,
+            min_entry_fee,
+            max_entry_fee,
+            allowed_payment_methods,
+        )
+    }
+
+    pub fn set_turn_timeout(
+        ctx: Context<SetTurnTimeout>,
+        game_id: u8,
+        turn_timeout_secs: u32,
+        policy: u8,
+    ) -> Result<()> {
+        instructions::common::registry::set_turn_timeout::handler(
+            ctx,
+            game_id,
+            turn_timeout_secs,
+            policy,
+        )
+    }
+
     pub fn submit_batch_moves(
         ctx: Context<SubmitBatchMoves>,
         match_id: String,
@@ -307,6 +419,26 @@ pub mod ocentra_games {
         )
     }
 
+    pub fn set_forfeit_routing(ctx: Context<SetForfeitRouting>, routing: u8) -> Result<()> {
+        instructions::common::config::set_forfeit_routing::handler(ctx, routing)
+    }
+
+    pub fn set_supported_mint(
+        ctx: Context<SetSupportedMint>,
+        min_entry_fee: u64,
+        max_entry_fee: u64,
+        withdrawal_fee: u64,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::common::config::set_supported_mint::handler(
+            ctx,
+            min_entry_fee,
+            max_entry_fee,
+            withdrawal_fee,
+            enabled,
+        )
+    }
+
     // Economic instructions (Phase 03)
     pub fn deposit_sol(ctx: Context<DepositSol>, amount: u64) -> Result<()> {
         instructions::common::economic::deposit_sol::handler(ctx, amount)
@@ -316,22 +448,15 @@ pub mod ocentra_games {
         instructions::common::economic::withdraw_sol::handler(ctx, amount)
     }
 
-    pub fn distribute_prizes(
-        ctx: Context<DistributePrizes>,
+    pub fn distribute_prizes<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DistributePrizes<'info>>,
         match_id: String,
-        winner_indices: Vec<u8>,
-        prize_amounts: Vec<u64>,
     ) -> Result<()> {
-        instructions::common::economic::distribute_prizes::handler(
-            ctx,
-            match_id,
-            winner_indices,
-            prize_amounts,
-        )
+        instructions::common::economic::distribute_prizes::handler(ctx, match_id)
     }
 
-    pub fn refund_escrow(
-        ctx: Context<RefundEscrow>,
+    pub fn refund_escrow<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RefundEscrow<'info>>,
         match_id: String,
         player_indices: Vec<u8>,
         cancellation_reason: u8,
@@ -345,4 +470,157 @@ pub mod ocentra_games {
             abandoned_player_index,
         )
     }
+
+    pub fn close_escrow(ctx: Context<CloseEscrow>, match_id: String) -> Result<()> {
+        instructions::common::economic::close_escrow::handler(ctx, match_id)
+    }
+
+    pub fn initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+        instructions::common::economic::initialize_treasury::handler(ctx)
+    }
+
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        instructions::common::economic::withdraw_treasury::handler(ctx, amount)
+    }
+
+    pub fn deposit_token(ctx: Context<DepositToken>, amount: u64) -> Result<()> {
+        instructions::common::economic::deposit_token::handler(ctx, amount)
+    }
+
+    pub fn withdraw_token(ctx: Context<WithdrawToken>, amount: u64) -> Result<()> {
+        instructions::common::economic::withdraw_token::handler(ctx, amount)
+    }
+
+    // Tournament instructions
+    pub fn create_tournament(
+        ctx: Context<CreateTournament>,
+        tournament_id: [u8; 16],
+        params: TournamentParams,
+    ) -> Result<()> {
+        instructions::common::tournaments::create_tournament::handler(ctx, tournament_id, params)
+    }
+
+    pub fn register_tournament(
+        ctx: Context<RegisterTournament>,
+        tournament_id: [u8; 16],
+    ) -> Result<()> {
+        instructions::common::tournaments::register_tournament::handler(ctx, tournament_id)
+    }
+
+    pub fn start_tournament(ctx: Context<StartTournament>, tournament_id: [u8; 16]) -> Result<()> {
+        instructions::common::tournaments::start_tournament::handler(ctx, tournament_id)
+    }
+
+    pub fn report_tournament_match(
+        ctx: Context<ReportTournamentMatch>,
+        tournament_id: [u8; 16],
+        match_id: String,
+    ) -> Result<()> {
+        instructions::common::tournaments::report_tournament_match::handler(
+            ctx,
+            tournament_id,
+            match_id,
+        )
+    }
+
+    pub fn report_tournament_no_show(
+        ctx: Context<ReportTournamentNoShow>,
+        tournament_id: [u8; 16],
+        match_id: String,
+    ) -> Result<()> {
+        instructions::common::tournaments::report_tournament_no_show::handler(
+            ctx,
+            tournament_id,
+            match_id,
+        )
+    }
+
+    pub fn reschedule_tournament_pairing<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RescheduleTournamentPairing<'info>>,
+        tournament_id: [u8; 16],
+        pairing: u8,
+    ) -> Result<()> {
+        instructions::common::tournaments::reschedule_tournament_pairing::handler(
+            ctx,
+            tournament_id,
+            pairing,
+        )
+    }
+
+    pub fn distribute_tournament_prizes<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DistributeTournamentPrizes<'info>>,
+        tournament_id: [u8; 16],
+    ) -> Result<()> {
+        instructions::common::tournaments::distribute_tournament_prizes::handler(ctx, tournament_id)
+    }
+
+    pub fn cancel_tournament<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CancelTournament<'info>>,
+        tournament_id: [u8; 16],
+    ) -> Result<()> {
+        instructions::common::tournaments::cancel_tournament::handler(ctx, tournament_id)
+    }
+
+    // Skill rating instructions
+    pub fn init_player_rating(ctx: Context<InitPlayerRating>, game_type: u8) -> Result<()> {
+        instructions::common::ratings::init_player_rating::handler(ctx, game_type)
+    }
+
+    pub fn update_ratings<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateRatings<'info>>,
+        match_id: String,
+    ) -> Result<()> {
+        instructions::common::ratings::update_ratings::handler(ctx, match_id)
+    }
+
+    // Leaderboard instructions
+    pub fn init_user_account(ctx: Context<InitUserAccount>, user_id: String) -> Result<()> {
+        instructions::common::leaderboards::init_user_account::handler(ctx, user_id)
+    }
+
+    pub fn init_leaderboard(
+        ctx: Context<InitLeaderboard>,
+        game_type: u8,
+        season_id: u64,
+    ) -> Result<()> {
+        instructions::common::leaderboards::init_leaderboard::handler(ctx, game_type, season_id)
+    }
+
+    pub fn record_result<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RecordResult<'info>>,
+        match_id: String,
+    ) -> Result<()> {
+        instructions::common::leaderboards::record_result::handler(ctx, match_id)
+    }
+
+    // Season instructions
+    pub fn fund_season_prizes(
+        ctx: Context<FundSeasonPrizes>,
+        game_type: u8,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::common::leaderboards::fund_season_prizes::handler(ctx, game_type, amount)
+    }
+
+    pub fn rollover_season(
+        ctx: Context<RolloverSeason>,
+        game_type: u8,
+        season_id: u64,
+        rewards: SeasonRewards,
+    ) -> Result<()> {
+        instructions::common::leaderboards::rollover_season::handler(
+            ctx, game_type, season_id, rewards,
+        )
+    }
+
+    pub fn claim_season_reward(
+        ctx: Context<ClaimSeasonReward>,
+        game_type: u8,
+        season_id: u64,
+        rank: u16,
+    ) -> Result<()> {
+        instructions::common::leaderboards::claim_season_reward::handler(
+            ctx, game_type, season_id, rank,
+        )
+    }
 }
@@ -49,7 +49,8 @@ pub struct ConfigAccount {
     pub kyc_tier_wallet: u8, // Minimum KYC tier required for wallet payments
     pub kyc_tier_platform: u8, // Minimum KYC tier required for platform payments
     pub supported_payment_methods: u8, // Bitmask: bit 0 = WALLET, bit 1 = PLATFORM
-    pub _padding_phase02: [u8; 5], // Explicit padding to align timestamps to 8 bytes
+    pub forfeit_routing: u8, // Where forfeited stakes go in refund_escrow (see forfeit_routing constants)
+    pub _padding_phase02: [u8; 4], // Explicit padding to align timestamps to 8 bytes
 
     // Timestamps
     pub created_at: i64,   // Account creation timestamp
@@ -82,11 +83,12 @@ impl ConfigAccount {
         1 +                                 // kyc_tier_wallet (u8)
         1 +                                 // kyc_tier_platform (u8)
         1 +                                 // supported_payment_methods (u8)
-        5 +                                 // _padding_phase02
+        1 +                                 // forfeit_routing (u8)
+        4 +                                 // _padding_phase02
         8 +                                 // created_at (i64)
         8; // last_updated (i64)
 
-    // Total: 8 + 32 + 8 + 8 + 8 + 4 + 4 + 1 + 8 + 8 + 1 + 4 + 40 + 8 + 8 + 32 + 2 + 8 + 8 + 8 + 8 + 1 + 1 + 1 + 1 + 5 + 8 + 8 = 248 bytes
+    // Total: 8 + 32 + 8 + 8 + 8 + 4 + 4 + 1 + 8 + 8 + 1 + 4 + 40 + 8 + 8 + 32 + 2 + 8 + 8 + 8 + 8 + 1 + 1 + 1 + 1 + 1 + 4 + 8 + 8 = 248 bytes
 
     pub fn get_ac_price_usd(&self) -> f64 {
         // Convert [u8; 8] back to f64
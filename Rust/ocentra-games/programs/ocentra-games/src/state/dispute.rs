@@ -1,5 +1,6 @@
 use crate::error::GameError;
 use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
 
 /// Dispute reason constants (replaces DisputeReason enum to reduce program size)
 pub mod dispute_reason {
@@ -18,6 +19,56 @@ pub mod dispute_resolution {
     pub const PARTIAL_REFUND: u8 = 4;
 }
 
+/// Voting window after a dispute is flagged (3 days)
+pub const DISPUTE_VOTING_WINDOW_SECONDS: i64 = 3 * 86_400;
+
+/// Paid matches can be disputed (freezing their escrow) until this long after they end (1 day)
+pub const POST_MATCH_DISPUTE_WINDOW_SECONDS: i64 = 86_400;
+
+/// Whether a match that ended at `ended_at` can still be disputed at `now`.
+/// flag_dispute is accepted while it is open; distribute_prizes waits until it has closed.
+pub fn post_match_dispute_window_open(ended_at: i64, now: i64) -> Result<bool> {
+    let window_end = ended_at
+        .checked_add(POST_MATCH_DISPUTE_WINDOW_SECONDS)
+        .ok_or(GameError::Overflow)?;
+    Ok(now <= window_end)
+}
+
+/// Votes a dispute needs before it can resolve ahead of its voting deadline
+pub const MIN_QUORUM_VOTES: u8 = 3;
+
+/// Share of the cast vote weight the leading resolution needs for quorum (two thirds)
+pub const QUORUM_THRESHOLD_BPS: u64 = 6_667;
+
+/// Validators drawn to vote on each dispute
+pub const DISPUTE_COMMITTEE_SIZE: usize = 5;
+
+/// Hash of the first slot after `flag_slot` from SlotHashes sysvar data (u64 entry count, then
+/// (u64 slot, [u8; 32] hash) entries, newest first). Fails while no later slot has a hash yet;
+/// Ok(None) once that slot has aged out of the sysvar.
+pub fn first_slot_hash_after(slot_hashes: &[u8], flag_slot: u64) -> Result<Option<[u8; 32]>> {
+    require!(slot_hashes.len() >= 8, GameError::InvalidPayload);
+    let mut count_bytes = [0u8; 8];
+    count_bytes.copy_from_slice(&slot_hashes[..8]);
+    let entry_count = u64::from_le_bytes(count_bytes) as usize;
+
+    let mut first_after = None;
+    for entry in slot_hashes[8..].chunks_exact(40).take(entry_count) {
+        let mut slot_bytes = [0u8; 8];
+        slot_bytes.copy_from_slice(&entry[..8]);
+        if u64::from_le_bytes(slot_bytes) <= flag_slot {
+            return match first_after {
+                Some(hash) => Ok(Some(hash)),
+                None => err!(GameError::CommitteeDrawTooEarly),
+            };
+        }
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(&entry[8..]);
+        first_after = Some(hash);
+    }
+    Ok(None)
+}
+
 /// ValidatorVote - uses zero-copy for efficiency.
 #[repr(C)]
 #[derive(Clone, Copy, AnchorSerialize, AnchorDeserialize, bytemuck::Pod, bytemuck::Zeroable)]
@@ -28,7 +79,11 @@ pub struct ValidatorVote {
     pub timestamp: u32, // Unix timestamp (u32, relative to epoch, saves 4 bytes per vote × 10 = 40 bytes!)
 }
 
-/// Dispute account - uses zero-copy for efficiency (564 bytes).
+/// Dispute account - uses zero-copy for efficiency (832 bytes).
+/// flag_dispute records `flag_slot`; draw_dispute_committee then draws a committee of staked
+/// validators once a later slot hash exists. Committee members vote until `voting_deadline`
+/// and each vote adds the validator's weight (stake scaled by reputation) to `vote_weights`
+/// for its resolution.
 #[repr(C)]
 #[account(zero_copy)]
 pub struct Dispute {
@@ -48,6 +103,12 @@ pub struct Dispute {
     pub vote_count: u8,     // Actual number of votes (0-10)
     pub _padding4: [u8; 6], // Explicit padding to align validator_votes array
     pub validator_votes: [ValidatorVote; 10], // Fixed array (max 10 validators, saves 4 bytes vs Vec)
+    pub voting_deadline: i64,                 // Votes are accepted until this timestamp
+    pub vote_weights: [u64; 4], // Summed vote weight per resolution (index = resolution - 1)
+    pub committee: [Pubkey; DISPUTE_COMMITTEE_SIZE], // Validators allowed to vote (first committee_size)
+    pub committee_size: u8,                          // Number of committee members drawn
+    pub _padding5: [u8; 7], // Explicit padding to align flag_slot to 8 bytes
+    pub flag_slot: u64,     // Slot the dispute was flagged in - the draw uses a later slot hash
 }
 
 // DO NOT manually implement Pod/Zeroable - Anchor's macro will derive them
@@ -64,7 +125,15 @@ impl Dispute {
         2 + 1 + 5 +                       // gp_deposit + gp_refunded + _padding3
         8 + 8 +                          // created_at + resolved_at
         1 + 1 + 6 +                       // resolution + vote_count + _padding4
-        (40 * 10); // validator_votes (ValidatorVote: 32 + 1 + 3 + 4 = 40 bytes each × 10 = 400 bytes)
+        (40 * 10) +                       // validator_votes (ValidatorVote: 32 + 1 + 3 + 4 = 40 bytes each × 10 = 400 bytes)
+        8 +                               // voting_deadline
+        (8 * 4) +                         // vote_weights
+        (32 * DISPUTE_COMMITTEE_SIZE) +   // committee
+        1 + 7 +                           // committee_size + _padding5
+        8; // flag_slot
+
+    // PDA seed pattern for Dispute (one dispute per flagger per match)
+    // Seeds: [b"dispute", match_account.key().as_ref(), flagger.as_ref()]
 
     pub fn is_resolved(&self) -> bool {
         self.resolution != 0 && self.resolved_at != 0
@@ -83,13 +152,55 @@ impl Dispute {
         Some(self.resolution)
     }
 
-    /// Add validator vote
-    pub fn add_vote(&mut self, validator: Pubkey, resolution: u8, timestamp: u32) -> Result<()> {
+    /// Whether `validator` was drawn onto this dispute's committee
+    pub fn is_committee_member(&self, validator: &Pubkey) -> bool {
+        self.committee[..(self.committee_size as usize).min(DISPUTE_COMMITTEE_SIZE)]
+            .contains(validator)
+    }
+
+    /// Draw up to DISPUTE_COMMITTEE_SIZE committee members from `candidates` (validator, weight)
+    /// without replacement, each draw picking a validator with probability proportional to its
+    /// weight among those not drawn yet. `seed` makes the draw deterministic.
+    pub fn draw_committee(&mut self, seed: &[u8; 32], candidates: &[(Pubkey, u64)]) {
+        let mut weights: Vec<u64> = candidates.iter().map(|&(_, weight)| weight).collect();
+        let mut remaining: u128 = weights.iter().map(|&weight| weight as u128).sum();
+        let mut size = 0;
+        while size < DISPUTE_COMMITTEE_SIZE && remaining > 0 {
+            let digest = hashv(&[seed, &[size as u8]]).to_bytes();
+            let mut bytes = [0u8; 16];
+            bytes.copy_from_slice(&digest[..16]);
+            let mut pick = u128::from_le_bytes(bytes) % remaining;
+            for (index, weight) in weights.iter_mut().enumerate() {
+                if pick < *weight as u128 {
+                    self.committee[size] = candidates[index].0;
+                    remaining -= *weight as u128;
+                    *weight = 0;
+                    break;
+                }
+                pick -= *weight as u128;
+            }
+            size += 1;
+        }
+        self.committee_size = size as u8;
+    }
+
+    /// Whether `validator` has already voted on this dispute
+    pub fn has_voted(&self, validator: &Pubkey) -> bool {
+        self.validator_votes[..(self.vote_count as usize).min(10)]
+            .iter()
+            .any(|vote| vote.validator == *validator)
+    }
+
+    /// Add validator vote carrying `weight` towards its resolution
+    pub fn add_vote(
+        &mut self,
+        validator: Pubkey,
+        resolution: u8,
+        timestamp: u32,
+        weight: u64,
+    ) -> Result<()> {
         require!(self.vote_count < 10, GameError::InvalidPayload);
-        require!(
-            resolution >= 1 && resolution <= 4,
-            GameError::InvalidPayload
-        );
+        require!((1..=4).contains(&resolution), GameError::InvalidPayload);
         self.validator_votes[self.vote_count as usize] = ValidatorVote {
             validator,
             resolution,
@@ -97,6 +208,211 @@ impl Dispute {
             timestamp,
         };
         self.vote_count += 1;
+        let tally = &mut self.vote_weights[(resolution - 1) as usize];
+        *tally = tally.checked_add(weight).ok_or(GameError::Overflow)?;
         Ok(())
     }
+
+    /// Total weight of all votes cast
+    pub fn total_vote_weight(&self) -> u64 {
+        self.vote_weights
+            .iter()
+            .fold(0u64, |sum, &w| sum.saturating_add(w))
+    }
+
+    /// Resolution with the most vote weight and that weight. None while no weight is cast or
+    /// when two resolutions tie for the lead.
+    pub fn leading_resolution(&self) -> Option<(u8, u64)> {
+        let mut leader: Option<(u8, u64)> = None;
+        let mut tied = false;
+        for (index, &weight) in self.vote_weights.iter().enumerate() {
+            match leader {
+                Some((_, best)) if weight < best => {}
+                Some((_, best)) if weight == best => tied = true,
+                _ if weight > 0 => {
+                    leader = Some((index as u8 + 1, weight));
+                    tied = false;
+                }
+                _ => {}
+            }
+        }
+        if tied {
+            None
+        } else {
+            leader
+        }
+    }
+
+    /// Resolution finalize_dispute applies, given whether the voting window has closed.
+    /// - Quorum: the leading resolution, even before the deadline
+    /// - No committee drawn (the draw was never cranked in time): nobody could vote, so the
+    ///   dispute is dismissed in favor of the defendant once the deadline passes
+    /// - Otherwise, after the deadline: the leading resolution, or dismissed without a clear leader
+    pub fn final_resolution(&self, voting_closed: bool) -> Result<u8> {
+        if self.has_quorum() {
+            return self
+                .leading_resolution()
+                .map(|(resolution, _)| resolution)
+                .ok_or(GameError::DisputeQuorumNotReached.into());
+        }
+        require!(voting_closed, GameError::DisputeQuorumNotReached);
+        if self.committee_size == 0 {
+            return Ok(dispute_resolution::RESOLVED_IN_FAVOR_OF_DEFENDANT);
+        }
+        Ok(self
+            .leading_resolution()
+            .map(|(resolution, _)| resolution)
+            .unwrap_or(dispute_resolution::RESOLVED_IN_FAVOR_OF_DEFENDANT))
+    }
+
+    /// Whether the votes cast already reach quorum: enough voters, and the leading resolution
+    /// holds at least QUORUM_THRESHOLD_BPS of the cast weight
+    pub fn has_quorum(&self) -> bool {
+        match self.leading_resolution() {
+            Some((_, weight)) if self.vote_count >= MIN_QUORUM_VOTES => {
+                (weight as u128) * 10_000
+                    >= (self.total_vote_weight() as u128) * QUORUM_THRESHOLD_BPS as u128
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dispute_with_votes(votes: &[(u8, u64)]) -> Dispute {
+        let mut dispute: Dispute = bytemuck::Zeroable::zeroed();
+        for (index, &(resolution, weight)) in votes.iter().enumerate() {
+            let validator = Pubkey::new_from_array([index as u8 + 1; 32]);
+            dispute.add_vote(validator, resolution, 0, weight).unwrap();
+        }
+        dispute
+    }
+
+    #[test]
+    fn leading_resolution_is_none_without_weight() {
+        assert_eq!(dispute_with_votes(&[]).leading_resolution(), None);
+        assert_eq!(dispute_with_votes(&[(1, 0)]).leading_resolution(), None);
+    }
+
+    #[test]
+    fn leading_resolution_picks_the_heaviest_resolution() {
+        let dispute = dispute_with_votes(&[(1, 10), (2, 30), (1, 15)]);
+        assert_eq!(dispute.leading_resolution(), Some((2, 30)));
+    }
+
+    #[test]
+    fn leading_resolution_is_none_on_a_tie() {
+        let dispute = dispute_with_votes(&[(1, 20), (3, 20)]);
+        assert_eq!(dispute.leading_resolution(), None);
+        assert!(!dispute.has_quorum());
+    }
+
+    #[test]
+    fn tie_is_broken_by_a_later_heavier_resolution() {
+        let dispute = dispute_with_votes(&[(1, 20), (2, 20), (4, 25)]);
+        assert_eq!(dispute.leading_resolution(), Some((4, 25)));
+    }
+
+    #[test]
+    fn quorum_needs_the_minimum_vote_count() {
+        let dispute = dispute_with_votes(&[(1, 100), (1, 100)]);
+        assert_eq!(dispute.leading_resolution(), Some((1, 200)));
+        assert!(!dispute.has_quorum());
+        assert!(dispute_with_votes(&[(1, 100), (1, 100), (1, 100)]).has_quorum());
+    }
+
+    #[test]
+    fn quorum_needs_two_thirds_of_the_cast_weight() {
+        // 6_667 / 10_000 of the cast weight is exactly the threshold
+        assert!(dispute_with_votes(&[(1, 3_334), (1, 3_333), (2, 3_333)]).has_quorum());
+        assert!(!dispute_with_votes(&[(1, 3_333), (1, 3_333), (2, 3_334)]).has_quorum());
+        assert!(!dispute_with_votes(&[(1, 60), (2, 20), (3, 20)]).has_quorum());
+    }
+
+    #[test]
+    fn add_vote_rejects_out_of_range_resolutions() {
+        let mut dispute: Dispute = bytemuck::Zeroable::zeroed();
+        assert!(dispute.add_vote(Pubkey::default(), 0, 0, 1).is_err());
+        assert!(dispute.add_vote(Pubkey::default(), 5, 0, 1).is_err());
+        assert_eq!(dispute.vote_count, 0);
+    }
+
+    #[test]
+    fn post_match_dispute_window_closes_after_its_last_second() {
+        let ended_at = 1_000;
+        let window_end = ended_at + POST_MATCH_DISPUTE_WINDOW_SECONDS;
+        assert!(post_match_dispute_window_open(ended_at, ended_at).unwrap());
+        assert!(post_match_dispute_window_open(ended_at, window_end).unwrap());
+        assert!(!post_match_dispute_window_open(ended_at, window_end + 1).unwrap());
+        assert!(post_match_dispute_window_open(i64::MAX, 0).is_err());
+    }
+
+    #[test]
+    fn dispute_without_a_committee_is_dismissed_at_the_deadline() {
+        let dispute: Dispute = bytemuck::Zeroable::zeroed();
+        assert_eq!(
+            dispute.final_resolution(false).unwrap_err(),
+            GameError::DisputeQuorumNotReached.into()
+        );
+        assert_eq!(
+            dispute.final_resolution(true).unwrap(),
+            dispute_resolution::RESOLVED_IN_FAVOR_OF_DEFENDANT
+        );
+    }
+
+    #[test]
+    fn final_resolution_resolves_early_only_on_quorum() {
+        let mut dispute = dispute_with_votes(&[(1, 100), (1, 100), (1, 100)]);
+        dispute.committee_size = 3;
+        assert_eq!(dispute.final_resolution(false).unwrap(), 1);
+
+        let mut split = dispute_with_votes(&[(3, 100), (4, 60)]);
+        split.committee_size = 3;
+        assert_eq!(
+            split.final_resolution(false).unwrap_err(),
+            GameError::DisputeQuorumNotReached.into()
+        );
+        assert_eq!(split.final_resolution(true).unwrap(), 3);
+
+        let mut tied = dispute_with_votes(&[(1, 50), (3, 50)]);
+        tied.committee_size = 3;
+        assert_eq!(
+            tied.final_resolution(true).unwrap(),
+            dispute_resolution::RESOLVED_IN_FAVOR_OF_DEFENDANT
+        );
+    }
+
+    /// SlotHashes sysvar data for `slots` (newest first), each slot's hash filled with its number
+    fn slot_hashes(slots: &[u64]) -> Vec<u8> {
+        let mut data = (slots.len() as u64).to_le_bytes().to_vec();
+        for &slot in slots {
+            data.extend_from_slice(&slot.to_le_bytes());
+            data.extend_from_slice(&[slot as u8; 32]);
+        }
+        data
+    }
+
+    #[test]
+    fn first_slot_hash_after_picks_the_oldest_later_slot() {
+        let data = slot_hashes(&[105, 103, 102, 100, 99]);
+        assert_eq!(first_slot_hash_after(&data, 100).unwrap(), Some([102; 32]));
+        assert_eq!(first_slot_hash_after(&data, 102).unwrap(), Some([103; 32]));
+    }
+
+    #[test]
+    fn first_slot_hash_after_waits_for_a_later_slot() {
+        let data = slot_hashes(&[100, 99]);
+        assert!(first_slot_hash_after(&data, 100).is_err());
+        assert!(first_slot_hash_after(&data, 101).is_err());
+    }
+
+    #[test]
+    fn first_slot_hash_after_is_none_once_the_slot_aged_out() {
+        let data = slot_hashes(&[105, 103, 102]);
+        assert_eq!(first_slot_hash_after(&data, 100).unwrap(), None);
+        assert_eq!(first_slot_hash_after(&slot_hashes(&[]), 100).unwrap(), None);
+    }
 }
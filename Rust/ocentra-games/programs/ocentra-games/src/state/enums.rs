@@ -25,5 +25,68 @@ pub mod cancellation_reason {
     pub const INSUFFICIENT_PLAYERS: u8 = 2;  // Not enough players joined (full refunds, small fee)
     pub const TIMEOUT: u8 = 3;               // Player timeout (abandoned player forfeits)
     pub const GRACE_PERIOD_EXPIRED: u8 = 4;  // Reconnection grace period expired (abandoned player forfeits)
-    // Values 5-7: reserved for future use
+    pub const DISPUTE_VOIDED: u8 = 5;        // Ended match voided by a dispute (full refunds, no platform fee)
+    pub const DISPUTE_PARTIAL_REFUND: u8 = 6; // Dispute resolved with a partial refund (refunds minus cancellation fee)
+    // Value 7: reserved for future use
+}
+
+/// Forfeited stake routing constants (ConfigAccount.forfeit_routing, applied by refund_escrow)
+pub mod forfeit_routing {
+    pub const TREASURY: u8 = 0; // Forfeited stake goes to the TreasuryVault
+    pub const REMAINING_PLAYERS: u8 = 1; // Split pro rata (by stake) between the refunded players
+}
+
+/// Turn timeout policy constants (GameDefinition.timeout_policy, applied by claim_turn_timeout)
+pub mod timeout_policy {
+    pub const AUTO_DECLINE: u8 = 0; // Play the game's default action for the stalled player (CLAIM: decline)
+    pub const SKIP: u8 = 1; // Pass the turn to the next seat
+    pub const FORFEIT: u8 = 2; // Player forfeits the match (skipped for the rest of it)
+}
+
+/// Per-game economics override flags (GameDefinition.economics_overrides, set by set_game_economics)
+/// Unset flags fall back to the ConfigAccount value.
+pub mod economics_override {
+    pub const PLATFORM_FEE: u8 = 0x01; // GameDefinition.platform_fee_bps replaces ConfigAccount.platform_fee_bps
+    pub const ENTRY_FEE_BOUNDS: u8 = 0x02; // GameDefinition min/max_entry_fee replace the ConfigAccount bounds
+    pub const PAYMENT_METHODS: u8 = 0x04; // GameDefinition.allowed_payment_methods narrows the config bitmask
+}
+
+/// Prize structure constants (Match.prize_structure, declared at create_match)
+pub mod prize_structure {
+    pub const WINNER_TAKES_ALL: u8 = 0; // Top score takes the whole pool (ties split it)
+    pub const TOP_N_SPLIT: u8 = 1; // Pool split by rank - Match.prize_split_bps (sums to 10,000 bps)
+    pub const PROPORTIONAL: u8 = 2; // Pool split in proportion to positive scores
+}
+
+/// Treasury fee source constants (index into TreasuryVault.fees_by_source)
+pub mod fee_source {
+    pub const PLATFORM_FEE: u8 = 0; // Platform fee taken from the prize pool in distribute_prizes
+    pub const WITHDRAWAL_FEE: u8 = 1; // Fixed fee charged by withdraw_sol
+    pub const CANCELLATION_FEE: u8 = 2; // Cancellation fee charged by refund_escrow
+    pub const FORFEITED_STAKE: u8 = 3; // Entry fee forfeited by an abandoning player
+    pub const SLASHED_STAKE: u8 = 4; // Validator stake slashed by slash_validator
+    pub const COUNT: usize = 5;
+}
+
+/// Validator slashing reason constants (slash_validator reason argument)
+pub mod slash_reason {
+    pub const MALICIOUS: u8 = 0; // Malicious behavior (50% reputation loss)
+    pub const NEGLIGENT: u8 = 1; // Negligent behavior (20% reputation loss)
+    pub const INACTIVITY: u8 = 2; // Inactivity (10% reputation loss)
+}
+
+/// Tournament format constants (Tournament.format, declared at create_tournament)
+pub mod tournament_format {
+    pub const SINGLE_ELIMINATION: u8 = 0; // Losers are out - winners of paired matches meet next round
+    pub const SWISS: u8 = 1; // Every round pairs entrants with equal points, ceil(log2(n)) rounds
+    pub const ROUND_ROBIN: u8 = 2; // Everyone plays everyone once
+}
+
+/// Tournament phase constants (Tournament.phase)
+pub mod tournament_phase {
+    pub const REGISTRATION: u8 = 0; // Entrants may register_tournament until registration closes
+    pub const IN_PROGRESS: u8 = 1; // Bracket running - rounds advance as matches are reported
+    pub const COMPLETED: u8 = 2; // Final standings set - prizes can be distributed
+    pub const SETTLED: u8 = 3; // Prize pool paid out
+    pub const CANCELLED: u8 = 4; // Cancelled during registration - buy-ins refunded
 }
@@ -4,7 +4,8 @@ use anchor_lang::prelude::*;
 pub const MAX_PLAYERS: usize = 10;
 
 /// Escrow account PDA schema for paid matches.
-/// Tracks per-player stakes, platform fees, and treasury amounts.
+/// Tracks per-player stakes and platform fees (the treasury cut itself is paid into the
+/// TreasuryVault PDA and withdrawn from there by withdraw_treasury).
 /// Uses zero-copy for efficiency.
 #[repr(C)]
 #[account(zero_copy)]
@@ -22,8 +23,8 @@ pub struct EscrowAccount {
     /// Platform fee accumulated (calculated as total_entry_lamports * platform_fee_bps / 10000)
     pub platform_fee_lamports: u64, // 8 bytes - 8-byte aligned
 
-    /// Amount owed to treasury (platform_fee_lamports - already withdrawn)
-    pub treasury_due_lamports: u64, // 8 bytes - 8-byte aligned
+    /// Reserved (formerly treasury_due_lamports - nothing is owed to the treasury from escrow)
+    pub _reserved: [u8; 8], // 8 bytes - keeps player_stakes at its existing offset
 
     /// Per-player stakes (one u64 per player slot)
     /// Format: [player0_stake(8) | player1_stake(8) | ... | player9_stake(8)]
@@ -35,16 +36,24 @@ pub struct EscrowAccount {
     /// Bit 1: distributed (prizes have been distributed)
     /// Bit 2: cancelled (match was cancelled, refunds issued)
     /// Bits 3-5: cancellation_reason (3 bits = 8 values, see cancellation_reason constants)
-    /// Bits 6-7: reserved
+    /// Bit 6: contested (an open dispute freezes settlement until it resolves)
+    /// Bit 7: score_correction_pending (dispute upheld - prizes wait for corrected scores)
     pub status_flags: u8, // 1 byte
     
     /// Abandoned player index (0-9 = player index, 255 = none/not applicable)
     /// Only set when cancellation_reason is PLAYER_ABANDONMENT, TIMEOUT, or GRACE_PERIOD_EXPIRED
     pub abandoned_player_index: u8, // 1 byte
-    pub _padding2: [u8; 6], // Explicit padding to align reserved to 8 bytes
 
-    /// Reserved space for future SPL token support (mint metadata, etc.)
-    pub reserved: [u8; 32], // 32 bytes - 8-byte aligned
+    /// Platform fee in basis points charged by distribute_prizes, fixed at create_match
+    /// from the game's override or the ConfigAccount default
+    pub platform_fee_bps: u16, // 2 bytes - 2-byte aligned
+    pub _padding2: [u8; 4], // Explicit padding to align entry_mint to 8 bytes
+
+    /// SPL token mint the entry fees are denominated in (Pubkey::default() = native SOL).
+    /// Token escrows hold stakes in the escrow vault token account
+    /// (seeds [b"escrow_vault", match_pda]) owned by this PDA; amounts above are then in
+    /// the mint's base units rather than lamports.
+    pub entry_mint: Pubkey, // 32 bytes - 8-byte aligned
 }
 
 impl EscrowAccount {
@@ -54,10 +63,10 @@ impl EscrowAccount {
         1 + 7 +                           // bump + _padding1
         8 +                               // total_entry_lamports
         8 +                               // platform_fee_lamports
-        8 +                               // treasury_due_lamports
+        8 +                               // _reserved
         (8 * MAX_PLAYERS) +               // player_stakes (10 × 8 = 80 bytes)
-        1 + 1 + 6 +                       // status_flags + abandoned_player_index + _padding2
-        32; // reserved
+        1 + 1 + 2 + 4 +                   // status_flags + abandoned_player_index + platform_fee_bps + _padding2
+        32; // entry_mint (Pubkey)
 
     /// PDA seed pattern for EscrowAccount
     /// Seeds: [b"escrow", match_pda.as_ref()]
@@ -100,6 +109,30 @@ impl EscrowAccount {
         }
     }
 
+    pub fn is_contested(&self) -> bool {
+        (self.status_flags & 0x40) != 0
+    }
+
+    pub fn set_contested(&mut self, contested: bool) {
+        if contested {
+            self.status_flags |= 0x40;
+        } else {
+            self.status_flags &= !0x40;
+        }
+    }
+
+    pub fn is_score_correction_pending(&self) -> bool {
+        (self.status_flags & 0x80) != 0
+    }
+
+    pub fn set_score_correction_pending(&mut self, pending: bool) {
+        if pending {
+            self.status_flags |= 0x80;
+        } else {
+            self.status_flags &= !0x80;
+        }
+    }
+
     /// Get cancellation reason (bits 3-5 of status_flags)
     pub fn get_cancellation_reason(&self) -> u8 {
         (self.status_flags >> 3) & 0x07
@@ -127,6 +160,23 @@ impl EscrowAccount {
         }
     }
 
+    /// Remove a seat's stake, shifting later stakes down one index (mirrors Match::remove_seat).
+    /// Returns the removed stake.
+    pub fn remove_player_stake(&mut self, player_index: usize) -> u64 {
+        if player_index >= MAX_PLAYERS {
+            return 0;
+        }
+        let stake = self.player_stakes[player_index];
+        self.player_stakes.copy_within(player_index + 1.., player_index);
+        self.player_stakes[MAX_PLAYERS - 1] = 0;
+        stake
+    }
+
+    /// Whether stakes are held in an SPL token escrow vault rather than as lamports
+    pub fn is_token_escrow(&self) -> bool {
+        self.entry_mint != Pubkey::default()
+    }
+
     /// Calculate total player stakes (sum of all player_stakes)
     pub fn total_player_stakes(&self) -> u64 {
         self.player_stakes.iter().sum()
@@ -1,5 +1,10 @@
+use crate::state::enums::{economics_override, payment_method};
+use crate::state::ConfigAccount;
 use anchor_lang::prelude::*;
 
+/// Default lobby lifetime for newly registered games (24 hours)
+pub const DEFAULT_LOBBY_TTL_SECS: u32 = 24 * 60 * 60;
+
 /// GameDefinition represents a single game in the registry.
 /// Per spec Section 16.5: Game registry system.
 /// Uses fixed-size arrays for optimization (no String/Vec overhead).
@@ -15,7 +20,15 @@ pub struct GameDefinition {
     pub rule_engine_url: [u8; 200], // Off-chain rule engine endpoint (fixed 200 bytes, null-padded)
     pub version: u8,    // Game version (for updates)
     pub enabled: u8,    // Is game enabled? (u8 instead of bool for zero-copy compatibility)
-    pub _padding: [u8; 6], // Explicit padding to align to 8 bytes (225 + 6 = 231, but we'll keep 225 for now)
+    pub turn_timeout_secs: [u8; 4], // Per-turn deadline in seconds (u32 LE bytes - struct is 1-byte aligned, 0 = no timer)
+    pub timeout_policy: u8, // What claim_turn_timeout does (see enums::timeout_policy)
+    pub lobby_ttl_secs: [u8; 4], // Seconds a match may wait in Dealing before expire_match (u32 LE bytes, 0 = never expires)
+    pub economics_overrides: u8, // Which ConfigAccount economics this game overrides (see enums::economics_override)
+    pub platform_fee_bps: [u8; 2], // Platform fee override in basis points (u16 LE bytes)
+    pub allowed_payment_methods: u8, // Payment method override - bitmask like ConfigAccount.supported_payment_methods
+    pub min_entry_fee: [u8; 8], // Minimum entry fee override in lamports (u64 LE bytes)
+    pub max_entry_fee: [u8; 8], // Maximum entry fee override in lamports (u64 LE bytes)
+    pub _padding: [u8; 1], // Explicit padding (225 + 4 + 1 + 4 + 20 + 1 = 255)
 }
 
 impl GameDefinition {
@@ -26,9 +39,17 @@ impl GameDefinition {
         200 +                              // rule_engine_url ([u8; 200])
         1 +                                // version (u8)
         1 +                                // enabled (u8)
-        6; // _padding
-
-    // Total: 1 + 20 + 1 + 1 + 200 + 1 + 1 + 6 = 231 bytes per entry
+        4 +                                // turn_timeout_secs ([u8; 4])
+        1 +                                // timeout_policy (u8)
+        4 +                                // lobby_ttl_secs ([u8; 4])
+        1 +                                // economics_overrides (u8)
+        2 +                                // platform_fee_bps ([u8; 2])
+        1 +                                // allowed_payment_methods (u8)
+        8 +                                // min_entry_fee ([u8; 8])
+        8 +                                // max_entry_fee ([u8; 8])
+        1; // _padding
+
+    // Total: 1 + 20 + 1 + 1 + 200 + 1 + 1 + 4 + 1 + 4 + 1 + 2 + 1 + 8 + 8 + 1 = 255 bytes per entry
 
     pub fn get_name_string(&self) -> String {
         String::from_utf8_lossy(&self.name)
@@ -36,6 +57,62 @@ impl GameDefinition {
             .to_string()
     }
 
+    pub fn get_turn_timeout_secs(&self) -> u32 {
+        u32::from_le_bytes(self.turn_timeout_secs)
+    }
+
+    pub fn set_turn_timeout_secs(&mut self, secs: u32) {
+        self.turn_timeout_secs = secs.to_le_bytes();
+    }
+
+    pub fn get_lobby_ttl_secs(&self) -> u32 {
+        u32::from_le_bytes(self.lobby_ttl_secs)
+    }
+
+    pub fn set_lobby_ttl_secs(&mut self, secs: u32) {
+        self.lobby_ttl_secs = secs.to_le_bytes();
+    }
+
+    pub fn has_override(&self, flag: u8) -> bool {
+        (self.economics_overrides & flag) != 0
+    }
+
+    /// Platform fee for this game's matches: the game override if set, else the global config.
+    pub fn effective_platform_fee_bps(&self, config: &ConfigAccount) -> u16 {
+        if self.has_override(economics_override::PLATFORM_FEE) {
+            u16::from_le_bytes(self.platform_fee_bps)
+        } else {
+            config.platform_fee_bps
+        }
+    }
+
+    /// Entry fee bounds (lamports) for this game's matches: the game override if set, else the global config.
+    pub fn effective_entry_fee_bounds(&self, config: &ConfigAccount) -> (u64, u64) {
+        if self.has_override(economics_override::ENTRY_FEE_BOUNDS) {
+            (
+                u64::from_le_bytes(self.min_entry_fee),
+                u64::from_le_bytes(self.max_entry_fee),
+            )
+        } else {
+            (config.min_entry_fee, config.max_entry_fee)
+        }
+    }
+
+    /// A payment method must be enabled globally and, if the game overrides them, by the game.
+    pub fn is_payment_method_allowed(&self, config: &ConfigAccount, payment_method: u8) -> bool {
+        if !config.is_payment_method_supported(payment_method) {
+            return false;
+        }
+        if !self.has_override(economics_override::PAYMENT_METHODS) {
+            return true;
+        }
+        match payment_method {
+            payment_method::WALLET => (self.allowed_payment_methods & 0x01) != 0,
+            payment_method::PLATFORM => (self.allowed_payment_methods & 0x02) != 0,
+            _ => false,
+        }
+    }
+
     pub fn get_rule_engine_url_string(&self) -> String {
         String::from_utf8_lossy(&self.rule_engine_url)
             .trim_end_matches('\0')
@@ -66,7 +143,7 @@ impl GameRegistry {
     pub const MAX_SIZE: usize = 8 +      // discriminator
         32 +                              // authority
         1 + 7 +                           // game_count + _padding1
-        (231 * 20) +                      // games (GameDefinition: 231 bytes each × 20 = 4620 bytes)
+        (255 * 20) +                      // games (GameDefinition: 255 bytes each × 20 = 5100 bytes)
         4 +                               // _padding2
         8; // last_updated
 
@@ -0,0 +1,71 @@
+use crate::error::GameError;
+use anchor_lang::prelude::*;
+
+/// Maximum disputes that can be open against one match at a time
+pub const MAX_OPEN_DISPUTES: usize = 10;
+
+/// MatchDisputes account PDA schema - per-match index of open disputes.
+/// Created by the first flag_dispute on a match; every flag adds its Dispute PDA to
+/// `open_disputes` and finalize_dispute removes it, so the match lifecycle can tell whether
+/// a match is contested without scanning Dispute accounts.
+/// Uses zero-copy for efficiency.
+#[repr(C)]
+#[account(zero_copy)]
+pub struct MatchDisputes {
+    pub match_account: Pubkey, // Match PDA the disputes are about
+    pub open_disputes: [Pubkey; MAX_OPEN_DISPUTES], // Unresolved Dispute PDAs (first open_count)
+    pub total_disputes: u32,   // Lifetime disputes flagged on the match
+    pub open_count: u8,        // Number of unresolved disputes
+    pub bump: u8,              // PDA bump seed
+    pub _padding: [u8; 2],     // Explicit padding to keep the struct 4-byte aligned
+}
+
+impl MatchDisputes {
+    /// Maximum size of MatchDisputes including discriminator
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        32 +                        // match_account (Pubkey)
+        (32 * MAX_OPEN_DISPUTES) +  // open_disputes
+        4 +                         // total_disputes (u32)
+        1 +                         // open_count (u8)
+        1 +                         // bump (u8)
+        2; // _padding
+
+    // PDA seed pattern for MatchDisputes
+    // Seeds: [b"match_disputes", match_account.key().as_ref()]
+
+    /// Whether any dispute against the match is still open
+    pub fn is_contested(&self) -> bool {
+        self.open_count > 0
+    }
+
+    /// Whether `dispute` is one of the open disputes
+    pub fn is_open(&self, dispute: &Pubkey) -> bool {
+        self.open_disputes[..(self.open_count as usize).min(MAX_OPEN_DISPUTES)].contains(dispute)
+    }
+
+    /// Index a newly flagged dispute
+    pub fn add_open(&mut self, dispute: Pubkey) -> Result<()> {
+        let count = self.open_count as usize;
+        require!(count < MAX_OPEN_DISPUTES, GameError::TooManyOpenDisputes);
+        self.open_disputes[count] = dispute;
+        self.open_count += 1;
+        self.total_disputes = self
+            .total_disputes
+            .checked_add(1)
+            .ok_or(GameError::Overflow)?;
+        Ok(())
+    }
+
+    /// Remove a resolved dispute, keeping the open disputes contiguous
+    pub fn remove_open(&mut self, dispute: &Pubkey) -> Result<()> {
+        let count = (self.open_count as usize).min(MAX_OPEN_DISPUTES);
+        let index = self.open_disputes[..count]
+            .iter()
+            .position(|open| open == dispute)
+            .ok_or(GameError::DisputeNotFound)?;
+        self.open_disputes.copy_within(index + 1..count, index);
+        self.open_disputes[count - 1] = Pubkey::default();
+        self.open_count -= 1;
+        Ok(())
+    }
+}
@@ -7,6 +7,7 @@ pub mod game_phase {
     pub const DEALING: u8 = 0;
     pub const PLAYING: u8 = 1;
     pub const ENDED: u8 = 2;
+    pub const CANCELLED: u8 = 3; // Terminal - set by cancel_match, escrow refundable
 }
 
 /// Match account - uses zero-copy for efficiency (1,140 bytes).
@@ -23,7 +24,7 @@ pub struct Match {
     pub game_type: u8,              // GameType enum as u8
     pub _padding2: [u8; 1],         // Explicit padding to align seed to 4 bytes
     pub seed: u32,                  // RNG seed (u32 sufficient, saves 4 bytes) - 4-byte aligned
-    pub phase: u8,                  // 0=Dealing, 1=Playing, 2=Ended
+    pub phase: u8,                  // 0=Dealing, 1=Playing, 2=Ended, 3=Cancelled
     pub current_player: u8,         // Index (0-9)
     pub player_count: u8,           // Current number of players
     pub _padding3: [u8; 1],         // Explicit padding
@@ -46,7 +47,11 @@ pub struct Match {
     // Pack boolean flags into single u8 (saves 1 byte)
     // Bit 0: floor_card_revealed
     // Bit 1: all_players_joined
-    // Bits 2-7: reserved
+    // Bit 2: scores_recorded (scores field holds the authoritative result)
+    // Bit 3: ratings_applied (update_ratings has rated every seat)
+    // Bit 4: results_recorded (record_result has updated season stats and the leaderboard)
+    // Bit 5: scores_finalized (finalize_scores replay or a dispute correction fixed the scores)
+    // Bits 6-7: reserved
     pub flags: u8,
     pub _padding5: [u8; 2], // Explicit padding to align floor_card_hash? Actually arrays are 1-byte aligned
 
@@ -81,6 +86,41 @@ pub struct Match {
     pub payment_method: u8,      // 0 = WALLET, 1 = PLATFORM (from enums::payment_method)
     pub _padding7: [u8; 6],      // Explicit padding to align tournament_id to 8 bytes
     pub tournament_id: [u8; 16], // Optional tournament ID (all zeros = not a tournament match) - 8-byte aligned
+
+    // Seat ownership: wallet (or session key) that signed join_match for each seat
+    // Per-player instructions must be signed by this key; player_ids are display metadata only
+    // Format: [player0_wallet(32) | player1_wallet(32) | ... | player9_wallet(32)]
+    // Pubkey::default() = empty seat
+    pub player_wallets: [Pubkey; 10], // 10 players × 32 bytes = 320 bytes
+
+    // Game-specific state (opaque to the framework, interpreted by games::<game> modules)
+    // CLAIM keeps its state in the dedicated fields above and leaves this zeroed
+    // Games read/write a Pod struct at offset 0 (see games::poker::state::PokerState)
+    pub game_state: [u8; 512], // All zeros = game state not initialized
+
+    // Final per-player scores from the game's scoring hook (GameRules::score_match)
+    // Written once when the match ends (end_match or a game-ending move); valid when
+    // scores_recorded flag is set. Read by prize distribution, leaderboards and disputes
+    // Format: [player0_score(4) | player1_score(4) | ... | player9_score(4)]
+    pub scores: [i32; 10], // 10 players × 4 bytes = 40 bytes
+
+    // Turn timer: when the current turn began (reset whenever the turn passes or the player
+    // in turn acts). claim_turn_timeout may resolve the turn once the game's
+    // GameDefinition.turn_timeout_secs has elapsed since this timestamp
+    pub turn_started_at: i64, // Unix timestamp (0 = match not started) - 8-byte aligned
+
+    // Seats forfeited by turn timeout (bit N = seat N) - skipped for the rest of the match
+    pub forfeited_players: u16,
+    // Last seat forfeited by timeout (255 = none) - abandoned player for TIMEOUT escrow refunds
+    pub timed_out_player: u8,
+    pub _padding8: [u8; 5], // Explicit padding to keep the struct 8-byte aligned
+
+    // Prize structure declared at create_match (see enums::prize_structure)
+    // distribute_prizes computes every payout from scores with it - callers supply no amounts
+    pub prize_structure: u8,
+    pub prize_split_count: u8, // TOP_N_SPLIT: number of paid ranks (0 otherwise)
+    pub prize_split_bps: [u16; 10], // TOP_N_SPLIT: pool share per rank in basis points - 2-byte aligned
+    pub _padding9: [u8; 2], // Explicit padding to keep the struct 8-byte aligned
 }
 
 // DO NOT manually implement Pod/Zeroable - Anchor's macro will derive them
@@ -104,7 +144,16 @@ impl Match {
         (8 * 10) +                       // last_nonce (u64 array, 8-byte aligned)
         8 + 8 +                          // entry_fee_lamports + prize_pool_lamports
         1 + 1 + 6 +                      // match_type + payment_method + _padding7
-        16; // tournament_id
+        16 +                             // tournament_id
+        (32 * 10) +                      // player_wallets
+        512 +                            // game_state
+        (4 * 10) +                       // scores
+        8 +                              // turn_started_at
+        2 + 1 + 5 +                      // forfeited_players + timed_out_player + _padding8
+        1 + 1 + (2 * 10) + 2; // prize_structure + prize_split_count + prize_split_bps + _padding9
+
+    /// Size of the game-specific state blob
+    pub const GAME_STATE_SIZE: usize = 512;
 
     /// Get game config from registry. Requires GameRegistry account to be passed.
     /// This method is used by instructions that have access to the registry.
@@ -238,6 +287,73 @@ impl Match {
         }
     }
 
+    pub fn scores_recorded(&self) -> bool {
+        (self.flags & 0x04) != 0
+    }
+
+    pub fn set_scores_recorded(&mut self, recorded: bool) {
+        if recorded {
+            self.flags |= 0x04;
+        } else {
+            self.flags &= !0x04;
+        }
+    }
+
+    pub fn ratings_applied(&self) -> bool {
+        (self.flags & 0x08) != 0
+    }
+
+    pub fn set_ratings_applied(&mut self, applied: bool) {
+        if applied {
+            self.flags |= 0x08;
+        } else {
+            self.flags &= !0x08;
+        }
+    }
+
+    pub fn results_recorded(&self) -> bool {
+        (self.flags & 0x10) != 0
+    }
+
+    pub fn set_results_recorded(&mut self, recorded: bool) {
+        if recorded {
+            self.flags |= 0x10;
+        } else {
+            self.flags &= !0x10;
+        }
+    }
+
+    pub fn scores_finalized(&self) -> bool {
+        (self.flags & 0x20) != 0
+    }
+
+    pub fn set_scores_finalized(&mut self, finalized: bool) {
+        if finalized {
+            self.flags |= 0x20;
+        } else {
+            self.flags &= !0x20;
+        }
+    }
+
+    // Helper to get a player's final score (None until scores are recorded)
+    pub fn get_score(&self, player_index: usize) -> Option<i32> {
+        if player_index >= 10 || !self.scores_recorded() {
+            return None;
+        }
+        Some(self.scores[player_index])
+    }
+
+    // Helper to record final scores (sets scores_recorded flag)
+    pub fn record_scores(&mut self, scores: [i32; 10]) {
+        self.scores = scores;
+        self.set_scores_recorded(true);
+    }
+
+    // Helper to check if match was cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.phase == game_phase::CANCELLED
+    }
+
     // Helper to check if match is ended
     pub fn is_ended(&self) -> bool {
         self.ended_at != 0
@@ -357,6 +473,92 @@ impl Match {
         self.find_player_index(user_id).is_some()
     }
 
+    // Helper to get the wallet bound to a seat
+    pub fn get_player_wallet(&self, player_index: usize) -> Option<Pubkey> {
+        if player_index >= 10 {
+            return None;
+        }
+        Some(self.player_wallets[player_index])
+    }
+
+    // Helper to bind a wallet to a seat
+    pub fn set_player_wallet(&mut self, player_index: usize, wallet: Pubkey) {
+        if player_index < 10 {
+            self.player_wallets[player_index] = wallet;
+        }
+    }
+
+    // Helper to find player index by the wallet that owns the seat
+    pub fn find_player_index_by_wallet(&self, wallet: &Pubkey) -> Option<usize> {
+        if *wallet == Pubkey::default() {
+            return None;
+        }
+        (0..self.player_count as usize).find(|&i| self.player_wallets[i] == *wallet)
+    }
+
+    // Helper to check if wallet already owns a seat in match
+    pub fn has_player_wallet(&self, wallet: &Pubkey) -> bool {
+        self.find_player_index_by_wallet(wallet).is_some()
+    }
+
+    // Helper to vacate a seat during Dealing: later seats shift down one index so seats stay
+    // contiguous (0..player_count). Per-seat arrays written before start_match move with them
+    pub fn remove_seat(&mut self, player_index: usize) {
+        let player_count = self.player_count as usize;
+        if player_index >= player_count || player_count > 10 {
+            return;
+        }
+        let last = player_count - 1;
+        self.player_ids.copy_within(player_index + 1..player_count, player_index);
+        self.player_wallets.copy_within(player_index + 1..player_count, player_index);
+        self.hand_sizes.copy_within(player_index + 1..player_count, player_index);
+        self.last_nonce.copy_within(player_index + 1..player_count, player_index);
+        self.committed_hand_hashes
+            .copy_within((player_index + 1) * 32..player_count * 32, player_index * 32);
+
+        self.player_ids[last] = [0u8; 64];
+        self.player_wallets[last] = Pubkey::default();
+        self.hand_sizes[last] = 0;
+        self.last_nonce[last] = 0;
+        self.committed_hand_hashes[last * 32..player_count * 32].fill(0);
+
+        self.player_count = last as u8;
+        self.set_all_players_joined(false);
+    }
+
+    // Turn timeout helpers
+    pub fn is_forfeited(&self, player_index: usize) -> bool {
+        player_index < 10 && (self.forfeited_players & (1 << player_index)) != 0
+    }
+
+    pub fn set_forfeited(&mut self, player_index: usize) {
+        if player_index < 10 {
+            self.forfeited_players |= 1 << player_index;
+        }
+    }
+
+    // Helper to count seats still playing (not forfeited)
+    pub fn active_player_count(&self) -> usize {
+        (0..self.player_count as usize)
+            .filter(|&i| !self.is_forfeited(i))
+            .count()
+    }
+
+    // Helper to move current_player off forfeited seats (no-op while the player in turn is active)
+    pub fn skip_forfeited_seats(&mut self) {
+        let player_count = self.player_count as usize;
+        let current = self.current_player as usize;
+        if player_count == 0 || !self.is_forfeited(current) {
+            return;
+        }
+        if let Some(next) = (1..player_count)
+            .map(|offset| (current + offset) % player_count)
+            .find(|&seat| !self.is_forfeited(seat))
+        {
+            self.current_player = next as u8;
+        }
+    }
+
     // Phase 02: Paid match helper methods
     pub fn is_paid_match(&self) -> bool {
         self.match_type == crate::state::enums::match_type::PAID
@@ -378,6 +580,11 @@ impl Match {
         self.payment_method == crate::state::enums::payment_method::PLATFORM
     }
 
+    // Helper to get the declared TOP_N_SPLIT shares (empty for other structures)
+    pub fn get_prize_split_bps(&self) -> &[u16] {
+        &self.prize_split_bps[..(self.prize_split_count as usize).min(10)]
+    }
+
     pub fn get_tournament_id(&self) -> Option<[u8; 16]> {
         if self.tournament_id.iter().all(|&b| b == 0) {
             None
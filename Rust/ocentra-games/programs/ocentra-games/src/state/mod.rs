@@ -6,9 +6,17 @@ pub mod escrow; // Phase 02: Escrow account for paid matches
 pub mod game_config;
 pub mod game_leaderboard; // Per spec Section 20.1.6: Leaderboard system
 pub mod game_registry;
+pub mod match_disputes; // Per-match index of open disputes
 pub mod match_state;
 pub mod move_state;
+pub mod player_rating; // Per-wallet, per-game-type skill rating (Elo)
+pub mod score_replay; // Resumable cursor for move-replay scoring
+pub mod season_archive; // Frozen end-of-season leaderboards and their rewards
+pub mod season_prize_vault; // Program-owned lamport prizes for season rewards
 pub mod signer_registry;
+pub mod supported_mint; // Allow-listed SPL token mints for paid matches and deposits
+pub mod tournament; // Tournaments: registration, buy-in escrow and bracket state
+pub mod treasury_vault; // Program-owned treasury vault with per-source/per-game fee ledger
 pub mod user_account; // Per spec Section 20: Economic model - UserAccount
 pub mod user_deposit; // Phase 02: User deposit account for platform deposits
 pub mod validator_reputation; // Per critique Issue #5: Validator reputation tracking // Per spec Section 16.5: Game registry system
@@ -21,9 +29,17 @@ pub use escrow::*;
 pub use game_config::*;
 pub use game_leaderboard::*;
 pub use game_registry::*;
+pub use match_disputes::*;
 pub use match_state::*;
 pub use move_state::*;
+pub use player_rating::*;
+pub use score_replay::*;
+pub use season_archive::*;
+pub use season_prize_vault::*;
 pub use signer_registry::*;
+pub use supported_mint::*;
+pub use tournament::*;
+pub use treasury_vault::*;
 pub use user_account::*;
 pub use user_deposit::*;
 pub use validator_reputation::*;
@@ -19,6 +19,8 @@ pub struct Move {
     pub payload: [u8; 128], // Fixed-size payload (saves 4 bytes vs Vec, reduced from 256 to 128)
     pub payload_len: u8,    // Actual payload length (0-128)
     pub timestamp: u32,     // Unix timestamp (u32, relative to epoch, saves 4 bytes)
+    pub nonce: u64,         // Replay-protection nonce (PDA seed - lets score replay re-derive the address)
+    pub flags: u8,          // Bit 0: batched, Bit 1: last move of its batch, Bit 2: turn timeout
 }
 
 impl Move {
@@ -29,9 +31,11 @@ impl Move {
         1 +                              // action_type (u8)
         128 +                            // payload (fixed [u8; 128])
         1 +                              // payload_len (u8)
-        4; // timestamp (u32, reduced from i64, saves 4 bytes)
+        4 +                              // timestamp (u32, reduced from i64, saves 4 bytes)
+        8 +                              // nonce (u64)
+        1; // flags (u8)
 
-    // Total: 8 + 36 + 32 + 2 + 1 + 128 + 1 + 4 = 212 bytes (saved 6 bytes)
+    // Total: 8 + 36 + 32 + 2 + 1 + 128 + 1 + 4 + 8 + 1 = 221 bytes
     // Previous: ~350 bytes (saved ~130 bytes)
 
     pub fn get_payload_slice(&self) -> &[u8] {
@@ -45,6 +49,29 @@ impl Move {
         Ok(())
     }
 
+    // Batch flags: batched moves apply without turn advancement, the batch's last move
+    // advances the turn once (score replay must reproduce the same sequence)
+    pub fn is_batched(&self) -> bool {
+        (self.flags & 0x01) != 0
+    }
+
+    pub fn is_batch_end(&self) -> bool {
+        (self.flags & 0x02) != 0
+    }
+
+    pub fn set_batch_position(&mut self, batched: bool, batch_end: bool) {
+        self.flags = (batched as u8) | ((batch_end as u8) << 1);
+    }
+
+    // Timeout moves record claim_turn_timeout resolutions (payload[0] = timeout policy)
+    pub fn is_timeout(&self) -> bool {
+        (self.flags & 0x04) != 0
+    }
+
+    pub fn set_timeout(&mut self) {
+        self.flags = 0x04;
+    }
+
     /// Get action type as u8 (0=PickUp, 1=Decline, 2=DeclareIntent, 3=CallShowdown, 4=Rebuttal)
     pub fn get_action_type(&self) -> u8 {
         self.action_type
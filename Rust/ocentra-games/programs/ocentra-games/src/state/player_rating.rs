@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+/// Rating every player starts from
+pub const INITIAL_RATING: u32 = 1200;
+
+/// PlayerRating account PDA schema - skill rating of one wallet in one game type.
+/// Created with init_player_rating; update_ratings applies each ended match once (pairwise Elo
+/// over every seat, see common::skill_rating). Matchmaking and leaderboards sort by `rating`.
+/// Uses zero-copy so settlement instructions can load one per seat from remaining_accounts.
+#[repr(C)]
+#[account(zero_copy)]
+pub struct PlayerRating {
+    pub wallet: Pubkey,    // Rated wallet (the seat owner in Match.player_wallets)
+    pub created_at: i64,   // Account creation timestamp - 8-byte aligned
+    pub last_updated: i64, // Last rated match end (0 = never rated) - 8-byte aligned
+    pub rating: u32,       // Current Elo rating (INITIAL_RATING for new players)
+    pub peak_rating: u32,  // Highest rating reached
+    pub games_rated: u32,  // Matches applied to this rating
+    pub game_type: u8,     // GameRegistry game_id
+    pub bump: u8,          // PDA bump seed
+    pub _padding: [u8; 2], // Explicit padding to keep the struct 8-byte aligned
+}
+
+impl PlayerRating {
+    /// Maximum size of PlayerRating including discriminator
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        32 +                        // wallet (Pubkey)
+        8 +                         // created_at (i64)
+        8 +                         // last_updated (i64)
+        4 +                         // rating (u32)
+        4 +                         // peak_rating (u32)
+        4 +                         // games_rated (u32)
+        1 +                         // game_type (u8)
+        1 +                         // bump (u8)
+        2; // _padding
+
+    // PDA seed pattern for PlayerRating
+    // Seeds: [b"player_rating", wallet.as_ref(), &[game_type]]
+}
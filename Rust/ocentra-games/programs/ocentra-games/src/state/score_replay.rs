@@ -0,0 +1,107 @@
+use crate::state::Match;
+use anchor_lang::prelude::*;
+
+/// Resumable cursor for finalize_scores.
+/// Long matches are replayed in chunks across several transactions; between chunks the
+/// replayed (scratch) copy of every Match field that game rules mutate is kept here.
+/// Seeds: [b"score_replay", match_pda.as_ref()]
+#[repr(C)]
+#[account(zero_copy)]
+pub struct ScoreReplay {
+    /// Match PDA being replayed
+    pub match_pda: Pubkey, // 32 bytes
+
+    /// Next Move.move_index expected (moves must be supplied in order)
+    pub next_move_index: u16, // 2 bytes
+
+    // Scratch Match fields (see save_from / restore_into)
+    pub move_count: u16,                  // 2 bytes
+    pub phase: u8,                        // 1 byte
+    pub current_player: u8,               // 1 byte
+    pub flags: u8,                        // 1 byte - Match.flags bits
+    pub declared_suits: [u8; 5],          // 5 bytes
+    pub hand_sizes: [u8; 10],             // 10 bytes
+    pub floor_card_hash: [u8; 32],        // 32 bytes
+    pub committed_hand_hashes: [u8; 320], // 320 bytes
+    pub game_state: [u8; 512],            // 512 bytes
+
+    /// Bit 0: initialized (scratch state seeded from the match start)
+    /// Bit 1: completed (all moves replayed, scores written to the match)
+    pub status_flags: u8, // 1 byte
+
+    // Scratch Match turn-timeout fields
+    pub timed_out_player: u8,   // 1 byte
+    pub forfeited_players: u16, // 2 bytes - 2-byte aligned
+    pub _padding1: [u8; 2],     // Explicit padding to align scores to 4 bytes
+
+    /// Replayed scores (valid once completed)
+    pub scores: [i32; 10], // 40 bytes - 4-byte aligned
+}
+
+impl ScoreReplay {
+    pub const MAX_SIZE: usize = 8 +      // discriminator
+        32 +                              // match_pda (Pubkey)
+        2 + 2 +                           // next_move_index + move_count
+        1 + 1 + 1 +                       // phase + current_player + flags
+        5 +                               // declared_suits
+        10 +                              // hand_sizes
+        32 +                              // floor_card_hash
+        320 +                             // committed_hand_hashes
+        512 +                             // game_state
+        1 + 1 + 2 + 2 +                   // status_flags + timed_out_player + forfeited_players + _padding1
+        (4 * 10); // scores
+
+    pub fn is_initialized(&self) -> bool {
+        (self.status_flags & 0x01) != 0
+    }
+
+    pub fn set_initialized(&mut self, initialized: bool) {
+        if initialized {
+            self.status_flags |= 0x01;
+        } else {
+            self.status_flags &= !0x01;
+        }
+    }
+
+    pub fn is_completed(&self) -> bool {
+        (self.status_flags & 0x02) != 0
+    }
+
+    pub fn set_completed(&mut self, completed: bool) {
+        if completed {
+            self.status_flags |= 0x02;
+        } else {
+            self.status_flags &= !0x02;
+        }
+    }
+
+    /// Store the scratch match state at the end of a chunk
+    pub fn save_from(&mut self, scratch: &Match) {
+        self.move_count = scratch.move_count;
+        self.phase = scratch.phase;
+        self.current_player = scratch.current_player;
+        self.flags = scratch.flags;
+        self.declared_suits = scratch.declared_suits;
+        self.hand_sizes = scratch.hand_sizes;
+        self.floor_card_hash = scratch.floor_card_hash;
+        self.committed_hand_hashes = scratch.committed_hand_hashes;
+        self.game_state = scratch.game_state;
+        self.forfeited_players = scratch.forfeited_players;
+        self.timed_out_player = scratch.timed_out_player;
+    }
+
+    /// Load the saved scratch state over a copy of the match at the start of a chunk
+    pub fn restore_into(&self, scratch: &mut Match) {
+        scratch.move_count = self.move_count;
+        scratch.phase = self.phase;
+        scratch.current_player = self.current_player;
+        scratch.flags = self.flags;
+        scratch.declared_suits = self.declared_suits;
+        scratch.hand_sizes = self.hand_sizes;
+        scratch.floor_card_hash = self.floor_card_hash;
+        scratch.committed_hand_hashes = self.committed_hand_hashes;
+        scratch.game_state = self.game_state;
+        scratch.forfeited_players = self.forfeited_players;
+        scratch.timed_out_player = self.timed_out_player;
+    }
+}
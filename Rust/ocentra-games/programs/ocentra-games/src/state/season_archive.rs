@@ -0,0 +1,60 @@
+use crate::state::LeaderboardEntry;
+use anchor_lang::prelude::*;
+
+/// Maximum number of ranks that can receive season rewards
+pub const MAX_SEASON_REWARD_RANKS: usize = 10;
+
+/// SeasonArchive account PDA schema.
+/// Frozen copy of a game type's GameLeaderboard at the end of a season, written once by
+/// rollover_season together with the rewards opened for the top ranks. The standings never
+/// change afterwards; claim_season_reward only marks ranks as claimed.
+/// Uses zero-copy to avoid stack overflow (8,992 bytes > 4,096 byte stack limit).
+#[repr(C)]
+#[account(zero_copy)]
+pub struct SeasonArchive {
+    pub season_id: u64,   // Archived season ID - 8-byte aligned
+    pub archived_at: i64, // rollover_season timestamp - 8-byte aligned
+
+    /// Lamports reserved in the SeasonPrizeVault for each rank (index 0 = rank 1)
+    pub reward_lamports: [u64; MAX_SEASON_REWARD_RANKS], // 10 × 8 = 80 bytes
+    /// GP credited to each rank's UserAccount on claim (GP balances live in the database)
+    pub reward_gp: [u64; MAX_SEASON_REWARD_RANKS], // 10 × 8 = 80 bytes
+
+    /// Final standings (copied from GameLeaderboard.entries)
+    pub entries: [LeaderboardEntry; 100], // 88 × 100 = 8800 bytes
+
+    pub claimed_ranks: u16, // Bit N = rank N + 1 has claimed its reward
+    pub game_type: u8,      // Game type of the archived leaderboard
+    pub entry_count: u8,    // Number of entries (0-100)
+    pub reward_count: u8,   // Ranks with a reward (0-10)
+    pub bump: u8,           // PDA bump seed
+    pub _padding: [u8; 2],  // Explicit padding to keep the struct 8-byte aligned
+}
+
+impl SeasonArchive {
+    /// Maximum size of SeasonArchive including discriminator
+    pub const MAX_SIZE: usize = 8 +           // discriminator
+        8 +                                    // season_id
+        8 +                                    // archived_at
+        (8 * MAX_SEASON_REWARD_RANKS) +        // reward_lamports (10 × 8 = 80 bytes)
+        (8 * MAX_SEASON_REWARD_RANKS) +        // reward_gp (10 × 8 = 80 bytes)
+        (88 * 100) +                           // entries (88 × 100 = 8800 bytes)
+        2 +                                    // claimed_ranks
+        1 + 1 + 1 + 1 +                        // game_type + entry_count + reward_count + bump
+        2; // _padding
+
+    // PDA seed pattern for SeasonArchive
+    // Seeds: [b"season_archive", &[game_type], season_id.to_le_bytes()]
+
+    pub fn is_claimed(&self, rank: u16) -> bool {
+        rank >= 1
+            && (rank as usize) <= MAX_SEASON_REWARD_RANKS
+            && (self.claimed_ranks & (1 << (rank - 1))) != 0
+    }
+
+    pub fn set_claimed(&mut self, rank: u16) {
+        if rank >= 1 && (rank as usize) <= MAX_SEASON_REWARD_RANKS {
+            self.claimed_ranks |= 1 << (rank - 1);
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+/// SeasonPrizeVault account PDA schema.
+/// Program-owned account holding the lamport prizes of one game type's seasons. Anyone can
+/// fund it with fund_season_prizes; rollover_season reserves part of the unreserved balance
+/// for the season's top ranks and claim_season_reward pays those reservations out.
+/// Uses zero-copy for efficiency.
+#[repr(C)]
+#[account(zero_copy)]
+pub struct SeasonPrizeVault {
+    pub total_funded_lamports: u64, // Lifetime total funded - 8-byte aligned
+    pub reserved_lamports: u64,     // Opened by rollover_season and not claimed yet
+    pub total_paid_lamports: u64,   // Lifetime total paid by claim_season_reward
+    pub created_at: i64,            // Account creation timestamp
+    pub game_type: u8,              // Game type whose seasons this vault rewards
+    pub bump: u8,                   // PDA bump seed
+    pub _padding: [u8; 6],          // Explicit padding to keep the struct 8-byte aligned
+}
+
+impl SeasonPrizeVault {
+    /// Maximum size of SeasonPrizeVault including discriminator
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        8 +                         // total_funded_lamports
+        8 +                         // reserved_lamports
+        8 +                         // total_paid_lamports
+        8 +                         // created_at
+        1 +                         // game_type
+        1 +                         // bump
+        6; // _padding
+
+    // PDA seed pattern for SeasonPrizeVault
+    // Seeds: [b"season_prize_vault", &[game_type]]
+}
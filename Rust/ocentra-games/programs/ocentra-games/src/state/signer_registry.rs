@@ -53,6 +53,14 @@ impl SignerRegistry {
         None
     }
 
+    /// Registered signers holding `role`, in registration order
+    pub fn signers_with_role(&self, role: u8) -> Vec<Pubkey> {
+        (0..(self.signer_count as usize).min(100))
+            .filter(|&i| self.roles[i] == role)
+            .map(|i| self.signers[i])
+            .collect()
+    }
+
     /// Add signer with role (0=Coordinator, 1=Validator, 2=Authority)
     pub fn add_signer(&mut self, pubkey: Pubkey, role: u8) -> Result<()> {
         use crate::error::GameError;
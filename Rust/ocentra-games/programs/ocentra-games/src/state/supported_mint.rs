@@ -0,0 +1,71 @@
+use crate::error::GameError;
+use crate::state::enums::fee_source;
+use anchor_lang::prelude::*;
+
+/// SupportedMint allow-lists an SPL token mint (e.g. USDC) for paid matches and platform
+/// deposits, with entry-fee bounds and withdrawal fee in the mint's base units.
+/// One account per mint, managed by the treasury multisig with set_supported_mint.
+/// Token fees paid to the treasury multisig's token account never reach the lamport
+/// TreasuryVault, so each mint keeps its own fee ledger by source.
+#[account]
+pub struct SupportedMint {
+    pub mint: Pubkey,        // SPL token mint
+    pub min_entry_fee: u64,  // Minimum entry fee per match (mint base units)
+    pub max_entry_fee: u64,  // Maximum entry fee per match (mint base units)
+    pub withdrawal_fee: u64, // Fixed withdrawal fee for withdraw_token (mint base units)
+    pub enabled: bool,       // New matches and deposits allowed (withdrawals always are)
+    pub bump: u8,            // PDA bump seed
+    pub created_at: i64,     // Account creation timestamp
+    pub last_updated: i64,   // Last update timestamp
+
+    /// Lifetime token fees paid to the treasury multisig (mint base units)
+    pub total_collected: u64,
+    /// Lifetime token fees per fee source (indexed by fee_source constants)
+    pub fees_by_source: [u64; fee_source::COUNT],
+}
+
+impl SupportedMint {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        32 +                        // mint (Pubkey)
+        8 +                         // min_entry_fee (u64)
+        8 +                         // max_entry_fee (u64)
+        8 +                         // withdrawal_fee (u64)
+        1 +                         // enabled (bool)
+        1 +                         // bump (u8)
+        8 +                         // created_at (i64)
+        8 +                         // last_updated (i64)
+        8 +                         // total_collected (u64)
+        (8 * fee_source::COUNT); // fees_by_source (5 × 8 = 40 bytes)
+
+    // PDA seed pattern for SupportedMint
+    // Seeds: [b"supported_mint", mint.as_ref()]
+
+    pub fn is_entry_fee_allowed(&self, entry_fee: u64) -> bool {
+        entry_fee >= self.min_entry_fee && entry_fee <= self.max_entry_fee
+    }
+
+    /// Lifetime token fees collected from one fee source
+    pub fn get_fees_by_source(&self, source: u8) -> u64 {
+        self.fees_by_source
+            .get(source as usize)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Record tokens that have just been moved to the treasury multisig's token account
+    pub fn record_fee(&mut self, source: u8, amount: u64) -> Result<()> {
+        require!(
+            (source as usize) < fee_source::COUNT,
+            GameError::InvalidPayload
+        );
+
+        self.total_collected = self
+            .total_collected
+            .checked_add(amount)
+            .ok_or(GameError::Overflow)?;
+        self.fees_by_source[source as usize] = self.fees_by_source[source as usize]
+            .checked_add(amount)
+            .ok_or(GameError::Overflow)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use crate::state::enums::tournament_phase;
+use anchor_lang::prelude::*;
+
+/// Maximum number of entrants per tournament
+pub const MAX_TOURNAMENT_ENTRANTS: usize = 32;
+
+/// Maximum number of heads-up pairings per round (MAX_TOURNAMENT_ENTRANTS / 2)
+pub const MAX_TOURNAMENT_PAIRINGS: usize = 16;
+
+/// Maximum number of paid places in the payout table
+pub const MAX_TOURNAMENT_PAYOUTS: usize = 8;
+
+/// Entrant index marking a bye (no opponent) in Tournament.pairings
+pub const TOURNAMENT_BYE: u8 = 255;
+
+/// Pairing result: not reported yet
+pub const PAIRING_PENDING: u8 = 255;
+
+/// Pairing result: drawn (Swiss / round robin only - single elimination always has a winner)
+pub const PAIRING_DRAW: u8 = 254;
+
+/// Tournament account PDA schema.
+/// Entrants register during the registration window by paying the buy-in; the account itself
+/// holds the buy-ins (the tournament escrow) until distribute_tournament_prizes or
+/// cancel_tournament. Rounds are heads-up matches created with create_match(tournament_id)
+/// and reported by report_tournament_match, which advances the bracket. A bracket match that
+/// never starts is settled by report_tournament_no_show after no_show_secs, or handed back to
+/// the organizer with reschedule_tournament_pairing.
+/// Uses zero-copy for efficiency (1,808 bytes).
+#[repr(C)]
+#[account(zero_copy)]
+pub struct Tournament {
+    /// Tournament ID - also the Match.tournament_id of every bracket match
+    pub tournament_id: [u8; 16], // 16 bytes
+
+    /// Organizer - creates bracket matches and may cancel during registration
+    pub authority: Pubkey, // 32 bytes
+
+    /// Buy-in per entrant in lamports (0 = freeroll)
+    pub buy_in_lamports: u64, // 8 bytes - 8-byte aligned
+
+    /// Buy-ins held by this account
+    pub prize_pool_lamports: u64, // 8 bytes - 8-byte aligned
+
+    /// Registration window (unix timestamps)
+    pub registration_opens_at: i64, // 8 bytes - 8-byte aligned
+    pub registration_closes_at: i64, // 8 bytes - 8-byte aligned
+
+    /// Account creation timestamp
+    pub created_at: i64, // 8 bytes - 8-byte aligned
+
+    /// Registered wallets in seed order (Pubkey::default() = empty slot)
+    pub entrants: [Pubkey; MAX_TOURNAMENT_ENTRANTS], // 32 × 32 = 1024 bytes
+
+    /// Match PDA created for each pairing of the current round (Pubkey::default() = not created)
+    pub pairing_matches: [Pubkey; MAX_TOURNAMENT_PAIRINGS], // 16 × 32 = 512 bytes
+
+    /// Points per entrant (win = 2, draw = 1, bye = 2)
+    pub points: [u16; MAX_TOURNAMENT_ENTRANTS], // 32 × 2 = 64 bytes - 2-byte aligned
+
+    /// Final payout table - share of the prize pool per place in basis points (sums to 10,000)
+    pub payout_bps: [u16; MAX_TOURNAMENT_PAYOUTS], // 8 × 2 = 16 bytes - 2-byte aligned
+
+    /// Platform fee in basis points, fixed at create_tournament from the game's economics
+    pub platform_fee_bps: u16, // 2 bytes - 2-byte aligned
+
+    pub game_type: u8,     // GameRegistry game_id every bracket match must use
+    pub format: u8,        // See enums::tournament_format
+    pub phase: u8,         // See enums::tournament_phase
+    pub max_entrants: u8,  // Registration cap (2-32)
+    pub entrant_count: u8, // Registered entrants
+    pub current_round: u8, // 0-based round in progress
+    pub total_rounds: u8,  // Rounds to play (set by start_tournament)
+    pub pairing_count: u8, // Pairings in the current round
+    pub payout_count: u8,  // Paid places in payout_bps
+    pub bump: u8,          // PDA bump seed
+
+    /// Current round pairings: entrant indices [a, b] (TOURNAMENT_BYE = no opponent)
+    pub pairings: [[u8; 2]; MAX_TOURNAMENT_PAIRINGS], // 16 × 2 = 32 bytes
+
+    /// Current round results: winning entrant index, PAIRING_DRAW or PAIRING_PENDING
+    pub pairing_results: [u8; MAX_TOURNAMENT_PAIRINGS], // 16 bytes
+
+    /// Final standings - entrant indices from first place down (valid once Completed)
+    pub placements: [u8; MAX_TOURNAMENT_ENTRANTS], // 32 bytes
+
+    /// Seconds a bracket match may sit in its lobby before a no-show can be reported
+    pub no_show_secs: u32, // 4 bytes - keeps the struct 8-byte aligned
+}
+
+impl Tournament {
+    /// Maximum size of Tournament including discriminator
+    pub const MAX_SIZE: usize = 8 +      // discriminator
+        16 +                              // tournament_id
+        32 +                              // authority (Pubkey)
+        8 +                               // buy_in_lamports
+        8 +                               // prize_pool_lamports
+        8 + 8 +                           // registration_opens_at + registration_closes_at
+        8 +                               // created_at
+        (32 * MAX_TOURNAMENT_ENTRANTS) +  // entrants (32 × 32 = 1024 bytes)
+        (32 * MAX_TOURNAMENT_PAIRINGS) +  // pairing_matches (16 × 32 = 512 bytes)
+        (2 * MAX_TOURNAMENT_ENTRANTS) +   // points (32 × 2 = 64 bytes)
+        (2 * MAX_TOURNAMENT_PAYOUTS) +    // payout_bps (8 × 2 = 16 bytes)
+        2 +                               // platform_fee_bps
+        10 +                              // game_type .. bump (10 × u8)
+        (2 * MAX_TOURNAMENT_PAIRINGS) +   // pairings (16 × 2 = 32 bytes)
+        MAX_TOURNAMENT_PAIRINGS +         // pairing_results
+        MAX_TOURNAMENT_ENTRANTS +         // placements
+        4; // no_show_secs
+
+    // PDA seed pattern for Tournament
+    // Seeds: [b"tournament", tournament_id.as_ref()]
+
+    pub fn is_registration_open(&self, now: i64) -> bool {
+        self.phase == tournament_phase::REGISTRATION
+            && now >= self.registration_opens_at
+            && now < self.registration_closes_at
+    }
+
+    pub fn find_entrant(&self, wallet: &Pubkey) -> Option<usize> {
+        self.entrants[..(self.entrant_count as usize).min(MAX_TOURNAMENT_ENTRANTS)]
+            .iter()
+            .position(|entrant| entrant == wallet)
+    }
+
+    /// Pairing of the current round the match was created for
+    pub fn find_pairing_by_match(&self, match_pda: &Pubkey) -> Option<usize> {
+        if *match_pda == Pubkey::default() {
+            return None;
+        }
+        self.pairing_matches[..(self.pairing_count as usize).min(MAX_TOURNAMENT_PAIRINGS)]
+            .iter()
+            .position(|pda| pda == match_pda)
+    }
+
+    /// Pending pairing of the current round the match holds (None once reported or rescheduled)
+    pub fn find_pending_pairing_by_match(&self, match_pda: &Pubkey) -> Option<usize> {
+        self.find_pairing_by_match(match_pda)
+            .filter(|&pairing| self.pairing_results[pairing] == PAIRING_PENDING)
+    }
+
+    /// First pairing of the current round that still needs a match
+    pub fn next_unscheduled_pairing(&self) -> Option<usize> {
+        (0..(self.pairing_count as usize).min(MAX_TOURNAMENT_PAIRINGS)).find(|&i| {
+            self.pairing_results[i] == PAIRING_PENDING
+                && self.pairing_matches[i] == Pubkey::default()
+        })
+    }
+
+    pub fn is_round_complete(&self) -> bool {
+        self.pairing_results[..(self.pairing_count as usize).min(MAX_TOURNAMENT_PAIRINGS)]
+            .iter()
+            .all(|&result| result != PAIRING_PENDING)
+    }
+
+    pub fn get_payout_bps(&self) -> &[u16] {
+        &self.payout_bps[..(self.payout_count as usize).min(MAX_TOURNAMENT_PAYOUTS)]
+    }
+}
@@ -0,0 +1,133 @@
+use crate::error::GameError;
+use crate::state::enums::fee_source;
+use anchor_lang::prelude::*;
+
+/// Maximum number of game types tracked separately (matches GameRegistry capacity)
+pub const MAX_TREASURY_GAMES: usize = 20;
+
+/// Treasury vault PDA schema.
+/// Program-owned account that receives every platform fee, withdrawal fee, cancellation fee,
+/// forfeited stake and slashed validator stake, and keeps its own ledger of them by source and by game type.
+/// Lamports only leave through withdraw_treasury, signed by ConfigAccount.treasury_multisig.
+/// Uses zero-copy for efficiency.
+#[repr(C)]
+#[account(zero_copy)]
+pub struct TreasuryVault {
+    /// PDA bump seed
+    pub bump: u8, // 1 byte
+
+    /// Number of used slots in game_types / fees_by_game
+    pub game_slot_count: u8, // 1 byte
+    pub _padding1: [u8; 6], // Explicit padding to align total_collected_lamports to 8 bytes
+
+    /// Lifetime total collected from all sources
+    pub total_collected_lamports: u64, // 8 bytes - 8-byte aligned
+
+    /// Lifetime total withdrawn by the treasury multisig
+    pub total_withdrawn_lamports: u64, // 8 bytes - 8-byte aligned
+
+    /// Lifetime totals per fee source (indexed by fee_source constants)
+    pub fees_by_source: [u64; fee_source::COUNT], // 5 × 8 = 40 bytes - 8-byte aligned
+
+    /// Game type of each per-game slot (game_id from GameRegistry)
+    pub game_types: [u8; MAX_TREASURY_GAMES], // 20 bytes
+    pub _padding2: [u8; 4], // Explicit padding to align fees_by_game to 8 bytes
+
+    /// Lifetime totals per game type (same slot order as game_types)
+    /// Withdrawal fees and slashed stake are not tied to a game - they only count by source
+    pub fees_by_game: [u64; MAX_TREASURY_GAMES], // 20 × 8 = 160 bytes - 8-byte aligned
+
+    /// Unix timestamp of the last withdraw_treasury (0 = never)
+    pub last_withdrawal_at: i64, // 8 bytes - 8-byte aligned
+
+    /// Account creation timestamp
+    pub created_at: i64, // 8 bytes - 8-byte aligned
+}
+
+impl TreasuryVault {
+    /// Maximum size of TreasuryVault including discriminator
+    pub const MAX_SIZE: usize = 8 +      // discriminator
+        1 + 1 + 6 +                       // bump + game_slot_count + _padding1
+        8 +                               // total_collected_lamports
+        8 +                               // total_withdrawn_lamports
+        (8 * fee_source::COUNT) +         // fees_by_source (5 × 8 = 40 bytes)
+        MAX_TREASURY_GAMES + 4 +          // game_types + _padding2
+        (8 * MAX_TREASURY_GAMES) +        // fees_by_game (20 × 8 = 160 bytes)
+        8 +                               // last_withdrawal_at
+        8; // created_at
+
+    // PDA seed pattern for TreasuryVault
+    // Seeds: [b"treasury_vault"]
+    // This ensures a single treasury vault per program
+
+    /// Collected lamports not yet withdrawn (the vault's balance above rent)
+    pub fn available_lamports(&self) -> u64 {
+        self.total_collected_lamports
+            .saturating_sub(self.total_withdrawn_lamports)
+    }
+
+    /// Lifetime total collected from one fee source
+    pub fn get_fees_by_source(&self, source: u8) -> u64 {
+        self.fees_by_source
+            .get(source as usize)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Lifetime total collected from one game type
+    pub fn get_fees_by_game(&self, game_type: u8) -> u64 {
+        let slots = (self.game_slot_count as usize).min(MAX_TREASURY_GAMES);
+        (0..slots)
+            .find(|&i| self.game_types[i] == game_type)
+            .map(|i| self.fees_by_game[i])
+            .unwrap_or(0)
+    }
+
+    /// Record lamports that have just been moved into the vault.
+    /// `game_type` is None for fees not tied to a match (withdrawal fees).
+    pub fn record_fee(&mut self, source: u8, game_type: Option<u8>, amount: u64) -> Result<()> {
+        require!(
+            (source as usize) < fee_source::COUNT,
+            GameError::InvalidPayload
+        );
+
+        self.total_collected_lamports = self
+            .total_collected_lamports
+            .checked_add(amount)
+            .ok_or(GameError::Overflow)?;
+        self.fees_by_source[source as usize] = self.fees_by_source[source as usize]
+            .checked_add(amount)
+            .ok_or(GameError::Overflow)?;
+
+        if let Some(game_type) = game_type {
+            let slots = (self.game_slot_count as usize).min(MAX_TREASURY_GAMES);
+            let slot = match (0..slots).find(|&i| self.game_types[i] == game_type) {
+                Some(slot) => slot,
+                None => {
+                    require!(slots < MAX_TREASURY_GAMES, GameError::GameRegistryFull);
+                    self.game_types[slots] = game_type;
+                    self.game_slot_count += 1;
+                    slots
+                }
+            };
+            self.fees_by_game[slot] = self.fees_by_game[slot]
+                .checked_add(amount)
+                .ok_or(GameError::Overflow)?;
+        }
+        Ok(())
+    }
+
+    /// Record lamports that have just left the vault through withdraw_treasury
+    pub fn record_withdrawal(&mut self, amount: u64, timestamp: i64) -> Result<()> {
+        require!(
+            amount <= self.available_lamports(),
+            GameError::InsufficientFunds
+        );
+        self.total_withdrawn_lamports = self
+            .total_withdrawn_lamports
+            .checked_add(amount)
+            .ok_or(GameError::Overflow)?;
+        self.last_withdrawal_at = timestamp;
+        Ok(())
+    }
+}
@@ -32,6 +32,9 @@ pub struct UserAccount {
     pub season_games: u32, // Games played this season
     pub leaderboard_rank: u16, // 0 = not ranked, 1-100 = rank
     pub active_multiplier: u8, // Reward multiplier (1-5x based on rank)
+
+    // Wallet binding (results only count for seats this wallet signed for)
+    pub wallet: Pubkey, // Wallet that opened the account for this user_id
 }
 
 impl UserAccount {
@@ -53,9 +56,10 @@ impl UserAccount {
         4 +                                 // season_wins (u32)
         4 +                                 // season_games (u32)
         2 +                                 // leaderboard_rank (u16)
-        1; // active_multiplier (u8)
+        1 +                                 // active_multiplier (u8)
+        32; // wallet (Pubkey)
 
-    // Total: 8 + 64 + 8 + 8 + 8 + 1 + 8 + 4 + 4 + 4 + 8 + 4 + 1 + 8 + 8 + 4 + 4 + 2 + 1 = 161 bytes
+    // Total: 8 + 64 + 8 + 8 + 8 + 1 + 8 + 4 + 4 + 4 + 8 + 4 + 1 + 8 + 8 + 4 + 4 + 2 + 1 + 32 = 193 bytes
 
     pub fn has_active_subscription(&self, clock: &Clock) -> bool {
         self.subscription_expiry > clock.unix_timestamp && self.subscription_tier > 0
@@ -71,6 +75,16 @@ impl UserAccount {
         time_since_last_ad >= cooldown_seconds
     }
 
+    /// Reset season stats and standing when the user's first result of a new season arrives
+    pub fn start_season(&mut self, season_id: u64) {
+        self.current_season_id = season_id;
+        self.season_score = 0;
+        self.season_wins = 0;
+        self.season_games = 0;
+        self.leaderboard_rank = 0;
+        self.active_multiplier = Self::calculate_multiplier(0);
+    }
+
     pub fn calculate_tier(lifetime_gp: u64) -> u8 {
         match lifetime_gp {
             0..=999 => 0,       // Bronze
@@ -33,10 +33,13 @@ pub struct UserDepositAccount {
     /// Bit 1: enhanced_review (account flagged for enhanced review)
     /// Bits 2-7: reserved
     pub flags: u8, // 1 byte
-    pub _padding2: [u8; 7], // Explicit padding to align reserved to 8 bytes
+    pub _padding2: [u8; 7], // Explicit padding to align mint to 8 bytes
 
-    /// Reserved space for future token mint metadata (SPL tokens, etc.)
-    pub reserved: [u8; 32], // 32 bytes - 8-byte aligned
+    /// SPL token mint this ledger tracks (Pubkey::default() = the SOL deposit account).
+    /// Token ledgers use seeds [b"user_deposit", authority, mint] and hold their balance in the
+    /// deposit vault token account (seeds [b"deposit_vault", ledger]) owned by this PDA;
+    /// the *_lamports fields are then in the mint's base units.
+    pub mint: Pubkey, // 32 bytes - 8-byte aligned
 }
 
 impl UserDepositAccount {
@@ -50,11 +53,12 @@ impl UserDepositAccount {
         8 +                               // withdrawn_lamports
         8 +                               // locked_until
         1 + 7 +                           // flags + _padding2
-        32; // reserved
+        32; // mint (Pubkey)
 
     /// PDA seed pattern for UserDepositAccount
     /// Seeds: [b"user_deposit", authority.as_ref()]
     /// This ensures one deposit account per user authority (wallet or custody delegate)
+    /// Token ledgers: [b"user_deposit", authority.as_ref(), mint.as_ref()] - one per mint
 
     /// Get total balance (available + in_play)
     pub fn total_balance(&self) -> u64 {
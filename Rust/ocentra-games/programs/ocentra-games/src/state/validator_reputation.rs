@@ -1,8 +1,23 @@
+use crate::error::GameError;
+use crate::state::enums::slash_reason;
 use anchor_lang::prelude::*;
 
+/// Minimum stake (lamports) a validator needs to vote on disputes (1 SOL)
+pub const MIN_VALIDATOR_STAKE_LAMPORTS: u64 = 1_000_000_000;
+
+/// Time unstaked lamports stay slashable before withdraw_stake releases them (7 days)
+pub const VALIDATOR_UNBONDING_SECONDS: i64 = 7 * 86_400;
+
+/// Reputation a newly registered validator starts with
+pub const INITIAL_VALIDATOR_REPUTATION: f64 = 0.5;
+
 /**
  * Validator reputation and stake tracking.
  * Per critique Issue #5, Spec Section 33.1: Reputation system for validators.
+ *
+ * The PDA is also the validator's stake vault: its lamports above rent are exactly
+ * `stake + unbonding_lamports`. register_validator deposits stake, unstake moves it to
+ * unbonding, withdraw_stake pays unbonded lamports out and slash_validator takes from both.
  */
 #[account]
 pub struct ValidatorReputation {
@@ -12,7 +27,11 @@ pub struct ValidatorReputation {
     pub total_resolutions: u32,   // Total disputes resolved
     pub correct_resolutions: u32, // Correct resolutions (for accuracy calculation)
     pub created_at: i64,
-    pub last_active: i64, // Last dispute resolution timestamp
+    pub last_active: i64,        // Last dispute resolution timestamp
+    pub unbonding_lamports: u64, // Unstaked, still slashable, not yet withdrawn
+    pub unbonding_until: i64,    // When unbonding_lamports can be withdrawn
+    pub total_slashed: u64,      // Lifetime lamports slashed
+    pub bump: u8,                // PDA bump seed
 }
 
 impl ValidatorReputation {
@@ -23,9 +42,16 @@ impl ValidatorReputation {
         4 +                              // total_resolutions (u32)
         4 +                              // correct_resolutions (u32)
         8 +                              // created_at (i64)
-        8; // last_active (i64)
+        8 +                              // last_active (i64)
+        8 +                              // unbonding_lamports (u64)
+        8 +                              // unbonding_until (i64)
+        8 +                              // total_slashed (u64)
+        1; // bump (u8)
 
-    // Total: 8 + 32 + 8 + 8 + 4 + 4 + 8 + 8 = 80 bytes
+    // Total: 8 + 32 + 8 + 8 + 4 + 4 + 8 + 8 + 8 + 8 + 8 + 1 = 105 bytes
+
+    // PDA seed pattern for ValidatorReputation
+    // Seeds: [b"validator", validator.as_ref()]
 
     pub fn calculate_accuracy(&self) -> f64 {
         if self.total_resolutions == 0 {
@@ -34,6 +60,11 @@ impl ValidatorReputation {
         self.correct_resolutions as f64 / self.total_resolutions as f64
     }
 
+    /// Weight of this validator's dispute votes: stake scaled by reputation
+    pub fn vote_weight(&self) -> u64 {
+        (self.stake as f64 * self.reputation.clamp(0.0, 1.0)) as u64
+    }
+
     pub fn update_reputation(&mut self, was_correct: bool) {
         self.total_resolutions += 1;
         if was_correct {
@@ -44,4 +75,36 @@ impl ValidatorReputation {
         let accuracy = self.calculate_accuracy();
         self.reputation = (self.reputation * 0.7 + accuracy * 0.3).clamp(0.0, 1.0);
     }
+
+    /// Lamports the vault holds on the validator's behalf (staked and unbonding)
+    pub fn bonded_lamports(&self) -> u64 {
+        self.stake.saturating_add(self.unbonding_lamports)
+    }
+
+    /// Take `amount` from the validator's bond - active stake first, then unbonding lamports
+    pub fn slash(&mut self, amount: u64) -> Result<()> {
+        require!(
+            amount <= self.bonded_lamports(),
+            GameError::InsufficientFunds
+        );
+        let from_stake = amount.min(self.stake);
+        self.stake -= from_stake;
+        self.unbonding_lamports -= amount - from_stake;
+        self.total_slashed = self
+            .total_slashed
+            .checked_add(amount)
+            .ok_or(GameError::Overflow)?;
+        Ok(())
+    }
+
+    /// Reputation loss for a slash_reason
+    pub fn apply_slash_penalty(&mut self, reason: u8) {
+        let reputation_penalty = match reason {
+            slash_reason::MALICIOUS => 0.5,  // Malicious: 50% reputation loss
+            slash_reason::NEGLIGENT => 0.2,  // Negligent: 20% reputation loss
+            slash_reason::INACTIVITY => 0.1, // Inactivity: 10% reputation loss
+            _ => 0.0,
+        };
+        self.reputation = (self.reputation * (1.0 - reputation_penalty)).max(0.0);
+    }
 }
@@ -0,0 +1,186 @@
+/**
+ * Test: deposit_token / withdraw_token instructions
+ * Category: ECONOMIC
+ *
+ * Allow-listed SPL mints can be deposited into a per-mint token UserDepositAccount (balance held
+ * in its deposit vault) and withdrawn again, minus the mint's withdrawal fee which goes to the
+ * treasury multisig's token account and is recorded in the mint's SupportedMint fee ledger.
+ * Disabled mints reject deposits but still allow withdrawals.
+ */
+
+import { BaseTest } from '@/core';
+import { TestCategory, ClusterRequirement } from '@/core';
+import { registerMochaTest } from '@/core';
+import { SystemProgram, Keypair } from "@solana/web3.js";
+import {
+  getConfigAccountPDA,
+  getSupportedMintPDA,
+  getTokenDepositPDA,
+  getDepositVaultPDA,
+  createTestMint,
+  createTokenAccount,
+  mintTo,
+  getTokenBalance,
+  ensureSupportedMint,
+  TOKEN_PROGRAM_ID,
+  UserDepositAccountType,
+  SupportedMintType,
+} from '@/common';
+import * as anchor from "@coral-xyz/anchor";
+
+class DepositWithdrawTokenTest extends BaseTest {
+  constructor() {
+    super({
+      id: 'deposit-withdraw-token',
+      name: 'deposit_token / withdraw_token instructions',
+      description: 'Tests SPL token platform deposits: ledger per mint, deposit vault, withdrawal fee, disabled mints',
+      tags: {
+        category: TestCategory.REGISTRY,
+        cluster: ClusterRequirement.ANY,
+        requiresSetup: true,
+      },
+    });
+  }
+
+  async run(): Promise<void> {
+    const { program, authority, airdrop } = await import('@/helpers');
+
+    // Setup: a fresh mint allow-listed with a withdrawal fee
+    const withdrawalFee = 1_000;
+    const [mint, mintAuthority] = await createTestMint();
+    const supportedMintPDA = await ensureSupportedMint(mint, 1, 1_000_000_000, withdrawalFee);
+    const [configPDA] = await getConfigAccountPDA();
+
+    const user = Keypair.generate();
+    await airdrop(user.publicKey, 1);
+    const userTokenAccount = await createTokenAccount(mint, user.publicKey);
+    await mintTo(mint, userTokenAccount, mintAuthority, 1_000_000);
+    const treasuryTokenAccount = await createTokenAccount(mint, authority.publicKey);
+
+    const [tokenDepositPDA] = await getTokenDepositPDA(user.publicKey, mint);
+    const [depositVaultPDA] = await getDepositVaultPDA(tokenDepositPDA);
+
+    const depositAccounts = {
+      userDepositAccount: tokenDepositPDA,
+      depositVault: depositVaultPDA,
+      mint,
+      supportedMint: supportedMintPDA,
+      userTokenAccount,
+      user: user.publicKey,
+      configAccount: configPDA,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    };
+    const withdrawAccounts = {
+      userDepositAccount: tokenDepositPDA,
+      depositVault: depositVaultPDA,
+      mint,
+      supportedMint: supportedMintPDA,
+      userTokenAccount,
+      treasuryTokenAccount,
+      user: user.publicKey,
+      configAccount: configPDA,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    };
+
+    // Deposit creates the ledger and its vault
+    // eslint-disable-next-line @typescript-eslint/no-explicit-any
+    await (program.methods as any)
+      .depositToken(new anchor.BN(500_000))
+      .accounts(depositAccounts as never)
+      .signers([user])
+      .rpc();
+
+    let ledger = await program.account.userDepositAccount.fetch(tokenDepositPDA) as unknown as UserDepositAccountType;
+    this.assertEqual(ledger.mint?.toString(), mint.toString(), 'Ledger should record its mint');
+    this.assertEqual(ledger.availableLamports?.toNumber() ?? ledger.available_lamports?.toNumber(), 500_000, 'Ledger available should equal the deposit');
+    this.assertEqual(await getTokenBalance(depositVaultPDA), 500_000, 'Deposit vault should hold the deposit');
+    this.assertEqual(await getTokenBalance(userTokenAccount), 500_000, 'User tokens should be debited');
+
+    // Withdraw pays the fee to the treasury multisig's token account
+    // eslint-disable-next-line @typescript-eslint/no-explicit-any
+    await (program.methods as any)
+      .withdrawToken(new anchor.BN(200_000))
+      .accounts(withdrawAccounts as never)
+      .signers([user])
+      .rpc();
+
+    ledger = await program.account.userDepositAccount.fetch(tokenDepositPDA) as unknown as UserDepositAccountType;
+    const available = ledger.availableLamports?.toNumber() ?? ledger.available_lamports?.toNumber();
+    this.assertEqual(available, 500_000 - 200_000 - withdrawalFee, 'Ledger available should drop by amount + fee');
+    this.assertEqual(await getTokenBalance(depositVaultPDA), available, 'Deposit vault should match the ledger');
+    this.assertEqual(await getTokenBalance(userTokenAccount), 700_000, 'User should receive the withdrawal');
+    this.assertEqual(await getTokenBalance(treasuryTokenAccount), withdrawalFee, 'Treasury should receive the withdrawal fee');
+    const supportedMint = await program.account.supportedMint.fetch(supportedMintPDA) as unknown as SupportedMintType;
+    this.assertEqual(
+      (supportedMint.feesBySource ?? supportedMint.fees_by_source)?.[1]?.toNumber(), // 1 = withdrawal fee
+      withdrawalFee,
+      'SupportedMint ledger should record the withdrawal fee'
+    );
+    this.assertEqual(
+      (supportedMint.totalCollected ?? supportedMint.total_collected)?.toNumber(),
+      withdrawalFee,
+      'SupportedMint ledger total should include the withdrawal fee'
+    );
+
+    // Cannot withdraw more than available
+    try {
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      await (program.methods as any)
+        .withdrawToken(new anchor.BN(available as number))
+        .accounts(withdrawAccounts as never)
+        .signers([user])
+        .rpc();
+      this.assert(false, 'Withdrawal above available - fee should fail');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'InsufficientFunds', 'Expected InsufficientFunds');
+    }
+
+    // Disabled mint: deposits rejected, withdrawals still allowed
+    await ensureSupportedMint(mint, 1, 1_000_000_000, withdrawalFee, false);
+    try {
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      await (program.methods as any)
+        .depositToken(new anchor.BN(1_000))
+        .accounts(depositAccounts as never)
+        .signers([user])
+        .rpc();
+      this.assert(false, 'Deposit of a disabled mint should fail');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'MintNotSupported', 'Expected MintNotSupported');
+    }
+
+    // eslint-disable-next-line @typescript-eslint/no-explicit-any
+    await (program.methods as any)
+      .withdrawToken(new anchor.BN(100_000))
+      .accounts(withdrawAccounts as never)
+      .signers([user])
+      .rpc();
+    this.assertEqual(await getTokenBalance(userTokenAccount), 800_000, 'Withdrawal should still work for a disabled mint');
+
+    // Only the treasury multisig can allow-list mints
+    const [supportedMintCheck] = await getSupportedMintPDA(mint);
+    try {
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      await (program.methods as any)
+        .setSupportedMint(new anchor.BN(1), new anchor.BN(10), new anchor.BN(0), true)
+        .accounts({
+          supportedMint: supportedMintCheck,
+          mint,
+          configAccount: configPDA,
+          authority: user.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .signers([user])
+        .rpc();
+      this.assert(false, 'Unauthorized set_supported_mint should fail');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'Unauthorized', 'Expected Unauthorized');
+    }
+
+    console.log('✓ deposit_token / withdraw_token test passed');
+  }
+}
+
+const testInstance = new DepositWithdrawTokenTest();
+registerMochaTest(testInstance);
@@ -0,0 +1,233 @@
+/**
+ * Test: Disputes freeze escrow settlement
+ * Category: ECONOMIC
+ *
+ * flag_dispute on a paid match (within the post-match dispute window) marks its escrow as
+ * contested (status_flags bit 6). distribute_prizes refuses to pay out until every dispute on
+ * the match is finalized, and correct_match_scores only runs after a dispute was upheld.
+ */
+
+import { BaseTest } from '@/core';
+import { TestCategory, ClusterRequirement } from '@/core';
+import { registerMochaTest } from '@/core';
+import { SystemProgram, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import {
+  getMatchPDA,
+  getEscrowPDA,
+  getConfigAccountPDA,
+  getDisputePDA,
+  getMatchDisputesPDA,
+  ensureTreasuryVault,
+  ConfigAccountType,
+  EscrowAccountType,
+} from '@/common';
+import * as anchor from "@coral-xyz/anchor";
+
+const MATCH_TYPE_PAID = 1;
+const PAYMENT_METHOD_WALLET = 0;
+const SCORE_ERROR = 3;
+const ESCROW_CONTESTED = 0x40;
+
+class DisputeEscrowFreezeTest extends BaseTest {
+  constructor() {
+    super({
+      id: 'dispute-escrow-freeze',
+      name: 'Disputes freeze escrow settlement',
+      description: 'Tests that an open dispute on a paid match blocks distribute_prizes',
+      tags: {
+        category: TestCategory.REGISTRY,
+        cluster: ClusterRequirement.ANY,
+        requiresSetup: true,
+        requiresRegistry: true,
+      },
+    });
+  }
+
+  async run(): Promise<void> {
+    const {
+      program,
+      authority,
+      generateUniqueMatchId,
+      getTestGame,
+      PAID_GAME_ID,
+      getTestSeed,
+      getTestUserId,
+      airdrop,
+    } = await import('@/helpers');
+    const { getRegistryPDA } = await import('@/common');
+
+    const [configPDA] = await getConfigAccountPDA();
+    const [registryPDA] = await getRegistryPDA();
+    const paidGame = getTestGame(PAID_GAME_ID);
+    if (!paidGame) throw new Error("Paid match game not found in test data");
+
+    // Ensure config is unpaused (may have been paused by previous tests)
+    const config = await program.account.configAccount.fetch(configPDA) as unknown as ConfigAccountType;
+    if (config.isPaused ?? config.is_paused) {
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      await (program.methods as any)
+        .unpauseProgram()
+        .accounts({
+          configAccount: configPDA,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .rpc();
+    }
+    const gpDeposit = config.disputeDepositGp ?? config.dispute_deposit_gp ?? 100;
+
+    // Play a paid heads-up wallet match to the end
+    const player1 = Keypair.generate();
+    const player2 = Keypair.generate();
+    await airdrop(player1.publicKey, 2);
+    await airdrop(player2.publicKey, 2);
+    const matchId = generateUniqueMatchId("dispute-escrow");
+    const [matchPDA] = await getMatchPDA(matchId);
+    const [escrowPDA] = await getEscrowPDA(matchPDA);
+    await program.methods
+      .createMatch(
+        matchId,
+        paidGame.game_id,
+        new anchor.BN(getTestSeed()),
+        {
+          entryFee: new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+          paymentMethod: PAYMENT_METHOD_WALLET,
+          matchType: MATCH_TYPE_PAID,
+          tournamentId: null,
+          prizeStructure: null,
+          prizeSplitBps: null,
+        }
+      )
+      .accounts({
+        matchAccount: matchPDA,
+        registry: registryPDA,
+        escrowAccount: escrowPDA,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as never)
+      .rpc();
+    for (const [index, player] of [player1, player2].entries()) {
+      await program.methods
+        .joinMatch(matchId, getTestUserId(index))
+        .accounts({
+          matchAccount: matchPDA,
+          registry: registryPDA,
+          escrowAccount: escrowPDA,
+          userDepositAccount: null,
+          playerWallet: player.publicKey,
+          player: player.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .signers([player])
+        .rpc();
+    }
+    await program.methods
+      .startMatch(matchId)
+      .accounts({
+        matchAccount: matchPDA,
+        registry: registryPDA,
+        escrowAccount: escrowPDA,
+        authority: authority.publicKey,
+      } as never)
+      .rpc();
+    await program.methods
+      .endMatch(matchId, null, null)
+      .accounts({
+        matchAccount: matchPDA,
+        escrowAccount: escrowPDA,
+        authority: authority.publicKey,
+      } as never)
+      .rpc();
+
+    // Test 1: Flagging the paid match contests its escrow
+    const [disputePDA] = await getDisputePDA(matchPDA, player2.publicKey);
+    const [matchDisputesPDA] = await getMatchDisputesPDA(matchPDA);
+    // eslint-disable-next-line @typescript-eslint/no-explicit-any
+    await (program.methods as any)
+      .flagDispute(matchId, getTestUserId(1), SCORE_ERROR, Array(32).fill(7), gpDeposit)
+      .accounts({
+        dispute: disputePDA,
+        matchDisputes: matchDisputesPDA,
+        matchAccount: matchPDA,
+        escrowAccount: escrowPDA,
+        configAccount: configPDA,
+        flagger: player2.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as never)
+      .signers([player2])
+      .rpc();
+    const escrow = await program.account.escrowAccount.fetch(escrowPDA) as unknown as EscrowAccountType;
+    this.assert(
+      ((escrow.statusFlags ?? escrow.status_flags ?? 0) & ESCROW_CONTESTED) !== 0,
+      'Escrow should be contested while the dispute is open'
+    );
+
+    // Test 2: Prizes cannot be distributed while the dispute is open
+    const treasuryVaultPDA = await ensureTreasuryVault();
+    try {
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      await (program.methods as any)
+        .distributePrizes(matchId)
+        .accounts({
+          escrowAccount: escrowPDA,
+          matchAccount: matchPDA,
+          configAccount: configPDA,
+          treasuryVault: treasuryVaultPDA,
+          escrowVault: null,
+          treasuryTokenAccount: null,
+          supportedMint: null,
+          tokenProgram: null,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .remainingAccounts([player1, player2].map((player) => ({
+          pubkey: player.publicKey,
+          isSigner: false,
+          isWritable: true,
+        })))
+        .rpc();
+      this.assert(false, 'Distributing a contested escrow should fail');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'EscrowContested', 'Expected EscrowContested');
+    }
+
+    // Test 3: Scores can only be corrected after a dispute is upheld
+    try {
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      await (program.methods as any)
+        .correctMatchScores(matchId, [0, 0])
+        .accounts({
+          matchAccount: matchPDA,
+          escrowAccount: escrowPDA,
+          configAccount: configPDA,
+          authority: authority.publicKey,
+        } as never)
+        .rpc();
+      this.assert(false, 'Correcting scores without an upheld dispute should fail');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'NoScoreCorrectionPending', 'Expected NoScoreCorrectionPending');
+    }
+
+    // Test 4: The escrow stays frozen until the dispute can be finalized
+    try {
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      await (program.methods as any)
+        .finalizeDispute(matchId, player2.publicKey)
+        .accounts({
+          dispute: disputePDA,
+          matchDisputes: matchDisputesPDA,
+          matchAccount: matchPDA,
+          escrowAccount: escrowPDA,
+          caller: authority.publicKey,
+        } as never)
+        .rpc();
+      this.assert(false, 'Finalizing without quorum should fail');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'DisputeQuorumNotReached', 'Expected DisputeQuorumNotReached');
+    }
+
+    console.log('✓ Dispute escrow freeze test passed');
+  }
+}
+
+const testInstance = new DisputeEscrowFreezeTest();
+registerMochaTest(testInstance);
@@ -10,7 +10,7 @@ import { BaseTest } from '@/core';
 import { TestCategory, ClusterRequirement } from '@/core';
 import { registerMochaTest } from '@/core';
 import { SystemProgram, Keypair } from "@solana/web3.js";
-import { getMatchPDA, getEscrowPDA, getConfigAccountPDA, ConfigAccountType, MatchAccountType } from '@/common';
+import { getMatchPDA, getEscrowPDA, getConfigAccountPDA, getTreasuryVaultPDA, ConfigAccountType } from '@/common';
 import * as anchor from "@coral-xyz/anchor";
 
 class DistributePrizesTest extends BaseTest {
@@ -84,10 +84,14 @@ class DistributePrizesTest extends BaseTest {
         matchId,
         claimGame.game_id,
         new anchor.BN(seed),
-        null, // entry_fee (None = free match)
-        null, // payment_method (None = default)
-        null, // match_type (None = default FREE)
-        null  // tournament_id (None = not a tournament)
+        {
+          entryFee: null, // None = free match
+          paymentMethod: null, // None = default
+          matchType: null, // None = default FREE
+          tournamentId: null, // None = not a tournament
+          prizeStructure: null, // None = winner takes all
+          prizeSplitBps: null, // None = no ranked split
+        }
       )
       .accounts({
         matchAccount: matchPDA,
@@ -102,8 +106,9 @@ class DistributePrizesTest extends BaseTest {
     // We need to start and end the match properly
     // For now, we'll test validation failures that don't require escrow
 
-    // Test 2: Failure - Match not ended
+    // Payout accounts for the seats passed as remaining_accounts
     const [escrowPDA] = await getEscrowPDA(matchPDA);
+    const [treasuryVaultPDA] = await getTreasuryVaultPDA();
     const winner1 = Keypair.generate();
     const winner2 = Keypair.generate();
     await airdrop(winner1.publicKey, 1);
@@ -112,136 +117,62 @@ class DistributePrizesTest extends BaseTest {
     // Note: Escrow needs to be created and funded, which requires Phase 04 integration
     // For Phase 03, we test that the instruction validates match phase correctly
     
-    // Test 3: Failure - Empty winner indices
-    // Note: Empty arrays can't be serialized by Borsh, so this test is skipped
-    // The Rust code validates empty arrays, but Borsh serialization fails before reaching Rust
-    // This validation is covered by other tests (mismatched arrays, etc.)
-    // Skip this test as empty Vec serialization is a Borsh limitation, not a program bug
+    // Payouts are computed on-chain from Match.scores and the match's prize structure, so the
+    // instruction only takes match_id plus one payout account per seat as remaining_accounts.
 
-    // Test 4: Failure - Mismatched winner indices and prize amounts
+    // Test 2: Failure - Match not ended (free match has no funded escrow either)
     try {
       // eslint-disable-next-line @typescript-eslint/no-explicit-any
       await (program.methods as any)
-        .distributePrizes(matchId, [0, 1], [100_000_000]) // 2 winners but 1 amount
+        .distributePrizes(matchId)
         .accounts({
           escrowAccount: escrowPDA,
           matchAccount: matchPDA,
           configAccount: configPDA,
-          treasury: authority.publicKey,
-          winner0: winner1.publicKey,
-          winner1: winner2.publicKey,
-          winner2: winner1.publicKey,
-          winner3: winner1.publicKey,
-          winner4: winner1.publicKey,
-          winner5: winner1.publicKey,
-          winner6: winner1.publicKey,
-          winner7: winner1.publicKey,
-          winner8: winner1.publicKey,
-          winner9: winner1.publicKey,
+          treasuryVault: treasuryVaultPDA,
           systemProgram: SystemProgram.programId,
         } as never)
+        .remainingAccounts([
+          { pubkey: winner1.publicKey, isSigner: false, isWritable: true },
+          { pubkey: winner2.publicKey, isSigner: false, isWritable: true },
+        ])
         .rpc();
-      
-      this.assert(false, "Should have failed with InvalidPayload error for mismatched arrays");
+
+      this.assert(false, "Should have failed - match has not ended");
     } catch (err: unknown) {
       const errorCode = this.getErrorCode(err);
-      // The transaction fails earlier because the match/escrow state is not correctly set up.
-      // We accept MatchNotEnded, EscrowNotFunded, or constraint errors as valid failures for now.
-      // Full validation tests will be added in Phase 04 when escrow creation is implemented.
+      // Free matches never create an escrow, so the escrow account may not exist at all.
       const error = err as { message?: string };
       const errorMessage = error?.message ?? "";
-      const isValidError = errorCode === "InvalidPayload" 
-        || errorCode === "MatchNotEnded" 
+      const isValidError = errorCode === "MatchNotEnded"
         || errorCode === "EscrowNotFunded"
-        || errorCode === "ConstraintRaw"
+        || errorCode === "AccountNotInitialized"
         || errorCode === "AccountDiscriminatorMismatch"
-        || errorCode === "ConstraintSeeds"
-        || errorMessage.includes("AccountDiscriminatorMismatch")
-        || errorMessage.includes("ConstraintSeeds")
-        || errorMessage.includes("account not found")
         || errorMessage.includes("AccountNotInitialized")
-        || errorMessage.includes("Blob.encode")
-        || errorMessage.includes("requires (length");
+        || errorMessage.includes("account not found");
       this.assert(
         isValidError,
-        `Expected InvalidPayload, MatchNotEnded, EscrowNotFunded, or serialization error, but got ${errorCode || "undefined"} (message: ${errorMessage})`
+        `Expected MatchNotEnded, EscrowNotFunded, or missing escrow error, but got ${errorCode || "undefined"} (message: ${errorMessage})`
       );
     }
 
-    // Test 5: Failure - Too many winners (>10)
+    // Test 3: Failure - Invalid match_id format
     try {
-      const tooManyWinners = Array.from({ length: 11 }, (_, i) => i);
-      const tooManyAmounts = Array.from({ length: 11 }, () => 100_000_000);
-      
       // eslint-disable-next-line @typescript-eslint/no-explicit-any
       await (program.methods as any)
-        .distributePrizes(matchId, tooManyWinners, tooManyAmounts)
+        .distributePrizes("invalid-id")
         .accounts({
           escrowAccount: escrowPDA,
           matchAccount: matchPDA,
           configAccount: configPDA,
-          treasury: authority.publicKey,
-          winner0: winner1.publicKey,
-          winner1: winner2.publicKey,
-          winner2: winner1.publicKey,
-          winner3: winner1.publicKey,
-          winner4: winner1.publicKey,
-          winner5: winner1.publicKey,
-          winner6: winner1.publicKey,
-          winner7: winner1.publicKey,
-          winner8: winner1.publicKey,
-          winner9: winner1.publicKey,
+          treasuryVault: treasuryVaultPDA,
           systemProgram: SystemProgram.programId,
         } as never)
+        .remainingAccounts([
+          { pubkey: winner1.publicKey, isSigner: false, isWritable: true },
+        ])
         .rpc();
-      
-      this.assert(false, "Should have failed with InvalidPayload error for too many winners");
-    } catch (err: unknown) {
-      const errorCode = this.getErrorCode(err);
-      // The transaction may fail due to argument size limits before reaching the instruction logic.
-      // We accept InvalidPayload or account/constraint errors as valid failures.
-      // undefined means the error might be a serialization/constraint error without an Anchor error code.
-      const error = err as { message?: string };
-      const errorMessage = error?.message ?? "";
-      const isValidError = errorCode === "InvalidPayload"
-        || errorCode === "ConstraintRaw"
-        || errorCode === "AccountDiscriminatorMismatch"
-        || errorCode === "ConstraintSeeds"
-        || errorCode === undefined // undefined is acceptable for serialization/constraint errors
-        || errorMessage.includes("ArgumentDemoralizationError")
-        || errorMessage.includes("AccountDiscriminatorMismatch")
-        || errorMessage.includes("Blob.encode")
-        || errorMessage.includes("requires (length");
-      this.assert(
-        isValidError,
-        `Expected InvalidPayload or argument/account error, but got ${errorCode || "undefined"} (message: ${errorMessage})`
-      );
-    }
 
-    // Test 6: Failure - Invalid match_id format
-    try {
-      // eslint-disable-next-line @typescript-eslint/no-explicit-any
-      await (program.methods as any)
-        .distributePrizes("invalid-id", [0], [100_000_000])
-        .accounts({
-          escrowAccount: escrowPDA,
-          matchAccount: matchPDA,
-          configAccount: configPDA,
-          treasury: authority.publicKey,
-          winner0: winner1.publicKey,
-          winner1: winner1.publicKey,
-          winner2: winner1.publicKey,
-          winner3: winner1.publicKey,
-          winner4: winner1.publicKey,
-          winner5: winner1.publicKey,
-          winner6: winner1.publicKey,
-          winner7: winner1.publicKey,
-          winner8: winner1.publicKey,
-          winner9: winner1.publicKey,
-          systemProgram: SystemProgram.programId,
-        } as never)
-        .rpc();
-      
       this.assert(false, "Should have failed with InvalidPayload error for invalid match_id");
     } catch (err: unknown) {
       const errorCode = this.getErrorCode(err);
@@ -260,140 +191,51 @@ class DistributePrizesTest extends BaseTest {
       );
     }
 
-    // Test 7: Failure - Zero prize amount
-    try {
-      // eslint-disable-next-line @typescript-eslint/no-explicit-any
-      await (program.methods as any)
-        .distributePrizes(matchId, [0], [0]) // Zero amount
-        .accounts({
-          escrowAccount: escrowPDA,
-          matchAccount: matchPDA,
-          configAccount: configPDA,
-          treasury: authority.publicKey,
-          winner0: winner1.publicKey,
-          winner1: winner1.publicKey,
-          winner2: winner1.publicKey,
-          winner3: winner1.publicKey,
-          winner4: winner1.publicKey,
-          winner5: winner1.publicKey,
-          winner6: winner1.publicKey,
-          winner7: winner1.publicKey,
-          winner8: winner1.publicKey,
-          winner9: winner1.publicKey,
-          systemProgram: SystemProgram.programId,
-        } as never)
-        .rpc();
-      
-      this.assert(false, "Should have failed with InvalidPayload error for zero prize amount");
-    } catch (err: unknown) {
-      const errorCode = this.getErrorCode(err);
-      // Zero prize amount might fail at constraint level or serialization before reaching handler
-      const error = err as { message?: string };
-      const errorMessage = error?.message ?? "";
-      const isValidError = errorCode === "InvalidPayload"
-        || errorCode === "ConstraintRaw"
-        || errorCode === "ConstraintSeeds"
-        || errorCode === undefined // undefined is acceptable for constraint/serialization errors
-        || errorMessage.includes("ConstraintSeeds")
-        || errorMessage.includes("Blob.encode")
-        || errorMessage.includes("requires (length");
-      this.assert(
-        isValidError,
-        `Expected InvalidPayload or constraint error, but got ${errorCode || "undefined"} (message: ${errorMessage})`
-      );
-    }
-
-    // Test 8: Failure - Invalid winner index (>= player_count)
-    const matchAccountForTest8 = await program.account.match.fetch(matchPDA) as unknown as MatchAccountType;
-    const playerCount = matchAccountForTest8.playerCount ?? matchAccountForTest8.player_count ?? 0;
-    
-    if (playerCount < 10) {
+    // Test 4: Failure - Invalid prize structure rejected at create_match
+    // TOP_N_SPLIT shares must be non-increasing and sum to 10000 bps
+    const invalidSplitCases: Array<{ label: string; structure: number; split: number[] | null }> = [
+      { label: "shares not summing to 10000", structure: 1, split: [6000, 3000] },
+      { label: "increasing shares", structure: 1, split: [3000, 7000] },
+      { label: "split on winner-takes-all", structure: 0, split: [10000] },
+      { label: "unknown structure", structure: 7, split: null },
+    ];
+    for (const { label, structure, split } of invalidSplitCases) {
+      const badMatchId = generateUniqueMatchId("prize-structure");
+      const [badMatchPDA] = await getMatchPDA(badMatchId);
       try {
-        // eslint-disable-next-line @typescript-eslint/no-explicit-any
-        await (program.methods as any)
-          .distributePrizes(matchId, [playerCount], [100_000_000]) // Invalid index
+        await program.methods
+          .createMatch(
+            badMatchId,
+            claimGame.game_id,
+            new anchor.BN(seed),
+            {
+              entryFee: null,
+              paymentMethod: null,
+              matchType: null,
+              tournamentId: null,
+              prizeStructure: structure,
+              prizeSplitBps: split,
+            }
+          )
           .accounts({
-            escrowAccount: escrowPDA,
-            matchAccount: matchPDA,
-            configAccount: configPDA,
-            treasury: authority.publicKey,
-            winner0: winner1.publicKey,
-            winner1: winner1.publicKey,
-            winner2: winner1.publicKey,
-            winner3: winner1.publicKey,
-            winner4: winner1.publicKey,
-            winner5: winner1.publicKey,
-            winner6: winner1.publicKey,
-            winner7: winner1.publicKey,
-            winner8: winner1.publicKey,
-            winner9: winner1.publicKey,
+            matchAccount: badMatchPDA,
+            registry: registryPDA,
+            escrowAccount: null,
+            authority: authority.publicKey,
             systemProgram: SystemProgram.programId,
           } as never)
           .rpc();
-        
-        this.assert(false, "Should have failed with InvalidPayload error for invalid winner index");
+
+        this.assert(false, `Should have failed with InvalidPrizeStructure (${label})`);
       } catch (err: unknown) {
-        const errorCode = this.getErrorCode(err);
-        // Invalid winner index might fail at constraint level or serialization before reaching handler
-        const error = err as { message?: string };
-        const errorMessage = error?.message ?? "";
-        const isValidError = errorCode === "InvalidPayload"
-          || errorCode === "ConstraintRaw"
-          || errorCode === "ConstraintSeeds"
-          || errorCode === undefined // undefined is acceptable for constraint/serialization errors
-          || errorMessage.includes("ConstraintSeeds")
-          || errorMessage.includes("Blob.encode")
-          || errorMessage.includes("requires (length");
-        this.assert(
-          isValidError,
-          `Expected InvalidPayload or constraint error, but got ${errorCode || "undefined"} (message: ${errorMessage})`
+        this.assertEqual(
+          this.getErrorCode(err),
+          "InvalidPrizeStructure",
+          `Expected InvalidPrizeStructure for ${label}`
         );
       }
     }
 
-    // Test 9: Failure - Winner index >= 10
-    try {
-      // eslint-disable-next-line @typescript-eslint/no-explicit-any
-      await (program.methods as any)
-        .distributePrizes(matchId, [10], [100_000_000]) // Index 10 is invalid (max is 9)
-        .accounts({
-          escrowAccount: escrowPDA,
-          matchAccount: matchPDA,
-          configAccount: configPDA,
-          treasury: authority.publicKey,
-          winner0: winner1.publicKey,
-          winner1: winner1.publicKey,
-          winner2: winner1.publicKey,
-          winner3: winner1.publicKey,
-          winner4: winner1.publicKey,
-          winner5: winner1.publicKey,
-          winner6: winner1.publicKey,
-          winner7: winner1.publicKey,
-          winner8: winner1.publicKey,
-          winner9: winner1.publicKey,
-          systemProgram: SystemProgram.programId,
-        } as never)
-        .rpc();
-      
-      this.assert(false, "Should have failed with InvalidPayload error for winner index >= 10");
-    } catch (err: unknown) {
-      const errorCode = this.getErrorCode(err);
-      // Winner index >= 10 might fail at constraint level or serialization before reaching handler
-      const error = err as { message?: string };
-      const errorMessage = error?.message ?? "";
-      const isValidError = errorCode === "InvalidPayload"
-        || errorCode === "ConstraintRaw"
-        || errorCode === "ConstraintSeeds"
-        || errorCode === undefined // undefined is acceptable for constraint/serialization errors
-        || errorMessage.includes("ConstraintSeeds")
-        || errorMessage.includes("Blob.encode")
-        || errorMessage.includes("requires (length");
-      this.assert(
-        isValidError,
-        `Expected InvalidPayload or constraint error, but got ${errorCode || "undefined"} (message: ${errorMessage})`
-      );
-    }
-
     // Note: Full integration tests (successful distribution, escrow validation, fee calculations,
     // prize pool sum validation, platform fee transfer) will be added in Phase 04 when escrow
     // creation/funding is integrated into match lifecycle
@@ -95,10 +95,14 @@ class RefundEscrowTest extends BaseTest {
         matchId,
         claimGame.game_id,
         new anchor.BN(seed),
-        null, // entry_fee (None = free match)
-        null, // payment_method (None = default)
-        null, // match_type (None = default FREE)
-        null  // tournament_id (None = not a tournament)
+        {
+          entryFee: null, // None = free match
+          paymentMethod: null, // None = default
+          matchType: null, // None = default FREE
+          tournamentId: null, // None = not a tournament
+          prizeStructure: null, // None = winner takes all
+          prizeSplitBps: null, // None = no ranked split
+        }
       )
       .accounts({
         matchAccount: matchPDA,
@@ -152,7 +156,7 @@ class RefundEscrowTest extends BaseTest {
           playerDeposit7: null,
           playerDeposit8: null,
           playerDeposit9: null,
-          treasury: null,
+          treasuryVault: null,
           systemProgram: SystemProgram.programId,
         } as never)
         .rpc();
@@ -211,7 +215,7 @@ class RefundEscrowTest extends BaseTest {
           playerDeposit7: null,
           playerDeposit8: null,
           playerDeposit9: null,
-          treasury: null,
+          treasuryVault: null,
           systemProgram: SystemProgram.programId,
         } as never)
         .rpc();
@@ -268,7 +272,7 @@ class RefundEscrowTest extends BaseTest {
             playerDeposit7: null,
             playerDeposit8: null,
             playerDeposit9: null,
-            treasury: null,
+            treasuryVault: null,
             systemProgram: SystemProgram.programId,
           } as never)
           .rpc();
@@ -317,7 +321,7 @@ class RefundEscrowTest extends BaseTest {
           playerDeposit7: null,
           playerDeposit8: null,
           playerDeposit9: null,
-          treasury: null,
+          treasuryVault: null,
           systemProgram: SystemProgram.programId,
         } as never)
         .rpc();
@@ -385,7 +389,7 @@ class RefundEscrowTest extends BaseTest {
           playerDeposit7: null,
           playerDeposit8: null,
           playerDeposit9: null,
-          treasury: null,
+          treasuryVault: null,
           systemProgram: SystemProgram.programId,
         } as never)
         .rpc();
@@ -438,7 +442,7 @@ class RefundEscrowTest extends BaseTest {
           playerDeposit7: null,
           playerDeposit8: null,
           playerDeposit9: null,
-          treasury: null,
+          treasuryVault: null,
           systemProgram: SystemProgram.programId,
         } as never)
         .rpc();
@@ -511,7 +515,7 @@ class RefundEscrowTest extends BaseTest {
             playerDeposit7: null,
             playerDeposit8: null,
             playerDeposit9: null,
-            treasury: null,
+            treasuryVault: null,
             systemProgram: SystemProgram.programId,
           } as never)
           .rpc();
@@ -0,0 +1,193 @@
+/**
+ * Test: Validator staking, unbonding and slashing
+ * Category: ECONOMIC
+ *
+ * register_validator stakes SOL into the validator's ValidatorReputation PDA (its stake vault),
+ * unstake starts an unbonding period before withdraw_stake can release lamports, and
+ * slash_validator (AUTHORITY role only) moves slashed lamports to a harmed party or the treasury.
+ */
+
+import { BaseTest } from '@/core';
+import { TestCategory, ClusterRequirement } from '@/core';
+import { registerMochaTest } from '@/core';
+import { SystemProgram, Keypair, PublicKey, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import {
+  getSignerRegistryPDA,
+  getValidatorReputationPDA,
+  ensureTreasuryVault,
+  registerSigner,
+  SIGNER_ROLE,
+  TreasuryVaultType,
+  ValidatorReputationType,
+} from '@/common';
+import * as anchor from "@coral-xyz/anchor";
+
+const SLASH_MALICIOUS = 0;
+const SLASH_NEGLIGENT = 1;
+const SLASHED_STAKE_SOURCE = 4;
+
+class ValidatorStakingTest extends BaseTest {
+  constructor() {
+    super({
+      id: 'validator-staking',
+      name: 'Validator staking, unbonding and slashing',
+      description: 'Tests register_validator, unstake, withdraw_stake and slash_validator lamport movements',
+      tags: {
+        category: TestCategory.REGISTRY,
+        cluster: ClusterRequirement.ANY,
+        requiresSetup: true,
+      },
+    });
+  }
+
+  async run(): Promise<void> {
+    const { program, authority, airdrop } = await import('@/helpers');
+    const connection = program.provider.connection;
+
+    const [signerRegistryPDA] = await getSignerRegistryPDA();
+    const treasuryVaultPDA = await ensureTreasuryVault();
+
+    // Setup: the test authority slashes, a fresh wallet validates
+    try {
+      await registerSigner(authority.publicKey, SIGNER_ROLE.AUTHORITY);
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'SignerAlreadyExists', 'Expected the authority to be registered already');
+    }
+    const validator = Keypair.generate();
+    await airdrop(validator.publicKey, 3);
+    await registerSigner(validator.publicKey, SIGNER_ROLE.VALIDATOR);
+    const [reputationPDA] = await getValidatorReputationPDA(validator.publicKey);
+
+    const registerValidator = async (staker: Keypair, lamports: number) => {
+      const [stakerReputationPDA] = await getValidatorReputationPDA(staker.publicKey);
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      await (program.methods as any)
+        .registerValidator(new anchor.BN(lamports))
+        .accounts({
+          validatorReputation: stakerReputationPDA,
+          registry: signerRegistryPDA,
+          validator: staker.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .signers([staker])
+        .rpc();
+    };
+    const slashValidator = (lamports: number, reason: number, harmedParty: PublicKey | null, signer?: Keypair) => {
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      const builder = (program.methods as any)
+        .slashValidator(validator.publicKey, new anchor.BN(lamports), reason)
+        .accounts({
+          validatorReputation: reputationPDA,
+          registry: signerRegistryPDA,
+          treasuryVault: harmedParty ? null : treasuryVaultPDA,
+          harmedParty,
+          authority: signer ? signer.publicKey : authority.publicKey,
+        } as never);
+      return signer ? builder.signers([signer]).rpc() : builder.rpc();
+    };
+    const fetchReputation = async () =>
+      await program.account.validatorReputation.fetch(reputationPDA) as unknown as ValidatorReputationType;
+
+    // Test 1: Failure - Wallets without the VALIDATOR role cannot stake
+    const outsider = Keypair.generate();
+    await airdrop(outsider.publicKey, 2);
+    try {
+      await registerValidator(outsider, 1_500_000_000);
+      this.assert(false, 'register_validator should fail without the VALIDATOR role');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'Unauthorized', 'Expected Unauthorized error');
+    }
+
+    // Test 2: Failure - Stake below the validator minimum
+    try {
+      await registerValidator(validator, 500_000_000);
+      this.assert(false, 'register_validator should fail below the minimum stake');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'InsufficientValidatorStake', 'Expected InsufficientValidatorStake error');
+    }
+
+    // Test 3: Success - Stake moves into the vault and registers the validator
+    await registerValidator(validator, 1_500_000_000);
+    let reputation = await fetchReputation();
+    const vaultInfo = await connection.getAccountInfo(reputationPDA);
+    const rentExempt = await connection.getMinimumBalanceForRentExemption(vaultInfo?.data.length ?? 0);
+    this.assertEqual(reputation.stake?.toNumber(), 1_500_000_000, 'Stake should be recorded');
+    this.assertEqual(reputation.reputation, 0.5, 'New validators start at 0.5 reputation');
+    this.assertEqual((vaultInfo?.lamports ?? 0) - rentExempt, 1_500_000_000, 'Vault should hold the stake above rent');
+
+    // Test 4: Unstaked lamports unbond before they can be withdrawn
+    // eslint-disable-next-line @typescript-eslint/no-explicit-any
+    await (program.methods as any)
+      .unstake(new anchor.BN(500_000_000))
+      .accounts({
+        validatorReputation: reputationPDA,
+        validator: validator.publicKey,
+      } as never)
+      .signers([validator])
+      .rpc();
+    reputation = await fetchReputation();
+    this.assertEqual(reputation.stake?.toNumber(), LAMPORTS_PER_SOL, 'Active stake should drop');
+    this.assertEqual(
+      (reputation.unbondingLamports ?? reputation.unbonding_lamports)?.toNumber(),
+      500_000_000,
+      'Unstaked lamports should be unbonding'
+    );
+    try {
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      await (program.methods as any)
+        .withdrawStake()
+        .accounts({
+          validatorReputation: reputationPDA,
+          validator: validator.publicKey,
+        } as never)
+        .signers([validator])
+        .rpc();
+      this.assert(false, 'withdraw_stake should fail while unbonding');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'StakeStillUnbonding', 'Expected StakeStillUnbonding error');
+    }
+
+    // Test 5: Failure - Only AUTHORITY-role signers can slash
+    try {
+      await slashValidator(100_000_000, SLASH_NEGLIGENT, null, validator);
+      this.assert(false, 'slash_validator should fail for a non-authority signer');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'Unauthorized', 'Expected Unauthorized error');
+    }
+
+    // Test 6: Success - Slashing pays the harmed party from stake, then unbonding lamports
+    const harmedParty = Keypair.generate();
+    await airdrop(harmedParty.publicKey, 0.1);
+    const harmedBefore = await connection.getBalance(harmedParty.publicKey);
+    await slashValidator(1_200_000_000, SLASH_MALICIOUS, harmedParty.publicKey);
+    const harmedAfter = await connection.getBalance(harmedParty.publicKey);
+    this.assertEqual(harmedAfter - harmedBefore, 1_200_000_000, 'Harmed party should receive the slashed lamports');
+    reputation = await fetchReputation();
+    this.assertEqual(reputation.stake?.toNumber(), 0, 'Active stake should be slashed first');
+    this.assertEqual(
+      (reputation.unbondingLamports ?? reputation.unbonding_lamports)?.toNumber(),
+      300_000_000,
+      'Unbonding lamports should cover the rest'
+    );
+    this.assertEqual(reputation.reputation, 0.25, 'Malicious slashing halves reputation');
+
+    // Test 7: Success - Without a harmed party the treasury collects the slash
+    const treasuryBefore = await program.account.treasuryVault.fetch(treasuryVaultPDA) as unknown as TreasuryVaultType;
+    await slashValidator(100_000_000, SLASH_NEGLIGENT, null);
+    const treasuryAfter = await program.account.treasuryVault.fetch(treasuryVaultPDA) as unknown as TreasuryVaultType;
+    const slashedBefore = (treasuryBefore.feesBySource ?? treasuryBefore.fees_by_source ?? [])[SLASHED_STAKE_SOURCE]?.toNumber() ?? 0;
+    const slashedAfter = (treasuryAfter.feesBySource ?? treasuryAfter.fees_by_source ?? [])[SLASHED_STAKE_SOURCE]?.toNumber() ?? 0;
+    this.assertEqual(slashedAfter - slashedBefore, 100_000_000, 'Treasury should record the slashed stake');
+    reputation = await fetchReputation();
+    this.assertEqual(
+      (reputation.totalSlashed ?? reputation.total_slashed)?.toNumber(),
+      1_300_000_000,
+      'Lifetime slashed lamports should be tracked'
+    );
+
+    console.log('✓ Validator staking test passed');
+  }
+}
+
+const testInstance = new ValidatorStakingTest();
+registerMochaTest(testInstance);
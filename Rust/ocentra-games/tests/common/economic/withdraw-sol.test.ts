@@ -7,7 +7,7 @@ import { BaseTest } from '@/core';
 import { TestCategory, ClusterRequirement } from '@/core';
 import { registerMochaTest } from '@/core';
 import { SystemProgram, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
-import { getUserDepositPDA, getConfigAccountPDA, ConfigAccountType, UserDepositAccountType } from '@/common';
+import { getUserDepositPDA, getConfigAccountPDA, ensureTreasuryVault, ConfigAccountType, UserDepositAccountType, TreasuryVaultType } from '@/common';
 import * as anchor from "@coral-xyz/anchor";
 
 class WithdrawSolTest extends BaseTest {
@@ -63,6 +63,9 @@ class WithdrawSolTest extends BaseTest {
     }
     const withdrawalFee = config.withdrawalFeeLamports?.toNumber() ?? config.withdrawal_fee_lamports?.toNumber() ?? 5000;
 
+    // Withdrawal fees are paid into the program-owned treasury vault
+    const treasuryVaultPDA = await ensureTreasuryVault();
+
     // Test 1: Success - Withdraw with fee deduction
     const user1 = Keypair.generate();
     await airdrop(user1.publicKey, 2);
@@ -93,7 +96,7 @@ class WithdrawSolTest extends BaseTest {
         userDepositAccount: depositPDA1,
         user: user1.publicKey,
         configAccount: configPDA,
-        treasury: authority.publicKey, // Use authority as treasury for testing
+        treasuryVault: treasuryVaultPDA,
         systemProgram: SystemProgram.programId,
       } as never)
       .signers([user1])
@@ -151,7 +154,7 @@ class WithdrawSolTest extends BaseTest {
           userDepositAccount: depositPDA2,
           user: user2.publicKey,
           configAccount: configPDA,
-          treasury: authority.publicKey,
+          treasuryVault: treasuryVaultPDA,
           systemProgram: SystemProgram.programId,
         } as never)
         .signers([user2])
@@ -172,7 +175,7 @@ class WithdrawSolTest extends BaseTest {
           userDepositAccount: depositPDA1,
           user: user1.publicKey,
           configAccount: configPDA,
-          treasury: authority.publicKey,
+          treasuryVault: treasuryVaultPDA,
           systemProgram: SystemProgram.programId,
         } as never)
         .signers([user1])
@@ -196,7 +199,7 @@ class WithdrawSolTest extends BaseTest {
           userDepositAccount: depositPDA1, // user1's account
           user: user3.publicKey, // but user3 is signing
           configAccount: configPDA,
-          treasury: authority.publicKey,
+          treasuryVault: treasuryVaultPDA,
           systemProgram: SystemProgram.programId,
         } as never)
         .signers([user3])
@@ -260,7 +263,7 @@ class WithdrawSolTest extends BaseTest {
         userDepositAccount: depositPDA4,
         user: user4.publicKey,
         configAccount: configPDA,
-        treasury: authority.publicKey,
+        treasuryVault: treasuryVaultPDA,
         systemProgram: SystemProgram.programId,
       } as never)
       .signers([user4])
@@ -274,11 +277,7 @@ class WithdrawSolTest extends BaseTest {
       'availableLamports should be 0 after withdrawing all'
     );
 
-    // Test 9: Success - Verify withdrawal fee is sent to treasury
-    // Use a separate treasury account to avoid interference from transaction fees
-    const treasury5 = Keypair.generate();
-    await airdrop(treasury5.publicKey, 0.1); // Small amount for rent
-
+    // Test 9: Success - Verify withdrawal fee is sent to the treasury vault and recorded by source
     const user5 = Keypair.generate();
     await airdrop(user5.publicKey, 2);
     const [depositPDA5] = await getUserDepositPDA(user5.publicKey);
@@ -297,8 +296,10 @@ class WithdrawSolTest extends BaseTest {
       .signers([user5])
       .rpc();
 
-    // Get treasury balance before withdrawal
-    const treasuryBalanceBefore = await program.provider.connection.getBalance(treasury5.publicKey);
+    // Get treasury vault balance and ledger before withdrawal
+    const treasuryBalanceBefore = await program.provider.connection.getBalance(treasuryVaultPDA);
+    const vaultBefore = await program.account.treasuryVault.fetch(treasuryVaultPDA) as unknown as TreasuryVaultType;
+    const withdrawalFeesBefore = (vaultBefore.feesBySource ?? vaultBefore.fees_by_source ?? [])[1]?.toNumber() ?? 0;
 
     // Withdraw
     // eslint-disable-next-line @typescript-eslint/no-explicit-any
@@ -308,7 +309,7 @@ class WithdrawSolTest extends BaseTest {
         userDepositAccount: depositPDA5,
         user: user5.publicKey,
         configAccount: configPDA,
-        treasury: treasury5.publicKey, // Use separate treasury
+        treasuryVault: treasuryVaultPDA,
         systemProgram: SystemProgram.programId,
       } as never)
       .signers([user5])
@@ -316,13 +317,22 @@ class WithdrawSolTest extends BaseTest {
 
     // Verify treasury received fee (if fee > 0)
     if (withdrawalFee > 0) {
-      const treasuryBalanceAfter = await program.provider.connection.getBalance(treasury5.publicKey);
+      const treasuryBalanceAfter = await program.provider.connection.getBalance(treasuryVaultPDA);
       const treasuryReceived = treasuryBalanceAfter - treasuryBalanceBefore;
       // Treasury should receive exactly the withdrawal fee
       this.assert(
         treasuryReceived === withdrawalFee,
         `Treasury should receive exactly ${withdrawalFee} lamports, got ${treasuryReceived}`
       );
+
+      // Ledger records the fee under the withdrawal fee source
+      const vaultAfter = await program.account.treasuryVault.fetch(treasuryVaultPDA) as unknown as TreasuryVaultType;
+      const withdrawalFeesAfter = (vaultAfter.feesBySource ?? vaultAfter.fees_by_source ?? [])[1]?.toNumber() ?? 0;
+      this.assertEqual(
+        withdrawalFeesAfter - withdrawalFeesBefore,
+        withdrawalFee,
+        'Treasury ledger should record the withdrawal fee'
+      );
     }
 
     // Test 10: Failure - Withdraw more than available (including fee)
@@ -354,7 +364,7 @@ class WithdrawSolTest extends BaseTest {
           userDepositAccount: depositPDA6,
           user: user6.publicKey,
           configAccount: configPDA,
-          treasury: authority.publicKey,
+          treasuryVault: treasuryVaultPDA,
           systemProgram: SystemProgram.programId,
         } as never)
         .signers([user6])
@@ -0,0 +1,124 @@
+/**
+ * Test: withdraw_treasury instruction
+ * Category: ECONOMIC
+ *
+ * Fees collected by the program-owned treasury vault can only leave through withdraw_treasury,
+ * signed by ConfigAccount.treasury_multisig, and never below the vault's rent-exempt balance.
+ */
+
+import { BaseTest } from '@/core';
+import { TestCategory, ClusterRequirement } from '@/core';
+import { registerMochaTest } from '@/core';
+import { SystemProgram, Keypair } from "@solana/web3.js";
+import { getUserDepositPDA, getConfigAccountPDA, ensureTreasuryVault, ConfigAccountType, TreasuryVaultType } from '@/common';
+import * as anchor from "@coral-xyz/anchor";
+
+class WithdrawTreasuryTest extends BaseTest {
+  constructor() {
+    super({
+      id: 'withdraw-treasury',
+      name: 'withdraw_treasury instruction',
+      description: 'Tests withdraw_treasury: multisig-only, ledger accounting, cannot exceed collected fees',
+      tags: {
+        category: TestCategory.REGISTRY,
+        cluster: ClusterRequirement.ANY,
+        requiresSetup: true,
+      },
+    });
+  }
+
+  async run(): Promise<void> {
+    const { program, authority, airdrop } = await import('@/helpers');
+
+    // Setup: config (authority is the treasury multisig) and treasury vault
+    const treasuryVaultPDA = await ensureTreasuryVault();
+    const [configPDA] = await getConfigAccountPDA();
+    const config = await program.account.configAccount.fetch(configPDA) as unknown as ConfigAccountType;
+    const withdrawalFee = config.withdrawalFeeLamports?.toNumber() ?? config.withdrawal_fee_lamports?.toNumber() ?? 5000;
+
+    // Collect a withdrawal fee so the vault has something to withdraw
+    const user = Keypair.generate();
+    await airdrop(user.publicKey, 1);
+    const [depositPDA] = await getUserDepositPDA(user.publicKey);
+    // eslint-disable-next-line @typescript-eslint/no-explicit-any
+    await (program.methods as any)
+      .depositSol(new anchor.BN(10_000_000))
+      .accounts({
+        userDepositAccount: depositPDA,
+        user: user.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as never)
+      .signers([user])
+      .rpc();
+    // eslint-disable-next-line @typescript-eslint/no-explicit-any
+    await (program.methods as any)
+      .withdrawSol(new anchor.BN(1_000_000))
+      .accounts({
+        userDepositAccount: depositPDA,
+        user: user.publicKey,
+        configAccount: configPDA,
+        treasuryVault: treasuryVaultPDA,
+        systemProgram: SystemProgram.programId,
+      } as never)
+      .signers([user])
+      .rpc();
+
+    const vaultBefore = await program.account.treasuryVault.fetch(treasuryVaultPDA) as unknown as TreasuryVaultType;
+    const collected = vaultBefore.totalCollectedLamports?.toNumber() ?? vaultBefore.total_collected_lamports?.toNumber() ?? 0;
+    const withdrawnBefore = vaultBefore.totalWithdrawnLamports?.toNumber() ?? vaultBefore.total_withdrawn_lamports?.toNumber() ?? 0;
+    const available = collected - withdrawnBefore;
+    this.assert(available >= withdrawalFee, `Vault should hold at least the withdrawal fee, has ${available}`);
+
+    const destination = Keypair.generate();
+    await airdrop(destination.publicKey, 0.1);
+
+    const withdrawTreasury = (amount: number, signer?: Keypair) => {
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      const builder = (program.methods as any)
+        .withdrawTreasury(new anchor.BN(amount))
+        .accounts({
+          treasuryVault: treasuryVaultPDA,
+          configAccount: configPDA,
+          destination: destination.publicKey,
+          authority: signer ? signer.publicKey : authority.publicKey,
+        } as never);
+      return signer ? builder.signers([signer]).rpc() : builder.rpc();
+    };
+
+    // Test 1: Failure - Signer is not the treasury multisig
+    const outsider = Keypair.generate();
+    await airdrop(outsider.publicKey, 0.1);
+    try {
+      await withdrawTreasury(withdrawalFee, outsider);
+      this.assert(false, "withdraw_treasury should fail for a non-multisig signer");
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), "Unauthorized", "Expected Unauthorized error");
+    }
+
+    // Test 2: Failure - More than collected and not yet withdrawn
+    try {
+      await withdrawTreasury(available + 1);
+      this.assert(false, "withdraw_treasury should fail above the vault's available balance");
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), "InsufficientFunds", "Expected InsufficientFunds error");
+    }
+
+    // Test 3: Success - Multisig withdraws the available balance
+    const destinationBefore = await program.provider.connection.getBalance(destination.publicKey);
+    await withdrawTreasury(available);
+    const destinationAfter = await program.provider.connection.getBalance(destination.publicKey);
+    this.assertEqual(destinationAfter - destinationBefore, available, 'Destination should receive the withdrawn amount');
+
+    const vaultAfter = await program.account.treasuryVault.fetch(treasuryVaultPDA) as unknown as TreasuryVaultType;
+    const withdrawnAfter = vaultAfter.totalWithdrawnLamports?.toNumber() ?? vaultAfter.total_withdrawn_lamports?.toNumber() ?? 0;
+    this.assertEqual(withdrawnAfter - withdrawnBefore, available, 'Ledger should record the withdrawal');
+
+    // Vault keeps its rent-exempt balance
+    const vaultInfo = await program.provider.connection.getAccountInfo(treasuryVaultPDA);
+    const rentExempt = await program.provider.connection.getMinimumBalanceForRentExemption(vaultInfo?.data.length ?? 0);
+    this.assert((vaultInfo?.lamports ?? 0) >= rentExempt, 'Treasury vault should stay rent-exempt');
+  }
+}
+
+const testInstance = new WithdrawTreasuryTest();
+registerMochaTest(testInstance);
@@ -0,0 +1,70 @@
+/**
+ * Test: Fails to submit move for a seat owned by another wallet
+ * Category: ERRORS
+ */
+
+import { BaseTest } from '@/core';
+import { TestCategory, ClusterRequirement } from '@/core';
+import { registerMochaTest } from '@/core';
+import * as anchor from "@coral-xyz/anchor";
+
+class FailMoveSeatSignerMismatchTest extends BaseTest {
+  constructor() {
+    super({
+      id: 'fail-move-seat-signer-mismatch',
+      name: 'Fails to submit move for a seat owned by another wallet',
+      description: 'Verifies that a wallet cannot act for a seat bound to a different wallet at join_match',
+      tags: {
+        category: TestCategory.ERRORS,
+        cluster: ClusterRequirement.DEVNET_ALLOWED,
+        expensive: true,
+        requiresSetup: true,
+        requiresRegistry: true,
+      },
+    });
+  }
+
+  async run(): Promise<void> {
+    const {
+      player2,
+      generateUniqueMatchId,
+      getMovePDA,
+      getTestUserId,
+      createStartedMatch,
+      submitMoveManual,
+    } = await import('@/helpers');
+
+    const matchId = generateUniqueMatchId("seat-signer");
+    const [matchPDA, registryPDA] = await createStartedMatch(matchId, 2);
+
+    // Seat 0 was joined by player1 - player2 claims its user ID
+    const userId = getTestUserId(0);
+    const nonce = new anchor.BN(Date.now());
+    const [movePDA] = await getMovePDA(matchId, player2.publicKey, nonce);
+
+    try {
+      await submitMoveManual(
+        matchId,
+        userId,
+        2,
+        Buffer.from([0]),
+        nonce,
+        matchPDA,
+        registryPDA,
+        movePDA,
+        player2
+      );
+
+      this.assert(false, 'Should have thrown SeatSignerMismatch error');
+    } catch (err: unknown) {
+      if (!this.isAnchorError(err)) {
+        throw new Error(`Expected AnchorError, got ${err?.constructor?.name}: ${err}`);
+      }
+      const errorCode = this.getErrorCode(err);
+      this.assertEqual(errorCode, "SeatSignerMismatch");
+    }
+  }
+}
+
+const testInstance = new FailMoveSeatSignerMismatchTest();
+registerMochaTest(testInstance);
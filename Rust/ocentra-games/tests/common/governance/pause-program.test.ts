@@ -7,7 +7,7 @@ import { BaseTest } from '@/core';
 import { TestCategory, ClusterRequirement } from '@/core';
 import { registerMochaTest } from '@/core';
 import { SystemProgram, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
-import { getConfigAccountPDA, getUserDepositPDA, getMatchPDA, getEscrowPDA, getRegistryPDA, ConfigAccountType } from '@/common';
+import { getConfigAccountPDA, getUserDepositPDA, getMatchPDA, getEscrowPDA, getRegistryPDA, ensureTreasuryVault, ConfigAccountType } from '@/common';
 import * as anchor from "@coral-xyz/anchor";
 
 // Match type and payment method constants
@@ -37,7 +37,7 @@ class PauseProgramTest extends BaseTest {
   }
 
   async run(): Promise<void> {
-    const { program, authority, airdrop, generateUniqueMatchId, getTestGame, getTestSeed, getTestUserId } = await import('@/helpers');
+    const { program, authority, airdrop, generateUniqueMatchId, getTestGame, PAID_GAME_ID, getTestSeed, getTestUserId } = await import('@/helpers');
     const [configPDA] = await getConfigAccountPDA();
     
     // Setup: Initialize config if it doesn't exist
@@ -64,6 +64,9 @@ class PauseProgramTest extends BaseTest {
     let config = await program.account.configAccount.fetch(configPDA) as unknown as ConfigAccountType;
     this.assertTruthy(config, 'Config should exist');
     this.assertEqual(config.isPaused ?? config.is_paused, false, 'Config should not be paused initially');
+
+    // Fee-collecting instructions take the treasury vault (created while unpaused)
+    const treasuryVaultPDA = await ensureTreasuryVault();
     
     // Pause program (treasury multisig)
     // eslint-disable-next-line @typescript-eslint/no-explicit-any
@@ -200,7 +203,7 @@ class PauseProgramTest extends BaseTest {
           userDepositAccount: depositPDA1,
           user: user1.publicKey,
           configAccount: configPDA,
-          treasury: treasuryMultisig,
+          treasuryVault: treasuryVaultPDA,
           systemProgram: SystemProgram.programId,
         } as never)
         .signers([user1])
@@ -217,8 +220,8 @@ class PauseProgramTest extends BaseTest {
     const [matchPDA1] = await getMatchPDA(matchId1);
     const [registryPDA] = await getRegistryPDA();
     const [escrowPDA1] = await getEscrowPDA(matchPDA1);
-    const claimGame = getTestGame(0);
-    if (!claimGame) throw new Error("CLAIM game not found in test data");
+    const paidGame = getTestGame(PAID_GAME_ID);
+    if (!paidGame) throw new Error("Paid match game not found in test data");
     const seed = getTestSeed();
     const entryFee = new anchor.BN(0.1 * LAMPORTS_PER_SOL);
 
@@ -226,12 +229,16 @@ class PauseProgramTest extends BaseTest {
       await program.methods
         .createMatch(
           matchId1,
-          claimGame.game_id,
+          paidGame.game_id,
           new anchor.BN(seed),
-          entryFee,
-          PAYMENT_METHOD.WALLET,
-          MATCH_TYPE.PAID,
-          null
+          {
+            entryFee,
+            paymentMethod: PAYMENT_METHOD.WALLET,
+            matchType: MATCH_TYPE.PAID,
+            tournamentId: null,
+            prizeStructure: null,
+            prizeSplitBps: null,
+          }
         )
         .accounts({
           matchAccount: matchPDA1,
@@ -270,12 +277,16 @@ class PauseProgramTest extends BaseTest {
     await program.methods
       .createMatch(
         matchId2,
-        claimGame.game_id,
+        paidGame.game_id,
         new anchor.BN(seed),
-        entryFee,
-        PAYMENT_METHOD.WALLET,
-        MATCH_TYPE.PAID,
-        null
+        {
+          entryFee,
+          paymentMethod: PAYMENT_METHOD.WALLET,
+          matchType: MATCH_TYPE.PAID,
+          tournamentId: null,
+          prizeStructure: null,
+          prizeSplitBps: null,
+        }
       )
       .accounts({
         matchAccount: matchPDA2,
@@ -329,38 +340,17 @@ class PauseProgramTest extends BaseTest {
     try {
       // eslint-disable-next-line @typescript-eslint/no-explicit-any
       await (program.methods as any)
-        .distributePrizes(
-          matchId3,
-          [0], // winners
-          [100] // percentages (100%)
-        )
+        .distributePrizes(matchId3)
         .accounts({
           escrowAccount: escrowPDA3,
           matchAccount: matchPDA3,
           configAccount: configPDA,
-          treasury: treasuryMultisig,
-          player0: player1.publicKey,
-          player1: player1.publicKey,
-          player2: player1.publicKey,
-          player3: player1.publicKey,
-          player4: player1.publicKey,
-          player5: player1.publicKey,
-          player6: player1.publicKey,
-          player7: player1.publicKey,
-          player8: player1.publicKey,
-          player9: player1.publicKey,
-          playerDeposit0: null,
-          playerDeposit1: null,
-          playerDeposit2: null,
-          playerDeposit3: null,
-          playerDeposit4: null,
-          playerDeposit5: null,
-          playerDeposit6: null,
-          playerDeposit7: null,
-          playerDeposit8: null,
-          playerDeposit9: null,
+          treasuryVault: treasuryVaultPDA,
           systemProgram: SystemProgram.programId,
         } as never)
+        .remainingAccounts([
+          { pubkey: player1.publicKey, isSigner: false, isWritable: true },
+        ])
         .rpc();
       
       // If it succeeds when paused, that's definitely wrong
@@ -414,7 +404,7 @@ class PauseProgramTest extends BaseTest {
           playerDeposit7: null,
           playerDeposit8: null,
           playerDeposit9: null,
-          treasury: null,
+          treasuryVault: null,
           systemProgram: SystemProgram.programId,
         } as never)
         .rpc();
@@ -443,12 +433,16 @@ class PauseProgramTest extends BaseTest {
     await program.methods
       .createMatch(
         matchId4,
-        claimGame.game_id,
+        paidGame.game_id,
         new anchor.BN(seed),
-        null, // entry_fee (None = free match)
-        null, // payment_method (None = default)
-        null, // match_type (None = default FREE)
-        null  // tournament_id (None = not a tournament)
+        {
+          entryFee: null, // None = free match
+          paymentMethod: null, // None = default
+          matchType: null, // None = default FREE
+          tournamentId: null, // None = not a tournament
+          prizeStructure: null, // None = winner takes all
+          prizeSplitBps: null, // None = no ranked split
+        }
       )
       .accounts({
         matchAccount: matchPDA4,
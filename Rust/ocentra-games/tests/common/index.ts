@@ -7,6 +7,7 @@ export * from './pda';
 export * from './test-context';
 export * from './test-data';
 export * from './match-helpers';
+export * from './token-helpers';
 export * from './errors';
 export * from './assertions';
 export * from './types';
@@ -0,0 +1,297 @@
+/**
+ * Test: Dispute addressing, validator committees and per-match dispute index
+ * Category: LIFECYCLE
+ *
+ * flag_dispute creates the Dispute at its canonical PDA (seeds ["dispute", match, flagger]) and
+ * adds it to the match's MatchDisputes index (seeds ["match_disputes", match]);
+ * draw_dispute_committee then draws its validator committee from the staked validators, seeded
+ * by the hash of a slot after the flag. resolve_dispute and finalize_dispute address the same
+ * PDA from (match_id, flagger); only committee members may vote, and a dispute without quorum
+ * stays open until its voting window closes.
+ */
+
+import { BaseTest } from '@/core';
+import { TestCategory, ClusterRequirement } from '@/core';
+import { registerMochaTest } from '@/core';
+import { SystemProgram, Keypair, PublicKey } from "@solana/web3.js";
+import {
+  getMatchPDA,
+  getConfigAccountPDA,
+  getDisputePDA,
+  getMatchDisputesPDA,
+  getValidatorReputationPDA,
+  getSignerRegistryPDA,
+  createStakedValidator,
+  drawDisputeCommittee,
+  getCommitteeReputationAccounts,
+  ConfigAccountType,
+  DisputeType,
+  MatchDisputesType,
+} from '@/common';
+import * as anchor from "@coral-xyz/anchor";
+
+const SCORE_ERROR = 3;
+const VOTING_WINDOW_SECONDS = 3 * 86_400;
+const COMMITTEE_SIZE = 5;
+
+class DisputeFlagAndIndexTest extends BaseTest {
+  constructor() {
+    super({
+      id: 'dispute-flag-and-index',
+      name: 'Dispute addressing, validator committees and per-match dispute index',
+      description: 'Tests canonical dispute PDAs, committee draws, the MatchDisputes index and quorum gating',
+      tags: {
+        category: TestCategory.LIFECYCLE,
+        cluster: ClusterRequirement.ANY,
+        requiresSetup: true,
+        requiresRegistry: true,
+      },
+    });
+  }
+
+  async run(): Promise<void> {
+    const {
+      program,
+      authority,
+      generateUniqueMatchId,
+      getTestGame,
+      getTestSeed,
+      getTestUserId,
+      airdrop,
+    } = await import('@/helpers');
+    const { getRegistryPDA } = await import('@/common');
+
+    const [configPDA] = await getConfigAccountPDA();
+    const [registryPDA] = await getRegistryPDA();
+    const [signerRegistryPDA] = await getSignerRegistryPDA();
+    const claimGame = getTestGame(0);
+    if (!claimGame) throw new Error("CLAIM game not found");
+    const config = await program.account.configAccount.fetch(configPDA) as unknown as ConfigAccountType;
+    const gpDeposit = config.disputeDepositGp ?? config.dispute_deposit_gp ?? 100;
+
+    // Committees are drawn from staked validators - make sure there is at least one
+    const validator = await createStakedValidator();
+
+    // Play a free heads-up match to the end
+    const player1 = Keypair.generate();
+    const player2 = Keypair.generate();
+    await airdrop(player1.publicKey, 1);
+    await airdrop(player2.publicKey, 1);
+    const matchId = generateUniqueMatchId("dispute");
+    const [matchPDA] = await getMatchPDA(matchId);
+    await program.methods
+      .createMatch(matchId, claimGame.game_id, new anchor.BN(getTestSeed()), { entryFee: null, paymentMethod: null, matchType: null, tournamentId: null, prizeStructure: null, prizeSplitBps: null })
+      .accounts({
+        matchAccount: matchPDA,
+        registry: registryPDA,
+        escrowAccount: null,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as never)
+      .rpc();
+    for (const [index, player] of [player1, player2].entries()) {
+      await program.methods
+        .joinMatch(matchId, getTestUserId(index))
+        .accounts({
+          matchAccount: matchPDA,
+          registry: registryPDA,
+          escrowAccount: null,
+          userDepositAccount: null,
+          playerWallet: null,
+          player: player.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .signers([player])
+        .rpc();
+    }
+    await program.methods
+      .startMatch(matchId)
+      .accounts({
+        matchAccount: matchPDA,
+        registry: registryPDA,
+        escrowAccount: null,
+        authority: authority.publicKey,
+      } as never)
+      .rpc();
+    await program.methods
+      .endMatch(matchId, null, null)
+      .accounts({
+        matchAccount: matchPDA,
+        escrowAccount: null,
+        authority: authority.publicKey,
+      } as never)
+      .rpc();
+
+    const [matchDisputesPDA] = await getMatchDisputesPDA(matchPDA);
+    const flag = async (flagger: Keypair, seat: number): Promise<PublicKey> => {
+      const [disputePDA] = await getDisputePDA(matchPDA, flagger.publicKey);
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      await (program.methods as any)
+        .flagDispute(matchId, getTestUserId(seat), SCORE_ERROR, Array(32).fill(seat + 1), gpDeposit)
+        .accounts({
+          dispute: disputePDA,
+          matchDisputes: matchDisputesPDA,
+          matchAccount: matchPDA,
+          escrowAccount: null,
+          configAccount: configPDA,
+          flagger: flagger.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .signers([flagger])
+        .rpc();
+      return disputePDA;
+    };
+    const fetchIndex = async () =>
+      await program.account.matchDisputes.fetch(matchDisputesPDA) as unknown as MatchDisputesType;
+
+    // Test 1: The first dispute creates the index at the canonical PDA
+    const dispute1 = await flag(player1, 0);
+    let index = await fetchIndex();
+    this.assertEqual((index.matchAccount ?? index.match_account)?.toString(), matchPDA.toString(), 'Index should belong to the match');
+    this.assertEqual(index.openCount ?? index.open_count, 1, 'One dispute should be open');
+    this.assertEqual((index.openDisputes ?? index.open_disputes)?.[0].toString(), dispute1.toString(), 'Index should list the dispute');
+    let dispute = await program.account.dispute.fetch(dispute1) as unknown as DisputeType;
+    this.assertEqual(dispute.flagger?.toString(), player1.publicKey.toString(), 'Dispute should record the flagger');
+    this.assert(
+      (dispute.votingDeadline ?? dispute.voting_deadline)!.toNumber() >= Math.floor(Date.now() / 1000) + VOTING_WINDOW_SECONDS - 600,
+      'Voting should stay open for the dispute window'
+    );
+    this.assertEqual(dispute.committeeSize ?? dispute.committee_size, 0, 'The committee is drawn after the flag');
+    this.assert(((dispute.flagSlot ?? dispute.flag_slot)?.toNumber() ?? 0) > 0, 'Dispute should record its flag slot');
+
+    // Votes wait for the committee draw
+    const [validatorReputationPDA] = await getValidatorReputationPDA(validator.publicKey);
+    try {
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      await (program.methods as any)
+        .resolveDispute(matchId, player1.publicKey, 1)
+        .accounts({
+          dispute: dispute1,
+          matchAccount: matchPDA,
+          registry: signerRegistryPDA,
+          validatorReputation: validatorReputationPDA,
+          validator: validator.publicKey,
+        } as never)
+        .signers([validator])
+        .rpc();
+      this.assert(false, 'Voting before the committee draw should fail');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'DisputeCommitteeNotDrawn', 'Expected DisputeCommitteeNotDrawn');
+    }
+
+    // The draw happens once, from the hash of a slot after the flag
+    await drawDisputeCommittee(matchId, player1.publicKey);
+    try {
+      await drawDisputeCommittee(matchId, player1.publicKey);
+      this.assert(false, 'Drawing the committee twice should fail');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'CommitteeAlreadyDrawn', 'Expected CommitteeAlreadyDrawn');
+    }
+    dispute = await program.account.dispute.fetch(dispute1) as unknown as DisputeType;
+    const committeeSize = dispute.committeeSize ?? dispute.committee_size ?? 0;
+    this.assert(committeeSize >= 1 && committeeSize <= COMMITTEE_SIZE, `Committee should have 1-${COMMITTEE_SIZE} members, has ${committeeSize}`);
+    const committee = (dispute.committee ?? []).slice(0, committeeSize).map((member) => member.toString());
+    for (const party of [player1.publicKey, player2.publicKey]) {
+      this.assert(!committee.includes(party.toString()), 'Parties to the match should not sit on the committee');
+    }
+
+    // Test 2: A second flagger opens a second dispute on the same match
+    const dispute2 = await flag(player2, 1);
+    index = await fetchIndex();
+    this.assertEqual(index.openCount ?? index.open_count, 2, 'Two disputes should be open');
+    this.assertEqual(index.totalDisputes ?? index.total_disputes, 2, 'Two disputes should have been flagged');
+    this.assertEqual((index.openDisputes ?? index.open_disputes)?.[1].toString(), dispute2.toString(), 'Index should list both disputes');
+
+    // Test 3: A flagger has one dispute per match
+    try {
+      await flag(player1, 0);
+      this.assert(false, 'Flagging the same match twice should fail');
+    } catch (err: unknown) {
+      const error = err as { message?: string };
+      this.assert(
+        error.message?.includes("already in use") || error.message?.includes("0x0") || false,
+        'Expected the canonical dispute PDA to be in use'
+      );
+    }
+
+    // Test 4: Wallets without a seat in the match cannot flag it
+    const spectator = Keypair.generate();
+    await airdrop(spectator.publicKey, 1);
+    try {
+      await flag(spectator, 0);
+      this.assert(false, 'A wallet outside the match should not flag it');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'PlayerNotInMatch', 'Expected PlayerNotInMatch');
+    }
+
+    // Test 5: A validator staked after the draw is not on the committee
+    const lateValidator = await createStakedValidator();
+    const [lateReputationPDA] = await getValidatorReputationPDA(lateValidator.publicKey);
+    try {
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      await (program.methods as any)
+        .resolveDispute(matchId, player1.publicKey, 1)
+        .accounts({
+          dispute: dispute1,
+          matchAccount: matchPDA,
+          registry: signerRegistryPDA,
+          validatorReputation: lateReputationPDA,
+          validator: lateValidator.publicKey,
+        } as never)
+        .signers([lateValidator])
+        .rpc();
+      this.assert(false, 'A validator outside the committee should not vote');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'NotDisputeCommitteeMember', 'Expected NotDisputeCommitteeMember');
+    }
+
+    // Test 6: Votes address the dispute by (match_id, flagger) and need a staked validator
+    const outsider = Keypair.generate();
+    await airdrop(outsider.publicKey, 1);
+    const [outsiderReputationPDA] = await getValidatorReputationPDA(outsider.publicKey);
+    try {
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      await (program.methods as any)
+        .resolveDispute(matchId, player1.publicKey, 1)
+        .accounts({
+          dispute: dispute1,
+          matchAccount: matchPDA,
+          registry: signerRegistryPDA,
+          validatorReputation: outsiderReputationPDA,
+          validator: outsider.publicKey,
+        } as never)
+        .signers([outsider])
+        .rpc();
+      this.assert(false, 'A wallet without validator stake should not vote');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'AccountNotInitialized', 'Expected a missing validator account');
+    }
+
+    // Test 7: Without quorum the dispute stays open until the voting window closes
+    const committeeMembers = committee.map((member) => new PublicKey(member));
+    try {
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      await (program.methods as any)
+        .finalizeDispute(matchId, player1.publicKey)
+        .accounts({
+          dispute: dispute1,
+          matchDisputes: matchDisputesPDA,
+          matchAccount: matchPDA,
+          escrowAccount: null,
+          caller: authority.publicKey,
+        } as never)
+        .remainingAccounts(await getCommitteeReputationAccounts(committeeMembers))
+        .rpc();
+      this.assert(false, 'Finalizing without quorum should fail');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'DisputeQuorumNotReached', 'Expected DisputeQuorumNotReached');
+    }
+    index = await fetchIndex();
+    this.assertEqual(index.openCount ?? index.open_count, 2, 'Both disputes should stay open');
+
+    console.log('✓ Dispute flag, committee and index test passed');
+  }
+}
+
+const testInstance = new DisputeFlagAndIndexTest();
+registerMochaTest(testInstance);
@@ -53,10 +53,14 @@ class EndMatchTest extends BaseTest {
         matchId,
         claimGame.game_id,
         new anchor.BN(seed),
-        null, // entry_fee (None = free match)
-        null, // payment_method (None = default)
-        null, // match_type (None = default FREE)
-        null  // tournament_id (None = not a tournament)
+        {
+          entryFee: null, // None = free match
+          paymentMethod: null, // None = default
+          matchType: null, // None = default FREE
+          tournamentId: null, // None = not a tournament
+          prizeStructure: null, // None = winner takes all
+          prizeSplitBps: null, // None = no ranked split
+        }
       )
       .accounts({
         matchAccount: matchPDA,
@@ -122,6 +126,12 @@ class EndMatchTest extends BaseTest {
     const matchAccount = await program.account.match.fetch(matchPDA);
     this.assertEqual(matchAccount.phase, 2); // Ended
     this.assert(matchAccount.endedAt.toNumber() !== 0, 'endedAt should be set');
+
+    // Scores are persisted by the game's scoring hook (flags bit 2 = scores_recorded)
+    this.assert((matchAccount.flags & 0x04) !== 0, 'scores_recorded flag should be set');
+    this.assertEqual(matchAccount.scores.length, 10);
+    // CLAIM: neither player declared a suit, so both take the undeclared penalty
+    this.assert(matchAccount.scores[0] < 0 && matchAccount.scores[1] < 0, 'undeclared players should score negative');
   }
 }
 
@@ -0,0 +1,179 @@
+/**
+ * Test: Expire a paid match stuck in the Dealing phase
+ * Category: LIFECYCLE
+ *
+ * Tests the permissionless expire_match crank:
+ * lobby TTL not reached → fails; TTL reached → stakes refunded in full, match and escrow closed
+ */
+
+import { BaseTest } from '@/core';
+import { TestCategory, ClusterRequirement } from '@/core';
+import { registerMochaTest } from '@/core';
+import { SystemProgram, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { getMatchPDA, getEscrowPDA } from '@/common';
+import * as anchor from "@coral-xyz/anchor";
+
+// Match type and payment method constants
+const MATCH_TYPE = {
+  FREE: 0,
+  PAID: 1,
+} as const;
+
+const PAYMENT_METHOD = {
+  WALLET: 0,
+  PLATFORM: 1,
+} as const;
+
+const DEFAULT_LOBBY_TTL_SECS = 24 * 60 * 60;
+
+class ExpireStaleMatchTest extends BaseTest {
+  constructor() {
+    super({
+      id: 'expire-stale-match',
+      name: 'Expire a paid match stuck in the Dealing phase',
+      description: 'Tests expire_match refunds every stake and closes the match and escrow after the lobby TTL',
+      tags: {
+        category: TestCategory.LIFECYCLE,
+        cluster: ClusterRequirement.ANY,
+        requiresSetup: true,
+        requiresRegistry: true,
+      },
+    });
+  }
+
+  async run(): Promise<void> {
+    const {
+      program,
+      authority,
+      generateUniqueMatchId,
+      getTestGame,
+      PAID_GAME_ID,
+      getTestSeed,
+      getTestUserId,
+      airdrop,
+    } = await import('@/helpers');
+    const { getRegistryPDA } = await import('@/common');
+
+    const paidGame = getTestGame(PAID_GAME_ID);
+    if (!paidGame) throw new Error("Paid match game not found in test data");
+
+    const matchId = generateUniqueMatchId("expire");
+    const [matchPDA] = await getMatchPDA(matchId);
+    const [registryPDA] = await getRegistryPDA();
+    const [escrowPDA] = await getEscrowPDA(matchPDA);
+
+    const setLobbyTtl = async (secs: number) => {
+      await program.methods
+        .setLobbyTtl(paidGame.game_id, secs)
+        .accounts({
+          registry: registryPDA,
+          authority: authority.publicKey,
+        } as never)
+        .rpc();
+    };
+
+    const player = Keypair.generate();
+    const cranker = Keypair.generate();
+    await airdrop(player.publicKey, 2);
+    await airdrop(cranker.publicKey, 1);
+
+    const entryFee = new anchor.BN(0.1 * LAMPORTS_PER_SOL);
+
+    await program.methods
+      .createMatch(
+        matchId,
+        paidGame.game_id,
+        new anchor.BN(getTestSeed()),
+        {
+          entryFee,
+          paymentMethod: PAYMENT_METHOD.WALLET,
+          matchType: MATCH_TYPE.PAID,
+          tournamentId: null,
+          prizeStructure: null,
+          prizeSplitBps: null,
+        }
+      )
+      .accounts({
+        matchAccount: matchPDA,
+        registry: registryPDA,
+        escrowAccount: escrowPDA,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as never)
+      .rpc();
+
+    // One player joins; the lobby never reaches min_players
+    await program.methods
+      .joinMatch(matchId, getTestUserId(0))
+      .accounts({
+        matchAccount: matchPDA,
+        registry: registryPDA,
+        escrowAccount: escrowPDA,
+        userDepositAccount: null,
+        playerWallet: player.publicKey,
+        player: player.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as never)
+      .signers([player])
+      .rpc();
+
+    const expire = async () => {
+      await program.methods
+        .expireMatch(matchId)
+        .accounts({
+          matchAccount: matchPDA,
+          registry: registryPDA,
+          escrowAccount: escrowPDA,
+          rentRecipient: authority.publicKey,
+          caller: cranker.publicKey,
+        } as never)
+        .remainingAccounts([
+          { pubkey: player.publicKey, isWritable: true, isSigner: false },
+        ])
+        .signers([cranker])
+        .rpc();
+    };
+
+    try {
+      // Test 1: Lobby TTL not reached
+      await setLobbyTtl(DEFAULT_LOBBY_TTL_SECS);
+      try {
+        await expire();
+        this.assert(false, 'Expire should fail before the lobby TTL');
+      } catch (err: unknown) {
+        this.assertEqual(this.getErrorCode(err), 'LobbyNotExpired', 'Expected LobbyNotExpired');
+      }
+
+      // Test 2: Short TTL - anyone can expire the lobby
+      await setLobbyTtl(1);
+      await new Promise(resolve => setTimeout(resolve, 2000));
+
+      const playerBalanceBefore = await program.provider.connection.getBalance(player.publicKey);
+      const authorityBalanceBefore = await program.provider.connection.getBalance(authority.publicKey);
+      await expire();
+
+      const playerBalanceAfter = await program.provider.connection.getBalance(player.publicKey);
+      this.assertEqual(
+        playerBalanceAfter - playerBalanceBefore,
+        entryFee.toNumber(),
+        'Player should be refunded the full entry fee'
+      );
+
+      const authorityBalanceAfter = await program.provider.connection.getBalance(authority.publicKey);
+      this.assert(
+        authorityBalanceAfter > authorityBalanceBefore,
+        'Match creator should receive the rent of the closed accounts'
+      );
+
+      const closedMatch = await program.provider.connection.getAccountInfo(matchPDA);
+      this.assert(closedMatch === null, 'Match account should be closed');
+      const closedEscrow = await program.provider.connection.getAccountInfo(escrowPDA);
+      this.assert(closedEscrow === null, 'Escrow account should be closed');
+    } finally {
+      await setLobbyTtl(DEFAULT_LOBBY_TTL_SECS);
+    }
+  }
+}
+
+const testInstance = new ExpireStaleMatchTest();
+registerMochaTest(testInstance);
@@ -0,0 +1,120 @@
+/**
+ * Test: Fails to create a paid match for a game without on-chain scoring
+ * Category: LIFECYCLE
+ */
+
+import { BaseTest } from '@/core';
+import { TestCategory, ClusterRequirement } from '@/core';
+import { registerMochaTest } from '@/core';
+import { SystemProgram, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import * as anchor from "@coral-xyz/anchor";
+import { getEscrowPDA, normalizeAndRethrowAnchorError } from '@/common';
+
+const MATCH_TYPE = { FREE: 0, PAID: 1 } as const;
+const PAYMENT_METHOD = { WALLET: 0, PLATFORM: 1 } as const;
+
+class FailCreatePaidUnscoredGameTest extends BaseTest {
+  constructor() {
+    super({
+      id: 'fail-create-paid-unscored-game',
+      name: 'Fails to create a paid match for a game without on-chain scoring',
+      description: 'Verifies that paid matches are refused for registered games whose rules do not score on-chain (Rummy, CLAIM)',
+      tags: {
+        category: TestCategory.LIFECYCLE,
+        cluster: ClusterRequirement.LOCALNET_ONLY,
+        requiresSetup: true,
+        requiresRegistry: true,
+      },
+    });
+  }
+
+  async run(): Promise<void> {
+    const {
+      program,
+      authority,
+      generateUniqueMatchId,
+      getTestGame,
+      getTestSeed,
+      getMatchPDA,
+      getRegistryPDA,
+    } = await import('@/helpers');
+
+    // Rummy is registered and enabled, but has no on-chain rules to score it
+    const rummyGame = getTestGame(4);
+    if (!rummyGame) throw new Error("Rummy game not found");
+    const seed = getTestSeed();
+    const [registryPDA] = await getRegistryPDA();
+
+    // Free matches are still allowed - nothing is paid from the scores
+    const freeMatchId = generateUniqueMatchId("unscored-free");
+    const [freeMatchPDA] = await getMatchPDA(freeMatchId);
+    await program.methods
+      .createMatch(
+        freeMatchId,
+        rummyGame.game_id,
+        new anchor.BN(seed),
+        {
+          entryFee: null,
+          paymentMethod: null,
+          matchType: null,
+          tournamentId: null,
+          prizeStructure: null,
+          prizeSplitBps: null,
+        }
+      )
+      .accounts({
+        matchAccount: freeMatchPDA,
+        registry: registryPDA,
+        escrowAccount: null,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as never)
+      .rpc();
+
+    // Paid matches - payouts would follow scores that don't measure who won, so they are refused:
+    // Rummy scores all zeros, and CLAIM's card values stay off-chain
+    const claimGame = getTestGame(0);
+    if (!claimGame) throw new Error("CLAIM game not found");
+    for (const game of [rummyGame, claimGame]) {
+      const paidMatchId = generateUniqueMatchId("unscored-paid");
+      const [paidMatchPDA] = await getMatchPDA(paidMatchId);
+      const [escrowPDA] = await getEscrowPDA(paidMatchPDA);
+      try {
+        await program.methods
+          .createMatch(
+            paidMatchId,
+            game.game_id,
+            new anchor.BN(seed),
+            {
+              entryFee: new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+              paymentMethod: PAYMENT_METHOD.WALLET,
+              matchType: MATCH_TYPE.PAID,
+              tournamentId: null,
+              prizeStructure: null,
+              prizeSplitBps: null,
+            }
+          )
+          .accounts({
+            matchAccount: paidMatchPDA,
+            registry: registryPDA,
+            escrowAccount: escrowPDA,
+            authority: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+          } as never)
+          .rpc();
+
+        this.assert(false, `Should have thrown GameNotScorable error for ${game.name}`);
+      } catch (err: unknown) {
+        try {
+          normalizeAndRethrowAnchorError(err, "createMatch paid for unscored game");
+        } catch (normalizedErr: unknown) {
+          const errorCode = this.getErrorCode(normalizedErr);
+          this.assertEqual(errorCode, "GameNotScorable");
+        }
+      }
+    }
+  }
+}
+
+const testInstance = new FailCreatePaidUnscoredGameTest();
+registerMochaTest(testInstance);
@@ -0,0 +1,139 @@
+/**
+ * Test: finalize_scores replays Move accounts in chunks and refuses once scores are final
+ * Category: LIFECYCLE
+ *
+ * An ended match's Move accounts are replayed across two finalize_scores calls; the ScoreReplay
+ * cursor rejects out-of-order moves, and the crank refuses to run again once the replay has
+ * written the final scores.
+ */
+
+import { BaseTest } from '@/core';
+import { TestCategory, ClusterRequirement } from '@/core';
+import { registerMochaTest } from '@/core';
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import * as anchor from "@coral-xyz/anchor";
+
+class FinalizeScoresChunkedReplayTest extends BaseTest {
+  constructor() {
+    super({
+      id: 'finalize-scores-chunked-replay',
+      name: 'finalize_scores replays moves in chunks and refuses once scores are final',
+      description: 'Replays an ended match in two chunks, checks cursor ordering and the ScoresFinalized guard',
+      tags: {
+        category: TestCategory.LIFECYCLE,
+        cluster: ClusterRequirement.ANY,
+        requiresSetup: true,
+        requiresRegistry: true,
+      },
+    });
+  }
+
+  async run(): Promise<void> {
+    const {
+      program,
+      authority,
+      player1,
+      player2,
+      generateUniqueMatchId,
+      getTestUserId,
+      getTestMatchHash,
+      getTestHotUrl,
+      getMovePDA,
+      getScoreReplayPDA,
+      createStartedMatch,
+    } = await import('@/helpers');
+    const { revealFloorCard, generateMockFloorCardHash, submitClaimMoveManual, CLAIM_ACTIONS } = await import('@/claim');
+
+    const matchId = generateUniqueMatchId("finalize-replay");
+    const [matchPDA, registryPDA] = await createStartedMatch(matchId, 2);
+    const [scoreReplayPDA] = await getScoreReplayPDA(matchPDA);
+    const players = [player1, player2];
+
+    // Setup: Play 4 moves (floor card reveal + 3 declines), remembering each Move PDA in order
+    const movePDAs: PublicKey[] = [];
+    const baseNonce = Date.now();
+    for (let i = 0; i < 4; i++) {
+      const matchAccount = await program.account.match.fetch(matchPDA);
+      const seat = matchAccount.currentPlayer;
+      const nonce = new anchor.BN(baseNonce + i);
+      const [movePDA] = await getMovePDA(matchId, players[seat].publicKey, nonce);
+      if (i === 0) {
+        await revealFloorCard(matchId, getTestUserId(seat), matchPDA, registryPDA, generateMockFloorCardHash(0), nonce, players[seat]);
+      } else {
+        await submitClaimMoveManual(matchId, getTestUserId(seat), CLAIM_ACTIONS.DECLINE, Buffer.alloc(0), nonce, matchPDA, registryPDA, movePDA, players[seat]);
+      }
+      movePDAs.push(movePDA);
+    }
+
+    await program.methods
+      .endMatch(matchId, Array.from(getTestMatchHash()), getTestHotUrl())
+      .accounts({
+        matchAccount: matchPDA,
+        escrowAccount: null, // Escrow not needed for free matches
+        authority: authority.publicKey,
+      } as never)
+      .rpc();
+
+    const finalizeScores = (moves: PublicKey[]) =>
+      program.methods
+        .finalizeScores(matchId)
+        .accounts({
+          matchAccount: matchPDA,
+          scoreReplay: scoreReplayPDA,
+          escrowAccount: null, // Escrow not needed for free matches
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .remainingAccounts(moves.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false })))
+        .rpc();
+
+    // Test 1: Failure - The replay must start at move 0
+    try {
+      await finalizeScores([movePDAs[1]]);
+      this.assert(false, 'finalize_scores should reject a chunk that skips move 0');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'MoveOutOfOrder', 'Expected MoveOutOfOrder error');
+    }
+
+    // Test 2: Success - First chunk advances the cursor without finalizing
+    await finalizeScores(movePDAs.slice(0, 2));
+    let replay = await program.account.scoreReplay.fetch(scoreReplayPDA);
+    this.assertEqual(replay.nextMoveIndex, 2, 'Cursor should stop after the first chunk');
+    let matchAccount = await program.account.match.fetch(matchPDA);
+    this.assert((matchAccount.flags & 0x20) === 0, 'Scores should not be final mid-replay');
+
+    // Test 3: Failure - A replayed move cannot be supplied twice
+    try {
+      await finalizeScores([movePDAs[1], movePDAs[2]]);
+      this.assert(false, 'finalize_scores should reject a move the cursor already replayed');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'MoveOutOfOrder', 'Expected MoveOutOfOrder error');
+    }
+
+    // Test 4: Success - The last chunk writes the replayed scores and finalizes them
+    await finalizeScores(movePDAs.slice(2));
+    replay = await program.account.scoreReplay.fetch(scoreReplayPDA);
+    matchAccount = await program.account.match.fetch(matchPDA);
+    this.assertEqual(replay.nextMoveIndex, 4, 'Cursor should cover every move');
+    this.assert((replay.statusFlags & 0x02) !== 0, 'Replay should be completed');
+    this.assert((matchAccount.flags & 0x20) !== 0, 'scores_finalized flag should be set');
+    this.assertEqual(
+      JSON.stringify(matchAccount.scores),
+      JSON.stringify(replay.scores),
+      'Match scores should be the replayed scores'
+    );
+
+    // Test 5: Failure - The crank refuses once scores are final
+    try {
+      await finalizeScores([]);
+      this.assert(false, 'finalize_scores should refuse finalized scores');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'ScoresFinalized', 'Expected ScoresFinalized error');
+    }
+
+    console.log('✓ Finalize scores chunked replay test passed');
+  }
+}
+
+const testInstance = new FinalizeScoresChunkedReplayTest();
+registerMochaTest(testInstance);
@@ -0,0 +1,202 @@
+/**
+ * Test: Entry fee bounds and per-game economics overrides
+ * Category: LIFECYCLE
+ *
+ * create_match enforces the ConfigAccount min/max entry fee for paid SOL matches. set_game_economics
+ * lets a GameDefinition override the platform fee, the entry fee bounds and the allowed payment
+ * methods; the effective platform fee is fixed into the escrow at create_match.
+ */
+
+import { BaseTest } from '@/core';
+import { TestCategory, ClusterRequirement } from '@/core';
+import { registerMochaTest } from '@/core';
+import { SystemProgram, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import {
+  getMatchPDA,
+  getEscrowPDA,
+  getConfigAccountPDA,
+  ensureConfigUnpaused,
+  ConfigAccountType,
+  EscrowAccountType,
+} from '@/common';
+import * as anchor from "@coral-xyz/anchor";
+
+const MATCH_TYPE = { FREE: 0, PAID: 1 } as const;
+const PAYMENT_METHOD = { WALLET: 0, PLATFORM: 1 } as const;
+
+class GameEconomicsOverridesTest extends BaseTest {
+  constructor() {
+    super({
+      id: 'game-economics-overrides',
+      name: 'Entry fee bounds and per-game economics overrides',
+      description: 'Tests config entry fee bounds in create_match and set_game_economics overrides',
+      tags: {
+        category: TestCategory.LIFECYCLE,
+        cluster: ClusterRequirement.ANY,
+        requiresSetup: true,
+        requiresRegistry: true,
+      },
+    });
+  }
+
+  async run(): Promise<void> {
+    const {
+      program,
+      authority,
+      generateUniqueMatchId,
+      getTestGame,
+      PAID_GAME_ID,
+      getTestSeed,
+    } = await import('@/helpers');
+    const { getRegistryPDA } = await import('@/common');
+
+    await ensureConfigUnpaused();
+    const [configPDA] = await getConfigAccountPDA();
+    const [registryPDA] = await getRegistryPDA();
+    const paidGame = getTestGame(PAID_GAME_ID);
+    if (!paidGame) throw new Error("Paid match game not found in test data");
+
+    const config = await program.account.configAccount.fetch(configPDA) as unknown as ConfigAccountType;
+    const configMinFee = config.minEntryFee?.toNumber() ?? config.min_entry_fee?.toNumber() ?? 0;
+    const configMaxFee = config.maxEntryFee?.toNumber() ?? config.max_entry_fee?.toNumber() ?? 0;
+    const configFeeBps = config.platformFeeBps ?? config.platform_fee_bps ?? 0;
+
+    const createPaidMatch = async (entryFee: number, paymentMethod: number) => {
+      const matchId = generateUniqueMatchId("economics");
+      const [matchPDA] = await getMatchPDA(matchId);
+      const [escrowPDA] = await getEscrowPDA(matchPDA);
+      await program.methods
+        .createMatch(
+          matchId,
+          paidGame.game_id,
+          new anchor.BN(getTestSeed()),
+          {
+            entryFee: new anchor.BN(entryFee),
+            paymentMethod,
+            matchType: MATCH_TYPE.PAID,
+            tournamentId: null,
+            prizeStructure: null,
+            prizeSplitBps: null,
+          }
+        )
+        .accounts({
+          matchAccount: matchPDA,
+          registry: registryPDA,
+          escrowAccount: escrowPDA,
+          configAccount: configPDA,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .rpc();
+      return escrowPDA;
+    };
+
+    const expectError = async (action: () => Promise<unknown>, code: string, message: string) => {
+      try {
+        await action();
+        this.assert(false, message);
+      } catch (err: unknown) {
+        this.assertEqual(this.getErrorCode(err), code, `Expected ${code}`);
+      }
+    };
+
+    const setGameEconomics = async (
+      feeBps: number | null,
+      minFee: number | null,
+      maxFee: number | null,
+      methods: number | null,
+      signer: Keypair | null = null
+    ) => {
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      const builder = (program.methods as any)
+        .setGameEconomics(
+          paidGame.game_id,
+          feeBps,
+          minFee === null ? null : new anchor.BN(minFee),
+          maxFee === null ? null : new anchor.BN(maxFee),
+          methods
+        )
+        .accounts({
+          registry: registryPDA,
+          authority: signer ? signer.publicKey : authority.publicKey,
+        } as never);
+      await (signer ? builder.signers([signer]) : builder).rpc();
+    };
+
+    // Test 1: Config entry fee bounds apply to paid SOL matches
+    if (configMinFee > 1) {
+      await expectError(
+        () => createPaidMatch(configMinFee - 1, PAYMENT_METHOD.WALLET),
+        'EntryFeeOutOfRange',
+        'Entry fee below the config minimum should fail'
+      );
+    }
+    await expectError(
+      () => createPaidMatch(configMaxFee + 1, PAYMENT_METHOD.WALLET),
+      'EntryFeeOutOfRange',
+      'Entry fee above the config maximum should fail'
+    );
+
+    // Without overrides the escrow uses the config platform fee
+    let escrowPDA = await createPaidMatch(0.1 * LAMPORTS_PER_SOL, PAYMENT_METHOD.WALLET);
+    let escrow = await program.account.escrowAccount.fetch(escrowPDA) as unknown as EscrowAccountType;
+    this.assertEqual(escrow.platformFeeBps ?? escrow.platform_fee_bps, configFeeBps, 'Escrow should use the config platform fee');
+
+    try {
+      // Test 2: High-stakes table - 10% fee, 0.5-5 SOL entry, platform deposits only
+      await setGameEconomics(1000, 0.5 * LAMPORTS_PER_SOL, 5 * LAMPORTS_PER_SOL, 0x02);
+
+      await expectError(
+        () => createPaidMatch(LAMPORTS_PER_SOL, PAYMENT_METHOD.WALLET),
+        'InvalidPaymentMethod',
+        'Wallet payment should be rejected when the game only allows platform deposits'
+      );
+      await expectError(
+        () => createPaidMatch(0.1 * LAMPORTS_PER_SOL, PAYMENT_METHOD.PLATFORM),
+        'EntryFeeOutOfRange',
+        'Entry fee below the game minimum should fail'
+      );
+
+      escrowPDA = await createPaidMatch(LAMPORTS_PER_SOL, PAYMENT_METHOD.PLATFORM);
+      escrow = await program.account.escrowAccount.fetch(escrowPDA) as unknown as EscrowAccountType;
+      this.assertEqual(escrow.platformFeeBps ?? escrow.platform_fee_bps, 1000, 'Escrow should use the game platform fee');
+
+      // Test 3: Invalid overrides
+      await expectError(
+        () => setGameEconomics(10001, null, null, null),
+        'InvalidFeeParameter',
+        'Platform fee above 100% should fail'
+      );
+      await expectError(
+        () => setGameEconomics(null, LAMPORTS_PER_SOL, null, null),
+        'InvalidFeeParameter',
+        'Min entry fee without max should fail'
+      );
+      await expectError(
+        () => setGameEconomics(null, null, null, 0),
+        'InvalidFeeParameter',
+        'Empty payment method mask should fail'
+      );
+
+      // Test 4: Only the registry authority can set game economics
+      const stranger = Keypair.generate();
+      await expectError(
+        () => setGameEconomics(0, null, null, null, stranger),
+        'Unauthorized',
+        'Unauthorized set_game_economics should fail'
+      );
+    } finally {
+      // Clear overrides - the game falls back to the config
+      await setGameEconomics(null, null, null, null);
+    }
+
+    escrowPDA = await createPaidMatch(0.1 * LAMPORTS_PER_SOL, PAYMENT_METHOD.WALLET);
+    escrow = await program.account.escrowAccount.fetch(escrowPDA) as unknown as EscrowAccountType;
+    this.assertEqual(escrow.platformFeeBps ?? escrow.platform_fee_bps, configFeeBps, 'Cleared overrides should restore the config platform fee');
+
+    console.log('✓ Game economics overrides test passed');
+  }
+}
+
+const testInstance = new GameEconomicsOverridesTest();
+registerMochaTest(testInstance);
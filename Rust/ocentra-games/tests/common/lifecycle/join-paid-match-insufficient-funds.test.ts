@@ -37,6 +37,7 @@ class JoinPaidMatchInsufficientFundsTest extends BaseTest {
       authority,
       generateUniqueMatchId,
       getTestGame,
+      PAID_GAME_ID,
       getTestSeed,
       getTestUserId,
       airdrop,
@@ -83,8 +84,8 @@ class JoinPaidMatchInsufficientFundsTest extends BaseTest {
     const [matchPDA1] = await getMatchPDA(matchId1);
     const [registryPDA] = await getRegistryPDA();
     const [escrowPDA1] = await getEscrowPDA(matchPDA1);
-    const claimGame = getTestGame(0);
-    if (!claimGame) throw new Error("CLAIM game not found");
+    const paidGame = getTestGame(PAID_GAME_ID);
+    if (!paidGame) throw new Error("Paid match game not found in test data");
     const seed = getTestSeed();
 
     const poorPlayer = Keypair.generate();
@@ -93,12 +94,16 @@ class JoinPaidMatchInsufficientFundsTest extends BaseTest {
     await program.methods
       .createMatch(
         matchId1,
-        claimGame.game_id,
+        paidGame.game_id,
         new anchor.BN(seed),
-        entryFee,
-        PAYMENT_METHOD.WALLET,
-        MATCH_TYPE.PAID,
-        null
+        {
+          entryFee,
+          paymentMethod: PAYMENT_METHOD.WALLET,
+          matchType: MATCH_TYPE.PAID,
+          tournamentId: null,
+          prizeStructure: null,
+          prizeSplitBps: null,
+        }
       )
       .accounts({
         matchAccount: matchPDA1,
@@ -166,12 +171,16 @@ class JoinPaidMatchInsufficientFundsTest extends BaseTest {
     await program.methods
       .createMatch(
         matchId2,
-        claimGame.game_id,
+        paidGame.game_id,
         new anchor.BN(seed),
-        entryFee,
-        PAYMENT_METHOD.PLATFORM,
-        MATCH_TYPE.PAID,
-        null
+        {
+          entryFee,
+          paymentMethod: PAYMENT_METHOD.PLATFORM,
+          matchType: MATCH_TYPE.PAID,
+          tournamentId: null,
+          prizeStructure: null,
+          prizeSplitBps: null,
+        }
       )
       .accounts({
         matchAccount: matchPDA2,
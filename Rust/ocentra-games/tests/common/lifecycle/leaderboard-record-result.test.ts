@@ -0,0 +1,260 @@
+/**
+ * Test: Leaderboard updates from match results
+ * Category: LIFECYCLE
+ *
+ * init_leaderboard creates the GameLeaderboard of a game type for a season (seeds
+ * ["leaderboard", game_type, season_id]). After a match ends with recorded scores, record_result
+ * updates every participant's UserAccount season stats and win streak, upserts them into the
+ * leaderboard of ConfigAccount.current_season_id and writes back their rank and multiplier.
+ * Each UserAccount is bound to the wallet that opened it; a match with a seat joined under
+ * someone else's user_id is never recorded.
+ */
+
+import { BaseTest } from '@/core';
+import { TestCategory, ClusterRequirement } from '@/core';
+import { registerMochaTest } from '@/core';
+import { SystemProgram, Keypair, PublicKey } from "@solana/web3.js";
+import {
+  getMatchPDA,
+  getConfigAccountPDA,
+  getUserAccountPDA,
+  getLeaderboardPDA,
+  ConfigAccountType,
+  UserAccountType,
+  GameLeaderboardType,
+} from '@/common';
+import * as anchor from "@coral-xyz/anchor";
+
+// Reward multiplier per leaderboard rank (UserAccount::calculate_multiplier)
+const multiplierForRank = (rank: number): number => {
+  if (rank === 0) return 1;
+  if (rank <= 5) return 5;
+  if (rank <= 10) return 4;
+  if (rank <= 25) return 3;
+  if (rank <= 50) return 2;
+  return 1;
+};
+
+class LeaderboardRecordResultTest extends BaseTest {
+  constructor() {
+    super({
+      id: 'leaderboard-record-result',
+      name: 'Leaderboard updates from match results',
+      description: 'Tests init_leaderboard and record_result: season stats, win streaks, ranks and multipliers',
+      tags: {
+        category: TestCategory.LIFECYCLE,
+        cluster: ClusterRequirement.ANY,
+        requiresSetup: true,
+        requiresRegistry: true,
+      },
+    });
+  }
+
+  async run(): Promise<void> {
+    const {
+      program,
+      authority,
+      generateUniqueMatchId,
+      getTestGame,
+      getTestSeed,
+      airdrop,
+    } = await import('@/helpers');
+    const { getRegistryPDA } = await import('@/common');
+
+    const [configPDA] = await getConfigAccountPDA();
+    const [registryPDA] = await getRegistryPDA();
+    const claimGame = getTestGame(0);
+    if (!claimGame) throw new Error("CLAIM game not found");
+
+    const config = await program.account.configAccount.fetch(configPDA) as unknown as ConfigAccountType;
+    const seasonId = new anchor.BN(
+      config.currentSeasonId?.toNumber() ?? config.current_season_id?.toNumber() ?? 0
+    );
+    const [leaderboardPDA] = await getLeaderboardPDA(claimGame.game_id, seasonId);
+
+    // Accounts may already exist from earlier runs
+    const ignoreAlreadyInUse = async (action: () => Promise<unknown>) => {
+      try {
+        await action();
+      } catch (err: unknown) {
+        const error = err as { message?: string };
+        if (!error.message?.includes("already in use") && !error.message?.includes("0x0")) {
+          throw err;
+        }
+      }
+    };
+
+    // Setup: current season leaderboard and both users' accounts, bound to their wallets
+    await ignoreAlreadyInUse(async () => {
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      await (program.methods as any)
+        .initLeaderboard(claimGame.game_id, seasonId)
+        .accounts({
+          leaderboard: leaderboardPDA,
+          registry: registryPDA,
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .rpc();
+    });
+    const player1 = Keypair.generate();
+    const player2 = Keypair.generate();
+    const impostor = Keypair.generate();
+    await airdrop(player1.publicKey, 1);
+    await airdrop(player2.publicKey, 1);
+    await airdrop(impostor.publicKey, 1);
+
+    // Fresh user IDs - a user_id stays bound to the wallet that opened it
+    const runTag = Date.now().toString(36);
+    const userIds = [`lb-${runTag}-0`, `lb-${runTag}-1`];
+    const userPDAs: PublicKey[] = [];
+    for (const [index, wallet] of [player1, player2].entries()) {
+      const [userPDA] = await getUserAccountPDA(userIds[index]);
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      await (program.methods as any)
+        .initUserAccount(userIds[index])
+        .accounts({
+          userAccount: userPDA,
+          configAccount: configPDA,
+          wallet: wallet.publicKey,
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .signers([wallet])
+        .rpc();
+      userPDAs.push(userPDA);
+    }
+
+    const fetchUsers = async () => Promise.all(
+      userPDAs.map(async (pda) => await program.account.userAccount.fetch(pda) as unknown as UserAccountType)
+    );
+    const usersBefore = await fetchUsers();
+
+    // Play a free heads-up match to the end, seats joined by `seats` under userIds
+    const playMatch = async (label: string, seats: Keypair[]) => {
+      const matchId = generateUniqueMatchId(label);
+      const [matchPDA] = await getMatchPDA(matchId);
+      await program.methods
+        .createMatch(matchId, claimGame.game_id, new anchor.BN(getTestSeed()), { entryFee: null, paymentMethod: null, matchType: null, tournamentId: null, prizeStructure: null, prizeSplitBps: null })
+        .accounts({
+          matchAccount: matchPDA,
+          registry: registryPDA,
+          escrowAccount: null,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .rpc();
+      for (const [index, player] of seats.entries()) {
+        await program.methods
+          .joinMatch(matchId, userIds[index])
+          .accounts({
+            matchAccount: matchPDA,
+            registry: registryPDA,
+            escrowAccount: null,
+            userDepositAccount: null,
+            playerWallet: null,
+            player: player.publicKey,
+            systemProgram: SystemProgram.programId,
+          } as never)
+          .signers([player])
+          .rpc();
+      }
+      await program.methods
+        .startMatch(matchId)
+        .accounts({
+          matchAccount: matchPDA,
+          registry: registryPDA,
+          escrowAccount: null,
+          authority: authority.publicKey,
+        } as never)
+        .rpc();
+      await program.methods
+        .endMatch(matchId, null, null)
+        .accounts({
+          matchAccount: matchPDA,
+          escrowAccount: null,
+          authority: authority.publicKey,
+        } as never)
+        .rpc();
+      return { matchId, matchPDA };
+    };
+
+    const recordResult = async (matchId: string, matchPDA: PublicKey) => {
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      await (program.methods as any)
+        .recordResult(matchId)
+        .accounts({
+          matchAccount: matchPDA,
+          leaderboard: leaderboardPDA,
+          configAccount: configPDA,
+          caller: authority.publicKey,
+        } as never)
+        .remainingAccounts(userPDAs.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })))
+        .rpc();
+    };
+
+    // Test 1: A seat joined under someone else's user_id blocks the result
+    const forged = await playMatch("leaderboard-forged", [impostor, player2]);
+    try {
+      await recordResult(forged.matchId, forged.matchPDA);
+      this.assert(false, 'Recording a seat joined under another wallet\'s user_id should fail');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'UserWalletMismatch', 'Expected UserWalletMismatch');
+    }
+    const usersAfterForged = await fetchUsers();
+    for (const seat of [0, 1]) {
+      this.assertEqual(
+        usersAfterForged[seat].seasonGames ?? usersAfterForged[seat].season_games,
+        usersBefore[seat].seasonGames ?? usersBefore[seat].season_games,
+        `Seat ${seat} season games should not change for a forged match`
+      );
+    }
+
+    const { matchId, matchPDA } = await playMatch("leaderboard", [player1, player2]);
+
+    // Test 2: Season stats, streaks, ranks and multipliers
+    await recordResult(matchId, matchPDA);
+    const scores = (await program.account.match.fetch(matchPDA) as unknown as { scores: number[] }).scores;
+    const topScore = Math.max(scores[0], scores[1]);
+    const usersAfter = await fetchUsers();
+    const leaderboard = await program.account.gameLeaderboard.fetch(leaderboardPDA) as unknown as GameLeaderboardType;
+    this.assert((leaderboard.entryCount ?? leaderboard.entry_count ?? 0) >= 2, 'Leaderboard should hold both players');
+
+    for (const seat of [0, 1]) {
+      const before = usersBefore[seat];
+      const after = usersAfter[seat];
+      const won = scores[seat] === topScore;
+      const sameSeason = (before.currentSeasonId ?? before.current_season_id)?.toString() === seasonId.toString();
+      const gamesBefore = sameSeason ? (before.seasonGames ?? before.season_games ?? 0) : 0;
+      const winsBefore = sameSeason ? (before.seasonWins ?? before.season_wins ?? 0) : 0;
+
+      this.assertEqual(after.seasonGames ?? after.season_games, gamesBefore + 1, `Seat ${seat} season games should increase`);
+      this.assertEqual(after.seasonWins ?? after.season_wins, winsBefore + (won ? 1 : 0), `Seat ${seat} season wins should follow the result`);
+      this.assertEqual(
+        after.winStreak ?? after.win_streak,
+        won ? (before.winStreak ?? before.win_streak ?? 0) + 1 : 0,
+        `Seat ${seat} win streak should follow the result`
+      );
+
+      const rank = after.leaderboardRank ?? after.leaderboard_rank ?? 0;
+      this.assert(rank > 0, `Seat ${seat} should be ranked`);
+      const entry = leaderboard.entries?.[rank - 1];
+      const entryUserId = Buffer.from(entry?.userId ?? entry?.user_id ?? []).toString().replace(/\0+$/, '');
+      this.assertEqual(entryUserId, userIds[seat], `Leaderboard rank ${rank} should hold seat ${seat}`);
+      this.assertEqual(after.activeMultiplier ?? after.active_multiplier, multiplierForRank(rank), `Seat ${seat} multiplier should follow its rank`);
+    }
+
+    // Test 3: A match is recorded only once
+    try {
+      await recordResult(matchId, matchPDA);
+      this.assert(false, 'Recording a match twice should fail');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'ResultsAlreadyRecorded', 'Expected ResultsAlreadyRecorded');
+    }
+
+    console.log('✓ Leaderboard record result test passed');
+  }
+}
+
+const testInstance = new LeaderboardRecordResultTest();
+registerMochaTest(testInstance);
@@ -0,0 +1,225 @@
+/**
+ * Test: Leave paid match during Dealing phase
+ * Category: LIFECYCLE
+ *
+ * Tests leave_match on a wallet-paid match:
+ * create → 3 players join → player 0 leaves (refunded, seats compacted) → player 1 leaves (escrow unfunded)
+ */
+
+import { BaseTest } from '@/core';
+import { TestCategory, ClusterRequirement } from '@/core';
+import { registerMochaTest } from '@/core';
+import { SystemProgram, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { getMatchPDA, getEscrowPDA, getConfigAccountPDA, ConfigAccountType } from '@/common';
+import * as anchor from "@coral-xyz/anchor";
+
+// Match type and payment method constants
+const MATCH_TYPE = {
+  FREE: 0,
+  PAID: 1,
+} as const;
+
+const PAYMENT_METHOD = {
+  WALLET: 0,
+  PLATFORM: 1,
+} as const;
+
+type EscrowView = {
+  totalEntryLamports?: { toNumber(): number };
+  total_entry_lamports?: { toNumber(): number };
+  playerStakes?: Array<{ toNumber(): number }>;
+  player_stakes?: Array<{ toNumber(): number }>;
+  statusFlags?: number;
+  status_flags?: number;
+};
+
+class LeavePaidMatchRefundTest extends BaseTest {
+  constructor() {
+    super({
+      id: 'leave-paid-match-refund',
+      name: 'Leave paid match during Dealing phase',
+      description: 'Tests leave_match refunds the stake, compacts seats and recomputes escrow funding',
+      tags: {
+        category: TestCategory.LIFECYCLE,
+        cluster: ClusterRequirement.ANY,
+        requiresSetup: true,
+        requiresRegistry: true,
+      },
+    });
+  }
+
+  async run(): Promise<void> {
+    const {
+      program,
+      authority,
+      generateUniqueMatchId,
+      getTestGame,
+      PAID_GAME_ID,
+      getTestSeed,
+      getTestUserId,
+      airdrop,
+    } = await import('@/helpers');
+    const { getRegistryPDA } = await import('@/common');
+
+    // Setup: Ensure config exists and is unpaused
+    const [configPDA] = await getConfigAccountPDA();
+    try {
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      await (program.methods as any)
+        .initializeConfig(authority.publicKey)
+        .accounts({
+          configAccount: configPDA,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .rpc();
+    } catch (err: unknown) {
+      const error = err as { message?: string };
+      if (!error.message?.includes("already in use") && !error.message?.includes("0x0")) {
+        throw err;
+      }
+    }
+    const config = await program.account.configAccount.fetch(configPDA) as unknown as ConfigAccountType;
+    if (config.isPaused ?? config.is_paused) {
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      await (program.methods as any)
+        .unpauseProgram()
+        .accounts({
+          configAccount: configPDA,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .rpc();
+    }
+
+    const matchId = generateUniqueMatchId("leave-paid");
+    const paidGame = getTestGame(PAID_GAME_ID);
+    if (!paidGame) throw new Error("Paid match game not found in test data");
+
+    const [matchPDA] = await getMatchPDA(matchId);
+    const [registryPDA] = await getRegistryPDA();
+    const [escrowPDA] = await getEscrowPDA(matchPDA);
+
+    const players = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+    for (const player of players) {
+      await airdrop(player.publicKey, 2);
+    }
+
+    const entryFee = new anchor.BN(0.1 * LAMPORTS_PER_SOL);
+
+    await program.methods
+      .createMatch(
+        matchId,
+        paidGame.game_id,
+        new anchor.BN(getTestSeed()),
+        {
+          entryFee,
+          paymentMethod: PAYMENT_METHOD.WALLET,
+          matchType: MATCH_TYPE.PAID,
+          tournamentId: null,
+          prizeStructure: null,
+          prizeSplitBps: null,
+        }
+      )
+      .accounts({
+        matchAccount: matchPDA,
+        registry: registryPDA,
+        escrowAccount: escrowPDA,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as never)
+      .rpc();
+
+    for (let i = 0; i < players.length; i++) {
+      await program.methods
+        .joinMatch(matchId, getTestUserId(i))
+        .accounts({
+          matchAccount: matchPDA,
+          registry: registryPDA,
+          escrowAccount: escrowPDA,
+          userDepositAccount: null,
+          playerWallet: players[i].publicKey,
+          player: players[i].publicKey,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .signers([players[i]])
+        .rpc();
+    }
+
+    const leave = async (player: Keypair) => {
+      await program.methods
+        .leaveMatch(matchId)
+        .accounts({
+          matchAccount: matchPDA,
+          registry: registryPDA,
+          escrowAccount: escrowPDA,
+          userDepositAccount: null,
+          configAccount: configPDA,
+          player: player.publicKey,
+        } as never)
+        .signers([player])
+        .rpc();
+    };
+
+    // Test 1: Player 0 leaves - refunded, later seats shift down
+    const balanceBefore = await program.provider.connection.getBalance(players[0].publicKey);
+    await leave(players[0]);
+    const balanceAfter = await program.provider.connection.getBalance(players[0].publicKey);
+    const tolerance = 10000; // Transaction fee
+    this.assert(
+      balanceAfter - balanceBefore >= entryFee.toNumber() - tolerance,
+      `Leaving player should be refunded the entry fee. Got ${balanceAfter - balanceBefore}`
+    );
+
+    const matchAfterLeave = await program.account.match.fetch(matchPDA);
+    this.assertEqual(matchAfterLeave.playerCount, 2, 'Two players should remain');
+    this.assertEqual(
+      matchAfterLeave.playerWallets[0].toString(),
+      players[1].publicKey.toString(),
+      'Player 1 should move into seat 0'
+    );
+    this.assertEqual(
+      matchAfterLeave.playerWallets[1].toString(),
+      players[2].publicKey.toString(),
+      'Player 2 should move into seat 1'
+    );
+
+    const escrowAfterLeave = await program.account.escrowAccount.fetch(escrowPDA) as unknown as EscrowView;
+    const stakes = escrowAfterLeave.playerStakes ?? escrowAfterLeave.player_stakes ?? [];
+    this.assertEqual(stakes[0]?.toNumber() ?? 0, entryFee.toNumber(), 'Seat 0 stake should be compacted');
+    this.assertEqual(stakes[1]?.toNumber() ?? 0, entryFee.toNumber(), 'Seat 1 stake should be compacted');
+    this.assertEqual(stakes[2]?.toNumber() ?? 0, 0, 'Vacated seat stake should be cleared');
+    this.assertEqual(
+      escrowAfterLeave.totalEntryLamports?.toNumber() ?? escrowAfterLeave.total_entry_lamports?.toNumber() ?? 0,
+      entryFee.toNumber() * 2,
+      'Escrow total should drop by the refunded stake'
+    );
+    const flagsAfterLeave = escrowAfterLeave.statusFlags ?? escrowAfterLeave.status_flags ?? 0;
+    this.assert((flagsAfterLeave & 0x01) !== 0, 'Escrow should stay funded with two players');
+
+    // Test 2: Leaving again fails (wallet no longer seated)
+    try {
+      await leave(players[0]);
+      this.assert(false, 'Second leave should fail');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'PlayerNotInMatch', 'Expected PlayerNotInMatch');
+    }
+
+    // Test 3: Dropping below minimum players clears the funded flag
+    await leave(players[1]);
+    const escrowAfterSecondLeave = await program.account.escrowAccount.fetch(escrowPDA) as unknown as EscrowView;
+    const flagsAfterSecondLeave = escrowAfterSecondLeave.statusFlags ?? escrowAfterSecondLeave.status_flags ?? 0;
+    this.assert((flagsAfterSecondLeave & 0x01) === 0, 'Escrow should not be funded below minimum players');
+
+    const matchAfterSecondLeave = await program.account.match.fetch(matchPDA);
+    this.assertEqual(matchAfterSecondLeave.playerCount, 1, 'One player should remain');
+    this.assertEqual(
+      matchAfterSecondLeave.playerWallets[0].toString(),
+      players[2].publicKey.toString(),
+      'Remaining player should hold seat 0'
+    );
+  }
+}
+
+const testInstance = new LeavePaidMatchRefundTest();
+registerMochaTest(testInstance);
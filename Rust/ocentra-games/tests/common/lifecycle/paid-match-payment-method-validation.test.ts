@@ -39,6 +39,7 @@ class PaidMatchPaymentMethodValidationTest extends BaseTest {
       authority,
       generateUniqueMatchId,
       getTestGame,
+      PAID_GAME_ID,
       getTestSeed,
       getTestUserId,
       airdrop,
@@ -78,8 +79,8 @@ class PaidMatchPaymentMethodValidationTest extends BaseTest {
         .rpc();
     }
 
-    const claimGame = getTestGame(0);
-    if (!claimGame) throw new Error("CLAIM game not found");
+    const paidGame = getTestGame(PAID_GAME_ID);
+    if (!paidGame) throw new Error("Paid match game not found in test data");
     const seed = getTestSeed();
     const entryFee = new anchor.BN(0.1 * LAMPORTS_PER_SOL);
 
@@ -108,12 +109,16 @@ class PaidMatchPaymentMethodValidationTest extends BaseTest {
     await program.methods
       .createMatch(
         matchId1,
-        claimGame.game_id,
+        paidGame.game_id,
         new anchor.BN(seed),
-        entryFee,
-        PAYMENT_METHOD.WALLET,
-        MATCH_TYPE.PAID,
-        null
+        {
+          entryFee,
+          paymentMethod: PAYMENT_METHOD.WALLET,
+          matchType: MATCH_TYPE.PAID,
+          tournamentId: null,
+          prizeStructure: null,
+          prizeSplitBps: null,
+        }
       )
       .accounts({
         matchAccount: matchPDA1,
@@ -172,12 +177,16 @@ class PaidMatchPaymentMethodValidationTest extends BaseTest {
     await program.methods
       .createMatch(
         matchId2,
-        claimGame.game_id,
+        paidGame.game_id,
         new anchor.BN(seed),
-        entryFee,
-        PAYMENT_METHOD.PLATFORM,
-        MATCH_TYPE.PAID,
-        null
+        {
+          entryFee,
+          paymentMethod: PAYMENT_METHOD.PLATFORM,
+          matchType: MATCH_TYPE.PAID,
+          tournamentId: null,
+          prizeStructure: null,
+          prizeSplitBps: null,
+        }
       )
       .accounts({
         matchAccount: matchPDA2,
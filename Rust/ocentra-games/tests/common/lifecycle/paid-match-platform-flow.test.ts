@@ -3,14 +3,14 @@
  * Category: LIFECYCLE
  * 
  * Tests the complete lifecycle of a paid match using platform payment method:
- * deposit → create → join → start → end → distribute prizes
+ * deposit → create → join → start → end → distribute prizes (held until the dispute window closes)
  */
 
 import { BaseTest } from '@/core';
 import { TestCategory, ClusterRequirement } from '@/core';
 import { registerMochaTest } from '@/core';
 import { SystemProgram, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
-import { getMatchPDA, getEscrowPDA, getConfigAccountPDA, getUserDepositPDA, ConfigAccountType, UserDepositAccountType } from '@/common';
+import { getMatchPDA, getEscrowPDA, getConfigAccountPDA, getUserDepositPDA, ensureTreasuryVault, ConfigAccountType, UserDepositAccountType } from '@/common';
 import * as anchor from "@coral-xyz/anchor";
 
 // Match type and payment method constants
@@ -45,6 +45,7 @@ class PaidMatchPlatformFlowTest extends BaseTest {
       authority,
       generateUniqueMatchId,
       getTestGame,
+      PAID_GAME_ID,
       getTestSeed,
       getTestUserId,
       airdrop,
@@ -85,8 +86,8 @@ class PaidMatchPlatformFlowTest extends BaseTest {
     }
 
     const matchId = generateUniqueMatchId("paid-platform");
-    const claimGame = getTestGame(0);
-    if (!claimGame) throw new Error("CLAIM game not found in test data");
+    const paidGame = getTestGame(PAID_GAME_ID);
+    if (!paidGame) throw new Error("Paid match game not found in test data");
     const seed = getTestSeed();
 
     const [matchPDA] = await getMatchPDA(matchId);
@@ -140,12 +141,16 @@ class PaidMatchPlatformFlowTest extends BaseTest {
     await program.methods
       .createMatch(
         matchId,
-        claimGame.game_id,
+        paidGame.game_id,
         new anchor.BN(seed),
-        entryFee,
-        PAYMENT_METHOD.PLATFORM,
-        MATCH_TYPE.PAID,
-        null
+        {
+          entryFee,
+          paymentMethod: PAYMENT_METHOD.PLATFORM,
+          matchType: MATCH_TYPE.PAID,
+          tournamentId: null,
+          prizeStructure: null,
+          prizeSplitBps: null,
+        }
       )
       .accounts({
         matchAccount: matchPDA,
@@ -247,116 +252,43 @@ class PaidMatchPlatformFlowTest extends BaseTest {
       } as never)
       .rpc();
 
-    // Step 6: Distribute prizes
-    const configForFee = await program.account.configAccount.fetch(configPDA) as unknown as ConfigAccountType;
-    const platformFeeBps = configForFee.platformFeeBps ?? configForFee.platform_fee_bps ?? 500;
-    const platformFee = Math.floor(expectedTotal * platformFeeBps / 10000);
-    const prizePool = expectedTotal - platformFee;
-
-    // Capture balances before distribution
-    const treasuryBalanceBefore = await program.provider.connection.getBalance(authority.publicKey);
-
-    // Provide all winner accounts (required by Anchor, even if not all used)
-    // Use player1 as dummy for unused slots
-    await program.methods
-      .distributePrizes(
-        matchId,
-        Buffer.from([0]), // Winner index - Buffer required for Vec<u8>
-        [new anchor.BN(prizePool)] // Prize amounts as BN[] required for Vec<u64>
-      )
-      .accounts({
-        escrowAccount: escrowPDA,
-        matchAccount: matchPDA,
-        configAccount: configPDA,
-        treasury: authority.publicKey,
-        winner0: player1.publicKey,
-        winner1: player1.publicKey, // Dummy - not used but required
-        winner2: player1.publicKey, // Dummy - not used but required
-        winner3: player1.publicKey, // Dummy - not used but required
-        winner4: player1.publicKey, // Dummy - not used but required
-        winner5: player1.publicKey, // Dummy - not used but required
-        winner6: player1.publicKey, // Dummy - not used but required
-        winner7: player1.publicKey, // Dummy - not used but required
-        winner8: player1.publicKey, // Dummy - not used but required
-        winner9: player1.publicKey, // Dummy - not used but required
-        winnerDeposit0: depositPDA1, // Platform payment - prize goes to deposit account
-        winnerDeposit1: null,
-        winnerDeposit2: null,
-        winnerDeposit3: null,
-        winnerDeposit4: null,
-        winnerDeposit5: null,
-        winnerDeposit6: null,
-        winnerDeposit7: null,
-        winnerDeposit8: null,
-        winnerDeposit9: null,
-        systemProgram: SystemProgram.programId,
-      } as never)
-      .rpc();
-
-    // Verify escrow balance is empty (all funds distributed)
-    const escrowBalanceAfter = await program.provider.connection.getBalance(escrowPDA);
-    // Escrow should only have rent-exempt balance left (minimal)
-    // Rent-exempt minimum for EscrowAccount is ~2.2M lamports (0.0022 SOL)
-    // Check that balance is close to rent-exempt minimum (within 10% tolerance)
-    const rentExemptMinimum = 2000000; // ~0.002 SOL
-    const rentExemptMaximum = 3000000; // ~0.003 SOL (allows for small variations)
-    this.assert(
-      escrowBalanceAfter >= rentExemptMinimum && escrowBalanceAfter <= rentExemptMaximum,
-      `Escrow should only have rent-exempt balance. Expected between ${rentExemptMinimum} and ${rentExemptMaximum} lamports, got ${escrowBalanceAfter}`
-    );
-
-    // Verify escrow account total_entry_lamports is 0
-    const escrowAccountAfterDistribute = await program.account.escrowAccount.fetch(escrowPDA) as unknown as {
-      totalEntryLamports?: { toNumber(): number };
-      total_entry_lamports?: { toNumber(): number };
-    };
-    const escrowTotalAfter = escrowAccountAfterDistribute.totalEntryLamports?.toNumber() ?? escrowAccountAfterDistribute.total_entry_lamports?.toNumber() ?? 0;
-    this.assertEqual(
-      escrowTotalAfter,
-      0,
-      'Escrow total_entry_lamports should be 0 after distribution'
-    );
-
-    // Verify treasury received platform fee (accounting for transaction fees)
-    const treasuryBalanceAfter = await program.provider.connection.getBalance(authority.publicKey);
-    const treasuryIncrease = treasuryBalanceAfter - treasuryBalanceBefore;
-    // Treasury receives platform fee but pays transaction fees (~5000 lamports)
-    // So net increase should be platformFee - transactionFee
-    // Allow tolerance for transaction fees (typically 5000-10000 lamports)
-    const transactionFeeTolerance = 10000; // Allow up to 0.00001 SOL for transaction fees
-    this.assert(
-      treasuryIncrease >= platformFee - transactionFeeTolerance,
-      `Treasury should receive platform fee (minus transaction fees). Expected >= ${platformFee - transactionFeeTolerance} lamports, got ${treasuryIncrease}`
-    );
-
-    // Verify winner's deposit account received prize (exact amount)
-    const depositAccount1AfterDistribute = await program.account.userDepositAccount.fetch(depositPDA1) as unknown as {
-      availableLamports?: { toNumber(): number };
-      available_lamports?: { toNumber(): number };
-      inPlayLamports?: { toNumber(): number };
-      in_play_lamports?: { toNumber(): number };
+    // Step 6: Failure - Prizes wait for the post-match dispute window to close
+    // (a local validator cannot advance the clock a full window, so payout is not reached here)
+    const treasuryVaultPDA = await ensureTreasuryVault();
+    const inPlayOf = async (depositPDA: typeof depositPDA1) => {
+      const depositAccount = await program.account.userDepositAccount.fetch(depositPDA) as unknown as UserDepositAccountType;
+      return depositAccount.inPlayLamports?.toNumber() ?? depositAccount.in_play_lamports?.toNumber() ?? 0;
     };
-    const availableFinal = depositAccount1AfterDistribute.availableLamports?.toNumber() ?? depositAccount1AfterDistribute.available_lamports?.toNumber() ?? 0;
-    const inPlayFinal = depositAccount1AfterDistribute.inPlayLamports?.toNumber() ?? depositAccount1AfterDistribute.in_play_lamports?.toNumber() ?? 0;
+    const inPlayBefore = [await inPlayOf(depositPDA1), await inPlayOf(depositPDA2)];
+    try {
+      await program.methods
+        .distributePrizes(matchId)
+        .accounts({
+          escrowAccount: escrowPDA,
+          matchAccount: matchPDA,
+          configAccount: configPDA,
+          treasuryVault: treasuryVaultPDA,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        // Platform payment - every seat's deposit account, in seat order (winners and losers)
+        .remainingAccounts([
+          { pubkey: depositPDA1, isWritable: true, isSigner: false },
+          { pubkey: depositPDA2, isWritable: true, isSigner: false },
+        ])
+        .rpc();
+      this.assert(false, 'distribute_prizes should wait for the dispute window to close');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'DisputeWindowOpen', 'Expected DisputeWindowOpen error');
+    }
 
-    // Winner should have prize added to available, and in_play should be unlocked (entry fee removed)
-    // Expected: availableAfter1 (after join) + prizePool = availableFinal
-    const expectedAvailableFinal = availableAfter1 + prizePool;
-    this.assertEqual(
-      availableFinal,
-      expectedAvailableFinal,
-      `Winner should receive exact prize pool. Expected ${expectedAvailableFinal} lamports, got ${availableFinal}`
-    );
-    this.assertEqual(
-      inPlayFinal,
-      0,
-      'Entry fee should be unlocked after match ends'
-    );
+    // Entry fees stay locked in play while seats can still flag a dispute
+    this.assertEqual(inPlayBefore[0], inPlayAfter1, 'Player 1 entry fee should still be locked');
+    this.assertEqual(await inPlayOf(depositPDA1), inPlayBefore[0], 'Player 1 entry fee should stay locked inside the dispute window');
+    this.assertEqual(await inPlayOf(depositPDA2), inPlayBefore[1], 'Player 2 entry fee should stay locked inside the dispute window');
 
     console.log('✓ Paid match platform flow completed successfully');
     console.log(`  - Entry fee: ${entryFee.toNumber() / LAMPORTS_PER_SOL} SOL`);
     console.log(`  - Total escrow: ${expectedTotal / LAMPORTS_PER_SOL} SOL`);
-    console.log(`  - Prize pool: ${prizePool / LAMPORTS_PER_SOL} SOL`);
   }
 }
 
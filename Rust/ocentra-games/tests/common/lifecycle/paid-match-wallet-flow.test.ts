@@ -3,14 +3,14 @@
  * Category: LIFECYCLE
  * 
  * Tests the complete lifecycle of a paid match using wallet payment method:
- * create → join → start → end → distribute prizes
+ * create → join → start → end → distribute prizes (held until the dispute window closes)
  */
 
 import { BaseTest } from '@/core';
 import { TestCategory, ClusterRequirement } from '@/core';
 import { registerMochaTest } from '@/core';
 import { SystemProgram, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
-import { getMatchPDA, getEscrowPDA, getConfigAccountPDA, ConfigAccountType, EscrowAccountType } from '@/common';
+import { getMatchPDA, getEscrowPDA, getConfigAccountPDA, ensureTreasuryVault, ConfigAccountType, EscrowAccountType } from '@/common';
 import * as anchor from "@coral-xyz/anchor";
 
 // Match type and payment method constants
@@ -45,6 +45,7 @@ class PaidMatchWalletFlowTest extends BaseTest {
       authority,
       generateUniqueMatchId,
       getTestGame,
+      PAID_GAME_ID,
       getTestSeed,
       getTestUserId,
       airdrop,
@@ -85,8 +86,8 @@ class PaidMatchWalletFlowTest extends BaseTest {
     }
 
     const matchId = generateUniqueMatchId("paid-wallet");
-    const claimGame = getTestGame(0);
-    if (!claimGame) throw new Error("CLAIM game not found in test data");
+    const paidGame = getTestGame(PAID_GAME_ID);
+    if (!paidGame) throw new Error("Paid match game not found in test data");
     const seed = getTestSeed();
 
     const [matchPDA] = await getMatchPDA(matchId);
@@ -105,12 +106,16 @@ class PaidMatchWalletFlowTest extends BaseTest {
     await program.methods
       .createMatch(
         matchId,
-        claimGame.game_id,
+        paidGame.game_id,
         new anchor.BN(seed),
-        entryFee, // entry_fee
-        PAYMENT_METHOD.WALLET, // payment_method
-        MATCH_TYPE.PAID, // match_type
-        null // tournament_id
+        {
+          entryFee,
+          paymentMethod: PAYMENT_METHOD.WALLET,
+          matchType: MATCH_TYPE.PAID,
+          tournamentId: null,
+          prizeStructure: null, // None = winner takes all
+          prizeSplitBps: null, // None = no ranked split
+        }
       )
       .accounts({
         matchAccount: matchPDA,
@@ -257,117 +262,43 @@ class PaidMatchWalletFlowTest extends BaseTest {
     const matchAccountAfterEnd = await program.account.match.fetch(matchPDA);
     this.assertEqual(matchAccountAfterEnd.phase, 2, 'Match should be in Ended phase');
 
-    // Test 5: Distribute prizes
-    // Get config to calculate platform fee
-    const configForFee = await program.account.configAccount.fetch(configPDA) as unknown as ConfigAccountType;
-    const platformFeeBps = configForFee.platformFeeBps ?? configForFee.platform_fee_bps ?? 500; // Default 5%
-    const platformFee = Math.floor(expectedTotal * platformFeeBps / 10000);
-    const prizePool = expectedTotal - platformFee;
-
-    // Capture balances before distribution
-    const treasuryBalanceBefore = await program.provider.connection.getBalance(authority.publicKey);
-    const player1BalanceBeforeDistribute = await program.provider.connection.getBalance(player1.publicKey);
-
-    // Distribute prizes (winner gets all prize pool)
-    // Note: All winner accounts must be provided (use dummy for unused slots)
-    await program.methods
-      .distributePrizes(
-        matchId,
-        Buffer.from([0]), // Winner index - Buffer required for Vec<u8>
-        [new anchor.BN(prizePool)] // Prize amount
-      )
-      .accounts({
-        escrowAccount: escrowPDA,
-        matchAccount: matchPDA,
-        configAccount: configPDA,
-        winner0: player1.publicKey,
-        winner1: player1.publicKey, // Dummy - not used but required
-        winner2: player1.publicKey, // Dummy - not used but required
-        winner3: player1.publicKey, // Dummy - not used but required
-        winner4: player1.publicKey, // Dummy - not used but required
-        winner5: player1.publicKey, // Dummy - not used but required
-        winner6: player1.publicKey, // Dummy - not used but required
-        winner7: player1.publicKey, // Dummy - not used but required
-        winner8: player1.publicKey, // Dummy - not used but required
-        winner9: player1.publicKey, // Dummy - not used but required
-        winnerDeposit0: null, // Wallet payment - no deposit accounts needed
-        winnerDeposit1: null,
-        winnerDeposit2: null,
-        winnerDeposit3: null,
-        winnerDeposit4: null,
-        winnerDeposit5: null,
-        winnerDeposit6: null,
-        winnerDeposit7: null,
-        winnerDeposit8: null,
-        winnerDeposit9: null,
-        treasury: authority.publicKey, // Treasury receives platform fee
-        systemProgram: SystemProgram.programId,
-      } as never)
-      .rpc();
-
-    // Verify escrow is marked as distributed
-    // status_flags bit 1 (0x02) = distributed
-    const escrowAccountAfterDistribute = await program.account.escrowAccount.fetch(escrowPDA) as unknown as {
-      statusFlags?: number;
-      status_flags?: number;
-      totalEntryLamports?: { toNumber(): number };
-      total_entry_lamports?: { toNumber(): number };
-    };
-    const distributedFlags = escrowAccountAfterDistribute.statusFlags ?? escrowAccountAfterDistribute.status_flags ?? 0;
-    const isDistributed = (distributedFlags & 0x02) !== 0;
-    this.assert(
-      isDistributed,
-      'Escrow should be marked as distributed'
-    );
-
-    // Verify escrow balance is empty (all funds distributed)
-    const escrowBalanceAfter = await program.provider.connection.getBalance(escrowPDA);
-    // Escrow should only have rent-exempt balance left (minimal)
-    // Rent-exempt minimum for EscrowAccount is ~2.2M lamports (0.0022 SOL)
-    // Check that balance is close to rent-exempt minimum (within 10% tolerance)
-    const rentExemptMinimum = 2000000; // ~0.002 SOL
-    const rentExemptMaximum = 3000000; // ~0.003 SOL (allows for small variations)
-    this.assert(
-      escrowBalanceAfter >= rentExemptMinimum && escrowBalanceAfter <= rentExemptMaximum,
-      `Escrow should only have rent-exempt balance. Expected between ${rentExemptMinimum} and ${rentExemptMaximum} lamports, got ${escrowBalanceAfter}`
-    );
+    // Test 5: Failure - Prizes wait for the post-match dispute window to close
+    // (a local validator cannot advance the clock a full window, so payout is not reached here)
+    const treasuryVaultPDA = await ensureTreasuryVault();
+    const escrowBalanceBefore = await program.provider.connection.getBalance(escrowPDA);
+    try {
+      await program.methods
+        .distributePrizes(matchId)
+        .accounts({
+          escrowAccount: escrowPDA,
+          matchAccount: matchPDA,
+          configAccount: configPDA,
+          treasuryVault: treasuryVaultPDA, // Treasury vault receives platform fee
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .remainingAccounts([
+          { pubkey: player1.publicKey, isSigner: false, isWritable: true },
+          { pubkey: player2.publicKey, isSigner: false, isWritable: true },
+        ])
+        .rpc();
+      this.assert(false, 'distribute_prizes should wait for the dispute window to close');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'DisputeWindowOpen', 'Expected DisputeWindowOpen error');
+    }
 
-    // Verify escrow account total_entry_lamports is 0
-    const escrowTotalAfter = escrowAccountAfterDistribute.totalEntryLamports?.toNumber() ?? escrowAccountAfterDistribute.total_entry_lamports?.toNumber() ?? 0;
+    // Escrow stays funded and undistributed while seats can still flag a dispute
+    const escrowAccountAfterEnd = await program.account.escrowAccount.fetch(escrowPDA) as unknown as EscrowAccountType;
+    const flagsAfterEnd = escrowAccountAfterEnd.statusFlags ?? escrowAccountAfterEnd.status_flags ?? 0;
+    this.assertEqual(flagsAfterEnd & 0x03, 0x01, 'Escrow should stay funded and undistributed');
     this.assertEqual(
-      escrowTotalAfter,
-      0,
-      'Escrow total_entry_lamports should be 0 after distribution'
-    );
-
-    // Verify treasury received platform fee (accounting for transaction fees)
-    const treasuryBalanceAfter = await program.provider.connection.getBalance(authority.publicKey);
-    const treasuryIncrease = treasuryBalanceAfter - treasuryBalanceBefore;
-    // Treasury receives platform fee but pays transaction fees (~5000 lamports)
-    // So net increase should be platformFee - transactionFee
-    // Allow tolerance for transaction fees (typically 5000-10000 lamports)
-    const transactionFeeTolerance = 10000; // Allow up to 0.00001 SOL for transaction fees
-    this.assert(
-      treasuryIncrease >= platformFee - transactionFeeTolerance,
-      `Treasury should receive platform fee (minus transaction fees). Expected >= ${platformFee - transactionFeeTolerance} lamports, got ${treasuryIncrease}`
-    );
-
-    // Verify winner received prize (exact amount, accounting for transaction fees)
-    const player1BalanceFinal = await program.provider.connection.getBalance(player1.publicKey);
-    const player1Increase = player1BalanceFinal - player1BalanceBeforeDistribute;
-    // Winner should receive prize pool (accounting for transaction fees)
-    // Transaction fees reduce the net increase, so check that increase is >= prize pool minus some tolerance
-    const tolerance = 5000; // 0.000005 SOL tolerance for transaction fees
-    this.assert(
-      player1Increase >= prizePool - tolerance,
-      `Winner should receive prize pool. Expected >= ${prizePool - tolerance} lamports, got ${player1Increase}`
+      await program.provider.connection.getBalance(escrowPDA),
+      escrowBalanceBefore,
+      'Escrow lamports should not move inside the dispute window'
     );
 
     console.log('✓ Paid match wallet flow completed successfully');
     console.log(`  - Entry fee: ${entryFee.toNumber() / LAMPORTS_PER_SOL} SOL`);
     console.log(`  - Total escrow: ${expectedTotal / LAMPORTS_PER_SOL} SOL`);
-    console.log(`  - Platform fee: ${platformFee / LAMPORTS_PER_SOL} SOL`);
-    console.log(`  - Prize pool: ${prizePool / LAMPORTS_PER_SOL} SOL`);
   }
 }
 
@@ -0,0 +1,171 @@
+/**
+ * Test: Skill rating per user per game type
+ * Category: LIFECYCLE
+ *
+ * init_player_rating opens a PlayerRating PDA (seeds ["player_rating", wallet, game_type]) at 1200.
+ * After a match ends with recorded scores, update_ratings applies pairwise Elo to every seat:
+ * two new players at equal ratings move by half the provisional K-factor (40) each way, or not at
+ * all on a draw. A match is rated only once.
+ */
+
+import { BaseTest } from '@/core';
+import { TestCategory, ClusterRequirement } from '@/core';
+import { registerMochaTest } from '@/core';
+import { SystemProgram, Keypair } from "@solana/web3.js";
+import {
+  getMatchPDA,
+  getPlayerRatingPDA,
+  PlayerRatingType,
+} from '@/common';
+import * as anchor from "@coral-xyz/anchor";
+
+const INITIAL_RATING = 1200;
+
+class SkillRatingTest extends BaseTest {
+  constructor() {
+    super({
+      id: 'skill-rating',
+      name: 'Skill rating per user per game type',
+      description: 'Tests init_player_rating and update_ratings after a match ends',
+      tags: {
+        category: TestCategory.LIFECYCLE,
+        cluster: ClusterRequirement.ANY,
+        requiresSetup: true,
+        requiresRegistry: true,
+      },
+    });
+  }
+
+  async run(): Promise<void> {
+    const {
+      program,
+      authority,
+      generateUniqueMatchId,
+      getTestGame,
+      getTestSeed,
+      getTestUserId,
+      airdrop,
+    } = await import('@/helpers');
+    const { getRegistryPDA } = await import('@/common');
+
+    const [registryPDA] = await getRegistryPDA();
+    const claimGame = getTestGame(0);
+    if (!claimGame) throw new Error("CLAIM game not found");
+
+    const player1 = Keypair.generate();
+    const player2 = Keypair.generate();
+    await airdrop(player1.publicKey, 1);
+    await airdrop(player2.publicKey, 1);
+
+    // Test 1: Open both ratings (the platform pays)
+    const ratingPDAs = [];
+    for (const player of [player1, player2]) {
+      const [ratingPDA] = await getPlayerRatingPDA(player.publicKey, claimGame.game_id);
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      await (program.methods as any)
+        .initPlayerRating(claimGame.game_id)
+        .accounts({
+          playerRating: ratingPDA,
+          registry: registryPDA,
+          wallet: player.publicKey,
+          payer: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .rpc();
+      const rating = await program.account.playerRating.fetch(ratingPDA) as unknown as PlayerRatingType;
+      this.assertEqual(rating.rating, INITIAL_RATING, 'New rating should start at 1200');
+      ratingPDAs.push(ratingPDA);
+    }
+
+    // Play a free heads-up match to the end
+    const matchId = generateUniqueMatchId("rating");
+    const [matchPDA] = await getMatchPDA(matchId);
+    await program.methods
+      .createMatch(matchId, claimGame.game_id, new anchor.BN(getTestSeed()), { entryFee: null, paymentMethod: null, matchType: null, tournamentId: null, prizeStructure: null, prizeSplitBps: null })
+      .accounts({
+        matchAccount: matchPDA,
+        registry: registryPDA,
+        escrowAccount: null,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as never)
+      .rpc();
+    for (const [index, player] of [player1, player2].entries()) {
+      await program.methods
+        .joinMatch(matchId, getTestUserId(index))
+        .accounts({
+          matchAccount: matchPDA,
+          registry: registryPDA,
+          escrowAccount: null,
+          userDepositAccount: null,
+          playerWallet: null,
+          player: player.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .signers([player])
+        .rpc();
+    }
+
+    const updateRatings = async () => {
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      await (program.methods as any)
+        .updateRatings(matchId)
+        .accounts({
+          matchAccount: matchPDA,
+          caller: authority.publicKey,
+        } as never)
+        .remainingAccounts(ratingPDAs.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true })))
+        .rpc();
+    };
+
+    // Test 2: A match in progress cannot be rated
+    await program.methods
+      .startMatch(matchId)
+      .accounts({
+        matchAccount: matchPDA,
+        registry: registryPDA,
+        escrowAccount: null,
+        authority: authority.publicKey,
+      } as never)
+      .rpc();
+    try {
+      await updateRatings();
+      this.assert(false, 'Rating a match in progress should fail');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'MatchNotEnded', 'Expected MatchNotEnded');
+    }
+
+    await program.methods
+      .endMatch(matchId, null, null)
+      .accounts({
+        matchAccount: matchPDA,
+        escrowAccount: null,
+        authority: authority.publicKey,
+      } as never)
+      .rpc();
+
+    // Test 3: Ratings move by the Elo result
+    await updateRatings();
+    const scores = (await program.account.match.fetch(matchPDA) as unknown as { scores: number[] }).scores;
+    const expectedChange = scores[0] === scores[1] ? 0 : (scores[0] > scores[1] ? 20 : -20);
+    const ratings = await Promise.all(
+      ratingPDAs.map(async (pda) => await program.account.playerRating.fetch(pda) as unknown as PlayerRatingType)
+    );
+    this.assertEqual(ratings[0].rating, INITIAL_RATING + expectedChange, 'Player 1 rating should follow the result');
+    this.assertEqual(ratings[1].rating, INITIAL_RATING - expectedChange, 'Player 2 rating should mirror player 1');
+    this.assertEqual(ratings[0].gamesRated ?? ratings[0].games_rated, 1, 'One match should be rated');
+
+    // Test 4: A match is rated only once
+    try {
+      await updateRatings();
+      this.assert(false, 'Rating a match twice should fail');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'RatingsAlreadyApplied', 'Expected RatingsAlreadyApplied');
+    }
+
+    console.log('✓ Skill rating test passed');
+  }
+}
+
+const testInstance = new SkillRatingTest();
+registerMochaTest(testInstance);
@@ -36,6 +36,7 @@ class StartPaidMatchEscrowVerificationTest extends BaseTest {
       authority,
       generateUniqueMatchId,
       getTestGame,
+      PAID_GAME_ID,
       getTestSeed,
       getTestUserId,
       airdrop,
@@ -79,8 +80,8 @@ class StartPaidMatchEscrowVerificationTest extends BaseTest {
     const [matchPDA] = await getMatchPDA(matchId);
     const [registryPDA] = await getRegistryPDA();
     const [escrowPDA] = await getEscrowPDA(matchPDA);
-    const claimGame = getTestGame(0);
-    if (!claimGame) throw new Error("CLAIM game not found");
+    const paidGame = getTestGame(PAID_GAME_ID);
+    if (!paidGame) throw new Error("Paid match game not found in test data");
     const seed = getTestSeed();
 
     const player1 = Keypair.generate();
@@ -94,12 +95,16 @@ class StartPaidMatchEscrowVerificationTest extends BaseTest {
     await program.methods
       .createMatch(
         matchId,
-        claimGame.game_id,
+        paidGame.game_id,
         new anchor.BN(seed),
-        entryFee,
-        PAYMENT_METHOD.WALLET,
-        MATCH_TYPE.PAID,
-        null
+        {
+          entryFee,
+          paymentMethod: PAYMENT_METHOD.WALLET,
+          matchType: MATCH_TYPE.PAID,
+          tournamentId: null,
+          prizeStructure: null,
+          prizeSplitBps: null,
+        }
       )
       .accounts({
         matchAccount: matchPDA,
@@ -0,0 +1,323 @@
+/**
+ * Test: SPL token match escrow, cancellation and refund
+ * Category: LIFECYCLE
+ *
+ * A paid match denominated in an allow-listed SPL mint holds entry fees in an escrow vault token
+ * account owned by the escrow PDA. After cancel_match, refund_escrow returns the tokens to the
+ * players' token accounts and close_escrow closes both the escrow and its vault. Cancellation fees
+ * go to the treasury multisig's token account and are recorded in the mint's SupportedMint ledger.
+ */
+
+import { BaseTest } from '@/core';
+import { TestCategory, ClusterRequirement } from '@/core';
+import { registerMochaTest } from '@/core';
+import { SystemProgram, Keypair, PublicKey } from "@solana/web3.js";
+import {
+  getMatchPDA,
+  getEscrowPDA,
+  getEscrowVaultPDA,
+  getConfigAccountPDA,
+  getSupportedMintPDA,
+  createTestMint,
+  createTokenAccount,
+  mintTo,
+  getTokenBalance,
+  ensureSupportedMint,
+  TOKEN_PROGRAM_ID,
+  ConfigAccountType,
+  EscrowAccountType,
+  SupportedMintType,
+} from '@/common';
+import * as anchor from "@coral-xyz/anchor";
+
+const MATCH_TYPE = { FREE: 0, PAID: 1 } as const;
+const PAYMENT_METHOD = { WALLET: 0, PLATFORM: 1 } as const;
+const CANCELLATION_REASON = { PLATFORM_FAULT: 0, INSUFFICIENT_PLAYERS: 2 } as const;
+const FEE_SOURCE = { CANCELLATION_FEE: 2 } as const;
+
+class TokenMatchCancellationRefundTest extends BaseTest {
+  constructor() {
+    super({
+      id: 'token-match-cancellation-refund',
+      name: 'SPL token match escrow, cancellation and refund',
+      description: 'Tests token-denominated matches: escrow vault funding, token refunds, per-mint fee ledger, vault closing',
+      tags: {
+        category: TestCategory.LIFECYCLE,
+        cluster: ClusterRequirement.ANY,
+        requiresSetup: true,
+        requiresRegistry: true,
+      },
+    });
+  }
+
+  async run(): Promise<void> {
+    const {
+      program,
+      authority,
+      generateUniqueMatchId,
+      getTestGame,
+      PAID_GAME_ID,
+      getTestSeed,
+      getTestUserId,
+      airdrop,
+    } = await import('@/helpers');
+    const { getRegistryPDA } = await import('@/common');
+
+    // Setup: a fresh mint allow-listed for entry fees of 1_000 - 1_000_000 base units
+    const [mint, mintAuthority] = await createTestMint();
+    const supportedMintPDA = await ensureSupportedMint(mint, 1_000, 1_000_000);
+    const [configPDA] = await getConfigAccountPDA();
+    const [registryPDA] = await getRegistryPDA();
+    const treasuryTokenAccount = await createTokenAccount(mint, authority.publicKey);
+    const paidGame = getTestGame(PAID_GAME_ID);
+    if (!paidGame) throw new Error("Paid match game not found in test data");
+
+    const player1 = Keypair.generate();
+    const player2 = Keypair.generate();
+    await airdrop(player1.publicKey, 1);
+    await airdrop(player2.publicKey, 1);
+    const player1Tokens = await createTokenAccount(mint, player1.publicKey);
+    const player2Tokens = await createTokenAccount(mint, player2.publicKey);
+    await mintTo(mint, player1Tokens, mintAuthority, 100_000);
+    await mintTo(mint, player2Tokens, mintAuthority, 100_000);
+
+    const entryFee = new anchor.BN(50_000);
+
+    const createTokenMatch = async (matchId: string, fee: anchor.BN) => {
+      const [matchPDA] = await getMatchPDA(matchId);
+      const [escrowPDA] = await getEscrowPDA(matchPDA);
+      const [escrowVaultPDA] = await getEscrowVaultPDA(matchPDA);
+      await program.methods
+        .createMatch(
+          matchId,
+          paidGame.game_id,
+          new anchor.BN(getTestSeed()),
+          {
+            entryFee: fee,
+            paymentMethod: PAYMENT_METHOD.WALLET,
+            matchType: MATCH_TYPE.PAID,
+            tournamentId: null,
+            prizeStructure: null,
+            prizeSplitBps: null,
+          }
+        )
+        .accounts({
+          matchAccount: matchPDA,
+          registry: registryPDA,
+          escrowAccount: escrowPDA,
+          configAccount: configPDA,
+          entryMint: mint,
+          supportedMint: supportedMintPDA,
+          escrowVault: escrowVaultPDA,
+          authority: authority.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .rpc();
+      return [matchPDA, escrowPDA, escrowVaultPDA];
+    };
+
+    // Both players pay from their own token accounts
+    const joinTokenMatch = async (matchId: string, matchPDA: PublicKey, escrowPDA: PublicKey, escrowVaultPDA: PublicKey) => {
+      for (const [index, player, tokens] of [[0, player1, player1Tokens], [1, player2, player2Tokens]] as const) {
+        await program.methods
+          .joinMatch(matchId, getTestUserId(index))
+          .accounts({
+            matchAccount: matchPDA,
+            registry: registryPDA,
+            escrowAccount: escrowPDA,
+            userDepositAccount: null,
+            playerWallet: null,
+            configAccount: configPDA,
+            escrowVault: escrowVaultPDA,
+            playerTokenAccount: tokens,
+            tokenDepositAccount: null,
+            player: player.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          } as never)
+          .signers([player])
+          .rpc();
+      }
+    };
+
+    const cancelTokenMatch = async (matchId: string, [matchPDA, escrowPDA]: PublicKey[], reason: number) => {
+      await program.methods
+        .cancelMatch(matchId, reason, null)
+        .accounts({
+          matchAccount: matchPDA,
+          escrowAccount: escrowPDA,
+          caller: authority.publicKey,
+        } as never)
+        .rpc();
+    };
+
+    // Refund both seats out of the escrow vault (the treasury's share needs the mint's ledger)
+    const refundTokenMatch = async (
+      matchId: string,
+      [matchPDA, escrowPDA, escrowVaultPDA]: PublicKey[],
+      reason: number,
+      supportedMint: PublicKey | null
+    ) => {
+      await program.methods
+        .refundEscrow(matchId, Buffer.from([0, 1]), reason, null)
+        .accounts({
+          escrowAccount: escrowPDA,
+          matchAccount: matchPDA,
+          configAccount: configPDA,
+          player0: player1.publicKey,
+          player1: player2.publicKey,
+          player2: player1.publicKey,
+          player3: player1.publicKey,
+          player4: player1.publicKey,
+          player5: player1.publicKey,
+          player6: player1.publicKey,
+          player7: player1.publicKey,
+          player8: player1.publicKey,
+          player9: player1.publicKey,
+          playerDeposit0: null,
+          playerDeposit1: null,
+          playerDeposit2: null,
+          playerDeposit3: null,
+          playerDeposit4: null,
+          playerDeposit5: null,
+          playerDeposit6: null,
+          playerDeposit7: null,
+          playerDeposit8: null,
+          playerDeposit9: null,
+          treasuryVault: null,
+          escrowVault: escrowVaultPDA,
+          treasuryTokenAccount,
+          supportedMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .remainingAccounts([
+          { pubkey: player1Tokens, isSigner: false, isWritable: true },
+          { pubkey: player2Tokens, isSigner: false, isWritable: true },
+        ])
+        .rpc();
+    };
+
+    // Entry fee must be within the mint's bounds
+    try {
+      await createTokenMatch(generateUniqueMatchId("token-fee-bounds"), new anchor.BN(2_000_000));
+      this.assert(false, 'Entry fee above the mint maximum should fail');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'EntryFeeOutOfRange', 'Expected EntryFeeOutOfRange');
+    }
+
+    const matchId = generateUniqueMatchId("token-refund");
+    const [matchPDA, escrowPDA, escrowVaultPDA] = await createTokenMatch(matchId, entryFee);
+
+    const escrow = await program.account.escrowAccount.fetch(escrowPDA) as unknown as EscrowAccountType;
+    this.assertEqual(
+      (escrow.entryMint ?? escrow.entry_mint)?.toString(),
+      mint.toString(),
+      'Escrow should record the entry mint'
+    );
+
+    await joinTokenMatch(matchId, matchPDA, escrowPDA, escrowVaultPDA);
+
+    this.assertEqual(await getTokenBalance(escrowVaultPDA), entryFee.toNumber() * 2, 'Escrow vault should hold both entry fees');
+    this.assertEqual(await getTokenBalance(player1Tokens), 100_000 - entryFee.toNumber(), 'Player 1 tokens should be debited');
+
+    // Cancel and refund (platform fault - full refunds, nothing owed to the treasury)
+    await cancelTokenMatch(matchId, [matchPDA, escrowPDA], CANCELLATION_REASON.PLATFORM_FAULT);
+    await refundTokenMatch(matchId, [matchPDA, escrowPDA, escrowVaultPDA], CANCELLATION_REASON.PLATFORM_FAULT, null);
+
+    this.assertEqual(await getTokenBalance(escrowVaultPDA), 0, 'Escrow vault should be empty after refund');
+    this.assertEqual(await getTokenBalance(player1Tokens), 100_000, 'Player 1 should be refunded in full');
+    this.assertEqual(await getTokenBalance(player2Tokens), 100_000, 'Player 2 should be refunded in full');
+
+    // Closing the settled escrow also closes its vault
+    await program.methods
+      .closeEscrow(matchId)
+      .accounts({
+        escrowAccount: escrowPDA,
+        matchAccount: matchPDA,
+        escrowVault: escrowVaultPDA,
+        rentRecipient: authority.publicKey,
+        caller: authority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      } as never)
+      .rpc();
+    this.assert(await program.provider.connection.getAccountInfo(escrowPDA) === null, 'Escrow should be closed');
+    this.assert(await program.provider.connection.getAccountInfo(escrowVaultPDA) === null, 'Escrow vault should be closed');
+
+    // Cancellation fees on a token match go to the treasury token account and the mint's ledger
+    const feeMatchId = generateUniqueMatchId("token-refund-fee");
+    const feeMatchAccounts = await createTokenMatch(feeMatchId, entryFee);
+    const [feeMatchPDA, feeEscrowPDA, feeEscrowVaultPDA] = feeMatchAccounts;
+    await joinTokenMatch(feeMatchId, feeMatchPDA, feeEscrowPDA, feeEscrowVaultPDA);
+
+    const config = await program.account.configAccount.fetch(configPDA) as unknown as ConfigAccountType;
+    const cancellationFeeBps = config.cancellationFeeBps ?? config.cancellation_fee_bps ?? 0;
+    const feePerSeat = Math.floor(entryFee.toNumber() * cancellationFeeBps / 10000);
+    this.assert(feePerSeat > 0, 'Config should charge a cancellation fee');
+
+    await cancelTokenMatch(feeMatchId, feeMatchAccounts, CANCELLATION_REASON.INSUFFICIENT_PLAYERS);
+
+    // The treasury's token share cannot be paid without the mint's fee ledger
+    try {
+      await refundTokenMatch(feeMatchId, feeMatchAccounts, CANCELLATION_REASON.INSUFFICIENT_PLAYERS, null);
+      this.assert(false, 'Token refund with a cancellation fee should require the SupportedMint ledger');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'InvalidPayload', 'Expected InvalidPayload');
+    }
+
+    const ledgerBefore = await program.account.supportedMint.fetch(supportedMintPDA) as unknown as SupportedMintType;
+    const cancellationFeesBefore = (ledgerBefore.feesBySource ?? ledgerBefore.fees_by_source)?.[FEE_SOURCE.CANCELLATION_FEE]?.toNumber() ?? 0;
+    const treasuryTokensBefore = await getTokenBalance(treasuryTokenAccount);
+
+    await refundTokenMatch(feeMatchId, feeMatchAccounts, CANCELLATION_REASON.INSUFFICIENT_PLAYERS, supportedMintPDA);
+
+    this.assertEqual(await getTokenBalance(feeEscrowVaultPDA), 0, 'Escrow vault should be empty after refund');
+    this.assertEqual(await getTokenBalance(player1Tokens), 100_000 - feePerSeat, 'Player 1 should be refunded minus the fee');
+    this.assertEqual(
+      await getTokenBalance(treasuryTokenAccount),
+      treasuryTokensBefore + feePerSeat * 2,
+      'Treasury token account should receive both cancellation fees'
+    );
+    const ledgerAfter = await program.account.supportedMint.fetch(supportedMintPDA) as unknown as SupportedMintType;
+    this.assertEqual(
+      (ledgerAfter.feesBySource ?? ledgerAfter.fees_by_source)?.[FEE_SOURCE.CANCELLATION_FEE]?.toNumber() ?? 0,
+      cancellationFeesBefore + feePerSeat * 2,
+      'SupportedMint ledger should record the cancellation fees'
+    );
+
+    // Unlisted mints cannot back a match
+    const [otherMint] = await createTestMint();
+    const [unlistedSupportedMint] = await getSupportedMintPDA(otherMint);
+    const otherMatchId = generateUniqueMatchId("token-unlisted");
+    const [otherMatchPDA] = await getMatchPDA(otherMatchId);
+    const [otherEscrowPDA] = await getEscrowPDA(otherMatchPDA);
+    const [otherVaultPDA] = await getEscrowVaultPDA(otherMatchPDA);
+    try {
+      await program.methods
+        .createMatch(otherMatchId, paidGame.game_id, new anchor.BN(getTestSeed()), { entryFee, paymentMethod: PAYMENT_METHOD.WALLET, matchType: MATCH_TYPE.PAID, tournamentId: null, prizeStructure: null, prizeSplitBps: null })
+        .accounts({
+          matchAccount: otherMatchPDA,
+          registry: registryPDA,
+          escrowAccount: otherEscrowPDA,
+          configAccount: configPDA,
+          entryMint: otherMint,
+          supportedMint: unlistedSupportedMint,
+          escrowVault: otherVaultPDA,
+          authority: authority.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .rpc();
+      this.assert(false, 'Match with an unlisted mint should fail');
+    } catch (err: unknown) {
+      // SupportedMint account does not exist for the unlisted mint
+      this.assert(err !== undefined, 'Expected create_match to fail for an unlisted mint');
+    }
+
+    console.log('✓ Token match cancellation refund test passed');
+  }
+}
+
+const testInstance = new TokenMatchCancellationRefundTest();
+registerMochaTest(testInstance);
@@ -0,0 +1,223 @@
+/**
+ * Test: SPL token match prize distribution and the per-mint fee ledger
+ * Category: LIFECYCLE
+ *
+ * A token-denominated paid match is played to the end. distribute_prizes needs the mint's
+ * SupportedMint fee ledger and waits for the post-match dispute window before paying the seats out
+ * of the escrow vault; the escrow's lamport fee fields stay untouched.
+ */
+
+import { BaseTest } from '@/core';
+import { TestCategory, ClusterRequirement } from '@/core';
+import { registerMochaTest } from '@/core';
+import { SystemProgram, Keypair } from "@solana/web3.js";
+import {
+  getMatchPDA,
+  getEscrowPDA,
+  getEscrowVaultPDA,
+  getConfigAccountPDA,
+  createTestMint,
+  createTokenAccount,
+  mintTo,
+  getTokenBalance,
+  ensureSupportedMint,
+  ensureTreasuryVault,
+  TOKEN_PROGRAM_ID,
+  EscrowAccountType,
+  SupportedMintType,
+} from '@/common';
+import * as anchor from "@coral-xyz/anchor";
+
+const MATCH_TYPE = { FREE: 0, PAID: 1 } as const;
+const PAYMENT_METHOD = { WALLET: 0, PLATFORM: 1 } as const;
+const FEE_SOURCE = { PLATFORM_FEE: 0 } as const;
+
+class TokenMatchPrizeDistributionTest extends BaseTest {
+  constructor() {
+    super({
+      id: 'token-match-prize-distribution',
+      name: 'SPL token match prize distribution and per-mint fee ledger',
+      description: 'Tests token prize payouts from the escrow vault and the SupportedMint platform fee ledger',
+      tags: {
+        category: TestCategory.LIFECYCLE,
+        cluster: ClusterRequirement.ANY,
+        requiresSetup: true,
+        requiresRegistry: true,
+      },
+    });
+  }
+
+  async run(): Promise<void> {
+    const {
+      program,
+      authority,
+      generateUniqueMatchId,
+      getTestGame,
+      PAID_GAME_ID,
+      getTestSeed,
+      getTestUserId,
+      airdrop,
+    } = await import('@/helpers');
+    const { getRegistryPDA } = await import('@/common');
+
+    // Setup: a fresh mint allow-listed for entry fees of 1_000 - 1_000_000 base units
+    const [mint, mintAuthority] = await createTestMint();
+    const supportedMintPDA = await ensureSupportedMint(mint, 1_000, 1_000_000);
+    const [configPDA] = await getConfigAccountPDA();
+    const [registryPDA] = await getRegistryPDA();
+    const treasuryVaultPDA = await ensureTreasuryVault();
+    const treasuryTokenAccount = await createTokenAccount(mint, authority.publicKey);
+    const paidGame = getTestGame(PAID_GAME_ID);
+    if (!paidGame) throw new Error("Paid match game not found in test data");
+
+    const player1 = Keypair.generate();
+    const player2 = Keypair.generate();
+    await airdrop(player1.publicKey, 1);
+    await airdrop(player2.publicKey, 1);
+    const player1Tokens = await createTokenAccount(mint, player1.publicKey);
+    const player2Tokens = await createTokenAccount(mint, player2.publicKey);
+    await mintTo(mint, player1Tokens, mintAuthority, 100_000);
+    await mintTo(mint, player2Tokens, mintAuthority, 100_000);
+
+    const entryFee = new anchor.BN(50_000);
+    const matchId = generateUniqueMatchId("token-distribute");
+    const [matchPDA] = await getMatchPDA(matchId);
+    const [escrowPDA] = await getEscrowPDA(matchPDA);
+    const [escrowVaultPDA] = await getEscrowVaultPDA(matchPDA);
+
+    // Setup: create, join with both players' token accounts, start and end
+    await program.methods
+      .createMatch(
+        matchId,
+        paidGame.game_id,
+        new anchor.BN(getTestSeed()),
+        {
+          entryFee,
+          paymentMethod: PAYMENT_METHOD.WALLET,
+          matchType: MATCH_TYPE.PAID,
+          tournamentId: null,
+          prizeStructure: null,
+          prizeSplitBps: null,
+        }
+      )
+      .accounts({
+        matchAccount: matchPDA,
+        registry: registryPDA,
+        escrowAccount: escrowPDA,
+        configAccount: configPDA,
+        entryMint: mint,
+        supportedMint: supportedMintPDA,
+        escrowVault: escrowVaultPDA,
+        authority: authority.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      } as never)
+      .rpc();
+
+    for (const [index, player, tokens] of [[0, player1, player1Tokens], [1, player2, player2Tokens]] as const) {
+      await program.methods
+        .joinMatch(matchId, getTestUserId(index))
+        .accounts({
+          matchAccount: matchPDA,
+          registry: registryPDA,
+          escrowAccount: escrowPDA,
+          userDepositAccount: null,
+          playerWallet: null,
+          configAccount: configPDA,
+          escrowVault: escrowVaultPDA,
+          playerTokenAccount: tokens,
+          tokenDepositAccount: null,
+          player: player.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .signers([player])
+        .rpc();
+    }
+
+    await program.methods
+      .startMatch(matchId)
+      .accounts({
+        matchAccount: matchPDA,
+        registry: registryPDA,
+        escrowAccount: escrowPDA,
+        authority: authority.publicKey,
+      } as never)
+      .rpc();
+
+    await program.methods
+      .endMatch(matchId, null, null)
+      .accounts({
+        matchAccount: matchPDA,
+        escrowAccount: escrowPDA,
+        authority: authority.publicKey,
+      } as never)
+      .rpc();
+
+    const escrow = await program.account.escrowAccount.fetch(escrowPDA) as unknown as EscrowAccountType;
+    const platformFeeBps = escrow.platformFeeBps ?? escrow.platform_fee_bps ?? 0;
+    const totalEntry = entryFee.toNumber() * 2;
+    const platformFee = Math.floor(totalEntry * platformFeeBps / 10000);
+    this.assert(platformFee > 0, 'Paid matches should charge a platform fee');
+
+    const ledgerBefore = await program.account.supportedMint.fetch(supportedMintPDA) as unknown as SupportedMintType;
+    const collectedBefore = (ledgerBefore.totalCollected ?? ledgerBefore.total_collected)?.toNumber() ?? 0;
+    const platformFeesBefore = (ledgerBefore.feesBySource ?? ledgerBefore.fees_by_source)?.[FEE_SOURCE.PLATFORM_FEE]?.toNumber() ?? 0;
+
+    const distributePrizes = (supportedMint: typeof supportedMintPDA | null) =>
+      program.methods
+        .distributePrizes(matchId)
+        .accounts({
+          escrowAccount: escrowPDA,
+          matchAccount: matchPDA,
+          configAccount: configPDA,
+          treasuryVault: treasuryVaultPDA,
+          escrowVault: escrowVaultPDA,
+          treasuryTokenAccount,
+          supportedMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .remainingAccounts([
+          { pubkey: player1Tokens, isSigner: false, isWritable: true },
+          { pubkey: player2Tokens, isSigner: false, isWritable: true },
+        ])
+        .rpc();
+
+    // Test 1: Failure - The token platform fee cannot be paid without the mint's fee ledger
+    try {
+      await distributePrizes(null);
+      this.assert(false, 'Token distribution without the SupportedMint ledger should fail');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'InvalidPayload', 'Expected InvalidPayload');
+    }
+
+    // Test 2: Failure - Token prizes wait for the post-match dispute window to close
+    // (a local validator cannot advance the clock a full window, so payout is not reached here)
+    const escrowVaultBefore = await getTokenBalance(escrowVaultPDA);
+    try {
+      await distributePrizes(supportedMintPDA);
+      this.assert(false, 'Token distribution should wait for the dispute window to close');
+    } catch (err: unknown) {
+      this.assertEqual(this.getErrorCode(err), 'DisputeWindowOpen', 'Expected DisputeWindowOpen');
+    }
+
+    this.assertEqual(await getTokenBalance(escrowVaultPDA), escrowVaultBefore, 'Escrow vault should keep the stakes');
+    const ledgerAfter = await program.account.supportedMint.fetch(supportedMintPDA) as unknown as SupportedMintType;
+    this.assertEqual(
+      (ledgerAfter.totalCollected ?? ledgerAfter.total_collected)?.toNumber() ?? 0,
+      collectedBefore,
+      'SupportedMint ledger should not record a fee before distribution'
+    );
+    this.assertEqual(
+      (ledgerAfter.feesBySource ?? ledgerAfter.fees_by_source)?.[FEE_SOURCE.PLATFORM_FEE]?.toNumber() ?? 0,
+      platformFeesBefore,
+      'SupportedMint ledger should not record a PLATFORM_FEE before distribution'
+    );
+
+    console.log('✓ Token match prize distribution test passed');
+  }
+}
+
+const testInstance = new TokenMatchPrizeDistributionTest();
+registerMochaTest(testInstance);
@@ -0,0 +1,279 @@
+/**
+ * Test: Tournament no-show - Stalled bracket matches
+ * Category: LIFECYCLE
+ *
+ * A bracket match that never starts must not lock the prize pool. Once no_show_secs has passed
+ * since the match was created, the organizer can free the pairing with
+ * reschedule_tournament_pairing and schedule a fresh match, and anyone can settle it with
+ * report_tournament_no_show - the entrant who took a seat wins by walkover. expire_match
+ * refuses a match whose pairing is still pending.
+ */
+
+import { BaseTest } from '@/core';
+import { TestCategory, ClusterRequirement } from '@/core';
+import { registerMochaTest } from '@/core';
+import { SystemProgram, Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import {
+  getMatchPDA,
+  getTournamentPDA,
+  getConfigAccountPDA,
+  ensureConfigUnpaused,
+  TournamentAccountType,
+} from '@/common';
+import * as anchor from "@coral-xyz/anchor";
+
+const MATCH_TYPE = { FREE: 0, PAID: 1 } as const;
+const PAYMENT_METHOD = { WALLET: 0, PLATFORM: 1 } as const;
+const TOURNAMENT_FORMAT = { SINGLE_ELIMINATION: 0, SWISS: 1, ROUND_ROBIN: 2 } as const;
+const TOURNAMENT_PHASE = { REGISTRATION: 0, IN_PROGRESS: 1, COMPLETED: 2, SETTLED: 3, CANCELLED: 4 } as const;
+const NO_SHOW_SECS = 2;
+
+class TournamentNoShowTest extends BaseTest {
+  constructor() {
+    super({
+      id: 'tournament-no-show',
+      name: 'Tournament no-show - Stalled bracket matches',
+      description: 'Tests no-show walkovers, pairing rescheduling and expire_match on pending bracket matches',
+      tags: {
+        category: TestCategory.LIFECYCLE,
+        cluster: ClusterRequirement.LOCALNET_ONLY,
+        requiresSetup: true,
+        requiresRegistry: true,
+      },
+    });
+  }
+
+  async run(): Promise<void> {
+    const {
+      program,
+      authority,
+      generateUniqueMatchId,
+      getTestGame,
+      PAID_GAME_ID,
+      getTestSeed,
+      getTestUserId,
+      airdrop,
+    } = await import('@/helpers');
+    const { getRegistryPDA } = await import('@/common');
+
+    await ensureConfigUnpaused();
+    const [configPDA] = await getConfigAccountPDA();
+    const [registryPDA] = await getRegistryPDA();
+    const paidGame = getTestGame(PAID_GAME_ID);
+    if (!paidGame) throw new Error("Paid match game not found in test data");
+
+    const player1 = Keypair.generate();
+    const player2 = Keypair.generate();
+    const cranker = Keypair.generate();
+    await airdrop(player1.publicKey, 1);
+    await airdrop(player2.publicKey, 1);
+    await airdrop(cranker.publicKey, 1);
+
+    const expectError = async (action: () => Promise<unknown>, code: string, message: string) => {
+      try {
+        await action();
+        this.assert(false, message);
+      } catch (err: unknown) {
+        this.assertEqual(this.getErrorCode(err), code, `Expected ${code}`);
+      }
+    };
+    const waitForNoShowDeadline = () => new Promise(resolve => setTimeout(resolve, (NO_SHOW_SECS + 2) * 1000));
+
+    // Two-entrant tournament with a short no-show deadline
+    const tournamentId = Keypair.generate().publicKey.toBytes().slice(0, 16);
+    const [tournamentPDA] = await getTournamentPDA(tournamentId);
+    const now = Math.floor(Date.now() / 1000);
+    // eslint-disable-next-line @typescript-eslint/no-explicit-any
+    await (program.methods as any)
+      .createTournament(Array.from(tournamentId), {
+        gameType: paidGame.game_id,
+        format: TOURNAMENT_FORMAT.SINGLE_ELIMINATION,
+        maxEntrants: 2,
+        buyInLamports: new anchor.BN(0.1 * LAMPORTS_PER_SOL),
+        registrationOpensAt: new anchor.BN(now - 60),
+        registrationClosesAt: new anchor.BN(now + 3600),
+        payoutBps: [10000],
+        noShowSecs: NO_SHOW_SECS,
+      })
+      .accounts({
+        tournament: tournamentPDA,
+        registry: registryPDA,
+        configAccount: configPDA,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as never)
+      .rpc();
+    for (const entrant of [player1, player2]) {
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      await (program.methods as any)
+        .registerTournament(Array.from(tournamentId))
+        .accounts({
+          tournament: tournamentPDA,
+          configAccount: configPDA,
+          entrant: entrant.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .signers([entrant])
+        .rpc();
+    }
+    // eslint-disable-next-line @typescript-eslint/no-explicit-any
+    await (program.methods as any)
+      .startTournament(Array.from(tournamentId))
+      .accounts({
+        tournament: tournamentPDA,
+        authority: authority.publicKey,
+      } as never)
+      .rpc();
+
+    const fetchTournament = async () =>
+      await program.account.tournament.fetch(tournamentPDA) as unknown as TournamentAccountType;
+
+    const scheduleMatch = async () => {
+      const matchId = generateUniqueMatchId("no-show");
+      const [matchPDA] = await getMatchPDA(matchId);
+      await program.methods
+        .createMatch(
+          matchId,
+          paidGame.game_id,
+          new anchor.BN(getTestSeed()),
+          {
+            entryFee: new anchor.BN(0),
+            paymentMethod: PAYMENT_METHOD.WALLET,
+            matchType: MATCH_TYPE.FREE,
+            tournamentId: Array.from(tournamentId),
+            prizeStructure: null,
+            prizeSplitBps: null,
+          }
+        )
+        .accounts({
+          matchAccount: matchPDA,
+          registry: registryPDA,
+          escrowAccount: null,
+          configAccount: configPDA,
+          tournament: tournamentPDA,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .rpc();
+      return { matchId, matchPDA };
+    };
+
+    const joinMatch = async (matchId: string, matchPDA: PublicKey, player: Keypair, index: number) => {
+      await program.methods
+        .joinMatch(matchId, getTestUserId(index))
+        .accounts({
+          matchAccount: matchPDA,
+          registry: registryPDA,
+          escrowAccount: null,
+          userDepositAccount: null,
+          playerWallet: null,
+          tournament: tournamentPDA,
+          player: player.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .signers([player])
+        .rpc();
+    };
+
+    const reportNoShow = async (matchId: string, matchPDA: PublicKey) => {
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      await (program.methods as any)
+        .reportTournamentNoShow(Array.from(tournamentId), matchId)
+        .accounts({
+          tournament: tournamentPDA,
+          matchAccount: matchPDA,
+          caller: cranker.publicKey,
+        } as never)
+        .signers([cranker])
+        .rpc();
+    };
+
+    const reschedule = async (matchPDA: PublicKey) => {
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      await (program.methods as any)
+        .rescheduleTournamentPairing(Array.from(tournamentId), 0)
+        .accounts({
+          tournament: tournamentPDA,
+          authority: authority.publicKey,
+        } as never)
+        .remainingAccounts([
+          { pubkey: matchPDA, isSigner: false, isWritable: false },
+        ])
+        .rpc();
+    };
+
+    const expireMatch = async (matchId: string, matchPDA: PublicKey) => {
+      await program.methods
+        .expireMatch(matchId)
+        .accounts({
+          matchAccount: matchPDA,
+          registry: registryPDA,
+          tournament: tournamentPDA,
+          rentRecipient: authority.publicKey,
+          caller: cranker.publicKey,
+        } as never)
+        .signers([cranker])
+        .rpc();
+    };
+
+    // Test 1: Nobody shows up - nothing can be settled before the deadline
+    const stalled = await scheduleMatch();
+    await expectError(
+      () => reportNoShow(stalled.matchId, stalled.matchPDA),
+      'NoShowDeadlineNotPassed',
+      'A no-show should not be reported before the deadline'
+    );
+    await expectError(
+      () => reschedule(stalled.matchPDA),
+      'NoShowDeadlineNotPassed',
+      'A pairing should not be rescheduled before the deadline'
+    );
+
+    // Test 2: expire_match refuses a match that still holds a pending pairing
+    await expectError(
+      () => expireMatch(stalled.matchId, stalled.matchPDA),
+      'TournamentPairingPending',
+      'A pending bracket match should not expire'
+    );
+
+    // Test 3: After the deadline the organizer frees the pairing for a fresh match
+    await waitForNoShowDeadline();
+    await reschedule(stalled.matchPDA);
+    let tournament = await fetchTournament();
+    this.assertEqual(
+      (tournament.pairingMatches ?? tournament.pairing_matches)?.[0]?.toString(),
+      PublicKey.default.toString(),
+      'Rescheduled pairing should no longer hold a match'
+    );
+    await expectError(
+      () => reportNoShow(stalled.matchId, stalled.matchPDA),
+      'NotInTournamentBracket',
+      'The replaced match should no longer settle the pairing'
+    );
+
+    // Test 4: Only player 2 takes a seat - a no-show report hands them the walkover
+    const rescheduled = await scheduleMatch();
+    await joinMatch(rescheduled.matchId, rescheduled.matchPDA, player2, 1);
+    await expectError(
+      () => reportNoShow(rescheduled.matchId, rescheduled.matchPDA),
+      'NoShowDeadlineNotPassed',
+      'A no-show should not be reported before the deadline'
+    );
+    await waitForNoShowDeadline();
+    await reportNoShow(rescheduled.matchId, rescheduled.matchPDA);
+
+    tournament = await fetchTournament();
+    this.assertEqual(tournament.phase, TOURNAMENT_PHASE.COMPLETED, 'Tournament should be completed');
+    this.assertEqual(tournament.placements?.[0], 1, 'The entrant who showed up should place first');
+    await expectError(
+      () => reportNoShow(rescheduled.matchId, rescheduled.matchPDA),
+      'InvalidTournamentPhase',
+      'Reporting a completed tournament should fail'
+    );
+
+    console.log('✓ Tournament no-show test passed');
+  }
+}
+
+const testInstance = new TournamentNoShowTest();
+registerMochaTest(testInstance);
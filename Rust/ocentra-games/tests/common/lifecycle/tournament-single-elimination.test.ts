@@ -0,0 +1,326 @@
+/**
+ * Test: Single elimination tournament - Full flow
+ * Category: LIFECYCLE
+ *
+ * Entrants pay the buy-in into the tournament account during registration. start_tournament
+ * pairs the first round; bracket matches are created with the tournament_id and only the paired
+ * entrants may join. report_tournament_match reads the winner from the match scores and advances
+ * the bracket, and distribute_tournament_prizes pays the final standings by the payout table.
+ * cancel_tournament refunds every buy-in while registration is open.
+ */
+
+import { BaseTest } from '@/core';
+import { TestCategory, ClusterRequirement } from '@/core';
+import { registerMochaTest } from '@/core';
+import { SystemProgram, Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import {
+  getMatchPDA,
+  getTournamentPDA,
+  getConfigAccountPDA,
+  ensureConfigUnpaused,
+  ensureTreasuryVault,
+  TournamentAccountType,
+} from '@/common';
+import * as anchor from "@coral-xyz/anchor";
+
+const MATCH_TYPE = { FREE: 0, PAID: 1 } as const;
+const PAYMENT_METHOD = { WALLET: 0, PLATFORM: 1 } as const;
+const TOURNAMENT_FORMAT = { SINGLE_ELIMINATION: 0, SWISS: 1, ROUND_ROBIN: 2 } as const;
+const TOURNAMENT_PHASE = { REGISTRATION: 0, IN_PROGRESS: 1, COMPLETED: 2, SETTLED: 3, CANCELLED: 4 } as const;
+
+class TournamentSingleEliminationTest extends BaseTest {
+  constructor() {
+    super({
+      id: 'tournament-single-elimination',
+      name: 'Single elimination tournament - Full flow',
+      description: 'Tests tournament registration, bracket matches, automatic advancement, payouts and cancellation',
+      tags: {
+        category: TestCategory.LIFECYCLE,
+        cluster: ClusterRequirement.ANY,
+        requiresSetup: true,
+        requiresRegistry: true,
+      },
+    });
+  }
+
+  async run(): Promise<void> {
+    const {
+      program,
+      authority,
+      generateUniqueMatchId,
+      getTestGame,
+      PAID_GAME_ID,
+      getTestSeed,
+      getTestUserId,
+      airdrop,
+    } = await import('@/helpers');
+    const { getRegistryPDA } = await import('@/common');
+
+    await ensureConfigUnpaused();
+    const [configPDA] = await getConfigAccountPDA();
+    const [registryPDA] = await getRegistryPDA();
+    const treasuryVaultPDA = await ensureTreasuryVault();
+    const paidGame = getTestGame(PAID_GAME_ID);
+    if (!paidGame) throw new Error("Paid match game not found in test data");
+    const connection = program.provider.connection;
+
+    const buyIn = 0.1 * LAMPORTS_PER_SOL;
+    const player1 = Keypair.generate();
+    const player2 = Keypair.generate();
+    const outsider = Keypair.generate();
+    await airdrop(player1.publicKey, 1);
+    await airdrop(player2.publicKey, 1);
+    await airdrop(outsider.publicKey, 1);
+
+    const expectError = async (action: () => Promise<unknown>, code: string, message: string) => {
+      try {
+        await action();
+        this.assert(false, message);
+      } catch (err: unknown) {
+        this.assertEqual(this.getErrorCode(err), code, `Expected ${code}`);
+      }
+    };
+
+    const createTournament = async (maxEntrants: number) => {
+      const tournamentId = Keypair.generate().publicKey.toBytes().slice(0, 16);
+      const [tournamentPDA] = await getTournamentPDA(tournamentId);
+      const now = Math.floor(Date.now() / 1000);
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      await (program.methods as any)
+        .createTournament(Array.from(tournamentId), {
+          gameType: paidGame.game_id,
+          format: TOURNAMENT_FORMAT.SINGLE_ELIMINATION,
+          maxEntrants,
+          buyInLamports: new anchor.BN(buyIn),
+          registrationOpensAt: new anchor.BN(now - 60),
+          registrationClosesAt: new anchor.BN(now + 3600),
+          payoutBps: [10000], // Winner takes all
+          noShowSecs: 600,
+        })
+        .accounts({
+          tournament: tournamentPDA,
+          registry: registryPDA,
+          configAccount: configPDA,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .rpc();
+      return { tournamentId, tournamentPDA };
+    };
+
+    const register = async (tournamentId: Uint8Array, tournamentPDA: PublicKey, entrant: Keypair) => {
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      await (program.methods as any)
+        .registerTournament(Array.from(tournamentId))
+        .accounts({
+          tournament: tournamentPDA,
+          configAccount: configPDA,
+          entrant: entrant.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .signers([entrant])
+        .rpc();
+    };
+
+    const fetchTournament = async (tournamentPDA: PublicKey) =>
+      await program.account.tournament.fetch(tournamentPDA) as unknown as TournamentAccountType;
+
+    // Test 1: Registration collects buy-ins into the tournament account
+    const { tournamentId, tournamentPDA } = await createTournament(2);
+    const poolBefore = await connection.getBalance(tournamentPDA);
+    await register(tournamentId, tournamentPDA, player1);
+    await expectError(
+      () => register(tournamentId, tournamentPDA, player1),
+      'AlreadyRegistered',
+      'Registering twice should fail'
+    );
+    await register(tournamentId, tournamentPDA, player2);
+    await expectError(
+      () => register(tournamentId, tournamentPDA, outsider),
+      'TournamentFull',
+      'Registering into a full tournament should fail'
+    );
+    this.assertEqual(
+      (await connection.getBalance(tournamentPDA)) - poolBefore,
+      buyIn * 2,
+      'Tournament account should hold both buy-ins'
+    );
+
+    // Test 2: A full tournament can start before registration closes
+    // eslint-disable-next-line @typescript-eslint/no-explicit-any
+    await (program.methods as any)
+      .startTournament(Array.from(tournamentId))
+      .accounts({
+        tournament: tournamentPDA,
+        authority: authority.publicKey,
+      } as never)
+      .rpc();
+    let tournament = await fetchTournament(tournamentPDA);
+    this.assertEqual(tournament.phase, TOURNAMENT_PHASE.IN_PROGRESS, 'Tournament should be in progress');
+    this.assertEqual(tournament.totalRounds ?? tournament.total_rounds, 1, 'Two entrants play a single round');
+
+    // Test 3: Bracket match - only the paired entrants may join
+    const matchId = generateUniqueMatchId("tournament");
+    const [matchPDA] = await getMatchPDA(matchId);
+    await program.methods
+      .createMatch(
+        matchId,
+        paidGame.game_id,
+        new anchor.BN(getTestSeed()),
+        {
+          entryFee: new anchor.BN(0),
+          paymentMethod: PAYMENT_METHOD.WALLET,
+          matchType: MATCH_TYPE.FREE,
+          tournamentId: Array.from(tournamentId),
+          prizeStructure: null,
+          prizeSplitBps: null,
+        }
+      )
+      .accounts({
+        matchAccount: matchPDA,
+        registry: registryPDA,
+        escrowAccount: null,
+        configAccount: configPDA,
+        tournament: tournamentPDA,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as never)
+      .rpc();
+    tournament = await fetchTournament(tournamentPDA);
+    this.assertEqual(
+      (tournament.pairingMatches ?? tournament.pairing_matches)?.[0]?.toString(),
+      matchPDA.toString(),
+      'Pairing 0 should be scheduled on the match'
+    );
+
+    const joinMatch = async (player: Keypair, index: number) => {
+      await program.methods
+        .joinMatch(matchId, getTestUserId(index))
+        .accounts({
+          matchAccount: matchPDA,
+          registry: registryPDA,
+          escrowAccount: null,
+          userDepositAccount: null,
+          playerWallet: null,
+          tournament: tournamentPDA,
+          player: player.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .signers([player])
+        .rpc();
+    };
+    await expectError(
+      () => joinMatch(outsider, 2),
+      'NotInTournamentBracket',
+      'A wallet outside the pairing should not join the bracket match'
+    );
+    await joinMatch(player1, 0);
+    await joinMatch(player2, 1);
+
+    await program.methods
+      .startMatch(matchId)
+      .accounts({
+        matchAccount: matchPDA,
+        registry: registryPDA,
+        escrowAccount: null,
+        authority: authority.publicKey,
+      } as never)
+      .rpc();
+    await program.methods
+      .endMatch(matchId, null, null)
+      .accounts({
+        matchAccount: matchPDA,
+        escrowAccount: null,
+        authority: authority.publicKey,
+      } as never)
+      .rpc();
+
+    // Test 4: Reporting the final match completes the tournament
+    const reportMatch = async () => {
+      // eslint-disable-next-line @typescript-eslint/no-explicit-any
+      await (program.methods as any)
+        .reportTournamentMatch(Array.from(tournamentId), matchId)
+        .accounts({
+          tournament: tournamentPDA,
+          matchAccount: matchPDA,
+          caller: outsider.publicKey,
+        } as never)
+        .signers([outsider])
+        .rpc();
+    };
+    await reportMatch();
+
+    // Higher score wins; a tie advances the better seed (player 1)
+    const scores = (await program.account.match.fetch(matchPDA) as unknown as { scores: number[] }).scores;
+    const [winner, winnerIndex] = scores[1] > scores[0] ? [player2, 1] : [player1, 0];
+
+    tournament = await fetchTournament(tournamentPDA);
+    this.assertEqual(tournament.phase, TOURNAMENT_PHASE.COMPLETED, 'Tournament should be completed');
+    this.assertEqual(tournament.placements?.[0], winnerIndex, 'Match winner should place first');
+
+    // The match cannot be reported twice
+    await expectError(reportMatch, 'InvalidTournamentPhase', 'Reporting a completed tournament should fail');
+
+    // Test 5: Prize distribution - platform fee to the treasury vault, the rest to first place
+    const total = tournament.prizePoolLamports?.toNumber() ?? tournament.prize_pool_lamports?.toNumber() ?? 0;
+    const feeBps = tournament.platformFeeBps ?? tournament.platform_fee_bps ?? 0;
+    const platformFee = Math.floor(total * feeBps / 10000);
+    const treasuryBefore = await connection.getBalance(treasuryVaultPDA);
+    const winnerBefore = await connection.getBalance(winner.publicKey);
+
+    // eslint-disable-next-line @typescript-eslint/no-explicit-any
+    await (program.methods as any)
+      .distributeTournamentPrizes(Array.from(tournamentId))
+      .accounts({
+        tournament: tournamentPDA,
+        treasuryVault: treasuryVaultPDA,
+        caller: authority.publicKey,
+      } as never)
+      .remainingAccounts([
+        { pubkey: winner.publicKey, isSigner: false, isWritable: true },
+      ])
+      .rpc();
+
+    this.assertEqual(total, buyIn * 2, 'Prize pool should hold both buy-ins');
+    this.assertEqual(
+      (await connection.getBalance(treasuryVaultPDA)) - treasuryBefore,
+      platformFee,
+      'Treasury vault should receive the platform fee'
+    );
+    this.assertEqual(
+      (await connection.getBalance(winner.publicKey)) - winnerBefore,
+      total - platformFee,
+      'Winner should receive the prize pool'
+    );
+    tournament = await fetchTournament(tournamentPDA);
+    this.assertEqual(tournament.phase, TOURNAMENT_PHASE.SETTLED, 'Tournament should be settled');
+
+    // Test 6: Cancelling during registration refunds every buy-in
+    const cancelled = await createTournament(4);
+    await register(cancelled.tournamentId, cancelled.tournamentPDA, outsider);
+    const outsiderBefore = await connection.getBalance(outsider.publicKey);
+    // eslint-disable-next-line @typescript-eslint/no-explicit-any
+    await (program.methods as any)
+      .cancelTournament(Array.from(cancelled.tournamentId))
+      .accounts({
+        tournament: cancelled.tournamentPDA,
+        authority: authority.publicKey,
+      } as never)
+      .remainingAccounts([
+        { pubkey: outsider.publicKey, isSigner: false, isWritable: true },
+      ])
+      .rpc();
+    this.assertEqual(
+      (await connection.getBalance(outsider.publicKey)) - outsiderBefore,
+      buyIn,
+      'Entrant should be refunded the buy-in'
+    );
+    tournament = await fetchTournament(cancelled.tournamentPDA);
+    this.assertEqual(tournament.phase, TOURNAMENT_PHASE.CANCELLED, 'Tournament should be cancelled');
+
+    console.log('✓ Single elimination tournament test passed');
+  }
+}
+
+const testInstance = new TournamentSingleEliminationTest();
+registerMochaTest(testInstance);
@@ -1,12 +1,12 @@
 // Match lifecycle helpers - applies to all games
 
 import * as anchor from "@coral-xyz/anchor";
-import { SystemProgram, PublicKey, Keypair } from "@solana/web3.js";
-import { program, authority } from "./setup";
-import { getMatchPDA, getRegistryPDA, getConfigAccountPDA, getUserDepositPDA, getEscrowPDA } from "./pda";
+import { SystemProgram, PublicKey, Keypair, AccountMeta, LAMPORTS_PER_SOL, SYSVAR_SLOT_HASHES_PUBKEY } from "@solana/web3.js";
+import { program, authority, airdrop } from "./setup";
+import { getMatchPDA, getRegistryPDA, getConfigAccountPDA, getUserDepositPDA, getEscrowPDA, getTreasuryVaultPDA, getSignerRegistryPDA, getValidatorReputationPDA, getDisputePDA } from "./pda";
 import { createTestContext, TestContext } from "./test-context";
 import { getTestUserId, getTestGame, getTestSeed } from "./test-data";
-import { ConfigAccountType } from "./types";
+import { ConfigAccountType, DisputeType, SignerRegistryType } from "./types";
 
 // Match type and payment method constants
 export const MATCH_TYPE = {
@@ -19,6 +19,10 @@ export const PAYMENT_METHOD = {
   PLATFORM: 1,
 } as const;
 
+// Game for paid matches and tournaments: they need a game scored on-chain, and CLAIM card values
+// stay off-chain (on-chain game table: 2 = Word search)
+export const PAID_GAME_ID = 2;
+
 /**
  * Create a started match (common for all games)
  * Returns [matchPDA, registryPDA]
@@ -54,10 +58,14 @@ export const createStartedMatch = async (
         matchId,
         claimGame.game_id,
         new anchor.BN(seed),
-        null, // entry_fee (None = free match)
-        null, // payment_method (None = default)
-        null, // match_type (None = default FREE)
-        null  // tournament_id (None = not a tournament)
+        {
+          entryFee: null, // None = free match
+          paymentMethod: null, // None = default
+          matchType: null, // None = default FREE
+          tournamentId: null, // None = not a tournament
+          prizeStructure: null, // None = winner takes all
+          prizeSplitBps: null, // None = no ranked split
+        }
       )
       .accounts({
         matchAccount: matchPDA,
@@ -144,10 +152,14 @@ export const createMatchWithContext = async (
       matchId,
       gameId,
       new anchor.BN(seed),
-      null, // entry_fee (None = free match)
-      null, // payment_method (None = default)
-      null, // match_type (None = default FREE)
-      null  // tournament_id (None = not a tournament)
+      {
+        entryFee: null, // None = free match
+        paymentMethod: null, // None = default
+        matchType: null, // None = default FREE
+        tournamentId: null, // None = not a tournament
+        prizeStructure: null, // None = winner takes all
+        prizeSplitBps: null, // None = no ranked split
+      }
     )
     .accounts({
       matchAccount: matchPDA,
@@ -189,7 +201,7 @@ export const checkGameRegistryStatus = async (ctx?: TestContext): Promise<{
  * Ensure config is initialized and unpaused
  * Helper function used by paid match operations
  */
-const ensureConfigUnpaused = async (): Promise<PublicKey> => {
+export const ensureConfigUnpaused = async (): Promise<PublicKey> => {
   const [configPDA] = await getConfigAccountPDA();
   
   // Initialize config if needed
@@ -258,6 +270,36 @@ const ensureConfigUnpaused = async (): Promise<PublicKey> => {
   return configPDA;
 };
 
+/**
+ * Ensure the TreasuryVault exists (fee-collecting instructions require it)
+ * Handles config initialization - the vault is created by the treasury multisig (authority)
+ * Returns treasuryVaultPDA
+ */
+export const ensureTreasuryVault = async (): Promise<PublicKey> => {
+  const configPDA = await ensureConfigUnpaused();
+  const [treasuryVaultPDA] = await getTreasuryVaultPDA();
+
+  try {
+    // eslint-disable-next-line @typescript-eslint/no-explicit-any
+    await (program.methods as any)
+      .initializeTreasury()
+      .accounts({
+        treasuryVault: treasuryVaultPDA,
+        configAccount: configPDA,
+        authority: authority.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as never)
+      .rpc();
+  } catch (err: unknown) {
+    const error = err as { message?: string };
+    if (!error.message?.includes("already in use") && !error.message?.includes("0x0")) {
+      throw err;
+    }
+  }
+
+  return treasuryVaultPDA;
+};
+
 /**
  * Deposit SOL to user's deposit account
  * Handles config initialization and unpause check
@@ -310,10 +352,14 @@ export const createPaidMatch = async (
       matchId,
       gameId,
       new anchor.BN(seed),
-      entryFee,
-      paymentMethod,
-      MATCH_TYPE.PAID,
-      tournamentIdArray ? Array.from(tournamentIdArray) as [number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number] : null
+      {
+        entryFee,
+        paymentMethod,
+        matchType: MATCH_TYPE.PAID,
+        tournamentId: tournamentIdArray ? Array.from(tournamentIdArray) as [number, number, number, number, number, number, number, number, number, number, number, number, number, number, number, number] : null,
+        prizeStructure: null,
+        prizeSplitBps: null,
+      }
     )
     .accounts({
       matchAccount: matchPDA,
@@ -365,3 +411,111 @@ export const joinPaidMatch = async (
     .rpc();
 };
 
+// SignerRegistry role constants
+export const SIGNER_ROLE = {
+  COORDINATOR: 0,
+  VALIDATOR: 1,
+  AUTHORITY: 2,
+} as const;
+
+/**
+ * Register a signer role in the SignerRegistry (creates the registry on first use)
+ * The test authority is the registry authority
+ */
+export const registerSigner = async (pubkey: PublicKey, role: number): Promise<void> => {
+  const [signerRegistryPDA] = await getSignerRegistryPDA();
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  await (program.methods as any)
+    .registerSigner(pubkey, role)
+    .accounts({
+      registry: signerRegistryPDA,
+      authority: authority.publicKey,
+      systemProgram: SystemProgram.programId,
+    } as never)
+    .rpc();
+};
+
+/**
+ * Create a fresh VALIDATOR-role signer with the minimum stake (1 SOL) in its stake vault
+ * Disputes draw their committee from staked validators, so draw_dispute_committee needs at least one
+ * Returns the validator keypair
+ */
+export const createStakedValidator = async (): Promise<Keypair> => {
+  const [signerRegistryPDA] = await getSignerRegistryPDA();
+  const validator = Keypair.generate();
+  await airdrop(validator.publicKey, 2);
+  await registerSigner(validator.publicKey, SIGNER_ROLE.VALIDATOR);
+
+  const [reputationPDA] = await getValidatorReputationPDA(validator.publicKey);
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  await (program.methods as any)
+    .registerValidator(new anchor.BN(LAMPORTS_PER_SOL))
+    .accounts({
+      validatorReputation: reputationPDA,
+      registry: signerRegistryPDA,
+      validator: validator.publicKey,
+      systemProgram: SystemProgram.programId,
+    } as never)
+    .signers([validator])
+    .rpc();
+
+  return validator;
+};
+
+/**
+ * Remaining accounts for draw_dispute_committee: the ValidatorReputation PDA of every VALIDATOR-role
+ * signer in the SignerRegistry, in registry order
+ */
+export const getCommitteeCandidateAccounts = async (): Promise<AccountMeta[]> => {
+  const [signerRegistryPDA] = await getSignerRegistryPDA();
+  const registry = await program.account.signerRegistry.fetch(signerRegistryPDA) as unknown as SignerRegistryType;
+  const signerCount = registry.signerCount ?? registry.signer_count ?? 0;
+  const candidates: AccountMeta[] = [];
+  for (let i = 0; i < signerCount; i++) {
+    if (registry.roles?.[i] !== SIGNER_ROLE.VALIDATOR) continue;
+    const [reputationPDA] = await getValidatorReputationPDA(new PublicKey(registry.signers![i].toString()));
+    candidates.push({ pubkey: reputationPDA, isSigner: false, isWritable: false });
+  }
+  return candidates;
+};
+
+/**
+ * Remaining accounts for finalize_dispute: the ValidatorReputation PDA of every committee
+ * member, in committee order
+ */
+export const getCommitteeReputationAccounts = async (committee: PublicKey[]): Promise<AccountMeta[]> => {
+  const accounts: AccountMeta[] = [];
+  for (const member of committee) {
+    const [reputationPDA] = await getValidatorReputationPDA(member);
+    accounts.push({ pubkey: reputationPDA, isSigner: false, isWritable: true });
+  }
+  return accounts;
+};
+
+/**
+ * Draw a flagged dispute's committee with draw_dispute_committee
+ * The draw uses the hash of the first slot after the flag, so this waits until that slot's
+ * hash is in the SlotHashes sysvar
+ */
+export const drawDisputeCommittee = async (matchId: string, flagger: PublicKey): Promise<void> => {
+  const [matchPDA] = await getMatchPDA(matchId);
+  const [disputePDA] = await getDisputePDA(matchPDA, flagger);
+  const [signerRegistryPDA] = await getSignerRegistryPDA();
+  const dispute = await program.account.dispute.fetch(disputePDA) as unknown as DisputeType;
+  const flagSlot = (dispute.flagSlot ?? dispute.flag_slot)?.toNumber() ?? 0;
+  while (await program.provider.connection.getSlot() <= flagSlot + 1) {
+    await new Promise((resolve) => setTimeout(resolve, 400));
+  }
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  await (program.methods as any)
+    .drawDisputeCommittee(matchId, flagger)
+    .accounts({
+      dispute: disputePDA,
+      matchAccount: matchPDA,
+      registry: signerRegistryPDA,
+      recentSlothashes: SYSVAR_SLOT_HASHES_PUBKEY,
+      caller: authority.publicKey,
+    } as never)
+    .remainingAccounts(await getCommitteeCandidateAccounts())
+    .rpc();
+};
@@ -68,13 +68,19 @@ export const getMovePDA = async (
   return [pda, bump];
 };
 
-// Helper to get batch move PDA (uses index instead of nonce; matchId split into two seeds)
+// Helper to get batch move PDA for a batch slot (matchId split into two seeds)
+// Slots holding a move use the move's nonce (same PDA as submit_move, so finalize_scores can
+// re-derive it); unused slots keep the legacy u32 slot-index seed
 // Common for all games - game-specific batch move logic is handled in instruction handlers
 export const getBatchMovePDA = async (
   matchId: string,
   player: PublicKey,
-  index: number
+  index: number,
+  nonce?: anchor.BN
 ): Promise<[PublicKey, number]> => {
+  if (nonce !== undefined) {
+    return getMovePDA(matchId, player, nonce);
+  }
   const matchIdBytes = Buffer.from(matchId, 'utf-8');
   const first32 = matchIdBytes.slice(0, Math.min(32, matchIdBytes.length));
   const rest = matchIdBytes.slice(Math.min(32, matchIdBytes.length));
@@ -121,3 +127,193 @@ export const getUserDepositPDA = async (
   return [pda, bump];
 };
 
+// Helper to get TreasuryVault PDA (receives platform, withdrawal and cancellation fees)
+// Seeds: ["treasury_vault"]
+export const getTreasuryVaultPDA = async (): Promise<[PublicKey, number]> => {
+  const [pda, bump] = await anchor.web3.PublicKey.findProgramAddress(
+    [Buffer.from("treasury_vault")],
+    program.programId
+  );
+  return [pda, bump];
+};
+
+// Helper to get SupportedMint PDA (allow-listed SPL token mint)
+// Seeds: ["supported_mint", mint]
+export const getSupportedMintPDA = async (
+  mint: PublicKey
+): Promise<[PublicKey, number]> => {
+  const [pda, bump] = await anchor.web3.PublicKey.findProgramAddress(
+    [Buffer.from("supported_mint"), mint.toBuffer()],
+    program.programId
+  );
+  return [pda, bump];
+};
+
+// Helper to get the escrow vault (token account owned by the EscrowAccount PDA) of a token match
+// Seeds: ["escrow_vault", matchPDA]
+export const getEscrowVaultPDA = async (
+  matchPDA: PublicKey
+): Promise<[PublicKey, number]> => {
+  const [pda, bump] = await anchor.web3.PublicKey.findProgramAddress(
+    [Buffer.from("escrow_vault"), matchPDA.toBuffer()],
+    program.programId
+  );
+  return [pda, bump];
+};
+
+// Helper to get a token UserDepositAccount PDA (one ledger per mint)
+// Seeds: ["user_deposit", authority, mint]
+export const getTokenDepositPDA = async (
+  authority: PublicKey,
+  mint: PublicKey
+): Promise<[PublicKey, number]> => {
+  const [pda, bump] = await anchor.web3.PublicKey.findProgramAddress(
+    [Buffer.from("user_deposit"), authority.toBuffer(), mint.toBuffer()],
+    program.programId
+  );
+  return [pda, bump];
+};
+
+// Helper to get the deposit vault (token account owned by a token UserDepositAccount)
+// Seeds: ["deposit_vault", tokenDepositPDA]
+export const getDepositVaultPDA = async (
+  tokenDepositPDA: PublicKey
+): Promise<[PublicKey, number]> => {
+  const [pda, bump] = await anchor.web3.PublicKey.findProgramAddress(
+    [Buffer.from("deposit_vault"), tokenDepositPDA.toBuffer()],
+    program.programId
+  );
+  return [pda, bump];
+};
+
+// Helper to get a Tournament PDA (also holds the entrants' buy-ins)
+// Seeds: ["tournament", tournamentId]
+export const getTournamentPDA = async (
+  tournamentId: Uint8Array
+): Promise<[PublicKey, number]> => {
+  const [pda, bump] = await anchor.web3.PublicKey.findProgramAddress(
+    [Buffer.from("tournament"), Buffer.from(tournamentId)],
+    program.programId
+  );
+  return [pda, bump];
+};
+
+// Helper to get a PlayerRating PDA (skill rating of a wallet in one game type)
+// Seeds: ["player_rating", wallet, gameType]
+export const getPlayerRatingPDA = async (
+  wallet: PublicKey,
+  gameType: number
+): Promise<[PublicKey, number]> => {
+  const [pda, bump] = await anchor.web3.PublicKey.findProgramAddress(
+    [Buffer.from("player_rating"), wallet.toBuffer(), Buffer.from([gameType])],
+    program.programId
+  );
+  return [pda, bump];
+};
+
+// Helper to get a UserAccount PDA (season stats and standing of a platform user)
+// Seeds: ["user_account", userId]
+export const getUserAccountPDA = async (
+  userId: string
+): Promise<[PublicKey, number]> => {
+  const [pda, bump] = await anchor.web3.PublicKey.findProgramAddress(
+    [Buffer.from("user_account"), Buffer.from(userId)],
+    program.programId
+  );
+  return [pda, bump];
+};
+
+// Helper to get a GameLeaderboard PDA (one per game type per season)
+// Seeds: ["leaderboard", gameType, seasonId (u64 little-endian)]
+export const getLeaderboardPDA = async (
+  gameType: number,
+  seasonId: anchor.BN
+): Promise<[PublicKey, number]> => {
+  const [pda, bump] = await anchor.web3.PublicKey.findProgramAddress(
+    [Buffer.from("leaderboard"), Buffer.from([gameType]), seasonId.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+  return [pda, bump];
+};
+
+// Helper to get a SeasonArchive PDA (frozen leaderboard of an ended season)
+// Seeds: ["season_archive", gameType, seasonId (u64 little-endian)]
+export const getSeasonArchivePDA = async (
+  gameType: number,
+  seasonId: anchor.BN
+): Promise<[PublicKey, number]> => {
+  const [pda, bump] = await anchor.web3.PublicKey.findProgramAddress(
+    [Buffer.from("season_archive"), Buffer.from([gameType]), seasonId.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+  return [pda, bump];
+};
+
+// Helper to get a SeasonPrizeVault PDA (lamport prizes for a game type's seasons)
+// Seeds: ["season_prize_vault", gameType]
+export const getSeasonPrizeVaultPDA = async (
+  gameType: number
+): Promise<[PublicKey, number]> => {
+  const [pda, bump] = await anchor.web3.PublicKey.findProgramAddress(
+    [Buffer.from("season_prize_vault"), Buffer.from([gameType])],
+    program.programId
+  );
+  return [pda, bump];
+};
+
+// Helper to get a Dispute PDA (canonical dispute ID: one dispute per flagger per match)
+// Seeds: ["dispute", matchPDA, flagger]
+export const getDisputePDA = async (
+  matchPDA: PublicKey,
+  flagger: PublicKey
+): Promise<[PublicKey, number]> => {
+  const [pda, bump] = await anchor.web3.PublicKey.findProgramAddress(
+    [Buffer.from("dispute"), matchPDA.toBuffer(), flagger.toBuffer()],
+    program.programId
+  );
+  return [pda, bump];
+};
+
+// Helper to get the MatchDisputes PDA (index of a match's open disputes)
+// Seeds: ["match_disputes", matchPDA]
+export const getMatchDisputesPDA = async (
+  matchPDA: PublicKey
+): Promise<[PublicKey, number]> => {
+  const [pda, bump] = await anchor.web3.PublicKey.findProgramAddress(
+    [Buffer.from("match_disputes"), matchPDA.toBuffer()],
+    program.programId
+  );
+  return [pda, bump];
+};
+
+// Helper to get a ValidatorReputation PDA (a validator's stake vault and reputation)
+// Seeds: ["validator", validator]
+export const getValidatorReputationPDA = async (
+  validator: PublicKey
+): Promise<[PublicKey, number]> => {
+  const [pda, bump] = await anchor.web3.PublicKey.findProgramAddress(
+    [Buffer.from("validator"), validator.toBuffer()],
+    program.programId
+  );
+  return [pda, bump];
+};
+
+// Helper to get SignerRegistry PDA (coordinator, validator and authority roles)
+export const getSignerRegistryPDA = async (): Promise<[PublicKey, number]> => {
+  return await anchor.web3.PublicKey.findProgramAddress(
+    [Buffer.from("signer_registry")],
+    program.programId
+  );
+};
+
+// Helper to get the ScoreReplay PDA (finalize_scores cursor)
+// Seeds: ["score_replay", matchPDA]
+export const getScoreReplayPDA = async (
+  matchPDA: PublicKey
+): Promise<[PublicKey, number]> => {
+  const [pda, bump] = await anchor.web3.PublicKey.findProgramAddress(
+    [Buffer.from("score_replay"), matchPDA.toBuffer()],
+    program.programId
+  );
+  return [pda, bump];
+};
@@ -0,0 +1,62 @@
+/**
+ * Test: Fails to set a turn timer on a game without turns
+ * Category: REGISTRY
+ */
+
+import { BaseTest } from '@/core';
+import { TestCategory, ClusterRequirement } from '@/core';
+import { registerMochaTest } from '@/core';
+
+const WORD_SEARCH_GAME_ID = 2; // Simultaneous play - on-chain rules have no turns
+const RUMMY_GAME_ID = 4; // Registered without on-chain rules
+const TIMEOUT_POLICY_SKIP = 1;
+
+class FailSetTurnTimeoutTurnlessGameTest extends BaseTest {
+  constructor() {
+    super({
+      id: 'fail-set-turn-timeout-turnless-game',
+      name: 'Fails to set a turn timer on a game without turns',
+      description: 'Verifies that set_turn_timeout rejects a timer for simultaneous-play games and games without on-chain rules',
+      tags: {
+        category: TestCategory.REGISTRY,
+        cluster: ClusterRequirement.LOCALNET_ONLY,
+        requiresSetup: true,
+        requiresRegistry: true,
+      },
+    });
+  }
+
+  async run(): Promise<void> {
+    const { program, authority, getRegistryPDA } = await import('@/helpers');
+    const [registryPDA] = await getRegistryPDA();
+
+    for (const gameId of [WORD_SEARCH_GAME_ID, RUMMY_GAME_ID]) {
+      try {
+        await program.methods
+          .setTurnTimeout(gameId, 30, TIMEOUT_POLICY_SKIP)
+          .accounts({
+            registry: registryPDA,
+            authority: authority.publicKey,
+          } as never)
+          .rpc();
+
+        this.assert(false, `Should have thrown TurnTimerDisabled for game ${gameId}`);
+      } catch (err: unknown) {
+        this.assertEqual(this.getErrorCode(err), "TurnTimerDisabled",
+          `Expected TurnTimerDisabled for game ${gameId}`);
+      }
+    }
+
+    // Clearing the timer is always allowed
+    await program.methods
+      .setTurnTimeout(WORD_SEARCH_GAME_ID, 0, TIMEOUT_POLICY_SKIP)
+      .accounts({
+        registry: registryPDA,
+        authority: authority.publicKey,
+      } as never)
+      .rpc();
+  }
+}
+
+const testInstance = new FailSetTurnTimeoutTurnlessGameTest();
+registerMochaTest(testInstance);
@@ -208,12 +208,12 @@ class BatchMovesSequenceTest extends BaseTest {
         }
       }
       
-      // Get PDAs using correct indices (0-4) as Rust expects
-      // Rust uses hardcoded indices 0-4 in the Anchor account constraints for each batch
-      // Each batch reuses the same 5 PDAs (indices 0-4), but the move_index stored in the account is sequential
+      // Get PDAs from each move's nonce (same scheme as submit_move)
       // Use current player's public key for PDA derivation
       const movePDAs = await Promise.all(
-        Array.from({ length: 5 }, (_, i) => getBatchMovePDA(matchId, currentPlayer.publicKey, i))
+        Array.from({ length: 5 }, (_, i) =>
+          getBatchMovePDA(matchId, currentPlayer.publicKey, i, moves[i]?.nonce)
+        )
       );
 
       // Extract PDAs and ensure we have exactly 5 (required tuple type)
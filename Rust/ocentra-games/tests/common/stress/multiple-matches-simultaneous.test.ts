@@ -55,10 +55,14 @@ class MultipleMatchesSimultaneousTest extends BaseTest {
               matchId,
               claimGame.game_id,
               new anchor.BN(seed + i),
-              null, // entry_fee (None = free match)
-              null, // payment_method (None = default)
-              null, // match_type (None = default FREE)
-              null  // tournament_id (None = not a tournament)
+              {
+                entryFee: null, // None = free match
+                paymentMethod: null, // None = default
+                matchType: null, // None = default FREE
+                tournamentId: null, // None = not a tournament
+                prizeStructure: null, // None = winner takes all
+                prizeSplitBps: null, // None = no ranked split
+              }
             )
             .accounts({
               matchAccount: matchPDAs[i][0],
@@ -0,0 +1,156 @@
+// SPL token helpers - minimal Token Program instructions built with web3.js
+// (mints, token accounts and mint_to for SPL token match and deposit tests)
+
+import * as anchor from "@coral-xyz/anchor";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import { program, provider, authority } from "./setup";
+import { getConfigAccountPDA, getSupportedMintPDA } from "./pda";
+import { ensureConfigUnpaused } from "./match-helpers";
+
+export const TOKEN_PROGRAM_ID = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+const MINT_SIZE = 82;
+const TOKEN_ACCOUNT_SIZE = 165;
+
+/**
+ * Create a new SPL mint (no freeze authority)
+ * Returns [mint, mintAuthority]
+ */
+export const createTestMint = async (decimals = 6): Promise<[PublicKey, Keypair]> => {
+  const mint = Keypair.generate();
+  const mintAuthority = Keypair.generate();
+  const lamports = await provider.connection.getMinimumBalanceForRentExemption(MINT_SIZE);
+
+  // InitializeMint2: [20, decimals, mint_authority, freeze_authority option (none)]
+  const data = Buffer.concat([
+    Buffer.from([20, decimals]),
+    mintAuthority.publicKey.toBuffer(),
+    Buffer.from([0]),
+  ]);
+  const tx = new Transaction().add(
+    SystemProgram.createAccount({
+      fromPubkey: authority.publicKey,
+      newAccountPubkey: mint.publicKey,
+      space: MINT_SIZE,
+      lamports,
+      programId: TOKEN_PROGRAM_ID,
+    }),
+    new TransactionInstruction({
+      programId: TOKEN_PROGRAM_ID,
+      keys: [{ pubkey: mint.publicKey, isSigner: false, isWritable: true }],
+      data,
+    })
+  );
+  await provider.sendAndConfirm(tx, [mint]);
+
+  return [mint.publicKey, mintAuthority];
+};
+
+/**
+ * Create a token account for `mint` owned by `owner`
+ * Returns the token account address
+ */
+export const createTokenAccount = async (
+  mint: PublicKey,
+  owner: PublicKey
+): Promise<PublicKey> => {
+  const account = Keypair.generate();
+  const lamports = await provider.connection.getMinimumBalanceForRentExemption(TOKEN_ACCOUNT_SIZE);
+
+  // InitializeAccount3: [18, owner]
+  const tx = new Transaction().add(
+    SystemProgram.createAccount({
+      fromPubkey: authority.publicKey,
+      newAccountPubkey: account.publicKey,
+      space: TOKEN_ACCOUNT_SIZE,
+      lamports,
+      programId: TOKEN_PROGRAM_ID,
+    }),
+    new TransactionInstruction({
+      programId: TOKEN_PROGRAM_ID,
+      keys: [
+        { pubkey: account.publicKey, isSigner: false, isWritable: true },
+        { pubkey: mint, isSigner: false, isWritable: false },
+      ],
+      data: Buffer.concat([Buffer.from([18]), owner.toBuffer()]),
+    })
+  );
+  await provider.sendAndConfirm(tx, [account]);
+
+  return account.publicKey;
+};
+
+/**
+ * Mint `amount` base units of `mint` into `destination`
+ */
+export const mintTo = async (
+  mint: PublicKey,
+  destination: PublicKey,
+  mintAuthority: Keypair,
+  amount: number
+): Promise<void> => {
+  // MintTo: [7, amount (u64 LE)]
+  const data = Buffer.concat([Buffer.from([7]), new anchor.BN(amount).toArrayLike(Buffer, "le", 8)]);
+  const tx = new Transaction().add(
+    new TransactionInstruction({
+      programId: TOKEN_PROGRAM_ID,
+      keys: [
+        { pubkey: mint, isSigner: false, isWritable: true },
+        { pubkey: destination, isSigner: false, isWritable: true },
+        { pubkey: mintAuthority.publicKey, isSigner: true, isWritable: false },
+      ],
+      data,
+    })
+  );
+  await provider.sendAndConfirm(tx, [mintAuthority]);
+};
+
+/**
+ * Token balance of a token account in base units
+ */
+export const getTokenBalance = async (tokenAccount: PublicKey): Promise<number> => {
+  const balance = await provider.connection.getTokenAccountBalance(tokenAccount);
+  return Number(balance.value.amount);
+};
+
+/**
+ * Allow-list a mint for paid matches and deposits (treasury multisig = authority)
+ * Handles config initialization and unpause check
+ * Returns supportedMintPDA
+ */
+export const ensureSupportedMint = async (
+  mint: PublicKey,
+  minEntryFee: number,
+  maxEntryFee: number,
+  withdrawalFee = 0,
+  enabled = true
+): Promise<PublicKey> => {
+  await ensureConfigUnpaused();
+  const [configPDA] = await getConfigAccountPDA();
+  const [supportedMintPDA] = await getSupportedMintPDA(mint);
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  await (program.methods as any)
+    .setSupportedMint(
+      new anchor.BN(minEntryFee),
+      new anchor.BN(maxEntryFee),
+      new anchor.BN(withdrawalFee),
+      enabled
+    )
+    .accounts({
+      supportedMint: supportedMintPDA,
+      mint,
+      configAccount: configPDA,
+      authority: authority.publicKey,
+      systemProgram: SystemProgram.programId,
+    } as never)
+    .rpc();
+
+  return supportedMintPDA;
+};
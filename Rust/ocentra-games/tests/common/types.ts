@@ -19,12 +19,20 @@ export type ConfigAccountType = {
   treasury_multisig?: { toString(): string; equals(other: { toString(): string }): boolean } | { toString(): string };
   supportedPaymentMethods?: number;
   supported_payment_methods?: number;
+  forfeitRouting?: number;
+  forfeit_routing?: number;
   cancellationFeeBps?: number;
   cancellation_fee_bps?: number;
   kycTierWallet?: number;
   kyc_tier_wallet?: number;
   kycTierPlatform?: number;
   kyc_tier_platform?: number;
+  currentSeasonId?: { toNumber(): number };
+  current_season_id?: { toNumber(): number };
+  seasonDurationSeconds?: { toNumber(): number };
+  season_duration_seconds?: { toNumber(): number };
+  disputeDepositGp?: number;
+  dispute_deposit_gp?: number;
   authority?: { toString(): string; equals(other: { toString(): string }): boolean } | { toString(): string };
 };
 
@@ -43,6 +51,7 @@ export type UserDepositAccountType = {
   flags?: number;
   lockedUntil?: { toNumber(): number };
   locked_until?: { toNumber(): number };
+  mint?: { toString(): string };
 };
 
 // Type for EscrowAccount that handles both camelCase and snake_case property names
@@ -56,6 +65,41 @@ export type EscrowAccountType = {
   player_stakes?: Array<{ toNumber(): number }>;
   statusFlags?: number;
   status_flags?: number;
+  entryMint?: { toString(): string };
+  entry_mint?: { toString(): string };
+  platformFeeBps?: number;
+  platform_fee_bps?: number;
+};
+
+// Type for TreasuryVault that handles both camelCase and snake_case property names
+// fees_by_source is indexed by fee source: 0 = platform fee, 1 = withdrawal fee,
+// 2 = cancellation fee, 3 = forfeited stake, 4 = slashed validator stake
+export type TreasuryVaultType = {
+  bump?: number;
+  totalCollectedLamports?: { toNumber(): number };
+  total_collected_lamports?: { toNumber(): number };
+  totalWithdrawnLamports?: { toNumber(): number };
+  total_withdrawn_lamports?: { toNumber(): number };
+  feesBySource?: Array<{ toNumber(): number }>;
+  fees_by_source?: Array<{ toNumber(): number }>;
+  gameSlotCount?: number;
+  game_slot_count?: number;
+  gameTypes?: number[];
+  game_types?: number[];
+  feesByGame?: Array<{ toNumber(): number }>;
+  fees_by_game?: Array<{ toNumber(): number }>;
+};
+
+// Type for SupportedMint account (per-mint token fee ledger) - handles camelCase and snake_case
+export type SupportedMintType = {
+  mint?: { toString(): string };
+  withdrawalFee?: { toNumber(): number };
+  withdrawal_fee?: { toNumber(): number };
+  enabled?: boolean;
+  totalCollected?: { toNumber(): number };
+  total_collected?: { toNumber(): number };
+  feesBySource?: Array<{ toNumber(): number }>;
+  fees_by_source?: Array<{ toNumber(): number }>;
 };
 
 // Type for Match account that handles both camelCase and snake_case property names
@@ -77,3 +121,135 @@ export type MatchAccountType = {
   tournament_id?: number[];
 };
 
+// Type for Tournament account that handles both camelCase and snake_case property names
+export type TournamentAccountType = {
+  phase?: number;
+  entrantCount?: number;
+  entrant_count?: number;
+  currentRound?: number;
+  current_round?: number;
+  totalRounds?: number;
+  total_rounds?: number;
+  pairingCount?: number;
+  pairing_count?: number;
+  pairingMatches?: Array<{ toString(): string }>;
+  pairing_matches?: Array<{ toString(): string }>;
+  pairingResults?: number[];
+  pairing_results?: number[];
+  points?: number[];
+  placements?: number[];
+  prizePoolLamports?: { toNumber(): number };
+  prize_pool_lamports?: { toNumber(): number };
+  platformFeeBps?: number;
+  platform_fee_bps?: number;
+  noShowSecs?: number;
+  no_show_secs?: number;
+};
+
+// Type for PlayerRating account that handles both camelCase and snake_case property names
+export type PlayerRatingType = {
+  wallet?: { toString(): string };
+  rating?: number;
+  peakRating?: number;
+  peak_rating?: number;
+  gamesRated?: number;
+  games_rated?: number;
+  gameType?: number;
+  game_type?: number;
+};
+
+// Type for UserAccount that handles both camelCase and snake_case property names
+export type UserAccountType = {
+  gamesWon?: number;
+  games_won?: number;
+  winStreak?: number;
+  win_streak?: number;
+  currentSeasonId?: { toString(): string };
+  current_season_id?: { toString(): string };
+  seasonScore?: { toNumber(): number };
+  season_score?: { toNumber(): number };
+  seasonWins?: number;
+  season_wins?: number;
+  seasonGames?: number;
+  season_games?: number;
+  leaderboardRank?: number;
+  leaderboard_rank?: number;
+  activeMultiplier?: number;
+  active_multiplier?: number;
+  wallet?: { toString(): string };
+};
+
+// Type for GameLeaderboard that handles both camelCase and snake_case property names
+export type GameLeaderboardType = {
+  gameType?: number;
+  game_type?: number;
+  seasonId?: { toString(): string };
+  season_id?: { toString(): string };
+  entryCount?: number;
+  entry_count?: number;
+  entries?: Array<{ userId?: number[]; user_id?: number[]; score: { toNumber(): number } }>;
+};
+
+// Type for SeasonPrizeVault that handles both camelCase and snake_case property names
+export type SeasonPrizeVaultType = {
+  totalFundedLamports?: { toNumber(): number };
+  total_funded_lamports?: { toNumber(): number };
+  reservedLamports?: { toNumber(): number };
+  reserved_lamports?: { toNumber(): number };
+  totalPaidLamports?: { toNumber(): number };
+  total_paid_lamports?: { toNumber(): number };
+  gameType?: number;
+  game_type?: number;
+};
+
+// Type for Dispute account that handles both camelCase and snake_case property names
+export type DisputeType = {
+  flagger?: { toString(): string };
+  reason?: number;
+  resolution?: number;
+  voteCount?: number;
+  vote_count?: number;
+  votingDeadline?: { toNumber(): number };
+  voting_deadline?: { toNumber(): number };
+  gpRefunded?: number;
+  gp_refunded?: number;
+  committee?: Array<{ toString(): string }>;
+  committeeSize?: number;
+  committee_size?: number;
+  flagSlot?: { toNumber(): number };
+  flag_slot?: { toNumber(): number };
+};
+
+// Type for MatchDisputes (per-match open dispute index) that handles both camelCase and snake_case
+export type MatchDisputesType = {
+  matchAccount?: { toString(): string };
+  match_account?: { toString(): string };
+  openDisputes?: Array<{ toString(): string }>;
+  open_disputes?: Array<{ toString(): string }>;
+  openCount?: number;
+  open_count?: number;
+  totalDisputes?: number;
+  total_disputes?: number;
+};
+
+// Type for ValidatorReputation (validator stake vault) that handles both camelCase and snake_case
+export type ValidatorReputationType = {
+  validator?: { toString(): string };
+  stake?: { toNumber(): number };
+  reputation?: number;
+  unbondingLamports?: { toNumber(): number };
+  unbonding_lamports?: { toNumber(): number };
+  unbondingUntil?: { toNumber(): number };
+  unbonding_until?: { toNumber(): number };
+  totalSlashed?: { toNumber(): number };
+  total_slashed?: { toNumber(): number };
+};
+
+// Type for SignerRegistry that handles both camelCase and snake_case property names
+// roles: 0 = coordinator, 1 = validator, 2 = authority
+export type SignerRegistryType = {
+  signers?: Array<{ toString(): string }>;
+  roles?: number[];
+  signerCount?: number;
+  signer_count?: number;
+};
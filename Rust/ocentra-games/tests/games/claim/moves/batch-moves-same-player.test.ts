@@ -84,11 +84,10 @@ class BatchMovesSamePlayerTest extends BaseTest {
       },
     ];
 
-    // Get move PDAs using correct indices (0-4) as Rust expects
-    // Rust uses hardcoded indices 0-4 in the Anchor account constraints for move_account_0 through move_account_4
-    // For 2 moves, we use indices 0 and 1; remaining 3 are dummy PDAs (not used but required by type)
-    const [movePDA0] = await getBatchMovePDA(testMatchId, player1.publicKey, 0);
-    const [movePDA1] = await getBatchMovePDA(testMatchId, player1.publicKey, 1);
+    // Get move PDAs: slots holding a move are derived from the move's nonce (same as submit_move)
+    // For 2 moves, slots 0 and 1 are used; remaining 3 are dummy PDAs (not used but required by type)
+    const [movePDA0] = await getBatchMovePDA(testMatchId, player1.publicKey, 0, moves[0].nonce);
+    const [movePDA1] = await getBatchMovePDA(testMatchId, player1.publicKey, 1, moves[1].nonce);
     const [movePDA2] = await getBatchMovePDA(testMatchId, player1.publicKey, 2); // Dummy
     const [movePDA3] = await getBatchMovePDA(testMatchId, player1.publicKey, 3); // Dummy
     const [movePDA4] = await getBatchMovePDA(testMatchId, player1.publicKey, 4); // Dummy
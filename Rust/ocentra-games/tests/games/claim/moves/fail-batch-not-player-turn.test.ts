@@ -53,7 +53,9 @@ class FailBatchNotPlayerTurnTest extends BaseTest {
     // so that Rust validation can run and throw NotPlayerTurn
     // Use different indices to ensure unique PDAs (Anchor validates all 5 accounts even if we only have 1 move)
     const movePDAs = await Promise.all(
-      Array.from({ length: 5 }, (_, i) => getBatchMovePDA(testMatchId, player2.publicKey, i))
+      Array.from({ length: 5 }, (_, i) =>
+        getBatchMovePDA(testMatchId, player2.publicKey, i, moves[i]?.nonce)
+      )
     );
 
     const moveAccountPDAs: [PublicKey, PublicKey, PublicKey, PublicKey, PublicKey] = [
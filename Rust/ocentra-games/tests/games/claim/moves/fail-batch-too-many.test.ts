@@ -48,11 +48,12 @@ class FailBatchTooManyTest extends BaseTest {
       nonce: new anchor.BN(baseNonce + i),
     }));
 
-    // Get PDAs for first 5 moves using correct indices (0-4) as Rust expects
-    // Rust uses hardcoded indices 0-4 in the Anchor account constraints
+    // Get PDAs for first 5 moves from their nonces (same scheme as submit_move)
     // Type requires 5 PDAs, but Rust will validate moves.length > 5
     const movePDAs = await Promise.all(
-      Array.from({ length: 5 }, (_, i) => getBatchMovePDA(testMatchId, player1.publicKey, i))
+      Array.from({ length: 5 }, (_, i) =>
+        getBatchMovePDA(testMatchId, player1.publicKey, i, moves[i]?.nonce)
+      )
     );
 
     const moveAccountPDAs: [PublicKey, PublicKey, PublicKey, PublicKey, PublicKey] = [
@@ -0,0 +1,175 @@
+/**
+ * Test: Dealer commits the poker deck
+ * Category: MOVES (Poker-specific)
+ *
+ * Poker cards come from the dealer's deck, not from the seats: the match authority commits the
+ * head of a hash chain with commit_deck during the Dealing phase, and every deal reveals the
+ * next key down the chain. start_match refuses a poker hand without a committed deck, only the
+ * match authority may commit it, and games that don't deal from a dealer deck reject it.
+ */
+
+import { BaseTest } from '@/core';
+import { TestCategory, ClusterRequirement } from '@/core';
+import { registerMochaTest } from '@/core';
+import { SystemProgram, Keypair } from "@solana/web3.js";
+import { createHash, randomBytes } from "crypto";
+import * as anchor from "@coral-xyz/anchor";
+
+const POKER_GAME_ID = 1; // On-chain game table: 1 = Poker
+const CLAIM_GAME_ID = 0;
+
+class CommitDeckTest extends BaseTest {
+  constructor() {
+    super({
+      id: 'poker-commit-deck',
+      name: 'Dealer commits the poker deck',
+      description: 'Verifies that poker hands start only on a deck committed by the match authority',
+      tags: {
+        category: TestCategory.MOVES,
+        cluster: ClusterRequirement.ANY,
+        game: 'poker',
+        requiresSetup: true,
+        requiresRegistry: true,
+      },
+    });
+  }
+
+  async run(): Promise<void> {
+    const {
+      program,
+      authority,
+      player1,
+      player2,
+      generateUniqueMatchId,
+      getTestSeed,
+      getTestUserId,
+      getMatchPDA,
+      getRegistryPDA,
+    } = await import('@/helpers');
+    const [registryPDA] = await getRegistryPDA();
+
+    const expectError = async (action: () => Promise<unknown>, code: string, message: string) => {
+      try {
+        await action();
+        this.assert(false, message);
+      } catch (err: unknown) {
+        this.assertEqual(this.getErrorCode(err), code, `Expected ${code}`);
+      }
+    };
+
+    // Dealer hash chain: the last key deals the hole cards, its fourth hash is the commitment
+    const sha256 = (data: Buffer) => createHash('sha256').update(data).digest();
+    let deckCommitment = randomBytes(32);
+    for (let level = 0; level < 4; level++) {
+      deckCommitment = sha256(deckCommitment);
+    }
+
+    const createMatch = async (gameId: number) => {
+      const matchId = generateUniqueMatchId("deck");
+      const [matchPDA] = await getMatchPDA(matchId);
+      await program.methods
+        .createMatch(
+          matchId,
+          gameId,
+          new anchor.BN(getTestSeed()),
+          {
+            entryFee: null,
+            paymentMethod: null,
+            matchType: null,
+            tournamentId: null,
+            prizeStructure: null,
+            prizeSplitBps: null,
+          }
+        )
+        .accounts({
+          matchAccount: matchPDA,
+          registry: registryPDA,
+          escrowAccount: null,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .rpc();
+      return { matchId, matchPDA };
+    };
+
+    const commitDeck = async (matchId: string, matchPDA: anchor.web3.PublicKey, signer: Keypair) => {
+      await program.methods
+        .commitDeck(matchId, Array.from(deckCommitment))
+        .accounts({
+          matchAccount: matchPDA,
+          authority: signer.publicKey,
+        } as never)
+        .signers([signer])
+        .rpc();
+    };
+
+    const startMatch = async (matchId: string, matchPDA: anchor.web3.PublicKey) => {
+      await program.methods
+        .startMatch(matchId)
+        .accounts({
+          matchAccount: matchPDA,
+          registry: registryPDA,
+          escrowAccount: null,
+          authority: authority.publicKey,
+        } as never)
+        .rpc();
+    };
+
+    // Test 1: Games without a dealer deck reject commit_deck
+    const claimMatch = await createMatch(CLAIM_GAME_ID);
+    await expectError(
+      () => commitDeck(claimMatch.matchId, claimMatch.matchPDA, authority),
+      'InvalidAction',
+      'CLAIM does not deal from a dealer deck'
+    );
+
+    // Poker lobby: both seats join and commit their hole-card hashes
+    const { matchId, matchPDA } = await createMatch(POKER_GAME_ID);
+    for (const [index, player] of [player1, player2].entries()) {
+      await program.methods
+        .joinMatch(matchId, getTestUserId(index))
+        .accounts({
+          matchAccount: matchPDA,
+          registry: registryPDA,
+          escrowAccount: null,
+          userDepositAccount: null,
+          playerWallet: null,
+          player: player.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as never)
+        .signers([player])
+        .rpc();
+      await program.methods
+        .commitHand(matchId, getTestUserId(index), Array.from(sha256(Buffer.from(`hole-${index}`))), 2)
+        .accounts({
+          matchAccount: matchPDA,
+          player: player.publicKey,
+        } as never)
+        .signers([player])
+        .rpc();
+    }
+
+    // Test 2: The hand cannot start before the dealer commits the deck
+    await expectError(
+      () => startMatch(matchId, matchPDA),
+      'DeckNotCommitted',
+      'A poker hand should not start without a committed deck'
+    );
+
+    // Test 3: A seat cannot commit the deck it will be dealt from
+    await expectError(
+      () => commitDeck(matchId, matchPDA, player1),
+      'Unauthorized',
+      'Only the match authority deals'
+    );
+
+    // Test 4: The match authority commits the deck and the hand starts
+    await commitDeck(matchId, matchPDA, authority);
+    await startMatch(matchId, matchPDA);
+    const matchAccount = await program.account.match.fetch(matchPDA);
+    this.assertEqual(matchAccount.phase, 1, 'Poker hand should be in progress');
+  }
+}
+
+const testInstance = new CommitDeckTest();
+registerMochaTest(testInstance);
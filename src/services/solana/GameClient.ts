@@ -138,10 +138,14 @@ export class GameClient {
           matchId,
           gameType,
           new BN(seed),
-          null, // entry_fee (null for free match)
-          null, // payment_method (null for free match)
-          null, // match_type (null for free match)
-          null  // tournament_id (null for non-tournament)
+          {
+            entryFee: null, // null for free match
+            paymentMethod: null, // null for free match
+            matchType: null, // null for free match
+            tournamentId: null, // null for non-tournament
+            prizeStructure: null, // null for winner takes all
+            prizeSplitBps: null, // null for no ranked split
+          }
         )
         .accounts({
           matchAccount: matchPda,